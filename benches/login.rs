@@ -0,0 +1,114 @@
+//! Load benchmark for `POST /login`
+//!
+//! Drives a running server with concurrent logins and reports throughput,
+//! latency percentiles and how many requests were shed with 503:
+//!
+//! ```sh
+//! LOGIN_BENCH_EMAIL=admin@example.com LOGIN_BENCH_PASSWORD=... cargo bench --bench login
+//! ```
+//!
+//! `LOGIN_BENCH_URL` (default `http://127.0.0.1:3000`), `LOGIN_BENCH_CONCURRENCY`
+//! (default 64) and `LOGIN_BENCH_SECS` (default 10) tune the run. Point it at a
+//! release build; rate limiting on `/login` should be relaxed for the run.
+
+use std::time::{Duration, Instant};
+
+fn env_or(name: &str, default: &str) -> String {
+    std::env::var(name).unwrap_or_else(|_| default.to_string())
+}
+
+#[derive(Default)]
+struct Tally {
+    ok: usize,
+    overloaded: usize,
+    failed: usize,
+    latencies: Vec<Duration>,
+}
+
+impl Tally {
+    fn merge(&mut self, other: Tally) {
+        self.ok += other.ok;
+        self.overloaded += other.overloaded;
+        self.failed += other.failed;
+        self.latencies.extend(other.latencies);
+    }
+
+    fn percentile(&self, p: f64) -> Duration {
+        if self.latencies.is_empty() {
+            return Duration::ZERO;
+        }
+        let index = ((self.latencies.len() - 1) as f64 * p).round() as usize;
+        self.latencies[index]
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let base_url = env_or("LOGIN_BENCH_URL", "http://127.0.0.1:3000");
+    let email = env_or("LOGIN_BENCH_EMAIL", "admin@example.com");
+    let password = std::env::var("LOGIN_BENCH_PASSWORD")
+        .or_else(|_| std::env::var("SEED_ADMIN_PASSWORD"))
+        .expect("LOGIN_BENCH_PASSWORD or SEED_ADMIN_PASSWORD must be set");
+    let concurrency: usize = env_or("LOGIN_BENCH_CONCURRENCY", "64").parse().unwrap();
+    let duration = Duration::from_secs(env_or("LOGIN_BENCH_SECS", "10").parse().unwrap());
+
+    let client = reqwest::Client::new();
+    let url = format!("{}/login", base_url.trim_end_matches('/'));
+    let body = serde_json::json!({ "email": email, "password": password }).to_string();
+
+    println!(
+        "POST {} with {} concurrent clients for {:?}",
+        url, concurrency, duration
+    );
+    let started = Instant::now();
+    let deadline = started + duration;
+
+    let workers: Vec<_> = (0..concurrency)
+        .map(|_| {
+            let client = client.clone();
+            let url = url.clone();
+            let body = body.clone();
+            tokio::spawn(async move {
+                let mut tally = Tally::default();
+                while Instant::now() < deadline {
+                    let sent = Instant::now();
+                    let status = client
+                        .post(&url)
+                        .header(reqwest::header::CONTENT_TYPE, "application/json")
+                        .body(body.clone())
+                        .send()
+                        .await
+                        .map(|response| response.status().as_u16());
+                    tally.latencies.push(sent.elapsed());
+                    match status {
+                        Ok(200) => tally.ok += 1,
+                        Ok(503) => tally.overloaded += 1,
+                        _ => tally.failed += 1,
+                    }
+                }
+                tally
+            })
+        })
+        .collect();
+
+    let mut tally = Tally::default();
+    for worker in workers {
+        tally.merge(worker.await.unwrap());
+    }
+    let elapsed = started.elapsed().as_secs_f64();
+    tally.latencies.sort();
+
+    println!(
+        "{} logins/s ({} ok, {} shed with 503, {} failed)",
+        (tally.ok as f64 / elapsed).round(),
+        tally.ok,
+        tally.overloaded,
+        tally.failed
+    );
+    println!(
+        "latency p50 {:?}, p95 {:?}, p99 {:?}",
+        tally.percentile(0.50),
+        tally.percentile(0.95),
+        tally.percentile(0.99)
+    );
+}
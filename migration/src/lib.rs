@@ -3,6 +3,17 @@ pub use sea_orm_migration::prelude::*;
 pub mod m20220101_000001_create_table;
 mod m20250203_000001_create_roles_table;
 mod m20250203_000002_create_user_roles_table;
+mod m20250301_000001_create_idempotency_keys_table;
+mod m20250310_000001_create_outbox_table;
+mod m20250315_000001_create_webhooks_tables;
+mod m20250320_000001_create_jobs_table;
+mod m20250325_000001_create_token_revocations_table;
+mod m20250401_000001_add_user_public_ids;
+mod m20250405_000001_replace_user_age_with_date_of_birth;
+mod m20250410_000001_create_password_history_table;
+mod m20250415_000001_create_organizations_tables;
+mod m20250420_000001_create_invitations_table;
+mod m20250425_000001_create_groups_tables;
 
 pub struct Migrator;
 
@@ -13,6 +24,17 @@ impl MigratorTrait for Migrator {
             Box::new(m20220101_000001_create_table::Migration),
             Box::new(m20250203_000001_create_roles_table::Migration),
             Box::new(m20250203_000002_create_user_roles_table::Migration),
+            Box::new(m20250301_000001_create_idempotency_keys_table::Migration),
+            Box::new(m20250310_000001_create_outbox_table::Migration),
+            Box::new(m20250315_000001_create_webhooks_tables::Migration),
+            Box::new(m20250320_000001_create_jobs_table::Migration),
+            Box::new(m20250325_000001_create_token_revocations_table::Migration),
+            Box::new(m20250401_000001_add_user_public_ids::Migration),
+            Box::new(m20250405_000001_replace_user_age_with_date_of_birth::Migration),
+            Box::new(m20250410_000001_create_password_history_table::Migration),
+            Box::new(m20250415_000001_create_organizations_tables::Migration),
+            Box::new(m20250420_000001_create_invitations_table::Migration),
+            Box::new(m20250425_000001_create_groups_tables::Migration),
         ]
     }
 }
@@ -0,0 +1,54 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Responses recorded per Idempotency-Key; response_status is NULL while in progress
+        manager
+            .create_table(
+                Table::create()
+                    .table(IdempotencyKeys::Table)
+                    .if_not_exists()
+                    .col(string(IdempotencyKeys::Key).primary_key())
+                    .col(string(IdempotencyKeys::Fingerprint))
+                    .col(integer_null(IdempotencyKeys::ResponseStatus))
+                    .col(json_null(IdempotencyKeys::ResponseHeaders))
+                    .col(binary_null(IdempotencyKeys::ResponseBody))
+                    .col(timestamp_with_time_zone(IdempotencyKeys::LockedAt))
+                    .col(timestamp_with_time_zone(IdempotencyKeys::ExpiresAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_idempotency_keys_expires_at")
+                    .table(IdempotencyKeys::Table)
+                    .col(IdempotencyKeys::ExpiresAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(IdempotencyKeys::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum IdempotencyKeys {
+    Table,
+    Key,
+    Fingerprint,
+    ResponseStatus,
+    ResponseHeaders,
+    ResponseBody,
+    LockedAt,
+    ExpiresAt,
+}
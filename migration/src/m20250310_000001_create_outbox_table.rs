@@ -0,0 +1,61 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Domain events written with the aggregate change; published_at is NULL until relayed
+        manager
+            .create_table(
+                Table::create()
+                    .table(Outbox::Table)
+                    .if_not_exists()
+                    .col(big_integer(Outbox::Id).auto_increment().primary_key())
+                    .col(integer(Outbox::AggregateId))
+                    .col(string(Outbox::EventType))
+                    .col(json_binary(Outbox::Payload))
+                    .col(timestamp_with_time_zone(Outbox::OccurredAt))
+                    .col(timestamp_with_time_zone_null(Outbox::PublishedAt))
+                    .col(integer(Outbox::Attempts).default(0))
+                    .col(text_null(Outbox::LastError))
+                    .col(
+                        timestamp_with_time_zone(Outbox::NextAttemptAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_outbox_published_at")
+                    .table(Outbox::Table)
+                    .col(Outbox::PublishedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Outbox::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Outbox {
+    Table,
+    Id,
+    AggregateId,
+    EventType,
+    Payload,
+    OccurredAt,
+    PublishedAt,
+    Attempts,
+    LastError,
+    NextAttemptAt,
+}
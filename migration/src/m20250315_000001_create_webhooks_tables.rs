@@ -0,0 +1,122 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Partner endpoints; events holds the event name filters
+        manager
+            .create_table(
+                Table::create()
+                    .table(Webhooks::Table)
+                    .if_not_exists()
+                    .col(pk_auto(Webhooks::Id))
+                    .col(string(Webhooks::Url))
+                    .col(json(Webhooks::Events))
+                    .col(string(Webhooks::Secret))
+                    .col(
+                        timestamp_with_time_zone(Webhooks::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // One row per event and webhook; status is pending, delivered or dead
+        manager
+            .create_table(
+                Table::create()
+                    .table(WebhookDeliveries::Table)
+                    .if_not_exists()
+                    .col(
+                        big_integer(WebhookDeliveries::Id)
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(integer(WebhookDeliveries::WebhookId))
+                    .col(uuid(WebhookDeliveries::EventId))
+                    .col(string(WebhookDeliveries::EventType))
+                    .col(json_binary(WebhookDeliveries::Payload))
+                    .col(string(WebhookDeliveries::Status))
+                    .col(integer(WebhookDeliveries::Attempts).default(0))
+                    .col(integer_null(WebhookDeliveries::LastStatusCode))
+                    .col(text_null(WebhookDeliveries::LastError))
+                    .col(timestamp_with_time_zone(WebhookDeliveries::NextAttemptAt))
+                    .col(
+                        timestamp_with_time_zone(WebhookDeliveries::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(timestamp_with_time_zone_null(
+                        WebhookDeliveries::DeliveredAt,
+                    ))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_webhook_deliveries_webhook_id")
+                            .from(WebhookDeliveries::Table, WebhookDeliveries::WebhookId)
+                            .to(Webhooks::Table, Webhooks::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_webhook_deliveries_due")
+                    .table(WebhookDeliveries::Table)
+                    .col(WebhookDeliveries::Status)
+                    .col(WebhookDeliveries::NextAttemptAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_webhook_deliveries_webhook_id")
+                    .table(WebhookDeliveries::Table)
+                    .col(WebhookDeliveries::WebhookId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(WebhookDeliveries::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Webhooks::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Webhooks {
+    Table,
+    Id,
+    Url,
+    Events,
+    Secret,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum WebhookDeliveries {
+    Table,
+    Id,
+    WebhookId,
+    EventId,
+    EventType,
+    Payload,
+    Status,
+    Attempts,
+    LastStatusCode,
+    LastError,
+    NextAttemptAt,
+    CreatedAt,
+    DeliveredAt,
+}
@@ -0,0 +1,68 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Background jobs; status is pending, running, completed or failed.
+        // locked_until is the lease of a running job, so jobs of a crashed
+        // worker are picked up again once it expires
+        manager
+            .create_table(
+                Table::create()
+                    .table(Jobs::Table)
+                    .if_not_exists()
+                    .col(big_integer(Jobs::Id).auto_increment().primary_key())
+                    .col(string(Jobs::Kind))
+                    .col(json_binary(Jobs::Payload))
+                    .col(string(Jobs::Status))
+                    .col(integer(Jobs::Attempts).default(0))
+                    .col(integer(Jobs::MaxAttempts))
+                    .col(timestamp_with_time_zone(Jobs::RunAt))
+                    .col(timestamp_with_time_zone_null(Jobs::LockedUntil))
+                    .col(text_null(Jobs::LastError))
+                    .col(
+                        timestamp_with_time_zone(Jobs::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(timestamp_with_time_zone_null(Jobs::FinishedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_jobs_due")
+                    .table(Jobs::Table)
+                    .col(Jobs::Status)
+                    .col(Jobs::RunAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Jobs::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Jobs {
+    Table,
+    Id,
+    Kind,
+    Payload,
+    Status,
+    Attempts,
+    MaxAttempts,
+    RunAt,
+    LockedUntil,
+    LastError,
+    CreatedAt,
+    FinishedAt,
+}
@@ -0,0 +1,43 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use super::m20220101_000001_create_table::Users;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Tokens issued to a user at or before revoked_at are rejected
+        manager
+            .create_table(
+                Table::create()
+                    .table(TokenRevocations::Table)
+                    .if_not_exists()
+                    .col(integer(TokenRevocations::UserId).primary_key())
+                    .col(timestamp_with_time_zone(TokenRevocations::RevokedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_token_revocations_user_id")
+                            .from(TokenRevocations::Table, TokenRevocations::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TokenRevocations::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum TokenRevocations {
+    Table,
+    UserId,
+    RevokedAt,
+}
@@ -0,0 +1,80 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        // Public identifiers for existing users are random (v4); new users get v7
+        db.execute_unprepared("ALTER TABLE users ADD COLUMN public_id uuid")
+            .await?;
+        db.execute_unprepared("UPDATE users SET public_id = gen_random_uuid()")
+            .await?;
+        db.execute_unprepared("ALTER TABLE users ALTER COLUMN public_id SET NOT NULL")
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_users_public_id")
+                    .table(Users::Table)
+                    .col(Users::PublicId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        // Published events identify users by their public ID too; users are
+        // never deleted, so every outbox row finds its user
+        db.execute_unprepared("ALTER TABLE outbox ADD COLUMN aggregate_public_id uuid")
+            .await?;
+        db.execute_unprepared(
+            "UPDATE outbox SET aggregate_public_id = users.public_id \
+             FROM users WHERE users.id = outbox.aggregate_id",
+        )
+        .await?;
+        db.execute_unprepared(
+            "ALTER TABLE outbox DROP COLUMN aggregate_id; \
+             ALTER TABLE outbox RENAME COLUMN aggregate_public_id TO aggregate_id; \
+             ALTER TABLE outbox ALTER COLUMN aggregate_id SET NOT NULL",
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared("ALTER TABLE outbox ADD COLUMN aggregate_internal_id integer")
+            .await?;
+        db.execute_unprepared(
+            "UPDATE outbox SET aggregate_internal_id = users.id \
+             FROM users WHERE users.public_id = outbox.aggregate_id",
+        )
+        .await?;
+        db.execute_unprepared(
+            "ALTER TABLE outbox DROP COLUMN aggregate_id; \
+             ALTER TABLE outbox RENAME COLUMN aggregate_internal_id TO aggregate_id; \
+             ALTER TABLE outbox ALTER COLUMN aggregate_id SET NOT NULL",
+        )
+        .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .drop_column(Users::PublicId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    PublicId,
+}
@@ -0,0 +1,60 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        // Only the age was known; assume each user turns it today, the latest
+        // date of birth consistent with it
+        db.execute_unprepared("ALTER TABLE users ADD COLUMN date_of_birth date")
+            .await?;
+        db.execute_unprepared(
+            "UPDATE users SET date_of_birth = (CURRENT_DATE - make_interval(years => age))::date",
+        )
+        .await?;
+        db.execute_unprepared("ALTER TABLE users ALTER COLUMN date_of_birth SET NOT NULL")
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .drop_column(Users::Age)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared("ALTER TABLE users ADD COLUMN age integer")
+            .await?;
+        db.execute_unprepared(
+            "UPDATE users SET age = date_part('year', age(CURRENT_DATE, date_of_birth))::integer",
+        )
+        .await?;
+        db.execute_unprepared("ALTER TABLE users ALTER COLUMN age SET NOT NULL")
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .drop_column(Users::DateOfBirth)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Age,
+    DateOfBirth,
+}
@@ -0,0 +1,159 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use super::m20220101_000001_create_table::Users;
+use super::m20250203_000001_create_roles_table::Roles;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Tenants; the slug addresses them in headers, subdomains and tokens
+        manager
+            .create_table(
+                Table::create()
+                    .table(Organizations::Table)
+                    .if_not_exists()
+                    .col(pk_auto(Organizations::Id))
+                    .col(string_uniq(Organizations::Slug))
+                    .col(string(Organizations::Name))
+                    .col(date(Organizations::CreatedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        // Which users belong to which organizations
+        manager
+            .create_table(
+                Table::create()
+                    .table(OrganizationMembers::Table)
+                    .if_not_exists()
+                    .col(integer(OrganizationMembers::OrganizationId))
+                    .col(integer(OrganizationMembers::UserId))
+                    .primary_key(
+                        Index::create()
+                            .col(OrganizationMembers::OrganizationId)
+                            .col(OrganizationMembers::UserId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_organization_members_organization_id")
+                            .from(
+                                OrganizationMembers::Table,
+                                OrganizationMembers::OrganizationId,
+                            )
+                            .to(Organizations::Table, Organizations::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_organization_members_user_id")
+                            .from(OrganizationMembers::Table, OrganizationMembers::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Finds a user's organizations without scanning every membership
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_organization_members_user_id")
+                    .table(OrganizationMembers::Table)
+                    .col(OrganizationMembers::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Roles a member holds within the organization
+        manager
+            .create_table(
+                Table::create()
+                    .table(OrganizationMemberRoles::Table)
+                    .if_not_exists()
+                    .col(integer(OrganizationMemberRoles::OrganizationId))
+                    .col(integer(OrganizationMemberRoles::UserId))
+                    .col(integer(OrganizationMemberRoles::RoleId))
+                    .primary_key(
+                        Index::create()
+                            .col(OrganizationMemberRoles::OrganizationId)
+                            .col(OrganizationMemberRoles::UserId)
+                            .col(OrganizationMemberRoles::RoleId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_organization_member_roles_member")
+                            .from(
+                                OrganizationMemberRoles::Table,
+                                (
+                                    OrganizationMemberRoles::OrganizationId,
+                                    OrganizationMemberRoles::UserId,
+                                ),
+                            )
+                            .to(
+                                OrganizationMembers::Table,
+                                (
+                                    OrganizationMembers::OrganizationId,
+                                    OrganizationMembers::UserId,
+                                ),
+                            )
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_organization_member_roles_role_id")
+                            .from(
+                                OrganizationMemberRoles::Table,
+                                OrganizationMemberRoles::RoleId,
+                            )
+                            .to(Roles::Table, Roles::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(OrganizationMemberRoles::Table)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(OrganizationMembers::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Organizations::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Organizations {
+    Table,
+    Id,
+    Slug,
+    Name,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum OrganizationMembers {
+    Table,
+    OrganizationId,
+    UserId,
+}
+
+#[derive(DeriveIden)]
+enum OrganizationMemberRoles {
+    Table,
+    OrganizationId,
+    UserId,
+    RoleId,
+}
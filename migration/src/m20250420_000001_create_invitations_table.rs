@@ -0,0 +1,73 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use super::m20250415_000001_create_organizations_tables::Organizations;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Pending sign-ups; status is pending, accepted or revoked, and roles
+        // are granted platform-wide or in organization_id when set
+        manager
+            .create_table(
+                Table::create()
+                    .table(Invitations::Table)
+                    .if_not_exists()
+                    .col(uuid(Invitations::Id).primary_key())
+                    .col(string(Invitations::Email))
+                    .col(json_binary(Invitations::Roles))
+                    .col(integer_null(Invitations::OrganizationId))
+                    .col(uuid_null(Invitations::InvitedBy))
+                    .col(string_null(Invitations::Locale))
+                    .col(string(Invitations::Status))
+                    .col(timestamp_with_time_zone(Invitations::ExpiresAt))
+                    .col(
+                        timestamp_with_time_zone(Invitations::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(timestamp_with_time_zone_null(Invitations::AcceptedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_invitations_organization_id")
+                            .from(Invitations::Table, Invitations::OrganizationId)
+                            .to(Organizations::Table, Organizations::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_invitations_email")
+                    .table(Invitations::Table)
+                    .col(Invitations::Email)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Invitations::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Invitations {
+    Table,
+    Id,
+    Email,
+    Roles,
+    OrganizationId,
+    InvitedBy,
+    Locale,
+    Status,
+    ExpiresAt,
+    CreatedAt,
+    AcceptedAt,
+}
@@ -0,0 +1,113 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use super::m20220101_000001_create_table::Users;
+use super::m20250415_000001_create_organizations_tables::Organizations;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Teams of users within a tenant (organization_id is null for the
+        // platform); grants lists what membership entitles members to
+        manager
+            .create_table(
+                Table::create()
+                    .table(Groups::Table)
+                    .if_not_exists()
+                    .col(uuid(Groups::Id).primary_key())
+                    .col(integer_null(Groups::OrganizationId))
+                    .col(string(Groups::Name))
+                    .col(json_binary(Groups::Grants))
+                    .col(date(Groups::CreatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_groups_organization_id")
+                            .from(Groups::Table, Groups::OrganizationId)
+                            .to(Organizations::Table, Organizations::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_groups_organization_id")
+                    .table(Groups::Table)
+                    .col(Groups::OrganizationId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Which users belong to which groups
+        manager
+            .create_table(
+                Table::create()
+                    .table(GroupMembers::Table)
+                    .if_not_exists()
+                    .col(uuid(GroupMembers::GroupId))
+                    .col(integer(GroupMembers::UserId))
+                    .primary_key(
+                        Index::create()
+                            .col(GroupMembers::GroupId)
+                            .col(GroupMembers::UserId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_group_members_group_id")
+                            .from(GroupMembers::Table, GroupMembers::GroupId)
+                            .to(Groups::Table, Groups::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_group_members_user_id")
+                            .from(GroupMembers::Table, GroupMembers::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Finds a user's groups (and teams) on every authenticated request
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_group_members_user_id")
+                    .table(GroupMembers::Table)
+                    .col(GroupMembers::UserId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(GroupMembers::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Groups::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Groups {
+    Table,
+    Id,
+    OrganizationId,
+    Name,
+    Grants,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum GroupMembers {
+    Table,
+    GroupId,
+    UserId,
+}
@@ -0,0 +1,101 @@
+use crate::app::errors::{AppResult, ApplicationError};
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+
+/// HashingPool - runs password hashing and verification off the async runtime
+///
+/// A hash costs tens of milliseconds of CPU, which would stall every other
+/// request on the same tokio worker. Work runs on the blocking threads
+/// instead, at most `max_concurrent` jobs at a time; up to `max_queued`
+/// more callers wait for a slot and any beyond that are turned away with
+/// `Overloaded` rather than piling up behind the CPU.
+pub struct HashingPool {
+    running: Arc<Semaphore>,
+    admitted: Arc<Semaphore>,
+}
+
+impl HashingPool {
+    pub fn new(max_concurrent: usize, max_queued: usize) -> Self {
+        Self {
+            running: Arc::new(Semaphore::new(max_concurrent)),
+            admitted: Arc::new(Semaphore::new(max_concurrent + max_queued)),
+        }
+    }
+
+    /// Run `work` on a blocking thread once a slot is free
+    pub async fn run<T, F>(&self, work: F) -> AppResult<T>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let admitted = self.admitted.clone().try_acquire_owned().map_err(|_| {
+            ApplicationError::Overloaded("Too many password checks in progress".to_string())
+        })?;
+        let running = self
+            .running
+            .clone()
+            .acquire_owned()
+            .await
+            .expect("hashing semaphore is never closed");
+
+        // The permits move into the job, so a caller that goes away does not
+        // free its slot before the hash it started has finished
+        let job = tokio::task::spawn_blocking(move || {
+            let _permits = (admitted, running);
+            work()
+        });
+        match job.await {
+            Ok(output) => Ok(output),
+            Err(e) => std::panic::resume_unwind(e.into_panic()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+
+    #[tokio::test]
+    async fn test_runs_work_on_a_blocking_thread() {
+        let pool = HashingPool::new(1, 0);
+        let worker = pool.run(|| std::thread::current().id()).await.unwrap();
+        assert_ne!(worker, std::thread::current().id());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_rejects_work_beyond_the_queue() {
+        let pool = Arc::new(HashingPool::new(1, 1));
+        let (release, blocked) = mpsc::channel::<()>();
+        let (started_tx, started) = mpsc::channel();
+
+        let busy = tokio::spawn({
+            let pool = pool.clone();
+            async move {
+                pool.run(move || {
+                    started_tx.send(()).unwrap();
+                    blocked.recv().unwrap();
+                })
+                .await
+            }
+        });
+        started.recv().unwrap();
+        let queued = tokio::spawn({
+            let pool = pool.clone();
+            async move { pool.run(|| ()).await }
+        });
+        while pool.admitted.available_permits() > 0 {
+            tokio::task::yield_now().await;
+        }
+
+        assert!(matches!(
+            pool.run(|| ()).await,
+            Err(ApplicationError::Overloaded(_))
+        ));
+
+        release.send(()).unwrap();
+        busy.await.unwrap().unwrap();
+        queued.await.unwrap().unwrap();
+        pool.run(|| ()).await.unwrap();
+    }
+}
@@ -1,23 +1,38 @@
-use super::{AuthToken, LoginCommand};
+use super::{AuthToken, HashingPool, LoginCommand};
 use crate::app::errors::{AppResult, ApplicationError};
+use crate::app::organization::find_organization;
 use crate::app::ports::TokenService;
-use crate::domain::user::{Email, UserRepository};
+use crate::domain::organization::OrganizationRepository;
+use crate::domain::user::{DomainError, Email, PasswordHasher, User, UserRepository};
 use std::sync::Arc;
 
 /// LoginUseCase - handles user login
+///
+/// A password stored with an outdated algorithm or parameters is re-hashed
+/// with `password_hasher` once it has been verified. Signing in to an
+/// organization requires membership and binds the token to it.
 pub struct LoginUseCase {
     user_repository: Arc<dyn UserRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
     token_service: Arc<dyn TokenService>,
+    password_hasher: PasswordHasher,
+    hashing_pool: Arc<HashingPool>,
 }
 
 impl LoginUseCase {
     pub fn new(
         user_repository: Arc<dyn UserRepository>,
+        organization_repository: Arc<dyn OrganizationRepository>,
         token_service: Arc<dyn TokenService>,
+        password_hasher: PasswordHasher,
+        hashing_pool: Arc<HashingPool>,
     ) -> Self {
         Self {
             user_repository,
+            organization_repository,
             token_service,
+            password_hasher,
+            hashing_pool,
         }
     }
 
@@ -31,20 +46,99 @@ impl LoginUseCase {
             .find_by_email(&email)
             .await?
             .ok_or(ApplicationError::InvalidCredentials)?;
+        let current_hash = user.password().hashed().to_string();
 
-        // Domain logic: authenticate user
-        user.authenticate(&command.password)
+        // Domain logic: authenticate user. The raw password is only available
+        // now, so an outdated hash is upgraded in the same job on the pool
+        let hasher = self.password_hasher.clone();
+        let (user, upgraded) = self
+            .hashing_pool
+            .run(move || {
+                let mut user = user;
+                user.authenticate(&command.password).map(|()| {
+                    let upgraded = user.upgrade_password_hash(&command.password, &hasher);
+                    (user, upgraded)
+                })
+            })
+            .await?
             .map_err(|_| ApplicationError::InvalidCredentials)?;
+        self.store_upgraded_hash(&user, &current_hash, upgraded)
+            .await;
+
+        // Only members may sign in to an organization
+        let organization = match command.organization.as_deref() {
+            Some(slug) => {
+                let organization =
+                    find_organization(self.organization_repository.as_ref(), slug).await?;
+                let (Some(organization_id), Some(user_id)) = (organization.id(), user.id()) else {
+                    return Err(ApplicationError::OrganizationNotFound);
+                };
+                self.organization_repository
+                    .find_membership(organization_id, user_id)
+                    .await?
+                    .ok_or_else(|| {
+                        ApplicationError::Forbidden(
+                            "You are not a member of this organization".to_string(),
+                        )
+                    })?;
+                Some(organization)
+            }
+            None => None,
+        };
 
         // Infrastructure concern: generate token (identity only, no roles)
         let token = self
             .token_service
             .generate_token(
-                user.id().ok_or(ApplicationError::UserNotFound)?.value(),
+                user.public_id(),
                 user.email().as_ref(),
+                organization.as_ref().map(|o| o.slug().as_ref()),
             )
             .await?;
 
         Ok(AuthToken::new(token))
     }
+
+    /// Best effort: a failed upgrade is retried on the next login
+    ///
+    /// Only the hash is written, and only if it is still the one verified, so
+    /// changes made to the user while hashing (roles, profile, a new password)
+    /// are never overwritten with the copy loaded here.
+    async fn store_upgraded_hash(
+        &self,
+        user: &User,
+        current_hash: &str,
+        upgraded: Result<bool, DomainError>,
+    ) {
+        let Some(user_id) = user.id() else {
+            return;
+        };
+        match upgraded {
+            Ok(false) => {}
+            Ok(true) => match self
+                .user_repository
+                .update_password_hash(user_id, current_hash, user.password().hashed())
+                .await
+            {
+                Ok(true) => tracing::info!(
+                    user_id = %user.public_id(),
+                    "Upgraded outdated password hash"
+                ),
+                Ok(false) => tracing::debug!(
+                    user_id = %user.public_id(),
+                    "Password hash changed meanwhile; upgrade skipped"
+                ),
+                Err(e) => tracing::warn!(
+                    user_id = %user.public_id(),
+                    error = %e,
+                    "Failed to store upgraded password hash"
+                ),
+            },
+            Err(e) => tracing::warn!(
+                user_id = %user.public_id(),
+                error = %e,
+                "Failed to upgrade password hash"
+            ),
+        }
+    }
 }
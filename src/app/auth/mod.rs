@@ -1,27 +1,69 @@
+pub mod hashing_pool;
 pub mod login_use_case;
+pub mod password_checker;
 pub mod register_use_case;
+pub mod revoke_tokens_use_case;
 
+pub use hashing_pool::HashingPool;
 pub use login_use_case::LoginUseCase;
+pub use password_checker::PasswordChecker;
 pub use register_use_case::RegisterUseCase;
+pub use revoke_tokens_use_case::RevokeTokensUseCase;
 
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
+use validator::Validate;
 
 /// Command for login
-#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[derive(Clone, Deserialize, ToSchema, Validate)]
 pub struct LoginCommand {
     pub email: String,
     pub password: String,
+    /// Slug of an organization to sign in to; the token is then bound to it
+    #[serde(default)]
+    pub organization: Option<String>,
 }
 
 /// Command for registration
-#[derive(Debug, Clone, Deserialize, ToSchema)]
+///
+/// Field rules are enforced by the domain when the user is registered.
+#[derive(Clone, Deserialize, ToSchema, Validate)]
 pub struct RegisterCommand {
     pub email: String,
     pub password: String,
     pub first_name: String,
     pub last_name: String,
-    pub age: u8,
+    /// Date of birth (YYYY-MM-DD)
+    #[schema(value_type = Option<String>, format = Date, example = "1990-04-21")]
+    pub date_of_birth: Option<NaiveDate>,
+    /// Deprecated: send `date_of_birth` instead
+    #[schema(deprecated)]
+    pub age: Option<u8>,
+}
+
+// Note: Debug is implemented by hand so the raw password never reaches the logs
+impl std::fmt::Debug for LoginCommand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LoginCommand")
+            .field("email", &self.email)
+            .field("password", &"[REDACTED]")
+            .field("organization", &self.organization)
+            .finish()
+    }
+}
+
+impl std::fmt::Debug for RegisterCommand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RegisterCommand")
+            .field("email", &self.email)
+            .field("password", &"[REDACTED]")
+            .field("first_name", &self.first_name)
+            .field("last_name", &self.last_name)
+            .field("date_of_birth", &self.date_of_birth)
+            .field("age", &self.age)
+            .finish()
+    }
 }
 
 /// Authentication result with token
@@ -39,3 +81,11 @@ impl AuthToken {
         }
     }
 }
+
+/// Result of revoking a user's tokens
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct TokensRevoked {
+    pub user_id: String,
+    /// Tokens issued at or before this time (RFC 3339) are rejected
+    pub revoked_at: String,
+}
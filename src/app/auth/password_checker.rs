@@ -0,0 +1,261 @@
+use super::HashingPool;
+use crate::app::errors::AppResult;
+use crate::app::ports::{BreachedPasswordList, PasswordHistoryStore};
+use crate::domain::user::{
+    DomainError, Password, PasswordHasher, PasswordPolicy, PasswordViolation, User,
+};
+use std::sync::Arc;
+
+/// PasswordChecker - applies the password policy to new passwords
+///
+/// Adds the checks that need lookups (breached list, the user's previous
+/// passwords) to the policy's own rules, so a rejected password reports
+/// every violation at once. Also carries the hasher accepted passwords are
+/// stored with.
+pub struct PasswordChecker {
+    policy: PasswordPolicy,
+    hasher: PasswordHasher,
+    hashing_pool: Arc<HashingPool>,
+    breached: Option<Arc<dyn BreachedPasswordList>>,
+    history: Arc<dyn PasswordHistoryStore>,
+}
+
+impl PasswordChecker {
+    pub fn new(
+        policy: PasswordPolicy,
+        hasher: PasswordHasher,
+        hashing_pool: Arc<HashingPool>,
+        breached: Option<Arc<dyn BreachedPasswordList>>,
+        history: Arc<dyn PasswordHistoryStore>,
+    ) -> Self {
+        Self {
+            policy,
+            hasher,
+            hashing_pool,
+            breached,
+            history,
+        }
+    }
+
+    /// The policy the domain enforces when hashing
+    pub fn policy(&self) -> &PasswordPolicy {
+        &self.policy
+    }
+
+    /// The hasher new passwords are stored with
+    pub fn hasher(&self) -> &PasswordHasher {
+        &self.hasher
+    }
+
+    /// Check the first password of a user who does not exist yet
+    pub async fn check_new(&self, password: &str, personal_info: &[&str]) -> AppResult<()> {
+        let violations = self.violations(password, personal_info).await;
+        Self::verdict(violations)
+    }
+
+    /// Check a replacement for `user`'s password, including reuse
+    pub async fn check_change(&self, password: &str, user: &User) -> AppResult<()> {
+        let personal_info = [
+            user.email().as_ref(),
+            user.profile().first_name(),
+            user.profile().last_name(),
+        ];
+        let mut violations = self.violations(password, &personal_info).await;
+
+        let window = self.policy.history_size;
+        if window > 0 && self.reused(password, user).await? {
+            violations.push(PasswordViolation::RecentlyUsed { window });
+        }
+
+        Self::verdict(violations)
+    }
+
+    /// Remember `user`'s current password for the reuse check
+    pub async fn remember(&self, user: &User) -> AppResult<()> {
+        let window = self.policy.history_size;
+        if let (true, Some(user_id)) = (window > 0, user.id()) {
+            self.history
+                .record(user_id.value(), user.password().hashed(), window)
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn violations(&self, password: &str, personal_info: &[&str]) -> Vec<PasswordViolation> {
+        let mut violations = self.policy.check(password, personal_info);
+
+        if let Some(breached) = &self.breached {
+            match breached.contains(password).await {
+                Ok(true) => violations.push(PasswordViolation::Breached),
+                Ok(false) => {}
+                // An unreadable list should not lock everyone out of signing up
+                Err(e) => tracing::warn!(error = %e, "Breached password list unavailable"),
+            }
+        }
+
+        violations
+    }
+
+    /// Whether `password` is the user's current one or among the recent ones
+    async fn reused(&self, password: &str, user: &User) -> AppResult<bool> {
+        let recent = match user.id() {
+            Some(user_id) => {
+                self.history
+                    .recent(user_id.value(), self.policy.history_size)
+                    .await?
+            }
+            None => Vec::new(),
+        };
+
+        // Every comparison is a full hash, so they run together on the pool
+        let current = user.password().clone();
+        let password = password.to_string();
+        self.hashing_pool
+            .run(move || {
+                current.verify(&password)
+                    || recent
+                        .into_iter()
+                        .any(|hash| Password::from_hash(hash).verify(&password))
+            })
+            .await
+    }
+
+    fn verdict(violations: Vec<PasswordViolation>) -> AppResult<()> {
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(DomainError::WeakPassword(violations).into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::errors::ApplicationError;
+    use crate::domain::shared::UserId;
+    use crate::domain::user::repository::RepositoryError;
+    use crate::domain::user::{DateOfBirth, Email};
+    use async_trait::async_trait;
+    use chrono::Utc;
+    use std::sync::Mutex;
+
+    struct Breached(&'static str);
+
+    #[async_trait]
+    impl BreachedPasswordList for Breached {
+        async fn contains(&self, password: &str) -> Result<bool, String> {
+            Ok(password == self.0)
+        }
+    }
+
+    #[derive(Default)]
+    struct History(Mutex<Vec<String>>);
+
+    #[async_trait]
+    impl PasswordHistoryStore for History {
+        async fn recent(
+            &self,
+            _user_id: i32,
+            limit: usize,
+        ) -> Result<Vec<String>, RepositoryError> {
+            Ok(self
+                .0
+                .lock()
+                .unwrap()
+                .iter()
+                .rev()
+                .take(limit)
+                .cloned()
+                .collect())
+        }
+
+        async fn record(
+            &self,
+            _user_id: i32,
+            password_hash: &str,
+            _keep: usize,
+        ) -> Result<(), RepositoryError> {
+            self.0.lock().unwrap().push(password_hash.to_string());
+            Ok(())
+        }
+    }
+
+    fn checker(history_size: usize) -> PasswordChecker {
+        let policy = PasswordPolicy {
+            min_length: 4,
+            history_size,
+            ..PasswordPolicy::default()
+        };
+        PasswordChecker::new(
+            policy,
+            PasswordHasher::bcrypt(4).unwrap(),
+            Arc::new(HashingPool::new(1, 0)),
+            Some(Arc::new(Breached("Password1"))),
+            Arc::new(History::default()),
+        )
+    }
+
+    fn violations(result: AppResult<()>) -> Vec<PasswordViolation> {
+        match result {
+            Err(ApplicationError::DomainError(DomainError::WeakPassword(v))) => v,
+            other => panic!("expected a weak password, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reports_policy_and_breach_together() {
+        let checker = checker(0);
+
+        assert_eq!(
+            violations(checker.check_new("Password1", &["ann@example.com"]).await),
+            [PasswordViolation::Breached]
+        );
+        assert_eq!(
+            violations(checker.check_new("ann", &["ann@example.com"]).await),
+            [
+                PasswordViolation::TooShort { min: 4 },
+                PasswordViolation::MissingUppercase,
+                PasswordViolation::MissingDigit,
+                PasswordViolation::ContainsPersonalInfo,
+            ]
+        );
+        checker
+            .check_new("Quokka42", &["ann@example.com"])
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_rejects_recent_passwords() {
+        let checker = checker(2);
+        let mut user = User::register(
+            Email::try_from("ann@example.com".to_string()).unwrap(),
+            "First111".to_string(),
+            "Ann".to_string(),
+            "Lee".to_string(),
+            DateOfBirth::from_age(30, Utc::now().date_naive()),
+            checker.policy(),
+            checker.hasher(),
+        )
+        .unwrap();
+        user.set_id(UserId::from(1));
+        checker.remember(&user).await.unwrap();
+
+        for next in ["Second22", "Third333"] {
+            checker.check_change(next, &user).await.unwrap();
+            user.change_password(next.to_string(), checker.policy(), checker.hasher())
+                .unwrap();
+            checker.remember(&user).await.unwrap();
+        }
+
+        // The current and the previous password are within the window of 2
+        for reused in ["Third333", "Second22"] {
+            assert_eq!(
+                violations(checker.check_change(reused, &user).await),
+                [PasswordViolation::RecentlyUsed { window: 2 }]
+            );
+        }
+        checker.check_change("First111", &user).await.unwrap();
+    }
+}
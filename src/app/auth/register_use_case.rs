@@ -1,17 +1,27 @@
-use super::RegisterCommand;
+use super::{HashingPool, PasswordChecker, RegisterCommand};
 use crate::app::errors::{AppResult, ApplicationError};
-use crate::app::user::UserResponse;
+use crate::app::user::{UserResponse, date_of_birth_from};
 use crate::domain::user::{Email, User, UserRepository};
 use std::sync::Arc;
 
 /// RegisterUseCase - handles user registration
 pub struct RegisterUseCase {
     user_repository: Arc<dyn UserRepository>,
+    password_checker: Arc<PasswordChecker>,
+    hashing_pool: Arc<HashingPool>,
 }
 
 impl RegisterUseCase {
-    pub fn new(user_repository: Arc<dyn UserRepository>) -> Self {
-        Self { user_repository }
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        password_checker: Arc<PasswordChecker>,
+        hashing_pool: Arc<HashingPool>,
+    ) -> Self {
+        Self {
+            user_repository,
+            password_checker,
+            hashing_pool,
+        }
     }
 
     pub async fn execute(&self, command: RegisterCommand) -> AppResult<UserResponse> {
@@ -23,17 +33,37 @@ impl RegisterUseCase {
             return Err(ApplicationError::EmailAlreadyExists(command.email));
         }
 
-        // Create user entity (domain logic)
-        let mut user = User::register(
-            email,
-            command.password,
-            command.first_name,
-            command.last_name,
-            command.age,
-        )?;
+        let date_of_birth = date_of_birth_from(command.date_of_birth, command.age)?;
+
+        // Policy, breached list; every violation is reported together
+        self.password_checker
+            .check_new(
+                &command.password,
+                &[email.as_ref(), &command.first_name, &command.last_name],
+            )
+            .await?;
+
+        // Create user entity (domain logic); hashing runs on the pool
+        let policy = self.password_checker.policy().clone();
+        let hasher = self.password_checker.hasher().clone();
+        let mut user = self
+            .hashing_pool
+            .run(move || {
+                User::register(
+                    email,
+                    command.password,
+                    command.first_name,
+                    command.last_name,
+                    date_of_birth,
+                    &policy,
+                    &hasher,
+                )
+            })
+            .await??;
 
         // Persist the user
         self.user_repository.save(&mut user).await?;
+        self.password_checker.remember(&user).await?;
 
         // Convert to response DTO
         Ok(UserResponse::from_domain(&user))
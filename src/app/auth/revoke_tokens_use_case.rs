@@ -0,0 +1,47 @@
+use super::TokensRevoked;
+use crate::app::caller_context::CallerContext;
+use crate::app::errors::{AppResult, ApplicationError};
+use crate::app::policy::{Action, Policy, UserResources};
+use crate::app::ports::TokenRevocationStore;
+use crate::domain::shared::PublicUserId;
+use std::sync::Arc;
+
+/// RevokeTokensUseCase - invalidates every token issued to a user (admin only)
+pub struct RevokeTokensUseCase {
+    revocation_store: Arc<dyn TokenRevocationStore>,
+    policy: Arc<Policy>,
+    user_resources: Arc<UserResources>,
+}
+
+impl RevokeTokensUseCase {
+    pub fn new(
+        revocation_store: Arc<dyn TokenRevocationStore>,
+        policy: Arc<Policy>,
+        user_resources: Arc<UserResources>,
+    ) -> Self {
+        Self {
+            revocation_store,
+            policy,
+            user_resources,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: PublicUserId,
+        caller: &CallerContext,
+    ) -> AppResult<TokensRevoked> {
+        let (user, resource) = self.user_resources.find(user_id, caller).await?;
+        self.policy
+            .authorize(caller, Action::UserRevokeTokens, &resource)?;
+        let user = user.ok_or(ApplicationError::UserNotFound)?;
+        let internal_id = user.id().ok_or(ApplicationError::UserNotFound)?.value();
+
+        let revoked_at = self.revocation_store.revoke_all(internal_id).await?;
+
+        Ok(TokensRevoked {
+            user_id: user_id.to_string(),
+            revoked_at: revoked_at.to_rfc3339(),
+        })
+    }
+}
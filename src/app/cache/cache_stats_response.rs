@@ -0,0 +1,32 @@
+use crate::app::ports::CacheStats;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// CacheStatsResponse DTO - counters of one cached lookup since startup
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CacheStatsResponse {
+    pub name: String,
+    pub hits: u64,
+    pub misses: u64,
+    pub errors: u64,
+    /// Share of lookups served from the cache; 0 before the first lookup
+    pub hit_ratio: f64,
+}
+
+impl CacheStatsResponse {
+    pub fn from_stats(stats: &CacheStats) -> Self {
+        let lookups = stats.hits + stats.misses + stats.errors;
+
+        Self {
+            name: stats.name.to_string(),
+            hits: stats.hits,
+            misses: stats.misses,
+            errors: stats.errors,
+            hit_ratio: if lookups == 0 {
+                0.0
+            } else {
+                stats.hits as f64 / lookups as f64
+            },
+        }
+    }
+}
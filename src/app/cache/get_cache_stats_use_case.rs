@@ -0,0 +1,31 @@
+use super::CacheStatsResponse;
+use crate::app::caller_context::CallerContext;
+use crate::app::errors::AppResult;
+use crate::app::policy::{Action, Policy, Resource};
+use crate::app::ports::CacheMetrics;
+use std::sync::Arc;
+
+/// GetCacheStatsUseCase - hit and miss counters of every cache (admin only)
+pub struct GetCacheStatsUseCase {
+    caches: Vec<Arc<dyn CacheMetrics>>,
+    policy: Arc<Policy>,
+}
+
+impl GetCacheStatsUseCase {
+    pub fn new(caches: Vec<Arc<dyn CacheMetrics>>, policy: Arc<Policy>) -> Self {
+        Self { caches, policy }
+    }
+
+    pub fn execute(&self, caller: &CallerContext) -> AppResult<Vec<CacheStatsResponse>> {
+        // Authorization: only admins inspect caches
+        self.policy
+            .authorize(caller, Action::CacheReadStats, &Resource::of_type("cache"))?;
+
+        Ok(self
+            .caches
+            .iter()
+            .flat_map(|cache| cache.stats())
+            .map(|stats| CacheStatsResponse::from_stats(&stats))
+            .collect())
+    }
+}
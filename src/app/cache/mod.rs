@@ -0,0 +1,5 @@
+pub mod cache_stats_response;
+pub mod get_cache_stats_use_case;
+
+pub use cache_stats_response::CacheStatsResponse;
+pub use get_cache_stats_use_case::GetCacheStatsUseCase;
@@ -1,9 +1,16 @@
 //! Caller context for authorization
 //!
 //! Represents the identity and roles of the authenticated caller.
-//! Built by the auth middleware from the JWT token + a DB role lookup,
+//! Built by the auth middleware from the JWT token + a DB role lookup
+//! (or from a mutual TLS client certificate for service callers),
 //! then inserted into request extensions for handler extraction.
+//! A caller acting in an organization holds their roles in that
+//! organization and only sees its members.
+//! Callers also see the members of their teams: the groups of the tenant
+//! they belong to that grant `view_members`.
 
+use crate::domain::organization::TenantScope;
+use crate::domain::shared::{GroupId, OrganizationId, PublicUserId};
 use crate::domain::user::Role;
 use axum::http::StatusCode;
 use axum::http::request::Parts;
@@ -13,13 +20,74 @@ use std::collections::HashSet;
 /// Context about the authenticated caller, passed to use cases for authorization
 #[derive(Debug, Clone)]
 pub struct CallerContext {
-    pub user_id: i32,
+    /// Public ID of the user the caller acts as; `None` for service callers
+    pub user_id: Option<PublicUserId>,
     pub roles: HashSet<Role>,
+    /// Service identity from a mutual TLS client certificate, if any
+    pub service: Option<String>,
+    /// Organization the caller is acting in; `None` for platform-wide callers
+    pub organization: Option<OrganizationId>,
+    /// Groups whose members the caller may view, from group grants
+    pub teams: HashSet<GroupId>,
 }
 
 impl CallerContext {
-    pub fn new(user_id: i32, roles: HashSet<Role>) -> Self {
-        Self { user_id, roles }
+    pub fn new(user_id: PublicUserId, roles: HashSet<Role>) -> Self {
+        Self {
+            user_id: Some(user_id),
+            roles,
+            service: None,
+            organization: None,
+            teams: HashSet::new(),
+        }
+    }
+
+    /// Context for a service authenticated only by its client certificate
+    ///
+    /// Services do not own user resources, so there is no `user_id`.
+    pub fn for_service(service: String, roles: HashSet<Role>) -> Self {
+        Self {
+            user_id: None,
+            roles,
+            service: Some(service),
+            organization: None,
+            teams: HashSet::new(),
+        }
+    }
+
+    /// Record the service a user request arrived through
+    pub fn with_service(mut self, service: Option<String>) -> Self {
+        self.service = service;
+        self
+    }
+
+    /// Act within an organization, holding `roles` there instead
+    ///
+    /// Teams belong to a tenant, so those of the previous one are dropped.
+    pub fn in_organization(mut self, organization: OrganizationId, roles: HashSet<Role>) -> Self {
+        self.organization = Some(organization);
+        self.roles = roles;
+        self.teams.clear();
+        self
+    }
+
+    /// Record the groups whose members the caller may view
+    pub fn with_teams(mut self, teams: HashSet<GroupId>) -> Self {
+        self.teams = teams;
+        self
+    }
+
+    /// The groups whose members the caller may view
+    pub fn team_ids(&self) -> Vec<GroupId> {
+        self.teams.iter().copied().collect()
+    }
+
+    /// The users this caller can see
+    pub fn scope(&self) -> TenantScope {
+        match self.organization {
+            Some(organization) => TenantScope::Organization(organization),
+            None => TenantScope::Platform,
+        }
     }
 
     /// Check if the caller has a specific role
@@ -32,13 +100,19 @@ impl CallerContext {
         self.roles.contains(&Role::Admin)
     }
 
+    /// Check if the caller is an admin of the whole platform rather than of
+    /// one organization
+    pub fn is_platform_admin(&self) -> bool {
+        self.organization.is_none() && self.is_admin()
+    }
+
     /// Check if the caller owns the resource (i.e., the resource belongs to them)
-    pub fn is_owner(&self, resource_user_id: i32) -> bool {
-        self.user_id == resource_user_id
+    pub fn is_owner(&self, resource_user_id: PublicUserId) -> bool {
+        self.user_id == Some(resource_user_id)
     }
 
     /// Check if the caller can access a user resource (admin OR owner)
-    pub fn can_access_user(&self, target_user_id: i32) -> bool {
+    pub fn can_access_user(&self, target_user_id: PublicUserId) -> bool {
         self.is_admin() || self.is_owner(target_user_id)
     }
 }
@@ -65,24 +139,49 @@ mod tests {
 
     #[test]
     fn test_admin_can_access_any_user() {
-        let caller = CallerContext::new(1, HashSet::from([Role::Admin, Role::User]));
-        assert!(caller.can_access_user(1));
-        assert!(caller.can_access_user(999));
+        let me = PublicUserId::generate();
+        let caller = CallerContext::new(me, HashSet::from([Role::Admin, Role::User]));
+        assert!(caller.can_access_user(me));
+        assert!(caller.can_access_user(PublicUserId::generate()));
         assert!(caller.is_admin());
     }
 
     #[test]
     fn test_user_can_only_access_own_data() {
-        let caller = CallerContext::new(1, HashSet::from([Role::User]));
-        assert!(caller.can_access_user(1));
-        assert!(!caller.can_access_user(2));
+        let me = PublicUserId::generate();
+        let caller = CallerContext::new(me, HashSet::from([Role::User]));
+        assert!(caller.can_access_user(me));
+        assert!(!caller.can_access_user(PublicUserId::generate()));
         assert!(!caller.is_admin());
     }
 
+    #[test]
+    fn test_service_caller_owns_no_user() {
+        let caller = CallerContext::for_service("billing".to_string(), HashSet::new());
+        assert_eq!(caller.service.as_deref(), Some("billing"));
+        assert!(!caller.can_access_user(PublicUserId::generate()));
+    }
+
+    #[test]
+    fn test_organization_roles_replace_own_roles() {
+        let me = PublicUserId::generate();
+        let organization = OrganizationId::from(3);
+        let caller = CallerContext::new(me, HashSet::from([Role::User]))
+            .in_organization(organization, HashSet::from([Role::Admin]));
+        assert!(caller.is_admin());
+        assert!(!caller.is_platform_admin());
+        assert_eq!(caller.scope(), TenantScope::Organization(organization));
+
+        let platform = CallerContext::new(me, HashSet::from([Role::Admin]));
+        assert!(platform.is_platform_admin());
+        assert_eq!(platform.scope(), TenantScope::Platform);
+    }
+
     #[test]
     fn test_is_owner() {
-        let caller = CallerContext::new(42, HashSet::from([Role::User]));
-        assert!(caller.is_owner(42));
-        assert!(!caller.is_owner(43));
+        let me = PublicUserId::generate();
+        let caller = CallerContext::new(me, HashSet::from([Role::User]));
+        assert!(caller.is_owner(me));
+        assert!(!caller.is_owner(PublicUserId::generate()));
     }
 }
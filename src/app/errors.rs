@@ -3,6 +3,7 @@
 //! These errors represent failures in application use cases.
 //! They are transport-agnostic - no HTTP concerns here.
 
+use crate::app::ports::JobQueueError;
 use crate::domain::user::errors::DomainError;
 use crate::domain::user::repository::RepositoryError;
 use thiserror::Error;
@@ -36,6 +37,54 @@ pub enum ApplicationError {
 
     #[error("Validation error: {0}")]
     ValidationError(String),
+
+    #[error("Webhook not found")]
+    WebhookNotFound,
+
+    #[error("Webhook delivery not found")]
+    WebhookDeliveryNotFound,
+
+    #[error("Job queue error: {0}")]
+    JobQueueError(#[from] JobQueueError),
+
+    #[error("Job not found")]
+    JobNotFound,
+
+    #[error("Job is {0} and cannot be retried")]
+    JobNotRetryable(String),
+
+    #[error("Organization not found")]
+    OrganizationNotFound,
+
+    #[error("Organization {0} already exists")]
+    OrganizationAlreadyExists(String),
+
+    #[error("Membership not found")]
+    MembershipNotFound,
+
+    #[error("Invitation not found")]
+    InvitationNotFound,
+
+    #[error("A pending invitation for {0} already exists")]
+    InvitationAlreadyExists(String),
+
+    #[error("Invitation is {0} and can no longer be changed")]
+    InvitationNotPending(String),
+
+    #[error("Invalid invitation: {0}")]
+    InvalidInvitation(String),
+
+    #[error("Group not found")]
+    GroupNotFound,
+
+    #[error("Group {0} already exists")]
+    GroupAlreadyExists(String),
+
+    #[error("Group member not found")]
+    GroupMemberNotFound,
+
+    #[error("Overloaded: {0}")]
+    Overloaded(String),
 }
 
 /// Type alias for application results
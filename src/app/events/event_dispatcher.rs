@@ -0,0 +1,96 @@
+use crate::app::ports::EventSubscriber;
+use crate::domain::user::UserEvent;
+use std::sync::Arc;
+
+/// EventDispatcher - hands domain events to every registered subscriber
+///
+/// Events are delivered in the order they were recorded. A failing
+/// subscriber is logged and does not stop delivery to the others; the
+/// change that raised the event has already been persisted.
+#[derive(Default)]
+pub struct EventDispatcher {
+    subscribers: Vec<Arc<dyn EventSubscriber>>,
+}
+
+impl EventDispatcher {
+    pub fn new(subscribers: Vec<Arc<dyn EventSubscriber>>) -> Self {
+        Self { subscribers }
+    }
+
+    pub async fn dispatch(&self, events: Vec<UserEvent>) {
+        for event in &events {
+            for subscriber in &self.subscribers {
+                if let Err(e) = subscriber.handle(event).await {
+                    tracing::warn!(
+                        subscriber = subscriber.name(),
+                        event = event.kind.name(),
+                        user_id = %event.user_id,
+                        error = %e,
+                        "Event subscriber failed"
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::shared::PublicUserId;
+    use crate::domain::user::UserEventKind;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    struct Recording {
+        seen: Mutex<Vec<&'static str>>,
+        fail: bool,
+    }
+
+    #[async_trait]
+    impl EventSubscriber for Recording {
+        fn name(&self) -> &'static str {
+            "recording"
+        }
+
+        async fn handle(&self, event: &UserEvent) -> Result<(), String> {
+            self.seen.lock().unwrap().push(event.kind.name());
+            if self.fail {
+                return Err("unavailable".to_string());
+            }
+            Ok(())
+        }
+    }
+
+    fn event(kind: UserEventKind) -> UserEvent {
+        UserEvent {
+            user_id: PublicUserId::generate(),
+            occurred_at: chrono::Utc::now(),
+            kind,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_delivers_in_order_despite_failures() {
+        let failing = Arc::new(Recording {
+            seen: Mutex::new(Vec::new()),
+            fail: true,
+        });
+        let healthy = Arc::new(Recording {
+            seen: Mutex::new(Vec::new()),
+            fail: false,
+        });
+        let dispatcher = EventDispatcher::new(vec![failing.clone(), healthy.clone()]);
+
+        dispatcher
+            .dispatch(vec![
+                event(UserEventKind::PasswordChanged),
+                event(UserEventKind::ProfileUpdated),
+            ])
+            .await;
+
+        let expected = ["user.password_changed", "user.profile_updated"];
+        assert_eq!(*failing.seen.lock().unwrap(), expected);
+        assert_eq!(*healthy.seen.lock().unwrap(), expected);
+    }
+}
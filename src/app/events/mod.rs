@@ -0,0 +1,3 @@
+pub mod event_dispatcher;
+
+pub use event_dispatcher::EventDispatcher;
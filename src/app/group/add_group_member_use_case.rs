@@ -0,0 +1,59 @@
+use super::{find_authorized, tenant_of};
+use crate::app::caller_context::CallerContext;
+use crate::app::errors::{AppResult, ApplicationError};
+use crate::app::policy::{Action, Policy};
+use crate::domain::group::GroupRepository;
+use crate::domain::shared::{GroupId, PublicUserId};
+use crate::domain::user::UserRepository;
+use std::sync::Arc;
+
+/// AddGroupMemberUseCase - adds a user to a group (admin only)
+///
+/// Only users of the group's tenant can join: members of its organization,
+/// or any user for a platform group. Adding a member again is a no-op.
+pub struct AddGroupMemberUseCase {
+    group_repository: Arc<dyn GroupRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    policy: Arc<Policy>,
+}
+
+impl AddGroupMemberUseCase {
+    pub fn new(
+        group_repository: Arc<dyn GroupRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        policy: Arc<Policy>,
+    ) -> Self {
+        Self {
+            group_repository,
+            user_repository,
+            policy,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        id: GroupId,
+        user_id: PublicUserId,
+        caller: &CallerContext,
+    ) -> AppResult<()> {
+        // Authorization: only admins manage groups
+        let group = find_authorized(
+            self.group_repository.as_ref(),
+            &self.policy,
+            id,
+            Action::GroupAddMember,
+            caller,
+        )
+        .await?;
+        let user = self
+            .user_repository
+            .find_by_public_id(user_id, tenant_of(&group))
+            .await?
+            .ok_or(ApplicationError::UserNotFound)?;
+        let internal_id = user.id().ok_or(ApplicationError::UserNotFound)?;
+
+        self.group_repository.add_member(id, internal_id).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use super::group_response::present_group;
+use super::{CreateGroupCommand, GroupResponse, parse_grants};
+use crate::app::caller_context::CallerContext;
+use crate::app::errors::{AppResult, ApplicationError};
+use crate::app::policy::{Action, Policy, Resource};
+use crate::domain::group::{Group, GroupRepository};
+use crate::domain::organization::OrganizationRepository;
+use std::sync::Arc;
+
+/// CreateGroupUseCase - adds a group to the caller's tenant (admin only)
+///
+/// Organization admins create groups in their organization, platform admins
+/// acting outside of one create platform groups. Names are unique per tenant.
+pub struct CreateGroupUseCase {
+    group_repository: Arc<dyn GroupRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+    policy: Arc<Policy>,
+}
+
+impl CreateGroupUseCase {
+    pub fn new(
+        group_repository: Arc<dyn GroupRepository>,
+        organization_repository: Arc<dyn OrganizationRepository>,
+        policy: Arc<Policy>,
+    ) -> Self {
+        Self {
+            group_repository,
+            organization_repository,
+            policy,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        command: CreateGroupCommand,
+        caller: &CallerContext,
+    ) -> AppResult<GroupResponse> {
+        // Authorization: only admins manage groups
+        self.policy
+            .authorize(caller, Action::GroupCreate, &Resource::of_type("group"))?;
+
+        let grants = parse_grants(&command.grants)?;
+        let group = Group::create(caller.organization, command.name, grants)?;
+        if self
+            .group_repository
+            .exists_with_name(group.organization_id(), group.name())
+            .await?
+        {
+            return Err(ApplicationError::GroupAlreadyExists(
+                group.name().to_string(),
+            ));
+        }
+
+        self.group_repository.save(&group).await?;
+
+        present_group(&group, self.organization_repository.as_ref()).await
+    }
+}
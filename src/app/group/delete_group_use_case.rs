@@ -0,0 +1,41 @@
+use super::find_authorized;
+use crate::app::caller_context::CallerContext;
+use crate::app::errors::{AppResult, ApplicationError};
+use crate::app::policy::{Action, Policy};
+use crate::domain::group::GroupRepository;
+use crate::domain::shared::GroupId;
+use std::sync::Arc;
+
+/// DeleteGroupUseCase - deletes a group and its memberships (admin only)
+///
+/// The members themselves are kept; they only lose what the group granted.
+pub struct DeleteGroupUseCase {
+    group_repository: Arc<dyn GroupRepository>,
+    policy: Arc<Policy>,
+}
+
+impl DeleteGroupUseCase {
+    pub fn new(group_repository: Arc<dyn GroupRepository>, policy: Arc<Policy>) -> Self {
+        Self {
+            group_repository,
+            policy,
+        }
+    }
+
+    pub async fn execute(&self, id: GroupId, caller: &CallerContext) -> AppResult<()> {
+        // Authorization: only admins manage groups
+        find_authorized(
+            self.group_repository.as_ref(),
+            &self.policy,
+            id,
+            Action::GroupDelete,
+            caller,
+        )
+        .await?;
+        if !self.group_repository.delete(id).await? {
+            return Err(ApplicationError::GroupNotFound);
+        }
+
+        Ok(())
+    }
+}
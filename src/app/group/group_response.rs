@@ -0,0 +1,66 @@
+use crate::app::errors::AppResult;
+use crate::app::organization::organization_slugs;
+use crate::domain::group::Group;
+use crate::domain::organization::OrganizationRepository;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// GroupResponse DTO - for API responses
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct GroupResponse {
+    pub id: String,
+    pub name: String,
+    /// Slug of the organization the group belongs to; absent for platform groups
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization: Option<String>,
+    /// What membership entitles members to
+    pub grants: Vec<String>,
+    pub created_at: String,
+}
+
+impl GroupResponse {
+    /// Convert from domain Group entity, given the slug of its organization
+    pub fn from_domain(group: &Group, organization: Option<String>) -> Self {
+        let mut grants: Vec<String> = group.grants().iter().map(|g| g.to_string()).collect();
+        grants.sort_unstable();
+
+        Self {
+            id: group.id().to_string(),
+            name: group.name().to_string(),
+            organization,
+            grants,
+            created_at: group.created_at().to_string(),
+        }
+    }
+}
+
+/// Groups with the slugs of the organizations they belong to
+pub(crate) async fn present_groups(
+    groups: &[Group],
+    organizations: &dyn OrganizationRepository,
+) -> AppResult<Vec<GroupResponse>> {
+    let organization_ids: Vec<_> = groups
+        .iter()
+        .filter_map(|group| group.organization_id())
+        .collect();
+    let slugs = organization_slugs(organizations, &organization_ids).await?;
+
+    Ok(groups
+        .iter()
+        .map(|group| {
+            let slug = group
+                .organization_id()
+                .and_then(|id| slugs.get(&id).cloned());
+            GroupResponse::from_domain(group, slug)
+        })
+        .collect())
+}
+
+/// A single group with the slug of its organization; see [`present_groups`]
+pub(crate) async fn present_group(
+    group: &Group,
+    organizations: &dyn OrganizationRepository,
+) -> AppResult<GroupResponse> {
+    let mut responses = present_groups(std::slice::from_ref(group), organizations).await?;
+    Ok(responses.remove(0))
+}
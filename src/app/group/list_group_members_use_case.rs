@@ -0,0 +1,68 @@
+use super::{ListGroupsQuery, find_authorized};
+use crate::app::caller_context::CallerContext;
+use crate::app::errors::AppResult;
+use crate::app::policy::{Action, Policy};
+use crate::app::user::UserResponse;
+use crate::app::user::user_response::present_users;
+use crate::domain::group::GroupRepository;
+use crate::domain::organization::OrganizationRepository;
+use crate::domain::shared::GroupId;
+use crate::domain::user::UserRepository;
+use std::sync::Arc;
+
+/// ListGroupMembersUseCase - lists the members of a group with pagination
+///
+/// Admins managing the group see its members, and so do members of a group
+/// granting `view_members`. Groups the caller cannot see are not found.
+pub struct ListGroupMembersUseCase {
+    group_repository: Arc<dyn GroupRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+    policy: Arc<Policy>,
+}
+
+impl ListGroupMembersUseCase {
+    pub fn new(
+        group_repository: Arc<dyn GroupRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        organization_repository: Arc<dyn OrganizationRepository>,
+        policy: Arc<Policy>,
+    ) -> Self {
+        Self {
+            group_repository,
+            user_repository,
+            organization_repository,
+            policy,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        id: GroupId,
+        query: ListGroupsQuery,
+        caller: &CallerContext,
+    ) -> AppResult<(Vec<UserResponse>, u64)> {
+        let group = find_authorized(
+            self.group_repository.as_ref(),
+            &self.policy,
+            id,
+            Action::GroupListMembers,
+            caller,
+        )
+        .await?;
+
+        let (users, total) = self
+            .user_repository
+            .list_in_groups(
+                &[group.id()],
+                caller.scope(),
+                query.page,
+                query.rows_per_page,
+            )
+            .await?;
+        let responses =
+            present_users(&users, caller, self.organization_repository.as_ref()).await?;
+
+        Ok((responses, total))
+    }
+}
@@ -0,0 +1,49 @@
+use super::group_response::present_groups;
+use super::{GroupResponse, ListGroupsQuery};
+use crate::app::caller_context::CallerContext;
+use crate::app::errors::AppResult;
+use crate::app::policy::{Action, Policy, Resource};
+use crate::domain::group::GroupRepository;
+use crate::domain::organization::OrganizationRepository;
+use std::sync::Arc;
+
+/// ListGroupsUseCase - lists groups with pagination (admin only)
+///
+/// Organization admins see the groups of their organization.
+pub struct ListGroupsUseCase {
+    group_repository: Arc<dyn GroupRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+    policy: Arc<Policy>,
+}
+
+impl ListGroupsUseCase {
+    pub fn new(
+        group_repository: Arc<dyn GroupRepository>,
+        organization_repository: Arc<dyn OrganizationRepository>,
+        policy: Arc<Policy>,
+    ) -> Self {
+        Self {
+            group_repository,
+            organization_repository,
+            policy,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        query: ListGroupsQuery,
+        caller: &CallerContext,
+    ) -> AppResult<(Vec<GroupResponse>, u64)> {
+        // Authorization: only admins manage groups
+        self.policy
+            .authorize(caller, Action::GroupList, &Resource::of_type("group"))?;
+
+        let (groups, total) = self
+            .group_repository
+            .list(caller.scope(), query.page, query.rows_per_page)
+            .await?;
+        let responses = present_groups(&groups, self.organization_repository.as_ref()).await?;
+
+        Ok((responses, total))
+    }
+}
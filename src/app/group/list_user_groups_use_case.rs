@@ -0,0 +1,54 @@
+use super::GroupResponse;
+use super::group_response::present_groups;
+use crate::app::caller_context::CallerContext;
+use crate::app::errors::{AppResult, ApplicationError};
+use crate::app::policy::{Action, Policy, UserResources};
+use crate::domain::group::GroupRepository;
+use crate::domain::organization::OrganizationRepository;
+use crate::domain::shared::PublicUserId;
+use std::sync::Arc;
+
+/// ListUserGroupsUseCase - lists the groups a user belongs to
+///
+/// Within an organization only its groups are listed.
+pub struct ListUserGroupsUseCase {
+    group_repository: Arc<dyn GroupRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+    policy: Arc<Policy>,
+    user_resources: Arc<UserResources>,
+}
+
+impl ListUserGroupsUseCase {
+    pub fn new(
+        group_repository: Arc<dyn GroupRepository>,
+        organization_repository: Arc<dyn OrganizationRepository>,
+        policy: Arc<Policy>,
+        user_resources: Arc<UserResources>,
+    ) -> Self {
+        Self {
+            group_repository,
+            organization_repository,
+            policy,
+            user_resources,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: PublicUserId,
+        caller: &CallerContext,
+    ) -> AppResult<Vec<GroupResponse>> {
+        let (user, resource) = self.user_resources.find(user_id, caller).await?;
+        self.policy
+            .authorize(caller, Action::UserListGroups, &resource)?;
+        let user = user.ok_or(ApplicationError::UserNotFound)?;
+        let internal_id = user.id().ok_or(ApplicationError::UserNotFound)?;
+
+        let groups = self
+            .group_repository
+            .list_for_user(internal_id, caller.scope())
+            .await?;
+
+        present_groups(&groups, self.organization_repository.as_ref()).await
+    }
+}
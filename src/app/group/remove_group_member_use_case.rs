@@ -0,0 +1,60 @@
+use super::find_authorized;
+use crate::app::caller_context::CallerContext;
+use crate::app::errors::{AppResult, ApplicationError};
+use crate::app::policy::{Action, Policy};
+use crate::domain::group::GroupRepository;
+use crate::domain::organization::TenantScope;
+use crate::domain::shared::{GroupId, PublicUserId};
+use crate::domain::user::UserRepository;
+use std::sync::Arc;
+
+/// RemoveGroupMemberUseCase - removes a user from a group (admin only)
+pub struct RemoveGroupMemberUseCase {
+    group_repository: Arc<dyn GroupRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    policy: Arc<Policy>,
+}
+
+impl RemoveGroupMemberUseCase {
+    pub fn new(
+        group_repository: Arc<dyn GroupRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        policy: Arc<Policy>,
+    ) -> Self {
+        Self {
+            group_repository,
+            user_repository,
+            policy,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        id: GroupId,
+        user_id: PublicUserId,
+        caller: &CallerContext,
+    ) -> AppResult<()> {
+        // Authorization: only admins manage groups
+        find_authorized(
+            self.group_repository.as_ref(),
+            &self.policy,
+            id,
+            Action::GroupRemoveMember,
+            caller,
+        )
+        .await?;
+        // Members who since left the group's organization can still be removed
+        let user = self
+            .user_repository
+            .find_by_public_id(user_id, TenantScope::Platform)
+            .await?
+            .ok_or(ApplicationError::GroupMemberNotFound)?;
+        let internal_id = user.id().ok_or(ApplicationError::GroupMemberNotFound)?;
+
+        if !self.group_repository.remove_member(id, internal_id).await? {
+            return Err(ApplicationError::GroupMemberNotFound);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+use super::group_response::present_group;
+use super::{GroupResponse, UpdateGroupCommand, find_authorized, parse_grants};
+use crate::app::caller_context::CallerContext;
+use crate::app::errors::{AppResult, ApplicationError};
+use crate::app::policy::{Action, Policy};
+use crate::domain::group::GroupRepository;
+use crate::domain::organization::OrganizationRepository;
+use crate::domain::shared::GroupId;
+use std::sync::Arc;
+
+/// UpdateGroupUseCase - renames a group or replaces its grants (admin only)
+///
+/// Grant changes take effect on the members' next request.
+pub struct UpdateGroupUseCase {
+    group_repository: Arc<dyn GroupRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+    policy: Arc<Policy>,
+}
+
+impl UpdateGroupUseCase {
+    pub fn new(
+        group_repository: Arc<dyn GroupRepository>,
+        organization_repository: Arc<dyn OrganizationRepository>,
+        policy: Arc<Policy>,
+    ) -> Self {
+        Self {
+            group_repository,
+            organization_repository,
+            policy,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        id: GroupId,
+        command: UpdateGroupCommand,
+        caller: &CallerContext,
+    ) -> AppResult<GroupResponse> {
+        // Authorization: only admins manage groups
+        let mut group = find_authorized(
+            self.group_repository.as_ref(),
+            &self.policy,
+            id,
+            Action::GroupUpdate,
+            caller,
+        )
+        .await?;
+
+        if let Some(name) = command.name {
+            let previous = group.name().to_string();
+            group.rename(name)?;
+            if group.name() != previous
+                && self
+                    .group_repository
+                    .exists_with_name(group.organization_id(), group.name())
+                    .await?
+            {
+                return Err(ApplicationError::GroupAlreadyExists(
+                    group.name().to_string(),
+                ));
+            }
+        }
+        if let Some(grants) = command.grants {
+            group.set_grants(parse_grants(&grants)?);
+        }
+
+        self.group_repository.save(&group).await?;
+
+        present_group(&group, self.organization_repository.as_ref()).await
+    }
+}
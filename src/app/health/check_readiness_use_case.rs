@@ -0,0 +1,139 @@
+use super::{ComponentHealth, HealthReport, HealthStatus};
+use crate::app::ports::HealthCheck;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// CheckReadinessUseCase - runs every registered health check with a timeout
+pub struct CheckReadinessUseCase {
+    checks: Vec<Arc<dyn HealthCheck>>,
+    timeout: Duration,
+}
+
+impl CheckReadinessUseCase {
+    pub fn new(checks: Vec<Arc<dyn HealthCheck>>, timeout: Duration) -> Self {
+        Self { checks, timeout }
+    }
+
+    pub async fn execute(&self) -> HealthReport {
+        let mut checks = BTreeMap::new();
+
+        for check in &self.checks {
+            let started = Instant::now();
+            let outcome = match tokio::time::timeout(self.timeout, check.check()).await {
+                Ok(result) => result,
+                Err(_) => Err(format!("timed out after {}ms", self.timeout.as_millis())),
+            };
+
+            let component = ComponentHealth {
+                status: if outcome.is_ok() {
+                    HealthStatus::Up
+                } else {
+                    HealthStatus::Down
+                },
+                duration_ms: started.elapsed().as_millis() as u64,
+                error: outcome.err(),
+            };
+            checks.insert(check.name().to_string(), component);
+        }
+
+        let status = if checks.values().all(|c| c.status == HealthStatus::Up) {
+            HealthStatus::Up
+        } else {
+            HealthStatus::Down
+        };
+
+        HealthReport { status, checks }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+
+    struct StaticCheck {
+        name: &'static str,
+        result: Result<(), String>,
+    }
+
+    #[async_trait]
+    impl HealthCheck for StaticCheck {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        async fn check(&self) -> Result<(), String> {
+            self.result.clone()
+        }
+    }
+
+    struct HangingCheck;
+
+    #[async_trait]
+    impl HealthCheck for HangingCheck {
+        fn name(&self) -> &'static str {
+            "hanging"
+        }
+
+        async fn check(&self) -> Result<(), String> {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_all_checks_up() {
+        let use_case = CheckReadinessUseCase::new(
+            vec![Arc::new(StaticCheck {
+                name: "database",
+                result: Ok(()),
+            })],
+            Duration::from_secs(1),
+        );
+
+        let report = use_case.execute().await;
+        assert!(report.is_up());
+        assert_eq!(report.checks["database"].status, HealthStatus::Up);
+    }
+
+    #[tokio::test]
+    async fn test_failing_check_marks_report_down() {
+        let use_case = CheckReadinessUseCase::new(
+            vec![
+                Arc::new(StaticCheck {
+                    name: "database",
+                    result: Ok(()),
+                }),
+                Arc::new(StaticCheck {
+                    name: "migrations",
+                    result: Err("2 pending migrations".to_string()),
+                }),
+            ],
+            Duration::from_secs(1),
+        );
+
+        let report = use_case.execute().await;
+        assert!(!report.is_up());
+        assert_eq!(
+            report.checks["migrations"].error.as_deref(),
+            Some("2 pending migrations")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_slow_check_times_out() {
+        let use_case =
+            CheckReadinessUseCase::new(vec![Arc::new(HangingCheck)], Duration::from_millis(50));
+
+        let report = use_case.execute().await;
+        assert!(!report.is_up());
+        assert!(
+            report.checks["hanging"]
+                .error
+                .as_ref()
+                .unwrap()
+                .contains("timed out")
+        );
+    }
+}
@@ -0,0 +1,46 @@
+pub mod check_readiness_use_case;
+
+pub use check_readiness_use_case::CheckReadinessUseCase;
+
+use serde::Serialize;
+use std::collections::BTreeMap;
+use utoipa::ToSchema;
+
+/// Status of a component or of the service as a whole
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    Up,
+    Down,
+}
+
+/// Result of a single component check
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ComponentHealth {
+    pub status: HealthStatus,
+    #[serde(rename = "durationMs")]
+    pub duration_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Aggregated health report; `Up` only when every component is up
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct HealthReport {
+    pub status: HealthStatus,
+    pub checks: BTreeMap<String, ComponentHealth>,
+}
+
+impl HealthReport {
+    /// Report for the liveness probe, which does not touch dependencies
+    pub fn alive() -> Self {
+        Self {
+            status: HealthStatus::Up,
+            checks: BTreeMap::new(),
+        }
+    }
+
+    pub fn is_up(&self) -> bool {
+        self.status == HealthStatus::Up
+    }
+}
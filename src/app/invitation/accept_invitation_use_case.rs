@@ -0,0 +1,134 @@
+use super::AcceptInvitationCommand;
+use crate::app::auth::{HashingPool, PasswordChecker};
+use crate::app::errors::{AppResult, ApplicationError};
+use crate::app::ports::{Invitation, InvitationSigner, InvitationStatus, InvitationStore};
+use crate::app::user::UserResponse;
+use crate::domain::organization::Membership;
+use crate::domain::user::repository::RepositoryError;
+use crate::domain::user::{DateOfBirth, Email, Role, User, UserRepository};
+use chrono::Utc;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// AcceptInvitationUseCase - signs up the invitee of an invitation link
+///
+/// The invitee chooses their password and profile; the email and roles
+/// come from the invitation. Anyone holding a valid link may accept it.
+pub struct AcceptInvitationUseCase {
+    invitation_store: Arc<dyn InvitationStore>,
+    signer: Arc<dyn InvitationSigner>,
+    user_repository: Arc<dyn UserRepository>,
+    password_checker: Arc<PasswordChecker>,
+    hashing_pool: Arc<HashingPool>,
+}
+
+impl AcceptInvitationUseCase {
+    pub fn new(
+        invitation_store: Arc<dyn InvitationStore>,
+        signer: Arc<dyn InvitationSigner>,
+        user_repository: Arc<dyn UserRepository>,
+        password_checker: Arc<PasswordChecker>,
+        hashing_pool: Arc<HashingPool>,
+    ) -> Self {
+        Self {
+            invitation_store,
+            signer,
+            user_repository,
+            password_checker,
+            hashing_pool,
+        }
+    }
+
+    pub async fn execute(&self, command: AcceptInvitationCommand) -> AppResult<UserResponse> {
+        let invitation = self.open_invitation(&command.token).await?;
+        let email = Email::try_from(invitation.email.clone())?;
+        let roles = invitation
+            .roles
+            .iter()
+            .map(|role| Role::from_str(role))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(RepositoryError::PersistenceFailure)?;
+
+        if self.user_repository.exists_with_email(&email).await? {
+            return Err(ApplicationError::EmailAlreadyExists(invitation.email));
+        }
+
+        // Policy, breached list; every violation is reported together
+        self.password_checker
+            .check_new(
+                &command.password,
+                &[email.as_ref(), &command.first_name, &command.last_name],
+            )
+            .await?;
+
+        // Create user entity (domain logic); hashing runs on the pool
+        let policy = self.password_checker.policy().clone();
+        let hasher = self.password_checker.hasher().clone();
+        let mut user = self
+            .hashing_pool
+            .run(move || {
+                User::register(
+                    email,
+                    command.password,
+                    command.first_name,
+                    command.last_name,
+                    DateOfBirth::from(command.date_of_birth),
+                    &policy,
+                    &hasher,
+                )
+            })
+            .await??;
+
+        // Claim the invitation last, so a rejected password leaves it open;
+        // the invitee and their membership are saved with the claim or not at all
+        let membership = match invitation.organization_id {
+            Some(organization_id) => Some((organization_id, roles.as_slice())),
+            None => {
+                for role in &roles {
+                    user.add_role(*role);
+                }
+                None
+            }
+        };
+        if !self
+            .invitation_store
+            .accept(invitation.id, &mut user, membership)
+            .await?
+        {
+            return Err(ApplicationError::InvalidInvitation(
+                "The invitation is no longer pending".to_string(),
+            ));
+        }
+        self.password_checker.remember(&user).await?;
+
+        let Some(organization_id) = invitation.organization_id else {
+            return Ok(UserResponse::from_domain(&user));
+        };
+        let internal_id = user.id().ok_or(ApplicationError::UserNotFound)?;
+        let membership = Membership::with_roles(organization_id, internal_id, roles);
+        Ok(UserResponse::from_membership(&user, &membership))
+    }
+
+    /// The invitation a token is for, if it can still be accepted
+    async fn open_invitation(&self, token: &str) -> AppResult<Invitation> {
+        let invalid =
+            || ApplicationError::InvalidInvitation("The invitation link is invalid".to_string());
+
+        let (id, expires_at) = self.signer.verify(token).ok_or_else(invalid)?;
+        let invitation = self.invitation_store.find(id).await?.ok_or_else(invalid)?;
+
+        let reason = match invitation.status {
+            InvitationStatus::Accepted => "The invitation has already been accepted",
+            InvitationStatus::Revoked => "The invitation has been revoked",
+            // Resending moves the expiry, replacing the links sent before
+            InvitationStatus::Pending if invitation.expires_at != expires_at => {
+                "The invitation link has been replaced by a newer one"
+            }
+            InvitationStatus::Pending if !invitation.is_open(Utc::now()) => {
+                "The invitation has expired"
+            }
+            InvitationStatus::Pending => return Ok(invitation),
+        };
+        Err(ApplicationError::InvalidInvitation(reason.to_string()))
+    }
+}
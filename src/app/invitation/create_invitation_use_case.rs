@@ -0,0 +1,96 @@
+use super::invitation_response::present_invitations;
+use super::{CreateInvitationCommand, InvitationResponse, InvitationSender, expiry_after};
+use crate::app::caller_context::CallerContext;
+use crate::app::errors::{AppResult, ApplicationError};
+use crate::app::policy::{Action, Policy, Resource};
+use crate::app::ports::{InvitationStore, NewInvitation};
+use crate::domain::organization::OrganizationRepository;
+use crate::domain::user::{Email, Role, UserRepository};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// CreateInvitationUseCase - invites an email to sign up (admin only)
+///
+/// Invitations sent within an organization make the invitee a member with
+/// the given roles there; otherwise the roles are granted platform-wide.
+pub struct CreateInvitationUseCase {
+    invitation_store: Arc<dyn InvitationStore>,
+    user_repository: Arc<dyn UserRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+    sender: Arc<InvitationSender>,
+    ttl: Duration,
+    policy: Arc<Policy>,
+}
+
+impl CreateInvitationUseCase {
+    pub fn new(
+        invitation_store: Arc<dyn InvitationStore>,
+        user_repository: Arc<dyn UserRepository>,
+        organization_repository: Arc<dyn OrganizationRepository>,
+        sender: Arc<InvitationSender>,
+        ttl: Duration,
+        policy: Arc<Policy>,
+    ) -> Self {
+        Self {
+            invitation_store,
+            user_repository,
+            organization_repository,
+            sender,
+            ttl,
+            policy,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        command: CreateInvitationCommand,
+        caller: &CallerContext,
+    ) -> AppResult<InvitationResponse> {
+        // Authorization: only admins invite users; organization admins into their organization
+        self.policy.authorize(
+            caller,
+            Action::InvitationCreate,
+            &Resource::of_type("invitation"),
+        )?;
+
+        let email = Email::try_from(command.email.clone())?;
+        let mut roles = command
+            .roles
+            .iter()
+            .map(|role| Role::from_str(role).map(|role| role.to_string()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(ApplicationError::ValidationError)?;
+        roles.sort_unstable();
+        roles.dedup();
+
+        if self.user_repository.exists_with_email(&email).await? {
+            return Err(ApplicationError::EmailAlreadyExists(command.email));
+        }
+        if self
+            .invitation_store
+            .find_pending(email.as_ref(), caller.organization)
+            .await?
+            .is_some()
+        {
+            return Err(ApplicationError::InvitationAlreadyExists(command.email));
+        }
+
+        let invitation = self
+            .invitation_store
+            .create(NewInvitation {
+                email: email.as_ref().to_string(),
+                roles,
+                organization_id: caller.organization,
+                invited_by: caller.user_id.map(|id| id.value()),
+                locale: command.locale,
+                expires_at: expiry_after(self.ttl),
+            })
+            .await?;
+        self.sender.send(&invitation).await?;
+
+        let mut responses =
+            present_invitations(&[invitation], self.organization_repository.as_ref()).await?;
+        Ok(responses.remove(0))
+    }
+}
@@ -0,0 +1,69 @@
+use crate::app::errors::AppResult;
+use crate::app::organization::organization_slugs;
+use crate::app::ports::{Invitation, InvitationStatus};
+use crate::domain::organization::OrganizationRepository;
+use chrono::Utc;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// InvitationResponse DTO - for API responses
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct InvitationResponse {
+    pub id: String,
+    pub email: String,
+    /// Roles granted on acceptance besides `user`
+    pub roles: Vec<String>,
+    /// Slug of the organization the invitee joins; absent for platform accounts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization: Option<String>,
+    /// pending, expired, accepted or revoked
+    pub status: String,
+    pub expires_at: String,
+    pub created_at: String,
+    pub accepted_at: Option<String>,
+}
+
+impl InvitationResponse {
+    /// Convert from a stored invitation, given the slug of its organization
+    pub fn from_invitation(invitation: &Invitation, organization: Option<String>) -> Self {
+        let status =
+            if invitation.status == InvitationStatus::Pending && !invitation.is_open(Utc::now()) {
+                "expired"
+            } else {
+                invitation.status.as_str()
+            };
+
+        Self {
+            id: invitation.id.to_string(),
+            email: invitation.email.clone(),
+            roles: invitation.roles.clone(),
+            organization,
+            status: status.to_string(),
+            expires_at: invitation.expires_at.to_rfc3339(),
+            created_at: invitation.created_at.to_rfc3339(),
+            accepted_at: invitation.accepted_at.map(|at| at.to_rfc3339()),
+        }
+    }
+}
+
+/// Invitations with the slugs of the organizations they are to
+pub(crate) async fn present_invitations(
+    invitations: &[Invitation],
+    organizations: &dyn OrganizationRepository,
+) -> AppResult<Vec<InvitationResponse>> {
+    let organization_ids: Vec<_> = invitations
+        .iter()
+        .filter_map(|i| i.organization_id)
+        .collect();
+    let slugs = organization_slugs(organizations, &organization_ids).await?;
+
+    Ok(invitations
+        .iter()
+        .map(|invitation| {
+            let slug = invitation
+                .organization_id
+                .and_then(|id| slugs.get(&id).cloned());
+            InvitationResponse::from_invitation(invitation, slug)
+        })
+        .collect())
+}
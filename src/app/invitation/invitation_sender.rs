@@ -0,0 +1,147 @@
+use crate::app::errors::AppResult;
+use crate::app::jobs::JobHandler;
+use crate::app::mail::{SendMail, SendMailHandler};
+use crate::app::ports::{Invitation, InvitationSigner, InvitationStore, Job, JobQueue};
+use crate::domain::organization::OrganizationRepository;
+use async_trait::async_trait;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// InvitationSender - emails an invitation its signed accept link
+///
+/// The email is queued as a `SendInvitation` job, so a slow mail server does
+/// not hold up the admin's request.
+pub struct InvitationSender {
+    job_queue: Arc<dyn JobQueue>,
+}
+
+impl InvitationSender {
+    pub fn new(job_queue: Arc<dyn JobQueue>) -> Self {
+        Self { job_queue }
+    }
+
+    /// Queue the invitation email
+    pub async fn send(&self, invitation: &Invitation) -> AppResult<()> {
+        self.job_queue
+            .enqueue(&SendInvitation {
+                invitation_id: invitation.id,
+            })
+            .await?;
+        Ok(())
+    }
+}
+
+/// Background job emailing an invitation its accept link
+///
+/// Only the invitation's ID is queued: job payloads are kept and shown to
+/// administrators, and the link's token would let anyone holding it accept.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SendInvitation {
+    pub invitation_id: Uuid,
+}
+
+impl Job for SendInvitation {
+    const KIND: &'static str = "invitation.send";
+}
+
+/// Runs `SendInvitation` jobs, signing a token for the invitation's current
+/// expiry and sending the email through `SendMailHandler`
+pub struct SendInvitationHandler {
+    invitation_store: Arc<dyn InvitationStore>,
+    signer: Arc<dyn InvitationSigner>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+    mail: Arc<SendMailHandler>,
+    accept_url: String,
+}
+
+impl SendInvitationHandler {
+    pub fn new(
+        invitation_store: Arc<dyn InvitationStore>,
+        signer: Arc<dyn InvitationSigner>,
+        organization_repository: Arc<dyn OrganizationRepository>,
+        mail: Arc<SendMailHandler>,
+        accept_url: String,
+    ) -> Self {
+        Self {
+            invitation_store,
+            signer,
+            organization_repository,
+            mail,
+            accept_url,
+        }
+    }
+
+    /// The email linking to the accept page with a token for the
+    /// invitation's current expiry
+    async fn email(&self, invitation: &Invitation) -> Result<SendMail, String> {
+        let token = self.signer.sign(invitation.id, invitation.expires_at);
+        let separator = if self.accept_url.contains('?') {
+            '&'
+        } else {
+            '?'
+        };
+
+        let mut vars = Map::new();
+        vars.insert(
+            "accept_url".to_string(),
+            Value::from(format!("{}{}token={}", self.accept_url, separator, token)),
+        );
+        vars.insert(
+            "expires_at".to_string(),
+            Value::from(
+                invitation
+                    .expires_at
+                    .format("%Y-%m-%d %H:%M UTC")
+                    .to_string(),
+            ),
+        );
+
+        let mut template = "invitation";
+        if let Some(organization_id) = invitation.organization_id {
+            let organization = self
+                .organization_repository
+                .find_by_id(organization_id)
+                .await
+                .map_err(|e| e.to_string())?;
+            if let Some(organization) = organization {
+                template = "organization_invitation";
+                vars.insert(
+                    "organization".to_string(),
+                    Value::from(organization.name().to_string()),
+                );
+            }
+        }
+
+        Ok(SendMail {
+            template: template.to_string(),
+            locale: invitation.locale.clone(),
+            to: invitation.email.clone(),
+            vars,
+        })
+    }
+}
+
+#[async_trait]
+impl JobHandler<SendInvitation> for SendInvitationHandler {
+    async fn handle(&self, job: SendInvitation) -> Result<(), String> {
+        let invitation = self
+            .invitation_store
+            .find(job.invitation_id)
+            .await
+            .map_err(|e| e.to_string())?;
+        // Accepted, revoked or expired while queued: the link would not work
+        let Some(invitation) = invitation.filter(|i| i.is_open(Utc::now())) else {
+            tracing::debug!(
+                invitation_id = %job.invitation_id,
+                "Invitation no longer open, not sending it"
+            );
+            return Ok(());
+        };
+
+        let email = self.email(&invitation).await?;
+        self.mail.handle(email).await
+    }
+}
@@ -0,0 +1,53 @@
+use super::invitation_response::present_invitations;
+use super::{InvitationResponse, ListInvitationsQuery};
+use crate::app::caller_context::CallerContext;
+use crate::app::errors::AppResult;
+use crate::app::policy::{Action, Policy, Resource};
+use crate::app::ports::InvitationStore;
+use crate::domain::organization::OrganizationRepository;
+use std::sync::Arc;
+
+/// ListInvitationsUseCase - lists invitations with pagination (admin only)
+///
+/// Organization admins see the invitations to their organization.
+pub struct ListInvitationsUseCase {
+    invitation_store: Arc<dyn InvitationStore>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+    policy: Arc<Policy>,
+}
+
+impl ListInvitationsUseCase {
+    pub fn new(
+        invitation_store: Arc<dyn InvitationStore>,
+        organization_repository: Arc<dyn OrganizationRepository>,
+        policy: Arc<Policy>,
+    ) -> Self {
+        Self {
+            invitation_store,
+            organization_repository,
+            policy,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        query: ListInvitationsQuery,
+        caller: &CallerContext,
+    ) -> AppResult<(Vec<InvitationResponse>, u64)> {
+        // Authorization: only admins see who was invited
+        self.policy.authorize(
+            caller,
+            Action::InvitationList,
+            &Resource::of_type("invitation"),
+        )?;
+
+        let (invitations, total) = self
+            .invitation_store
+            .list(caller.scope(), query.page, query.rows_per_page)
+            .await?;
+        let responses =
+            present_invitations(&invitations, self.organization_repository.as_ref()).await?;
+
+        Ok((responses, total))
+    }
+}
@@ -0,0 +1,70 @@
+use super::invitation_response::present_invitations;
+use super::{InvitationResponse, InvitationSender, expiry_after, find_authorized};
+use crate::app::caller_context::CallerContext;
+use crate::app::errors::{AppResult, ApplicationError};
+use crate::app::policy::{Action, Policy};
+use crate::app::ports::{InvitationStatus, InvitationStore};
+use crate::domain::organization::OrganizationRepository;
+use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
+
+/// ResendInvitationUseCase - emails a pending invitation again (admin only)
+///
+/// The expiry starts over, which invalidates the links sent before.
+pub struct ResendInvitationUseCase {
+    invitation_store: Arc<dyn InvitationStore>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+    sender: Arc<InvitationSender>,
+    ttl: Duration,
+    policy: Arc<Policy>,
+}
+
+impl ResendInvitationUseCase {
+    pub fn new(
+        invitation_store: Arc<dyn InvitationStore>,
+        organization_repository: Arc<dyn OrganizationRepository>,
+        sender: Arc<InvitationSender>,
+        ttl: Duration,
+        policy: Arc<Policy>,
+    ) -> Self {
+        Self {
+            invitation_store,
+            organization_repository,
+            sender,
+            ttl,
+            policy,
+        }
+    }
+
+    pub async fn execute(&self, id: Uuid, caller: &CallerContext) -> AppResult<InvitationResponse> {
+        // Authorization: only admins manage invitations
+        let invitation = find_authorized(
+            self.invitation_store.as_ref(),
+            &self.policy,
+            id,
+            Action::InvitationResend,
+            caller,
+        )
+        .await?;
+        if invitation.status != InvitationStatus::Pending {
+            return Err(ApplicationError::InvitationNotPending(
+                invitation.status.as_str().to_string(),
+            ));
+        }
+
+        // Accepted or revoked since it was read
+        let invitation = self
+            .invitation_store
+            .renew(id, expiry_after(self.ttl))
+            .await?
+            .ok_or_else(|| {
+                ApplicationError::InvitationNotPending("no longer pending".to_string())
+            })?;
+        self.sender.send(&invitation).await?;
+
+        let mut responses =
+            present_invitations(&[invitation], self.organization_repository.as_ref()).await?;
+        Ok(responses.remove(0))
+    }
+}
@@ -0,0 +1,50 @@
+use super::find_authorized;
+use crate::app::caller_context::CallerContext;
+use crate::app::errors::{AppResult, ApplicationError};
+use crate::app::policy::{Action, Policy};
+use crate::app::ports::{InvitationStatus, InvitationStore};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// RevokeInvitationUseCase - withdraws a pending invitation (admin only)
+///
+/// The invitation is kept, marked revoked, so its link stops working.
+pub struct RevokeInvitationUseCase {
+    invitation_store: Arc<dyn InvitationStore>,
+    policy: Arc<Policy>,
+}
+
+impl RevokeInvitationUseCase {
+    pub fn new(invitation_store: Arc<dyn InvitationStore>, policy: Arc<Policy>) -> Self {
+        Self {
+            invitation_store,
+            policy,
+        }
+    }
+
+    pub async fn execute(&self, id: Uuid, caller: &CallerContext) -> AppResult<()> {
+        // Authorization: only admins manage invitations
+        let invitation = find_authorized(
+            self.invitation_store.as_ref(),
+            &self.policy,
+            id,
+            Action::InvitationRevoke,
+            caller,
+        )
+        .await?;
+        if invitation.status != InvitationStatus::Pending {
+            return Err(ApplicationError::InvitationNotPending(
+                invitation.status.as_str().to_string(),
+            ));
+        }
+
+        // Accepted since it was read
+        if !self.invitation_store.revoke(id).await? {
+            return Err(ApplicationError::InvitationNotPending(
+                "no longer pending".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
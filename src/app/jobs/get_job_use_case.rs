@@ -0,0 +1,32 @@
+use super::JobResponse;
+use crate::app::caller_context::CallerContext;
+use crate::app::errors::{AppResult, ApplicationError};
+use crate::app::policy::{Action, Policy, Resource};
+use crate::app::ports::JobQueue;
+use std::sync::Arc;
+
+/// GetJobUseCase - a single background job (admin only)
+pub struct GetJobUseCase {
+    job_queue: Arc<dyn JobQueue>,
+    policy: Arc<Policy>,
+}
+
+impl GetJobUseCase {
+    pub fn new(job_queue: Arc<dyn JobQueue>, policy: Arc<Policy>) -> Self {
+        Self { job_queue, policy }
+    }
+
+    pub async fn execute(&self, id: i64, caller: &CallerContext) -> AppResult<JobResponse> {
+        // Authorization: only admins inspect jobs
+        self.policy
+            .authorize(caller, Action::JobRead, &Resource::of_type("job"))?;
+
+        let job = self
+            .job_queue
+            .find(id)
+            .await?
+            .ok_or(ApplicationError::JobNotFound)?;
+
+        Ok(JobResponse::from_job(&job))
+    }
+}
@@ -0,0 +1,122 @@
+use crate::app::ports::Job;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// Runs jobs of one type
+///
+/// Returning an error schedules a retry until the job's attempts run out.
+#[async_trait]
+pub trait JobHandler<J: Job>: Send + Sync {
+    async fn handle(&self, job: J) -> Result<(), String>;
+}
+
+/// Handler with the job type erased, fed the stored JSON payload
+#[async_trait]
+trait ErasedHandler: Send + Sync {
+    async fn handle(&self, payload: serde_json::Value) -> Result<(), String>;
+}
+
+struct Typed<J> {
+    handler: Arc<dyn JobHandler<J>>,
+    job: PhantomData<fn() -> J>,
+}
+
+#[async_trait]
+impl<J: Job> ErasedHandler for Typed<J> {
+    async fn handle(&self, payload: serde_json::Value) -> Result<(), String> {
+        let job: J = serde_json::from_value(payload)
+            .map_err(|e| format!("Invalid {} payload: {}", J::KIND, e))?;
+        self.handler.handle(job).await
+    }
+}
+
+/// JobRegistry - maps job kinds to their handlers
+///
+/// Workers only claim jobs whose kind is registered, so a process can run
+/// a subset of the job types.
+#[derive(Default)]
+pub struct JobRegistry {
+    handlers: HashMap<&'static str, Arc<dyn ErasedHandler>>,
+}
+
+impl JobRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register<J: Job>(mut self, handler: Arc<dyn JobHandler<J>>) -> Self {
+        let typed = Typed {
+            handler,
+            job: PhantomData,
+        };
+        if self.handlers.insert(J::KIND, Arc::new(typed)).is_some() {
+            panic!("Job kind {} is registered twice", J::KIND);
+        }
+        self
+    }
+
+    /// Kinds with a registered handler
+    pub fn kinds(&self) -> Vec<&'static str> {
+        let mut kinds: Vec<_> = self.handlers.keys().copied().collect();
+        kinds.sort_unstable();
+        kinds
+    }
+
+    /// Run a stored job through the handler of its kind
+    pub async fn run(&self, kind: &str, payload: serde_json::Value) -> Result<(), String> {
+        let handler = self
+            .handlers
+            .get(kind)
+            .ok_or_else(|| format!("No handler for job kind {}", kind))?;
+        handler.handle(payload).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    use std::sync::Mutex;
+
+    #[derive(Serialize, Deserialize)]
+    struct Cleanup {
+        days: u32,
+    }
+
+    impl Job for Cleanup {
+        const KIND: &'static str = "cleanup";
+    }
+
+    #[derive(Default)]
+    struct Recording {
+        seen: Mutex<Vec<u32>>,
+    }
+
+    #[async_trait]
+    impl JobHandler<Cleanup> for Recording {
+        async fn handle(&self, job: Cleanup) -> Result<(), String> {
+            self.seen.lock().unwrap().push(job.days);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_runs_typed_handler_by_kind() {
+        let handler = Arc::new(Recording::default());
+        let registry = JobRegistry::new().register::<Cleanup>(handler.clone());
+
+        assert_eq!(registry.kinds(), vec!["cleanup"]);
+        registry
+            .run("cleanup", serde_json::json!({ "days": 30 }))
+            .await
+            .unwrap();
+        assert_eq!(*handler.seen.lock().unwrap(), vec![30]);
+
+        let invalid = registry.run("cleanup", serde_json::json!({})).await;
+        assert!(invalid.unwrap_err().starts_with("Invalid cleanup payload"));
+        let unknown = registry.run("export", serde_json::json!({})).await;
+        assert_eq!(unknown.unwrap_err(), "No handler for job kind export");
+    }
+}
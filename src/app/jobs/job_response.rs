@@ -0,0 +1,37 @@
+use crate::app::ports::QueuedJob;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// JobResponse DTO - a background job as seen by administrators
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct JobResponse {
+    pub id: i64,
+    pub kind: String,
+    /// pending, running, completed or failed
+    pub status: String,
+    pub attempts: i32,
+    pub max_attempts: i32,
+    pub run_at: String,
+    pub last_error: Option<String>,
+    pub created_at: String,
+    pub finished_at: Option<String>,
+    #[schema(value_type = Object)]
+    pub payload: serde_json::Value,
+}
+
+impl JobResponse {
+    pub fn from_job(job: &QueuedJob) -> Self {
+        Self {
+            id: job.id,
+            kind: job.kind.clone(),
+            status: job.status.as_str().to_string(),
+            attempts: job.attempts,
+            max_attempts: job.max_attempts,
+            run_at: job.run_at.to_rfc3339(),
+            last_error: job.last_error.clone(),
+            created_at: job.created_at.to_rfc3339(),
+            finished_at: job.finished_at.map(|at| at.to_rfc3339()),
+            payload: job.payload.clone(),
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use super::{JobResponse, ListJobsQuery};
+use crate::app::caller_context::CallerContext;
+use crate::app::errors::{AppResult, ApplicationError};
+use crate::app::policy::{Action, Policy, Resource};
+use crate::app::ports::{JobQueue, JobStatus};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// ListJobsUseCase - background jobs, optionally filtered by status (admin only)
+pub struct ListJobsUseCase {
+    job_queue: Arc<dyn JobQueue>,
+    policy: Arc<Policy>,
+}
+
+impl ListJobsUseCase {
+    pub fn new(job_queue: Arc<dyn JobQueue>, policy: Arc<Policy>) -> Self {
+        Self { job_queue, policy }
+    }
+
+    pub async fn execute(
+        &self,
+        query: ListJobsQuery,
+        caller: &CallerContext,
+    ) -> AppResult<(Vec<JobResponse>, u64)> {
+        // Authorization: only admins inspect jobs
+        self.policy
+            .authorize(caller, Action::JobList, &Resource::of_type("job"))?;
+
+        let status = query
+            .status
+            .as_deref()
+            .map(JobStatus::from_str)
+            .transpose()
+            .map_err(ApplicationError::ValidationError)?;
+
+        let (jobs, total) = self
+            .job_queue
+            .list(status, query.page, query.rows_per_page)
+            .await?;
+
+        Ok((jobs.iter().map(JobResponse::from_job).collect(), total))
+    }
+}
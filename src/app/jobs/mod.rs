@@ -0,0 +1,20 @@
+pub mod get_job_use_case;
+pub mod job_registry;
+pub mod job_response;
+pub mod list_jobs_use_case;
+pub mod retry_job_use_case;
+
+pub use get_job_use_case::GetJobUseCase;
+pub use job_registry::{JobHandler, JobRegistry};
+pub use job_response::JobResponse;
+pub use list_jobs_use_case::ListJobsUseCase;
+pub use retry_job_use_case::RetryJobUseCase;
+
+/// Query for listing background jobs
+#[derive(Debug, Clone)]
+pub struct ListJobsQuery {
+    /// pending, running, completed or failed; all jobs when `None`
+    pub status: Option<String>,
+    pub page: u64,
+    pub rows_per_page: u64,
+}
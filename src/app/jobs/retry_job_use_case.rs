@@ -0,0 +1,36 @@
+use super::JobResponse;
+use crate::app::caller_context::CallerContext;
+use crate::app::errors::{AppResult, ApplicationError};
+use crate::app::policy::{Action, Policy, Resource};
+use crate::app::ports::JobQueue;
+use std::sync::Arc;
+
+/// RetryJobUseCase - queues a failed job again (admin only)
+pub struct RetryJobUseCase {
+    job_queue: Arc<dyn JobQueue>,
+    policy: Arc<Policy>,
+}
+
+impl RetryJobUseCase {
+    pub fn new(job_queue: Arc<dyn JobQueue>, policy: Arc<Policy>) -> Self {
+        Self { job_queue, policy }
+    }
+
+    pub async fn execute(&self, id: i64, caller: &CallerContext) -> AppResult<JobResponse> {
+        // Authorization: only admins retry jobs
+        self.policy
+            .authorize(caller, Action::JobRetry, &Resource::of_type("job"))?;
+
+        if let Some(job) = self.job_queue.retry(id).await? {
+            return Ok(JobResponse::from_job(&job));
+        }
+
+        // Tell a missing job apart from one that has not failed
+        match self.job_queue.find(id).await? {
+            Some(job) => Err(ApplicationError::JobNotRetryable(
+                job.status.as_str().to_string(),
+            )),
+            None => Err(ApplicationError::JobNotFound),
+        }
+    }
+}
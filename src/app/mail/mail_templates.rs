@@ -0,0 +1,217 @@
+use crate::app::ports::MailMessage;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// Subject, HTML and text bodies of one template in one locale
+///
+/// Each part may reference variables as `{{ name }}`; values are HTML-escaped
+/// in the HTML body only.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MailTemplate {
+    pub subject: String,
+    pub html: String,
+    pub text: String,
+}
+
+/// MailTemplates - named email templates with per-locale variants
+///
+/// A locale such as `pt-BR` falls back to `pt`, then to the default locale.
+pub struct MailTemplates {
+    templates: HashMap<(String, String), MailTemplate>,
+    default_locale: String,
+}
+
+impl MailTemplates {
+    pub fn new(default_locale: &str) -> Self {
+        Self {
+            templates: HashMap::new(),
+            default_locale: normalize_locale(default_locale),
+        }
+    }
+
+    pub fn insert(&mut self, name: &str, locale: &str, template: MailTemplate) {
+        self.templates
+            .insert((name.to_string(), normalize_locale(locale)), template);
+    }
+
+    /// Locales available for a template, sorted
+    pub fn locales(&self, name: &str) -> Vec<&str> {
+        let mut locales: Vec<_> = self
+            .templates
+            .keys()
+            .filter(|(template, _)| template == name)
+            .map(|(_, locale)| locale.as_str())
+            .collect();
+        locales.sort_unstable();
+        locales
+    }
+
+    /// Render template `name` for `to` in the closest available locale
+    pub fn render(
+        &self,
+        name: &str,
+        locale: Option<&str>,
+        to: &str,
+        vars: &Map<String, Value>,
+    ) -> Result<MailMessage, String> {
+        let template = self
+            .resolve(name, locale)
+            .ok_or_else(|| format!("Unknown mail template: {}", name))?;
+
+        Ok(MailMessage {
+            to: to.to_string(),
+            subject: substitute(&template.subject, vars, false)?,
+            html: substitute(&template.html, vars, true)?,
+            text: substitute(&template.text, vars, false)?,
+        })
+    }
+
+    fn resolve(&self, name: &str, locale: Option<&str>) -> Option<&MailTemplate> {
+        let mut candidates = Vec::with_capacity(3);
+        if let Some(locale) = locale.map(normalize_locale) {
+            let language = locale
+                .split_once('-')
+                .map(|(language, _)| language.to_string());
+            candidates.push(locale);
+            candidates.extend(language);
+        }
+        candidates.push(self.default_locale.clone());
+
+        candidates
+            .into_iter()
+            .find_map(|locale| self.templates.get(&(name.to_string(), locale)))
+    }
+}
+
+/// `pt_BR` and `PT-br` both become `pt-br`
+fn normalize_locale(locale: &str) -> String {
+    locale.trim().replace('_', "-").to_lowercase()
+}
+
+/// Replace each `{{ name }}` with its value; unknown names are an error so a
+/// typo never reaches a recipient
+fn substitute(template: &str, vars: &Map<String, Value>, escape: bool) -> Result<String, String> {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let end = after
+            .find("}}")
+            .ok_or_else(|| "Unclosed {{ in mail template".to_string())?;
+        let name = after[..end].trim();
+
+        let value = match vars.get(name) {
+            Some(Value::String(s)) => s.clone(),
+            Some(Value::Null) => String::new(),
+            Some(other) => other.to_string(),
+            None => return Err(format!("Missing mail template variable: {}", name)),
+        };
+        if escape {
+            out.push_str(&escape_html(&value));
+        } else {
+            out.push_str(&value);
+        }
+
+        rest = &after[end + 2..];
+    }
+    out.push_str(rest);
+
+    Ok(out)
+}
+
+fn escape_html(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn templates() -> MailTemplates {
+        let mut templates = MailTemplates::new("en");
+        templates.insert(
+            "welcome",
+            "en",
+            MailTemplate {
+                subject: "Welcome, {{ name }}".to_string(),
+                html: "<p>Hello {{name}}, you are number {{ count }}</p>".to_string(),
+                text: "Hello {{ name }}".to_string(),
+            },
+        );
+        templates.insert(
+            "welcome",
+            "pt",
+            MailTemplate {
+                subject: "Bem-vindo, {{ name }}".to_string(),
+                html: "<p>Olá {{ name }}</p>".to_string(),
+                text: "Olá {{ name }}".to_string(),
+            },
+        );
+        templates
+    }
+
+    fn vars(value: Value) -> Map<String, Value> {
+        value.as_object().unwrap().clone()
+    }
+
+    #[test]
+    fn test_renders_with_html_escaping() {
+        let message = templates()
+            .render(
+                "welcome",
+                None,
+                "a@example.com",
+                &vars(json!({ "name": "Ann <Admin>", "count": 3 })),
+            )
+            .unwrap();
+
+        assert_eq!(message.to, "a@example.com");
+        assert_eq!(message.subject, "Welcome, Ann <Admin>");
+        assert_eq!(
+            message.html,
+            "<p>Hello Ann &lt;Admin&gt;, you are number 3</p>"
+        );
+        assert_eq!(message.text, "Hello Ann <Admin>");
+    }
+
+    #[test]
+    fn test_locale_fallback() {
+        let templates = templates();
+        let name = vars(json!({ "name": "Ana", "count": 1 }));
+
+        let subject = |locale| {
+            templates
+                .render("welcome", locale, "a@example.com", &name)
+                .unwrap()
+                .subject
+        };
+        assert_eq!(subject(Some("pt_BR")), "Bem-vindo, Ana");
+        assert_eq!(subject(Some("PT")), "Bem-vindo, Ana");
+        assert_eq!(subject(Some("de-DE")), "Welcome, Ana");
+        assert_eq!(templates.locales("welcome"), vec!["en", "pt"]);
+    }
+
+    #[test]
+    fn test_missing_template_or_variable() {
+        let templates = templates();
+
+        let unknown = templates.render("reset", None, "a@example.com", &Map::new());
+        assert_eq!(unknown.unwrap_err(), "Unknown mail template: reset");
+        let missing = templates.render("welcome", None, "a@example.com", &Map::new());
+        assert_eq!(missing.unwrap_err(), "Missing mail template variable: name");
+    }
+}
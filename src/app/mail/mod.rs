@@ -0,0 +1,5 @@
+pub mod mail_templates;
+pub mod send_mail_job;
+
+pub use mail_templates::{MailTemplate, MailTemplates};
+pub use send_mail_job::{SendMail, SendMailHandler};
@@ -0,0 +1,46 @@
+use super::MailTemplates;
+use crate::app::jobs::JobHandler;
+use crate::app::ports::{Job, Mailer};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::sync::Arc;
+
+/// Background job rendering a template and sending it to one recipient
+///
+/// Enqueue it instead of calling the mailer from a request, so a slow or
+/// failing mail server only delays the email and failures are retried.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SendMail {
+    pub template: String,
+    /// Preferred locale, e.g. `pt-BR`; the default locale when `None`
+    pub locale: Option<String>,
+    pub to: String,
+    pub vars: Map<String, Value>,
+}
+
+impl Job for SendMail {
+    const KIND: &'static str = "mail.send";
+}
+
+/// Runs `SendMail` jobs through the configured mailer
+pub struct SendMailHandler {
+    templates: Arc<MailTemplates>,
+    mailer: Arc<dyn Mailer>,
+}
+
+impl SendMailHandler {
+    pub fn new(templates: Arc<MailTemplates>, mailer: Arc<dyn Mailer>) -> Self {
+        Self { templates, mailer }
+    }
+}
+
+#[async_trait]
+impl JobHandler<SendMail> for SendMailHandler {
+    async fn handle(&self, job: SendMail) -> Result<(), String> {
+        let message =
+            self.templates
+                .render(&job.template, job.locale.as_deref(), &job.to, &job.vars)?;
+        self.mailer.send(&message).await
+    }
+}
@@ -1,9 +1,19 @@
 pub mod auth;
+pub mod cache;
 pub mod caller_context;
 pub mod errors;
+pub mod events;
+pub mod group;
+pub mod health;
+pub mod invitation;
+pub mod jobs;
+pub mod mail;
+pub mod organization;
+pub mod policy;
 pub mod ports;
 pub mod user;
+pub mod webhook;
 
 pub use caller_context::CallerContext;
 pub use errors::ApplicationError;
-pub use ports::TokenService;
+pub use ports::{HealthCheck, TokenService};
@@ -0,0 +1,81 @@
+use super::{AddMemberCommand, MembershipResponse, find_organization};
+use crate::app::caller_context::CallerContext;
+use crate::app::errors::{AppResult, ApplicationError};
+use crate::app::policy::{Action, Policy, Resource};
+use crate::domain::organization::{Membership, OrganizationRepository, TenantScope};
+use crate::domain::shared::PublicUserId;
+use crate::domain::user::{Role, UserRepository};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// AddMemberUseCase - adds an existing user to an organization (platform admin only)
+///
+/// Organization admins cannot add users from elsewhere on the platform, or
+/// they could pull in any user to see them; they create members instead.
+/// Adding a member again replaces the roles they hold there.
+pub struct AddMemberUseCase {
+    user_repository: Arc<dyn UserRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+    policy: Arc<Policy>,
+}
+
+impl AddMemberUseCase {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        organization_repository: Arc<dyn OrganizationRepository>,
+        policy: Arc<Policy>,
+    ) -> Self {
+        Self {
+            user_repository,
+            organization_repository,
+            policy,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        slug: &str,
+        user_id: PublicUserId,
+        command: AddMemberCommand,
+        caller: &CallerContext,
+    ) -> AppResult<MembershipResponse> {
+        // Authorization: membership spans tenants, so only the platform manages it
+        self.policy.authorize(
+            caller,
+            Action::OrganizationAddMember,
+            &Resource::of_type("organization"),
+        )?;
+
+        let roles = command
+            .roles
+            .iter()
+            .map(|role| Role::from_str(role))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(ApplicationError::ValidationError)?;
+
+        let organization = find_organization(self.organization_repository.as_ref(), slug).await?;
+        let organization_id = organization
+            .id()
+            .ok_or(ApplicationError::OrganizationNotFound)?;
+        let user = self
+            .user_repository
+            .find_by_public_id(user_id, TenantScope::Platform)
+            .await?
+            .ok_or(ApplicationError::UserNotFound)?;
+        let internal_id = user.id().ok_or(ApplicationError::UserNotFound)?;
+
+        let mut membership = Membership::new(organization_id, internal_id);
+        for role in roles {
+            membership.grant(role);
+        }
+        self.organization_repository
+            .save_membership(&membership)
+            .await?;
+
+        Ok(MembershipResponse::from_domain(
+            &organization,
+            user_id,
+            &membership,
+        ))
+    }
+}
@@ -0,0 +1,49 @@
+use super::{CreateOrganizationCommand, OrganizationResponse};
+use crate::app::caller_context::CallerContext;
+use crate::app::errors::{AppResult, ApplicationError};
+use crate::app::policy::{Action, Policy, Resource};
+use crate::domain::organization::{Organization, OrganizationRepository, OrganizationSlug};
+use std::sync::Arc;
+
+/// CreateOrganizationUseCase - adds a tenant (platform admin only)
+pub struct CreateOrganizationUseCase {
+    organization_repository: Arc<dyn OrganizationRepository>,
+    policy: Arc<Policy>,
+}
+
+impl CreateOrganizationUseCase {
+    pub fn new(
+        organization_repository: Arc<dyn OrganizationRepository>,
+        policy: Arc<Policy>,
+    ) -> Self {
+        Self {
+            organization_repository,
+            policy,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        command: CreateOrganizationCommand,
+        caller: &CallerContext,
+    ) -> AppResult<OrganizationResponse> {
+        // Authorization: organizations are created by the platform, not by tenants
+        self.policy.authorize(
+            caller,
+            Action::OrganizationCreate,
+            &Resource::of_type("organization"),
+        )?;
+
+        let slug = OrganizationSlug::try_from(command.slug)?;
+        if self.organization_repository.exists_with_slug(&slug).await? {
+            return Err(ApplicationError::OrganizationAlreadyExists(
+                slug.to_string(),
+            ));
+        }
+
+        let mut organization = Organization::create(slug, command.name)?;
+        self.organization_repository.save(&mut organization).await?;
+
+        Ok(OrganizationResponse::from_domain(&organization))
+    }
+}
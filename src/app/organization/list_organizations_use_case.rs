@@ -0,0 +1,60 @@
+use super::OrganizationResponse;
+use crate::app::caller_context::CallerContext;
+use crate::app::errors::{AppResult, ApplicationError};
+use crate::app::policy::{Action, Policy, Resource};
+use crate::domain::organization::{OrganizationRepository, TenantScope};
+use crate::domain::user::UserRepository;
+use std::sync::Arc;
+
+/// ListOrganizationsUseCase - lists organizations the caller can act in
+///
+/// Platform admins see every organization; other users see those they are
+/// a member of.
+pub struct ListOrganizationsUseCase {
+    user_repository: Arc<dyn UserRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+    policy: Arc<Policy>,
+}
+
+impl ListOrganizationsUseCase {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        organization_repository: Arc<dyn OrganizationRepository>,
+        policy: Arc<Policy>,
+    ) -> Self {
+        Self {
+            user_repository,
+            organization_repository,
+            policy,
+        }
+    }
+
+    pub async fn execute(&self, caller: &CallerContext) -> AppResult<Vec<OrganizationResponse>> {
+        // Callers who may not list every organization see those they belong to
+        let list_all = self.policy.allows(
+            caller,
+            Action::OrganizationList,
+            &Resource::of_type("organization"),
+        );
+        let organizations = if list_all {
+            self.organization_repository.list().await?
+        } else {
+            // Service callers belong to no organization
+            let public_id = caller.user_id.ok_or_else(|| {
+                ApplicationError::Forbidden(Action::OrganizationList.denial().to_string())
+            })?;
+            let user = self
+                .user_repository
+                .find_by_public_id(public_id, TenantScope::Platform)
+                .await?
+                .ok_or(ApplicationError::UserNotFound)?;
+            let user_id = user.id().ok_or(ApplicationError::UserNotFound)?;
+            self.organization_repository.list_for_user(user_id).await?
+        };
+
+        Ok(organizations
+            .iter()
+            .map(OrganizationResponse::from_domain)
+            .collect())
+    }
+}
@@ -0,0 +1,68 @@
+pub mod add_member_use_case;
+pub mod create_organization_use_case;
+pub mod list_organizations_use_case;
+pub mod organization_response;
+pub mod remove_member_use_case;
+
+pub use add_member_use_case::AddMemberUseCase;
+pub use create_organization_use_case::CreateOrganizationUseCase;
+pub use list_organizations_use_case::ListOrganizationsUseCase;
+pub use organization_response::{MembershipResponse, OrganizationResponse};
+pub use remove_member_use_case::RemoveMemberUseCase;
+
+use crate::app::errors::{AppResult, ApplicationError};
+use crate::domain::organization::{Organization, OrganizationRepository, OrganizationSlug};
+use crate::domain::shared::OrganizationId;
+use serde::Deserialize;
+use std::collections::HashMap;
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// Command for creating an organization
+#[derive(Debug, Clone, Deserialize, ToSchema, Validate)]
+pub struct CreateOrganizationCommand {
+    /// Lowercase letters, digits and inner hyphens; used in the
+    /// `X-Organization` header and as a subdomain
+    #[schema(example = "acme")]
+    pub slug: String,
+    #[validate(length(min = 1))]
+    pub name: String,
+}
+
+/// Command for adding a user to an organization
+#[derive(Debug, Clone, Default, Deserialize, ToSchema, Validate)]
+pub struct AddMemberCommand {
+    /// Roles to hold in the organization besides `user`
+    #[serde(default)]
+    #[schema(example = json!(["admin"]))]
+    pub roles: Vec<String>,
+}
+
+/// Find an organization by the slug a caller addressed it with
+pub(crate) async fn find_organization(
+    organizations: &dyn OrganizationRepository,
+    slug: &str,
+) -> AppResult<Organization> {
+    let slug = OrganizationSlug::try_from(slug.to_string())?;
+    organizations
+        .find_by_slug(&slug)
+        .await?
+        .ok_or(ApplicationError::OrganizationNotFound)
+}
+
+/// The slugs of the given organizations, for presenting what belongs to them
+pub(crate) async fn organization_slugs(
+    organizations: &dyn OrganizationRepository,
+    ids: &[OrganizationId],
+) -> AppResult<HashMap<OrganizationId, String>> {
+    let mut slugs = HashMap::new();
+    for &organization_id in ids {
+        if slugs.contains_key(&organization_id) {
+            continue;
+        }
+        if let Some(organization) = organizations.find_by_id(organization_id).await? {
+            slugs.insert(organization_id, organization.slug().to_string());
+        }
+    }
+    Ok(slugs)
+}
@@ -0,0 +1,49 @@
+use crate::domain::organization::{Membership, Organization};
+use crate::domain::shared::PublicUserId;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// OrganizationResponse DTO - for API responses
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct OrganizationResponse {
+    pub slug: String,
+    pub name: String,
+    pub created_at: String,
+}
+
+impl OrganizationResponse {
+    /// Convert from domain Organization entity
+    pub fn from_domain(organization: &Organization) -> Self {
+        Self {
+            slug: organization.slug().to_string(),
+            name: organization.name().to_string(),
+            created_at: organization.created_at().to_string(),
+        }
+    }
+}
+
+/// MembershipResponse DTO - a user's place in an organization
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct MembershipResponse {
+    /// Organization slug
+    pub organization: String,
+    /// Public user ID (UUID)
+    pub user_id: String,
+    /// Roles held in the organization
+    pub roles: Vec<String>,
+}
+
+impl MembershipResponse {
+    /// Convert from domain Membership, given the identifiers it is addressed by
+    pub fn from_domain(
+        organization: &Organization,
+        user_id: PublicUserId,
+        membership: &Membership,
+    ) -> Self {
+        Self {
+            organization: organization.slug().to_string(),
+            user_id: user_id.to_string(),
+            roles: membership.roles().iter().map(|r| r.to_string()).collect(),
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use super::find_organization;
+use crate::app::caller_context::CallerContext;
+use crate::app::errors::{AppResult, ApplicationError};
+use crate::app::policy::{Action, Policy, Resource};
+use crate::domain::organization::{OrganizationRepository, TenantScope};
+use crate::domain::shared::PublicUserId;
+use crate::domain::user::UserRepository;
+use std::sync::Arc;
+
+/// RemoveMemberUseCase - removes a user from an organization
+///
+/// Allowed to platform admins and to admins of that organization; to admins
+/// of another organization it is not found. The user account itself is kept.
+pub struct RemoveMemberUseCase {
+    user_repository: Arc<dyn UserRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+    policy: Arc<Policy>,
+}
+
+impl RemoveMemberUseCase {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        organization_repository: Arc<dyn OrganizationRepository>,
+        policy: Arc<Policy>,
+    ) -> Self {
+        Self {
+            user_repository,
+            organization_repository,
+            policy,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        slug: &str,
+        user_id: PublicUserId,
+        caller: &CallerContext,
+    ) -> AppResult<()> {
+        let organization = find_organization(self.organization_repository.as_ref(), slug).await?;
+        self.policy.authorize(
+            caller,
+            Action::OrganizationRemoveMember,
+            &Resource::organization(organization.slug()),
+        )?;
+
+        // Admins of another organization learn nothing about this one
+        let organization_id = organization
+            .id()
+            .filter(|&id| caller.organization.is_none_or(|acting_in| acting_in == id))
+            .ok_or(ApplicationError::OrganizationNotFound)?;
+
+        // An organization admin leaving could leave nobody to manage it
+        if caller.organization.is_some() && caller.is_owner(user_id) {
+            return Err(ApplicationError::Forbidden(
+                "Administrators cannot remove themselves from their organization".to_string(),
+            ));
+        }
+
+        let user = self
+            .user_repository
+            .find_by_public_id(user_id, TenantScope::Organization(organization_id))
+            .await?
+            .ok_or(ApplicationError::MembershipNotFound)?;
+        let internal_id = user.id().ok_or(ApplicationError::UserNotFound)?;
+
+        if !self
+            .organization_repository
+            .remove_membership(organization_id, internal_id)
+            .await?
+        {
+            return Err(ApplicationError::MembershipNotFound);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,225 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Action - what a caller asks to do, as named in policy rules
+///
+/// Names are `<resource type>:<verb>`; rules may match every action on a
+/// type with `<resource type>:*`, or every action with `*`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Action {
+    UserCreate,
+    UserRead,
+    /// List every user in the caller's scope
+    UserList,
+    /// List the members of the caller's teams
+    UserListTeammates,
+    UserUpdate,
+    /// Change a user's email, which they sign in with
+    UserChangeEmail,
+    UserGrantRole,
+    UserRevokeRole,
+    UserResetPassword,
+    UserRevokeTokens,
+    UserListGroups,
+    GroupCreate,
+    GroupList,
+    GroupUpdate,
+    GroupDelete,
+    GroupAddMember,
+    GroupRemoveMember,
+    GroupListMembers,
+    InvitationCreate,
+    InvitationList,
+    InvitationResend,
+    InvitationRevoke,
+    OrganizationCreate,
+    /// List every organization, rather than those the caller belongs to
+    OrganizationList,
+    OrganizationAddMember,
+    OrganizationRemoveMember,
+    WebhookCreate,
+    WebhookList,
+    WebhookDelete,
+    WebhookListDeliveries,
+    WebhookRedeliver,
+    JobList,
+    JobRead,
+    JobRetry,
+    CacheReadStats,
+}
+
+impl Action {
+    /// Every action, for validating policies
+    pub const ALL: [Action; 35] = [
+        Action::UserCreate,
+        Action::UserRead,
+        Action::UserList,
+        Action::UserListTeammates,
+        Action::UserUpdate,
+        Action::UserChangeEmail,
+        Action::UserGrantRole,
+        Action::UserRevokeRole,
+        Action::UserResetPassword,
+        Action::UserRevokeTokens,
+        Action::UserListGroups,
+        Action::GroupCreate,
+        Action::GroupList,
+        Action::GroupUpdate,
+        Action::GroupDelete,
+        Action::GroupAddMember,
+        Action::GroupRemoveMember,
+        Action::GroupListMembers,
+        Action::InvitationCreate,
+        Action::InvitationList,
+        Action::InvitationResend,
+        Action::InvitationRevoke,
+        Action::OrganizationCreate,
+        Action::OrganizationList,
+        Action::OrganizationAddMember,
+        Action::OrganizationRemoveMember,
+        Action::WebhookCreate,
+        Action::WebhookList,
+        Action::WebhookDelete,
+        Action::WebhookListDeliveries,
+        Action::WebhookRedeliver,
+        Action::JobList,
+        Action::JobRead,
+        Action::JobRetry,
+        Action::CacheReadStats,
+    ];
+
+    /// Returns the name used in policy files and the explain endpoint
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Action::UserCreate => "user:create",
+            Action::UserRead => "user:read",
+            Action::UserList => "user:list",
+            Action::UserListTeammates => "user:list_teammates",
+            Action::UserUpdate => "user:update",
+            Action::UserChangeEmail => "user:change_email",
+            Action::UserGrantRole => "user:grant_role",
+            Action::UserRevokeRole => "user:revoke_role",
+            Action::UserResetPassword => "user:reset_password",
+            Action::UserRevokeTokens => "user:revoke_tokens",
+            Action::UserListGroups => "user:list_groups",
+            Action::GroupCreate => "group:create",
+            Action::GroupList => "group:list",
+            Action::GroupUpdate => "group:update",
+            Action::GroupDelete => "group:delete",
+            Action::GroupAddMember => "group:add_member",
+            Action::GroupRemoveMember => "group:remove_member",
+            Action::GroupListMembers => "group:list_members",
+            Action::InvitationCreate => "invitation:create",
+            Action::InvitationList => "invitation:list",
+            Action::InvitationResend => "invitation:resend",
+            Action::InvitationRevoke => "invitation:revoke",
+            Action::OrganizationCreate => "organization:create",
+            Action::OrganizationList => "organization:list",
+            Action::OrganizationAddMember => "organization:add_member",
+            Action::OrganizationRemoveMember => "organization:remove_member",
+            Action::WebhookCreate => "webhook:create",
+            Action::WebhookList => "webhook:list",
+            Action::WebhookDelete => "webhook:delete",
+            Action::WebhookListDeliveries => "webhook:list_deliveries",
+            Action::WebhookRedeliver => "webhook:redeliver",
+            Action::JobList => "job:list",
+            Action::JobRead => "job:read",
+            Action::JobRetry => "job:retry",
+            Action::CacheReadStats => "cache:read_stats",
+        }
+    }
+
+    /// The type of resource the action is on
+    pub fn resource_type(&self) -> &'static str {
+        self.as_str().split_once(':').map_or("", |(kind, _)| kind)
+    }
+
+    /// Whether a rule's action pattern covers this action
+    pub fn matches(&self, pattern: &str) -> bool {
+        pattern == "*"
+            || pattern == self.as_str()
+            || pattern
+                .strip_suffix(":*")
+                .is_some_and(|kind| kind == self.resource_type())
+    }
+
+    /// What a denied caller is told
+    pub fn denial(&self) -> &'static str {
+        match self {
+            Action::UserCreate => "Only administrators can create users",
+            Action::UserRead => "You can only view your own profile",
+            Action::UserList | Action::UserListTeammates => {
+                "Only administrators can list all users"
+            }
+            Action::UserUpdate => "You can only update your own profile",
+            Action::UserChangeEmail => "You cannot change this user's email",
+            Action::UserGrantRole | Action::UserRevokeRole => {
+                "Only administrators can manage roles"
+            }
+            Action::UserResetPassword => "Only administrators can reset passwords",
+            Action::UserRevokeTokens => "Only administrators can revoke tokens",
+            Action::UserListGroups => "You can only view your own groups",
+            Action::GroupCreate
+            | Action::GroupUpdate
+            | Action::GroupDelete
+            | Action::GroupAddMember
+            | Action::GroupRemoveMember => "Only administrators can manage groups",
+            Action::GroupList => "Only administrators can list groups",
+            Action::GroupListMembers => "You can only list the members of your teams",
+            Action::InvitationCreate => "Only administrators can invite users",
+            Action::InvitationList => "Only administrators can list invitations",
+            Action::InvitationResend | Action::InvitationRevoke => {
+                "Only administrators can manage invitations"
+            }
+            Action::OrganizationCreate => "Only platform administrators can create organizations",
+            Action::OrganizationList => "Only platform administrators can list all organizations",
+            Action::OrganizationAddMember => "Only platform administrators can add members",
+            Action::OrganizationRemoveMember => {
+                "Only administrators of the organization can remove members"
+            }
+            Action::WebhookCreate
+            | Action::WebhookList
+            | Action::WebhookDelete
+            | Action::WebhookListDeliveries
+            | Action::WebhookRedeliver => "Only platform administrators can manage webhooks",
+            Action::JobList | Action::JobRead | Action::JobRetry => {
+                "Only platform administrators can manage jobs"
+            }
+            Action::CacheReadStats => "Only platform administrators can inspect caches",
+        }
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for Action {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Action::ALL
+            .into_iter()
+            .find(|action| action.as_str() == s)
+            .ok_or_else(|| format!("Unknown action: {}", s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_patterns_match_by_name_type_or_wildcard() {
+        assert!(Action::UserRead.matches("user:read"));
+        assert!(Action::UserRead.matches("user:*"));
+        assert!(Action::UserRead.matches("*"));
+        assert!(!Action::UserRead.matches("user:update"));
+        assert!(!Action::UserRead.matches("group:*"));
+        assert!(Action::GroupListMembers.matches("group:*"));
+        assert_eq!("user:list_groups".parse(), Ok(Action::UserListGroups));
+        assert!("user:delete".parse::<Action>().is_err());
+    }
+}
@@ -0,0 +1,48 @@
+use super::rules::{Effect, Op};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use utoipa::ToSchema;
+
+/// Decision - whether a caller may take an action, and which rules said so
+///
+/// Only the caller's own attributes are shown; those of the resource may
+/// describe a user the caller is not allowed to see.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Decision {
+    #[schema(example = "user:read")]
+    pub action: String,
+    pub allowed: bool,
+    /// The rule that decided: the first holding `deny` rule, else the first
+    /// holding `allow` rule; `None` when no rule holds and access is denied
+    /// by default
+    pub decided_by: Option<String>,
+    /// The caller's attributes, as `subject.<name>` in rules
+    #[schema(value_type = Object)]
+    pub subject: BTreeMap<String, Value>,
+    /// Every rule covering the action, in policy order
+    pub rules: Vec<RuleTrace>,
+}
+
+/// How a rule covering the action evaluated
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RuleTrace {
+    pub id: String,
+    pub description: Option<String>,
+    pub effect: Effect,
+    /// Whether every condition held
+    pub holds: bool,
+    pub conditions: Vec<ConditionTrace>,
+}
+
+/// How one condition of a rule evaluated
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ConditionTrace {
+    #[schema(example = "subject.roles")]
+    pub attr: String,
+    pub op: Op,
+    /// The operand as declared in the policy
+    #[schema(value_type = Object)]
+    pub value: Option<Value>,
+    pub holds: bool,
+}
@@ -0,0 +1,46 @@
+use super::{Action, Decision, ExplainDecisionQuery, Policy, Resource, UserResources};
+use crate::app::caller_context::CallerContext;
+use crate::app::errors::{AppResult, ApplicationError};
+use std::sync::Arc;
+
+/// ExplainDecisionUseCase - shows how the policy decides an action for the caller
+///
+/// Callers only explain their own decisions, for debugging denials, and
+/// only on users they may read.
+pub struct ExplainDecisionUseCase {
+    policy: Arc<Policy>,
+    user_resources: Arc<UserResources>,
+}
+
+impl ExplainDecisionUseCase {
+    pub fn new(policy: Arc<Policy>, user_resources: Arc<UserResources>) -> Self {
+        Self {
+            policy,
+            user_resources,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        query: ExplainDecisionQuery,
+        caller: &CallerContext,
+    ) -> AppResult<Decision> {
+        let action = query
+            .action
+            .parse::<Action>()
+            .map_err(ApplicationError::ValidationError)?;
+
+        let resource = match (query.user_id, action.resource_type()) {
+            (Some(user_id), "user") => self.user_resources.find(user_id, caller).await?.1,
+            (Some(_), _) => {
+                return Err(ApplicationError::ValidationError(format!(
+                    "{} is not an action on a user",
+                    action
+                )));
+            }
+            (None, kind) => Resource::of_type(kind),
+        };
+
+        self.policy.explain(caller, action, &resource)
+    }
+}
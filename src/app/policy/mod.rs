@@ -0,0 +1,33 @@
+//! Authorization policy
+//!
+//! Who may do what is declared as rules in a policy file loaded at startup
+//! rather than checked by hand in each use case. Rules test attributes of
+//! the caller (`subject.*`, from `CallerContext`) and of the resource the
+//! action is on (`resource.*`); use cases ask `Policy::authorize` before
+//! acting, and `Policy::evaluate` explains how a decision was reached.
+
+pub mod action;
+pub mod decision;
+pub mod explain_decision_use_case;
+pub mod resource;
+pub mod rules;
+pub mod user_resources;
+
+pub use action::Action;
+pub use decision::{ConditionTrace, Decision, RuleTrace};
+pub use explain_decision_use_case::ExplainDecisionUseCase;
+pub use resource::Resource;
+pub use rules::{Effect, Op, Policy};
+pub use user_resources::UserResources;
+
+use crate::domain::shared::PublicUserId;
+
+/// Query for explaining whether the caller may take an action
+#[derive(Debug, Clone)]
+pub struct ExplainDecisionQuery {
+    /// Action name, e.g. `user:read`
+    pub action: String,
+    /// User the action is on; `None` for actions on all resources of the
+    /// action's type
+    pub user_id: Option<PublicUserId>,
+}
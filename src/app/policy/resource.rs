@@ -0,0 +1,147 @@
+use crate::app::caller_context::CallerContext;
+use crate::domain::group::Group;
+use crate::domain::organization::{Organization, OrganizationSlug};
+use crate::domain::shared::{GroupId, OrganizationId, PublicUserId};
+use crate::domain::user::Role;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashSet};
+use uuid::Uuid;
+
+/// Attributes of the caller that rules may test, as `subject.<name>`
+///
+/// `platform` is true outside of an organization.
+pub const SUBJECT_ATTRIBUTES: [&str; 5] = ["id", "roles", "service", "platform", "teams"];
+
+/// Attributes of the resource that rules may test, as `resource.<name>`
+///
+/// Every resource has a `type`, the part of its actions' names before the
+/// colon, and one resource of a type an `id`. A user's `roles` are those
+/// held on the platform, `organizations` the slugs of those they belong to
+/// and `other_organizations` the slugs of those besides the one the caller
+/// acts in.
+pub const RESOURCE_ATTRIBUTES: [&str; 6] = [
+    "type",
+    "id",
+    "roles",
+    "organizations",
+    "other_organizations",
+    "groups",
+];
+
+/// Resource - what an action is on, described by its attributes
+///
+/// Attributes a resource lacks are null, as are those of a user who does
+/// not exist, so rules about them never hold.
+#[derive(Debug, Clone, Default)]
+pub struct Resource {
+    attributes: BTreeMap<&'static str, Value>,
+}
+
+impl Resource {
+    /// The resources of a type in the caller's scope as a whole, for
+    /// creating, listing and actions on the service itself
+    pub fn of_type(kind: &'static str) -> Self {
+        Self::default().with("type", Value::from(kind))
+    }
+
+    /// The users of the caller's scope as a whole, for creating and listing
+    pub fn users() -> Self {
+        Self::of_type("user")
+    }
+
+    /// One user, known only by ID until the attributes below are added
+    pub fn user(id: PublicUserId) -> Self {
+        Self::users().with("id", Value::from(id.to_string()))
+    }
+
+    /// One group of the caller's scope
+    pub fn group(id: GroupId) -> Self {
+        Self::of_type("group").with("id", Value::from(id.to_string()))
+    }
+
+    /// One invitation of the caller's scope
+    pub fn invitation(id: Uuid) -> Self {
+        Self::of_type("invitation").with("id", Value::from(id.to_string()))
+    }
+
+    /// One organization, known by its slug
+    pub fn organization(slug: &OrganizationSlug) -> Self {
+        Self::of_type("organization").with("id", Value::from(slug.to_string()))
+    }
+
+    /// The user's platform roles
+    pub fn with_roles(self, roles: &HashSet<Role>) -> Self {
+        self.with(
+            "roles",
+            sorted(roles.iter().map(|role| role.as_str().to_string())),
+        )
+    }
+
+    /// The organizations the user belongs to, and which of them are not the
+    /// one the caller acts in
+    pub fn with_organizations(
+        self,
+        organizations: &[Organization],
+        acting_in: Option<OrganizationId>,
+    ) -> Self {
+        let slugs = |others_only: bool| {
+            sorted(
+                organizations
+                    .iter()
+                    .filter(|organization| !others_only || organization.id() != acting_in)
+                    .map(|organization| organization.slug().to_string()),
+            )
+        };
+        self.with("organizations", slugs(false))
+            .with("other_organizations", slugs(true))
+    }
+
+    /// The groups the user belongs to in the caller's scope
+    pub fn with_groups(self, groups: &[Group]) -> Self {
+        self.with(
+            "groups",
+            sorted(groups.iter().map(|group| group.id().to_string())),
+        )
+    }
+
+    fn with(mut self, name: &'static str, value: Value) -> Self {
+        self.attributes.insert(name, value);
+        self
+    }
+
+    /// The value of `name`, null when the resource lacks it
+    pub fn attribute(&self, name: &str) -> Value {
+        self.attributes.get(name).cloned().unwrap_or(Value::Null)
+    }
+}
+
+/// The caller's attributes, keyed by their names in `SUBJECT_ATTRIBUTES`
+pub fn subject_attributes(caller: &CallerContext) -> BTreeMap<String, Value> {
+    let optional = |value: Option<String>| value.map_or(Value::Null, Value::from);
+    BTreeMap::from([
+        (
+            "id".to_string(),
+            optional(caller.user_id.map(|id| id.to_string())),
+        ),
+        (
+            "roles".to_string(),
+            sorted(caller.roles.iter().map(|role| role.as_str().to_string())),
+        ),
+        ("service".to_string(), optional(caller.service.clone())),
+        (
+            "platform".to_string(),
+            Value::from(caller.organization.is_none()),
+        ),
+        (
+            "teams".to_string(),
+            sorted(caller.teams.iter().map(|id| id.to_string())),
+        ),
+    ])
+}
+
+/// Sets as arrays in a stable order, so explanations read the same each time
+fn sorted(values: impl Iterator<Item = String>) -> Value {
+    let mut values: Vec<String> = values.collect();
+    values.sort();
+    Value::from(values)
+}
@@ -0,0 +1,535 @@
+use super::action::Action;
+use super::decision::{ConditionTrace, Decision, RuleTrace};
+use super::resource::{RESOURCE_ATTRIBUTES, Resource, SUBJECT_ATTRIBUTES, subject_attributes};
+use crate::app::caller_context::CallerContext;
+use crate::app::errors::{AppResult, ApplicationError};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashSet;
+use utoipa::ToSchema;
+
+/// Policy - the rules deciding who may take which action on what
+///
+/// A caller may take an action when an `allow` rule covering it holds and
+/// no `deny` rule covering it does; when no rule holds, access is denied.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Policy {
+    rules: Vec<Rule>,
+}
+
+/// A rule granting or refusing actions to callers meeting every condition
+///
+/// The description of a `deny` rule is what the callers it refuses are told.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Rule {
+    id: String,
+    #[serde(default)]
+    description: Option<String>,
+    effect: Effect,
+    /// Action names, `<resource type>:*` or `*`
+    actions: Vec<String>,
+    /// Conditions that must all hold; a rule without any always holds
+    #[serde(default)]
+    when: Vec<Condition>,
+}
+
+/// Whether a holding rule grants or refuses the action
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Effect {
+    Allow,
+    Deny,
+}
+
+/// A test of one attribute, against a literal or another attribute
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Condition {
+    /// `subject.<name>` or `resource.<name>`
+    attr: String,
+    op: Op,
+    #[serde(default)]
+    value: Option<Operand>,
+}
+
+/// How a condition compares
+///
+/// Null attributes never compare true, except that `ne` holds between null
+/// and a value: a service caller, without an ID, is not the user it acts on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Op {
+    /// Equal to the value
+    Eq,
+    /// Different from the value
+    Ne,
+    /// One of the values in an array
+    In,
+    /// An array holding the value
+    Contains,
+    /// An array sharing an element with another array
+    Intersects,
+    /// Not null, nor an empty array; takes no value
+    Exists,
+}
+
+/// Right-hand side of a condition: `{"attr": "<name>"}` or a literal
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+enum Operand {
+    Attr { attr: String },
+    Literal(Value),
+}
+
+impl Policy {
+    /// Parse and validate a policy document
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let policy: Policy = serde_json::from_str(content).map_err(|e| e.to_string())?;
+
+        // Rules are looked up by id when explaining decisions; reject typos
+        // in names up front rather than letting a rule silently never hold
+        let mut ids = HashSet::new();
+        for rule in &policy.rules {
+            if rule.id.trim().is_empty() {
+                return Err("rule id must not be empty".to_string());
+            }
+            if !ids.insert(rule.id.as_str()) {
+                return Err(format!("duplicate rule id: {}", rule.id));
+            }
+            rule.validate()
+                .map_err(|e| format!("rule {}: {}", rule.id, e))?;
+        }
+        Ok(policy)
+    }
+
+    /// Decide whether the caller may take the action, tracing every rule covering it
+    pub fn evaluate(
+        &self,
+        caller: &CallerContext,
+        action: Action,
+        resource: &Resource,
+    ) -> Decision {
+        let subject = subject_attributes(caller);
+        let lookup = |name: &str| match name.split_once('.') {
+            Some(("subject", name)) => subject.get(name).cloned().unwrap_or(Value::Null),
+            Some(("resource", name)) => resource.attribute(name),
+            _ => Value::Null,
+        };
+
+        let rules: Vec<RuleTrace> = self
+            .rules
+            .iter()
+            .filter(|rule| rule.actions.iter().any(|pattern| action.matches(pattern)))
+            .map(|rule| {
+                let conditions: Vec<ConditionTrace> = rule
+                    .when
+                    .iter()
+                    .map(|condition| ConditionTrace {
+                        attr: condition.attr.clone(),
+                        op: condition.op,
+                        value: condition
+                            .value
+                            .as_ref()
+                            .and_then(|operand| serde_json::to_value(operand).ok()),
+                        holds: condition.holds(&lookup),
+                    })
+                    .collect();
+                RuleTrace {
+                    id: rule.id.clone(),
+                    description: rule.description.clone(),
+                    effect: rule.effect,
+                    holds: conditions.iter().all(|condition| condition.holds),
+                    conditions,
+                }
+            })
+            .collect();
+
+        let holding = |effect: Effect| {
+            rules
+                .iter()
+                .find(|rule| rule.holds && rule.effect == effect)
+                .map(|rule| rule.id.clone())
+        };
+        let (allowed, decided_by) = match holding(Effect::Deny) {
+            Some(id) => (false, Some(id)),
+            None => {
+                let allowed_by = holding(Effect::Allow);
+                (allowed_by.is_some(), allowed_by)
+            }
+        };
+
+        Decision {
+            action: action.to_string(),
+            allowed,
+            decided_by,
+            subject,
+            rules,
+        }
+    }
+
+    /// Explain the decision on an action, for a caller who may see the resource
+    ///
+    /// Traces show which conditions on the resource hold, so explaining an
+    /// action on a user is refused like reading them would be.
+    pub fn explain(
+        &self,
+        caller: &CallerContext,
+        action: Action,
+        resource: &Resource,
+    ) -> AppResult<Decision> {
+        if resource.attribute("type") == "user" && !resource.attribute("id").is_null() {
+            self.authorize(caller, Action::UserRead, resource)?;
+        }
+        Ok(self.evaluate(caller, action, resource))
+    }
+
+    /// Whether the caller may take the action
+    pub fn allows(&self, caller: &CallerContext, action: Action, resource: &Resource) -> bool {
+        self.evaluate(caller, action, resource).allowed
+    }
+
+    /// Fail with `Forbidden` unless the caller may take the action
+    pub fn authorize(
+        &self,
+        caller: &CallerContext,
+        action: Action,
+        resource: &Resource,
+    ) -> AppResult<()> {
+        let decision = self.evaluate(caller, action, resource);
+        if decision.allowed {
+            return Ok(());
+        }
+        tracing::debug!(
+            action = %action,
+            decided_by = decision.decided_by.as_deref().unwrap_or("default deny"),
+            "Authorization denied"
+        );
+        let reason = decision
+            .rules
+            .iter()
+            .find(|rule| Some(&rule.id) == decision.decided_by.as_ref())
+            .and_then(|rule| rule.description.clone());
+        Err(ApplicationError::Forbidden(
+            reason.unwrap_or_else(|| action.denial().to_string()),
+        ))
+    }
+}
+
+impl Rule {
+    fn validate(&self) -> Result<(), String> {
+        if self.actions.is_empty() {
+            return Err("actions must not be empty".to_string());
+        }
+        for pattern in &self.actions {
+            if !Action::ALL.iter().any(|action| action.matches(pattern)) {
+                return Err(format!("unknown action: {}", pattern));
+            }
+        }
+        for condition in &self.when {
+            condition.validate()?;
+        }
+        Ok(())
+    }
+}
+
+impl Condition {
+    fn validate(&self) -> Result<(), String> {
+        validate_attribute(&self.attr)?;
+        match (&self.value, self.op) {
+            (None, Op::Exists) => Ok(()),
+            (Some(_), Op::Exists) => Err(format!("{}: exists takes no value", self.attr)),
+            (None, op) => Err(format!("{}: {} needs a value", self.attr, op.name())),
+            (Some(Operand::Attr { attr }), _) => validate_attribute(attr),
+            (Some(Operand::Literal(value)), Op::In | Op::Intersects) if !value.is_array() => {
+                Err(format!("{}: {} needs an array", self.attr, self.op.name()))
+            }
+            (Some(Operand::Literal(_)), _) => Ok(()),
+        }
+    }
+
+    fn holds(&self, lookup: &impl Fn(&str) -> Value) -> bool {
+        let left = lookup(&self.attr);
+        let right = match &self.value {
+            Some(Operand::Attr { attr }) => lookup(attr),
+            Some(Operand::Literal(value)) => value.clone(),
+            None => Value::Null,
+        };
+        match self.op {
+            Op::Exists => !(left.is_null() || left.as_array().is_some_and(Vec::is_empty)),
+            Op::Ne if left.is_null() || right.is_null() => left != right,
+            _ if left.is_null() || right.is_null() => false,
+            Op::Eq => left == right,
+            Op::Ne => left != right,
+            Op::In => right
+                .as_array()
+                .is_some_and(|values| values.contains(&left)),
+            Op::Contains => left
+                .as_array()
+                .is_some_and(|values| values.contains(&right)),
+            Op::Intersects => match (left.as_array(), right.as_array()) {
+                (Some(left), Some(right)) => left.iter().any(|value| right.contains(value)),
+                _ => false,
+            },
+        }
+    }
+}
+
+impl Op {
+    fn name(&self) -> &'static str {
+        match self {
+            Op::Eq => "eq",
+            Op::Ne => "ne",
+            Op::In => "in",
+            Op::Contains => "contains",
+            Op::Intersects => "intersects",
+            Op::Exists => "exists",
+        }
+    }
+}
+
+fn validate_attribute(name: &str) -> Result<(), String> {
+    let known = match name.split_once('.') {
+        Some(("subject", name)) => SUBJECT_ATTRIBUTES.contains(&name),
+        Some(("resource", name)) => RESOURCE_ATTRIBUTES.contains(&name),
+        _ => false,
+    };
+    if known {
+        Ok(())
+    } else {
+        Err(format!("unknown attribute: {}", name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::group::{Group, GroupGrant};
+    use crate::domain::organization::{Organization, OrganizationSlug};
+    use crate::domain::shared::{OrganizationId, PublicUserId};
+    use crate::domain::user::Role;
+    use chrono::NaiveDate;
+
+    const DEFAULT_POLICY: &str = include_str!("../../../policies/default.json");
+
+    #[test]
+    fn test_default_policy_grants_admins_owners_and_teammates() {
+        let policy = Policy::parse(DEFAULT_POLICY).unwrap();
+        let team = Group::create(
+            None,
+            "Support".to_string(),
+            HashSet::from([GroupGrant::ViewMembers]),
+        )
+        .unwrap();
+        let target = PublicUserId::generate();
+        let teammate = Resource::user(target).with_groups(std::slice::from_ref(&team));
+        let stranger = Resource::user(target).with_groups(&[]);
+
+        let admin = CallerContext::new(PublicUserId::generate(), HashSet::from([Role::Admin]));
+        for action in Action::ALL {
+            assert!(policy.allows(&admin, action, &stranger), "{}", action);
+        }
+
+        let owner = CallerContext::new(target, HashSet::from([Role::User]));
+        assert!(policy.allows(&owner, Action::UserUpdate, &stranger));
+        assert!(policy.allows(&owner, Action::UserListGroups, &stranger));
+        assert!(!policy.allows(&owner, Action::UserGrantRole, &stranger));
+        assert!(!policy.allows(&owner, Action::UserListTeammates, &Resource::users()));
+
+        let member = CallerContext::new(PublicUserId::generate(), HashSet::from([Role::User]))
+            .with_teams(HashSet::from([team.id()]));
+        assert!(policy.allows(&member, Action::UserRead, &teammate));
+        assert!(!policy.allows(&member, Action::UserRead, &stranger));
+        assert!(!policy.allows(&member, Action::UserUpdate, &teammate));
+        assert!(policy.allows(&member, Action::UserListTeammates, &Resource::users()));
+        assert!(!policy.allows(&member, Action::UserList, &Resource::users()));
+
+        let decision = policy.evaluate(&member, Action::UserRead, &stranger);
+        assert_eq!(decision.decided_by, None);
+        assert_eq!(
+            decision
+                .rules
+                .iter()
+                .map(|rule| rule.id.as_str())
+                .collect::<Vec<_>>(),
+            [
+                "admins-manage-users",
+                "users-manage-themselves",
+                "teammates-view-each-other"
+            ]
+        );
+        assert!(matches!(
+            policy.authorize(&member, Action::UserRead, &stranger),
+            Err(ApplicationError::Forbidden(message)) if message == "You can only view your own profile"
+        ));
+    }
+
+    #[test]
+    fn test_organization_admins_cannot_take_over_shared_accounts() {
+        let policy = Policy::parse(DEFAULT_POLICY).unwrap();
+        let organization = |id: i32, slug: &str| {
+            Organization::reconstitute(
+                OrganizationId::from(id),
+                OrganizationSlug::try_from(slug.to_string()).unwrap(),
+                slug.to_string(),
+                NaiveDate::default(),
+            )
+        };
+        let (acme, globex) = (organization(1, "acme"), organization(2, "globex"));
+        let member = |organizations: &[Organization], roles: HashSet<Role>| {
+            Resource::user(PublicUserId::generate())
+                .with_roles(&roles)
+                .with_organizations(organizations, acme.id())
+        };
+        let acme_only = member(std::slice::from_ref(&acme), HashSet::from([Role::User]));
+        let both = member(&[acme.clone(), globex.clone()], HashSet::from([Role::User]));
+        let platform_admin = member(std::slice::from_ref(&acme), HashSet::from([Role::Admin]));
+
+        let acme_admin = CallerContext::new(PublicUserId::generate(), HashSet::new())
+            .in_organization(OrganizationId::from(1), HashSet::from([Role::Admin]));
+        for action in [
+            Action::UserChangeEmail,
+            Action::UserResetPassword,
+            Action::UserRevokeTokens,
+        ] {
+            assert!(policy.allows(&acme_admin, action, &acme_only), "{}", action);
+            assert!(!policy.allows(&acme_admin, action, &both), "{}", action);
+            assert!(
+                !policy.allows(&acme_admin, action, &platform_admin),
+                "{}",
+                action
+            );
+        }
+        assert!(policy.allows(&acme_admin, Action::UserUpdate, &both));
+        assert!(matches!(
+            policy.authorize(&acme_admin, Action::UserResetPassword, &both),
+            Err(ApplicationError::Forbidden(message)) if message.contains("other organizations")
+        ));
+
+        let root = CallerContext::new(PublicUserId::generate(), HashSet::from([Role::Admin]));
+        let both = Resource::user(PublicUserId::generate())
+            .with_roles(&HashSet::from([Role::User]))
+            .with_organizations(&[acme, globex], None);
+        assert!(policy.allows(&root, Action::UserResetPassword, &both));
+    }
+
+    #[test]
+    fn test_default_policy_keeps_operations_to_platform_admins() {
+        let policy = Policy::parse(DEFAULT_POLICY).unwrap();
+        let team = Group::create(
+            Some(OrganizationId::from(1)),
+            "Support".to_string(),
+            HashSet::from([GroupGrant::ViewMembers]),
+        )
+        .unwrap();
+        let acme_admin = CallerContext::new(PublicUserId::generate(), HashSet::new())
+            .in_organization(OrganizationId::from(1), HashSet::from([Role::Admin]));
+        let acme_user = CallerContext::new(PublicUserId::generate(), HashSet::new())
+            .in_organization(OrganizationId::from(1), HashSet::from([Role::User]))
+            .with_teams(HashSet::from([team.id()]));
+
+        for action in Action::ALL {
+            let resource = Resource::of_type(action.resource_type());
+            let expected = matches!(action.resource_type(), "user" | "group" | "invitation")
+                || action == Action::OrganizationRemoveMember;
+            assert_eq!(
+                policy.allows(&acme_admin, action, &resource),
+                expected,
+                "{}",
+                action
+            );
+        }
+
+        assert!(policy.allows(
+            &acme_user,
+            Action::GroupListMembers,
+            &Resource::group(team.id())
+        ));
+        let other = Group::create(None, "Operators".to_string(), HashSet::new()).unwrap();
+        assert!(!policy.allows(
+            &acme_user,
+            Action::GroupListMembers,
+            &Resource::group(other.id())
+        ));
+        assert!(!policy.allows(&acme_user, Action::GroupUpdate, &Resource::group(team.id())));
+        assert!(matches!(
+            policy.authorize(&acme_user, Action::JobRetry, &Resource::of_type("job")),
+            Err(ApplicationError::Forbidden(message)) if message == "Only platform administrators can manage jobs"
+        ));
+    }
+
+    #[test]
+    fn test_explaining_actions_on_unreadable_users_is_refused() {
+        let policy = Policy::parse(DEFAULT_POLICY).unwrap();
+        let admin = Resource::user(PublicUserId::generate())
+            .with_roles(&HashSet::from([Role::Admin]))
+            .with_groups(&[]);
+        let prober = CallerContext::new(PublicUserId::generate(), HashSet::from([Role::User]));
+
+        for action in [Action::UserRead, Action::UserResetPassword] {
+            assert!(matches!(
+                policy.explain(&prober, action, &admin),
+                Err(ApplicationError::Forbidden(_))
+            ));
+        }
+        assert!(
+            policy
+                .explain(&prober, Action::UserList, &Resource::users())
+                .is_ok()
+        );
+
+        let root = CallerContext::new(PublicUserId::generate(), HashSet::from([Role::Admin]));
+        let decision = policy
+            .explain(&root, Action::UserResetPassword, &admin)
+            .unwrap();
+        assert!(decision.allowed);
+    }
+
+    #[test]
+    fn test_deny_rules_override_allow_rules() {
+        let policy = Policy::parse(
+            r#"{"rules": [
+                {"id": "everyone", "effect": "allow", "actions": ["*"]},
+                {"id": "no-services", "effect": "deny", "actions": ["user:*"],
+                 "when": [{"attr": "subject.service", "op": "exists"}]}
+            ]}"#,
+        )
+        .unwrap();
+        let user = CallerContext::new(PublicUserId::generate(), HashSet::new());
+        let service = CallerContext::for_service("billing".to_string(), HashSet::new());
+
+        assert!(policy.allows(&user, Action::UserCreate, &Resource::users()));
+        let decision = policy.evaluate(&service, Action::UserCreate, &Resource::users());
+        assert!(!decision.allowed);
+        assert_eq!(decision.decided_by.as_deref(), Some("no-services"));
+    }
+
+    #[test]
+    fn test_invalid_policies_are_rejected() {
+        let rule = |rule: &str| Policy::parse(&format!(r#"{{"rules": [{}]}}"#, rule));
+
+        assert!(rule(r#"{"id": "a", "effect": "allow", "actions": ["user:delete"]}"#).is_err());
+        assert!(rule(r#"{"id": "a", "effect": "allow", "actions": []}"#).is_err());
+        assert!(rule(r#"{"id": "a", "effect": "permit", "actions": ["*"]}"#).is_err());
+        assert!(
+            rule(r#"{"id": "a", "effect": "allow", "actions": ["*"], "when": [{"attr": "subject.email", "op": "exists"}]}"#)
+                .is_err()
+        );
+        assert!(
+            rule(r#"{"id": "a", "effect": "allow", "actions": ["*"], "when": [{"attr": "subject.id", "op": "eq"}]}"#)
+                .is_err()
+        );
+        assert!(
+            rule(r#"{"id": "a", "effect": "allow", "actions": ["*"], "when": [{"attr": "subject.roles", "op": "intersects", "value": "admin"}]}"#)
+                .is_err()
+        );
+        assert!(
+            Policy::parse(
+                r#"{"rules": [{"id": "a", "effect": "allow", "actions": ["*"]},
+                              {"id": "a", "effect": "deny", "actions": ["*"]}]}"#
+            )
+            .is_err()
+        );
+    }
+}
@@ -0,0 +1,62 @@
+use super::Resource;
+use crate::app::caller_context::CallerContext;
+use crate::app::errors::{AppResult, ApplicationError};
+use crate::domain::group::GroupRepository;
+use crate::domain::organization::OrganizationRepository;
+use crate::domain::shared::PublicUserId;
+use crate::domain::user::{User, UserRepository};
+use std::sync::Arc;
+
+/// UserResources - looks users up along with their policy attributes
+///
+/// Users outside the caller's scope are not found.
+pub struct UserResources {
+    user_repository: Arc<dyn UserRepository>,
+    group_repository: Arc<dyn GroupRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+}
+
+impl UserResources {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        group_repository: Arc<dyn GroupRepository>,
+        organization_repository: Arc<dyn OrganizationRepository>,
+    ) -> Self {
+        Self {
+            user_repository,
+            group_repository,
+            organization_repository,
+        }
+    }
+
+    /// The user, if found, and the resource rules see
+    ///
+    /// A missing user is still described by its ID, so callers who may not
+    /// see it get a denial rather than learning it does not exist.
+    pub async fn find(
+        &self,
+        user_id: PublicUserId,
+        caller: &CallerContext,
+    ) -> AppResult<(Option<User>, Resource)> {
+        let user = self
+            .user_repository
+            .find_by_public_id(user_id, caller.scope())
+            .await?;
+        let mut resource = Resource::user(user_id);
+        if let Some(user) = &user {
+            let id = user.id().ok_or(ApplicationError::UserNotFound)?;
+            // Accounts are global: the organizations are all of the user's,
+            // not only those in the caller's scope
+            let organizations = self.organization_repository.list_for_user(id).await?;
+            let groups = self
+                .group_repository
+                .list_for_user(id, caller.scope())
+                .await?;
+            resource = resource
+                .with_roles(user.roles())
+                .with_organizations(&organizations, caller.organization)
+                .with_groups(&groups);
+        }
+        Ok((user, resource))
+    }
+}
@@ -0,0 +1,8 @@
+use async_trait::async_trait;
+
+/// BreachedPasswordList port - passwords known from public data breaches
+#[async_trait]
+pub trait BreachedPasswordList: Send + Sync {
+    /// Whether `password` appears in the list
+    async fn contains(&self, password: &str) -> Result<bool, String>;
+}
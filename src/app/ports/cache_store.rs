@@ -0,0 +1,29 @@
+use async_trait::async_trait;
+use std::time::Duration;
+
+/// Hit and miss counters of one cached lookup
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheStats {
+    pub name: &'static str,
+    pub hits: u64,
+    pub misses: u64,
+    /// Lookups that fell back to the source because the cache failed
+    pub errors: u64,
+}
+
+/// CacheStore port - short-lived copies of values by key
+/// Implementations live in infrastructure (in-memory LRU, Redis)
+#[async_trait]
+pub trait CacheStore: Send + Sync {
+    /// The value stored under `key`, unless missing or expired
+    async fn get(&self, key: &str) -> Result<Option<String>, String>;
+
+    async fn set(&self, key: &str, value: &str, ttl: Duration) -> Result<(), String>;
+
+    async fn delete(&self, keys: &[String]) -> Result<(), String>;
+}
+
+/// CacheMetrics port - counters of a caching component, for operators
+pub trait CacheMetrics: Send + Sync {
+    fn stats(&self) -> Vec<CacheStats>;
+}
@@ -0,0 +1,13 @@
+use crate::domain::user::UserEvent;
+use async_trait::async_trait;
+
+/// EventSubscriber port - reacts to domain events after they are persisted
+/// Implementations live in infrastructure (audit log, email, webhooks, ...)
+#[async_trait]
+pub trait EventSubscriber: Send + Sync {
+    /// Stable subscriber name used in logs
+    fn name(&self) -> &'static str;
+
+    /// Handle one event, returning a human-readable reason on failure
+    async fn handle(&self, event: &UserEvent) -> Result<(), String>;
+}
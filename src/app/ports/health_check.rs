@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+
+/// HealthCheck port - a single dependency probed by the readiness endpoint
+/// Implementations live in infrastructure (database, migrations, keys, ...)
+#[async_trait]
+pub trait HealthCheck: Send + Sync {
+    /// Stable component name used as the key in the health report
+    fn name(&self) -> &'static str;
+
+    /// Probe the component, returning a human-readable reason on failure
+    async fn check(&self) -> Result<(), String>;
+}
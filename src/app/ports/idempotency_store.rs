@@ -0,0 +1,49 @@
+use async_trait::async_trait;
+use std::time::Duration;
+
+/// A response recorded for replay under an idempotency key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+/// State of an idempotency key when a request tries to claim it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdempotencyClaim {
+    /// The key was free; the caller must `complete` or `release` it
+    Claimed,
+    /// Another request with this key is still being processed
+    InProgress { fingerprint: String },
+    /// A request with this key already finished
+    Completed {
+        fingerprint: String,
+        response: StoredResponse,
+    },
+}
+
+/// IdempotencyStore port - remembers responses per idempotency key
+/// Implementations live in infrastructure (in-memory, database)
+#[async_trait]
+pub trait IdempotencyStore: Send + Sync {
+    /// Atomically claim `key` for a request with the given fingerprint
+    ///
+    /// Keys expire `ttl` after being claimed. A claim whose request never
+    /// completed can be taken over once the store's lock timeout has passed.
+    async fn claim(
+        &self,
+        key: &str,
+        fingerprint: &str,
+        ttl: Duration,
+    ) -> Result<IdempotencyClaim, String>;
+
+    /// Record the response for a claimed key
+    async fn complete(&self, key: &str, response: &StoredResponse) -> Result<(), String>;
+
+    /// Give up a claimed key so the request can be retried
+    async fn release(&self, key: &str) -> Result<(), String>;
+
+    /// Remove expired keys, returning how many were removed
+    async fn purge_expired(&self) -> Result<u64, String>;
+}
@@ -0,0 +1,14 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// InvitationSigner port - signs the tokens carried by invitation links
+///
+/// A token names one invitation and the expiry it was issued with, so
+/// moving the expiry (on resend) invalidates links sent earlier.
+pub trait InvitationSigner: Send + Sync {
+    fn sign(&self, invitation_id: Uuid, expires_at: DateTime<Utc>) -> String;
+
+    /// The invitation and expiry of a token, or `None` if it was not
+    /// signed by this service or has been tampered with
+    fn verify(&self, token: &str) -> Option<(Uuid, DateTime<Utc>)>;
+}
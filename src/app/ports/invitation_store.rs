@@ -0,0 +1,148 @@
+use crate::domain::organization::TenantScope;
+use crate::domain::shared::OrganizationId;
+use crate::domain::user::repository::RepositoryError;
+use crate::domain::user::{Role, User};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Invitation state: acceptable while `Pending` and not yet expired
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InvitationStatus {
+    Pending,
+    Accepted,
+    Revoked,
+}
+
+impl InvitationStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InvitationStatus::Pending => "pending",
+            InvitationStatus::Accepted => "accepted",
+            InvitationStatus::Revoked => "revoked",
+        }
+    }
+}
+
+impl std::str::FromStr for InvitationStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(InvitationStatus::Pending),
+            "accepted" => Ok(InvitationStatus::Accepted),
+            "revoked" => Ok(InvitationStatus::Revoked),
+            other => Err(format!("Unknown invitation status: {}", other)),
+        }
+    }
+}
+
+/// An email invited to sign up with preassigned roles
+#[derive(Clone, Debug, PartialEq)]
+pub struct Invitation {
+    pub id: Uuid,
+    pub email: String,
+    /// Role names granted on acceptance besides `user`
+    pub roles: Vec<String>,
+    /// Organization the invitee joins; `None` for a platform-wide account
+    pub organization_id: Option<OrganizationId>,
+    /// Public ID of the admin who sent the invitation
+    pub invited_by: Option<Uuid>,
+    /// Locale the invitation email is rendered in
+    pub locale: Option<String>,
+    pub status: InvitationStatus,
+    pub expires_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub accepted_at: Option<DateTime<Utc>>,
+}
+
+impl Invitation {
+    /// Whether the invitation can still be accepted at `now`
+    pub fn is_open(&self, now: DateTime<Utc>) -> bool {
+        self.status == InvitationStatus::Pending && self.expires_at > now
+    }
+}
+
+/// Fields of an invitation being created
+#[derive(Clone, Debug)]
+pub struct NewInvitation {
+    pub email: String,
+    pub roles: Vec<String>,
+    pub organization_id: Option<OrganizationId>,
+    pub invited_by: Option<Uuid>,
+    pub locale: Option<String>,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// InvitationStore port - invitations and their state
+/// Implementations live in infrastructure
+#[async_trait]
+pub trait InvitationStore: Send + Sync {
+    async fn create(&self, invitation: NewInvitation) -> Result<Invitation, RepositoryError>;
+
+    async fn find(&self, id: Uuid) -> Result<Option<Invitation>, RepositoryError>;
+
+    /// The pending invitation of `email` to the organization (or platform), if any
+    async fn find_pending(
+        &self,
+        email: &str,
+        organization_id: Option<OrganizationId>,
+    ) -> Result<Option<Invitation>, RepositoryError>;
+
+    /// Invitations in the scope, newest first, with the total count; the
+    /// platform scope sees every invitation
+    async fn list(
+        &self,
+        scope: TenantScope,
+        page: u64,
+        rows_per_page: u64,
+    ) -> Result<(Vec<Invitation>, u64), RepositoryError>;
+
+    /// Move the expiry of a pending invitation; `None` unless it is pending
+    async fn renew(
+        &self,
+        id: Uuid,
+        expires_at: DateTime<Utc>,
+    ) -> Result<Option<Invitation>, RepositoryError>;
+
+    /// Mark a pending invitation revoked; `false` unless it was pending
+    async fn revoke(&self, id: Uuid) -> Result<bool, RepositoryError>;
+
+    /// Mark a pending invitation accepted and save its invitee in the same
+    /// transaction, with their membership of an organization when given;
+    /// `false`, saving nothing, unless it was pending
+    async fn accept(
+        &self,
+        id: Uuid,
+        invitee: &mut User,
+        membership: Option<(OrganizationId, &[Role])>,
+    ) -> Result<bool, RepositoryError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_only_pending_unexpired_invitations_are_open() {
+        let now = Utc::now();
+        let invitation = |status, expires_at| Invitation {
+            id: Uuid::now_v7(),
+            email: "ann@example.com".to_string(),
+            roles: Vec::new(),
+            organization_id: None,
+            invited_by: None,
+            locale: None,
+            status,
+            expires_at,
+            created_at: now,
+            accepted_at: None,
+        };
+
+        assert!(invitation(InvitationStatus::Pending, now + Duration::hours(1)).is_open(now));
+        assert!(!invitation(InvitationStatus::Pending, now).is_open(now));
+        assert!(!invitation(InvitationStatus::Accepted, now + Duration::hours(1)).is_open(now));
+        assert!(!invitation(InvitationStatus::Revoked, now + Duration::hours(1)).is_open(now));
+    }
+}
@@ -0,0 +1,167 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use thiserror::Error;
+
+/// Job queue errors
+#[derive(Error, Debug)]
+pub enum JobQueueError {
+    #[error("Invalid job payload: {0}")]
+    InvalidPayload(#[from] serde_json::Error),
+
+    #[error("Job queue failure: {0}")]
+    QueueFailure(String),
+}
+
+/// A unit of background work, stored as JSON in the job queue
+///
+/// `KIND` identifies the job type in the queue and must stay stable across
+/// releases, since queued payloads outlive the process that enqueued them.
+pub trait Job: Serialize + DeserializeOwned + Send + Sync + 'static {
+    const KIND: &'static str;
+
+    /// Runs before the job is marked failed
+    const MAX_ATTEMPTS: u32 = 5;
+}
+
+/// Job state: retried while `Pending`, `Failed` once attempts run out
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JobStatus {
+    Pending,
+    Running,
+    Completed,
+    Failed,
+}
+
+impl JobStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JobStatus::Pending => "pending",
+            JobStatus::Running => "running",
+            JobStatus::Completed => "completed",
+            JobStatus::Failed => "failed",
+        }
+    }
+}
+
+impl std::str::FromStr for JobStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(JobStatus::Pending),
+            "running" => Ok(JobStatus::Running),
+            "completed" => Ok(JobStatus::Completed),
+            "failed" => Ok(JobStatus::Failed),
+            other => Err(format!("Unknown job status: {}", other)),
+        }
+    }
+}
+
+/// A job being enqueued
+#[derive(Clone, Debug)]
+pub struct NewJob {
+    pub kind: String,
+    pub payload: serde_json::Value,
+    pub max_attempts: u32,
+    /// Earliest time the job may run
+    pub run_at: DateTime<Utc>,
+}
+
+impl NewJob {
+    /// Serialize a typed job to run as soon as a worker is free
+    pub fn of<J: Job>(job: &J) -> Result<Self, serde_json::Error> {
+        Ok(Self {
+            kind: J::KIND.to_string(),
+            payload: serde_json::to_value(job)?,
+            max_attempts: J::MAX_ATTEMPTS,
+            run_at: Utc::now(),
+        })
+    }
+
+    /// Delay the job until `run_at`
+    pub fn at(self, run_at: DateTime<Utc>) -> Self {
+        Self { run_at, ..self }
+    }
+}
+
+/// A job as stored in the queue
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueuedJob {
+    pub id: i64,
+    pub kind: String,
+    pub payload: serde_json::Value,
+    pub status: JobStatus,
+    pub attempts: i32,
+    pub max_attempts: i32,
+    pub run_at: DateTime<Utc>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+/// JobQueue port - durable queue of background jobs
+/// Implementations live in infrastructure
+#[async_trait]
+pub trait JobQueue: Send + Sync {
+    /// Add a job to the queue, returning its id
+    async fn push(&self, job: NewJob) -> Result<i64, JobQueueError>;
+
+    async fn find(&self, id: i64) -> Result<Option<QueuedJob>, JobQueueError>;
+
+    /// Jobs, newest first, optionally filtered by status, with the total count
+    async fn list(
+        &self,
+        status: Option<JobStatus>,
+        page: u64,
+        rows_per_page: u64,
+    ) -> Result<(Vec<QueuedJob>, u64), JobQueueError>;
+
+    /// Queue a failed job again from a fresh attempt count; `None` unless
+    /// the job exists and has failed
+    async fn retry(&self, id: i64) -> Result<Option<QueuedJob>, JobQueueError>;
+}
+
+impl dyn JobQueue {
+    /// Enqueue a typed job to run as soon as a worker is free
+    pub async fn enqueue<J: Job>(&self, job: &J) -> Result<i64, JobQueueError> {
+        self.push(NewJob::of(job)?).await
+    }
+
+    /// Enqueue a typed job to run no earlier than `run_at`
+    pub async fn schedule<J: Job>(
+        &self,
+        job: &J,
+        run_at: DateTime<Utc>,
+    ) -> Result<i64, JobQueueError> {
+        self.push(NewJob::of(job)?.at(run_at)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Serialize, Deserialize)]
+    struct Export {
+        user_id: i32,
+    }
+
+    impl Job for Export {
+        const KIND: &'static str = "export";
+        const MAX_ATTEMPTS: u32 = 2;
+    }
+
+    #[test]
+    fn test_new_job_from_typed_job() {
+        let run_at = Utc::now() + chrono::Duration::hours(1);
+        let job = NewJob::of(&Export { user_id: 7 }).unwrap().at(run_at);
+
+        assert_eq!(job.kind, "export");
+        assert_eq!(job.payload, serde_json::json!({ "user_id": 7 }));
+        assert_eq!(job.max_attempts, 2);
+        assert_eq!(job.run_at, run_at);
+    }
+}
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+
+/// An email ready to send, with HTML and plain text alternatives
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MailMessage {
+    pub to: String,
+    pub subject: String,
+    pub html: String,
+    pub text: String,
+}
+
+/// Mailer port - sends email
+/// Implementations live in infrastructure (SMTP, mbox file, in-memory)
+#[async_trait]
+pub trait Mailer: Send + Sync {
+    /// Send one message; `Ok` once the transport has accepted it
+    async fn send(&self, message: &MailMessage) -> Result<(), String>;
+}
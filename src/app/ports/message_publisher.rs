@@ -0,0 +1,29 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+/// A domain event as published to other services
+///
+/// `id` is unique per event; delivery is at-least-once, so consumers use it
+/// to discard duplicates.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutboundMessage {
+    pub id: i64,
+    /// Event name such as `user.registered`, also used as the routing key
+    #[serde(rename = "type")]
+    pub event_type: String,
+    /// Public ID of the user the event is about
+    pub aggregate_id: Uuid,
+    pub occurred_at: DateTime<Utc>,
+    pub data: serde_json::Value,
+}
+
+/// MessagePublisher port - delivers outbox messages to a broker or sink
+/// Implementations live in infrastructure (AMQP, file, stdout)
+#[async_trait]
+pub trait MessagePublisher: Send + Sync {
+    /// Publish one message; `Ok` only once the sink has accepted it
+    async fn publish(&self, message: &OutboundMessage) -> Result<(), String>;
+}
@@ -1,3 +1,33 @@
+pub mod breached_password_list;
+pub mod cache_store;
+pub mod event_subscriber;
+pub mod health_check;
+pub mod idempotency_store;
+pub mod invitation_signer;
+pub mod invitation_store;
+pub mod job_queue;
+pub mod mailer;
+pub mod message_publisher;
+pub mod password_history_store;
+pub mod rate_limit_store;
+pub mod token_revocation_store;
 pub mod token_service;
+pub mod webhook_store;
 
+pub use breached_password_list::BreachedPasswordList;
+pub use cache_store::{CacheMetrics, CacheStats, CacheStore};
+pub use event_subscriber::EventSubscriber;
+pub use health_check::HealthCheck;
+pub use idempotency_store::{IdempotencyClaim, IdempotencyStore, StoredResponse};
+pub use invitation_signer::InvitationSigner;
+pub use invitation_store::{Invitation, InvitationStatus, InvitationStore, NewInvitation};
+pub use job_queue::{Job, JobQueue, JobQueueError, JobStatus, NewJob, QueuedJob};
+pub use mailer::{MailMessage, Mailer};
+pub use message_publisher::{MessagePublisher, OutboundMessage};
+pub use password_history_store::PasswordHistoryStore;
+pub use rate_limit_store::{
+    RateLimitAlgorithm, RateLimitDecision, RateLimitPolicy, RateLimitStore,
+};
+pub use token_revocation_store::TokenRevocationStore;
 pub use token_service::TokenService;
+pub use webhook_store::{DeliveryStatus, NewWebhook, Webhook, WebhookDelivery, WebhookStore};
@@ -0,0 +1,18 @@
+use crate::domain::user::repository::RepositoryError;
+use async_trait::async_trait;
+
+/// PasswordHistoryStore port - hashes of the passwords a user has had
+/// Only hashes are kept, so checking reuse means verifying against each.
+#[async_trait]
+pub trait PasswordHistoryStore: Send + Sync {
+    /// The user's most recent password hashes, newest first
+    async fn recent(&self, user_id: i32, limit: usize) -> Result<Vec<String>, RepositoryError>;
+
+    /// Record the user's new password hash, keeping only the latest `keep`
+    async fn record(
+        &self,
+        user_id: i32,
+        password_hash: &str,
+        keep: usize,
+    ) -> Result<(), RepositoryError>;
+}
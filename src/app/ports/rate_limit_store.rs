@@ -0,0 +1,42 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::time::Duration;
+
+/// Counting strategy for a rate limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum RateLimitAlgorithm {
+    /// Bursts up to `limit`, refilled continuously at `limit` per `window`
+    #[default]
+    TokenBucket,
+    /// At most `limit` requests in any rolling `window`
+    SlidingWindow,
+}
+
+/// How many requests are allowed per window, and how they are counted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitPolicy {
+    pub limit: u32,
+    pub window: Duration,
+    pub algorithm: RateLimitAlgorithm,
+}
+
+/// Outcome of counting one request against a policy
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitDecision {
+    pub allowed: bool,
+    pub limit: u32,
+    pub remaining: u32,
+    /// Time until the quota is fully restored
+    pub reset_after: Duration,
+    /// Time until the next request would be allowed; set only when rejected
+    pub retry_after: Option<Duration>,
+}
+
+/// RateLimitStore port - counts requests per key
+/// Implementations live in infrastructure (in-memory, Redis)
+#[async_trait]
+pub trait RateLimitStore: Send + Sync {
+    /// Record one request for `key` and decide whether it is allowed
+    async fn hit(&self, key: &str, policy: &RateLimitPolicy) -> Result<RateLimitDecision, String>;
+}
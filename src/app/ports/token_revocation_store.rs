@@ -0,0 +1,15 @@
+use crate::domain::user::repository::RepositoryError;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+/// TokenRevocationStore port - per-user cut-off for issued tokens
+/// Tokens are stateless, so revoking them means rejecting every token
+/// issued to the user at or before the recorded time.
+#[async_trait]
+pub trait TokenRevocationStore: Send + Sync {
+    /// Revoke every token issued to the user so far, returning the cut-off
+    async fn revoke_all(&self, user_id: i32) -> Result<DateTime<Utc>, RepositoryError>;
+
+    /// The cut-off recorded for the user, if their tokens were ever revoked
+    async fn revoked_at(&self, user_id: i32) -> Result<Option<DateTime<Utc>>, RepositoryError>;
+}
@@ -1,14 +1,17 @@
 use crate::app::errors::ApplicationError;
+use crate::domain::shared::PublicUserId;
 use async_trait::async_trait;
 
 /// TokenService port - defines the contract for token generation
 /// This trait lives in the application layer, implementations are in infrastructure
 #[async_trait]
 pub trait TokenService: Send + Sync {
-    /// Generate an authentication token for a user
+    /// Generate an authentication token for a user, bound to the
+    /// organization with the given slug if any
     async fn generate_token(
         &self,
-        user_id: i32,
+        user_id: PublicUserId,
         user_email: &str,
+        organization: Option<&str>,
     ) -> Result<String, ApplicationError>;
 }
@@ -0,0 +1,163 @@
+use crate::domain::user::UserEventKind;
+use crate::domain::user::repository::RepositoryError;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+/// A partner endpoint subscribed to domain events
+#[derive(Clone, Debug, PartialEq)]
+pub struct Webhook {
+    pub id: i32,
+    pub url: String,
+    /// Event name filters such as `user.registered`, `user.*` or `*`
+    pub events: Vec<String>,
+    /// Shared secret used to sign deliveries
+    pub secret: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Webhook {
+    /// Whether an event with this name should be delivered to the webhook
+    pub fn wants(&self, event_type: &str) -> bool {
+        self.events
+            .iter()
+            .any(|filter| filter_matches(filter, event_type))
+    }
+
+    /// Whether the filter matches any event the service emits
+    pub fn is_known_filter(filter: &str) -> bool {
+        UserEventKind::NAMES
+            .iter()
+            .any(|name| filter_matches(filter, name))
+    }
+}
+
+fn filter_matches(filter: &str, event_type: &str) -> bool {
+    filter == "*"
+        || filter == event_type
+        || filter
+            .strip_suffix('*')
+            .is_some_and(|prefix| event_type.starts_with(prefix))
+}
+
+/// Fields of a webhook being created
+#[derive(Clone, Debug)]
+pub struct NewWebhook {
+    pub url: String,
+    pub events: Vec<String>,
+    pub secret: String,
+}
+
+/// Delivery state: retried while `Pending`, `Dead` once attempts run out
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeliveryStatus {
+    Pending,
+    Delivered,
+    Dead,
+}
+
+impl DeliveryStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DeliveryStatus::Pending => "pending",
+            DeliveryStatus::Delivered => "delivered",
+            DeliveryStatus::Dead => "dead",
+        }
+    }
+}
+
+impl std::str::FromStr for DeliveryStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(DeliveryStatus::Pending),
+            "delivered" => Ok(DeliveryStatus::Delivered),
+            "dead" => Ok(DeliveryStatus::Dead),
+            other => Err(format!("Unknown delivery status: {}", other)),
+        }
+    }
+}
+
+/// One event sent (or to be sent) to one webhook
+#[derive(Clone, Debug, PartialEq)]
+pub struct WebhookDelivery {
+    pub id: i64,
+    pub webhook_id: i32,
+    /// Shared by the deliveries of the same event to different webhooks
+    pub event_id: uuid::Uuid,
+    pub event_type: String,
+    pub payload: serde_json::Value,
+    pub status: DeliveryStatus,
+    pub attempts: i32,
+    pub last_status_code: Option<u16>,
+    pub last_error: Option<String>,
+    pub next_attempt_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+/// WebhookStore port - webhook subscriptions and their delivery log
+/// Implementations live in infrastructure
+#[async_trait]
+pub trait WebhookStore: Send + Sync {
+    async fn create(&self, webhook: NewWebhook) -> Result<Webhook, RepositoryError>;
+
+    async fn list(&self) -> Result<Vec<Webhook>, RepositoryError>;
+
+    /// Delete a webhook and its delivery log; `false` if it did not exist
+    async fn delete(&self, id: i32) -> Result<bool, RepositoryError>;
+
+    /// Queue a delivery of the event to every webhook whose filters match
+    async fn enqueue(
+        &self,
+        event_type: &str,
+        payload: serde_json::Value,
+    ) -> Result<u64, RepositoryError>;
+
+    /// Deliveries of a webhook, newest first, with the total count
+    async fn deliveries(
+        &self,
+        webhook_id: i32,
+        page: u64,
+        rows_per_page: u64,
+    ) -> Result<Option<(Vec<WebhookDelivery>, u64)>, RepositoryError>;
+
+    /// Send a delivery again from a fresh attempt count
+    async fn redeliver(
+        &self,
+        webhook_id: i32,
+        delivery_id: i64,
+    ) -> Result<Option<WebhookDelivery>, RepositoryError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_filters() {
+        let webhook = |events: &[&str]| Webhook {
+            id: 1,
+            url: "http://localhost".to_string(),
+            events: events.iter().map(|e| e.to_string()).collect(),
+            secret: "secret".to_string(),
+            created_at: Utc::now(),
+        };
+
+        assert!(webhook(&["*"]).wants("user.registered"));
+        assert!(webhook(&["user.*"]).wants("user.email_changed"));
+        assert!(webhook(&["user.registered"]).wants("user.registered"));
+        assert!(!webhook(&["user.registered"]).wants("user.role_granted"));
+        assert!(!webhook(&["order.*"]).wants("user.registered"));
+    }
+
+    #[test]
+    fn test_filters_must_match_an_emitted_event() {
+        for filter in ["*", "user.*", "user.role_*", "user.registered"] {
+            assert!(Webhook::is_known_filter(filter), "{}", filter);
+        }
+        for filter in ["user.deleted", "order.*", ""] {
+            assert!(!Webhook::is_known_filter(filter), "{}", filter);
+        }
+    }
+}
@@ -1,17 +1,38 @@
-use super::{CreateUserCommand, UserResponse};
+use super::{CreateUserCommand, UserResponse, date_of_birth_from};
+use crate::app::auth::{HashingPool, PasswordChecker};
 use crate::app::caller_context::CallerContext;
 use crate::app::errors::{AppResult, ApplicationError};
+use crate::app::policy::{Action, Policy, Resource};
+use crate::domain::organization::{Membership, OrganizationRepository};
 use crate::domain::user::{Email, User, UserRepository};
 use std::sync::Arc;
 
 /// CreateUserUseCase - handles creating a new user (admin only)
+///
+/// Users created within an organization join it as members.
 pub struct CreateUserUseCase {
     user_repository: Arc<dyn UserRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+    password_checker: Arc<PasswordChecker>,
+    hashing_pool: Arc<HashingPool>,
+    policy: Arc<Policy>,
 }
 
 impl CreateUserUseCase {
-    pub fn new(user_repository: Arc<dyn UserRepository>) -> Self {
-        Self { user_repository }
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        organization_repository: Arc<dyn OrganizationRepository>,
+        password_checker: Arc<PasswordChecker>,
+        hashing_pool: Arc<HashingPool>,
+        policy: Arc<Policy>,
+    ) -> Self {
+        Self {
+            user_repository,
+            organization_repository,
+            password_checker,
+            hashing_pool,
+            policy,
+        }
     }
 
     pub async fn execute(
@@ -19,12 +40,8 @@ impl CreateUserUseCase {
         command: CreateUserCommand,
         caller: &CallerContext,
     ) -> AppResult<UserResponse> {
-        // Authorization: only admins can create users via this endpoint
-        if !caller.is_admin() {
-            return Err(ApplicationError::Forbidden(
-                "Only administrators can create users".to_string(),
-            ));
-        }
+        self.policy
+            .authorize(caller, Action::UserCreate, &Resource::users())?;
 
         // Parse and validate email (domain validation)
         let email = Email::try_from(command.email.clone())?;
@@ -34,17 +51,46 @@ impl CreateUserUseCase {
             return Err(ApplicationError::EmailAlreadyExists(command.email));
         }
 
-        // Create user entity (domain logic)
-        let mut user = User::register(
-            email,
-            command.password,
-            command.first_name,
-            command.last_name,
-            command.age,
-        )?;
+        let date_of_birth = date_of_birth_from(command.date_of_birth, command.age)?;
+
+        // Policy, breached list; every violation is reported together
+        self.password_checker
+            .check_new(
+                &command.password,
+                &[email.as_ref(), &command.first_name, &command.last_name],
+            )
+            .await?;
+
+        // Create user entity (domain logic); hashing runs on the pool
+        let policy = self.password_checker.policy().clone();
+        let hasher = self.password_checker.hasher().clone();
+        let mut user = self
+            .hashing_pool
+            .run(move || {
+                User::register(
+                    email,
+                    command.password,
+                    command.first_name,
+                    command.last_name,
+                    date_of_birth,
+                    &policy,
+                    &hasher,
+                )
+            })
+            .await??;
 
         // Persist the user
         self.user_repository.save(&mut user).await?;
+        self.password_checker.remember(&user).await?;
+
+        if let Some(organization_id) = caller.organization {
+            let internal_id = user.id().ok_or(ApplicationError::UserNotFound)?;
+            let membership = Membership::new(organization_id, internal_id);
+            self.organization_repository
+                .save_membership(&membership)
+                .await?;
+            return Ok(UserResponse::from_membership(&user, &membership));
+        }
 
         // Convert to response DTO
         Ok(UserResponse::from_domain(&user))
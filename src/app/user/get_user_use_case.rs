@@ -1,36 +1,41 @@
 use super::UserResponse;
+use super::user_response::present_user;
 use crate::app::caller_context::CallerContext;
 use crate::app::errors::{AppResult, ApplicationError};
-use crate::domain::shared::UserId;
-use crate::domain::user::UserRepository;
+use crate::app::policy::{Action, Policy, UserResources};
+use crate::domain::organization::OrganizationRepository;
+use crate::domain::shared::PublicUserId;
 use std::sync::Arc;
 
 /// GetUserUseCase - handles retrieving a single user
 pub struct GetUserUseCase {
-    user_repository: Arc<dyn UserRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+    policy: Arc<Policy>,
+    user_resources: Arc<UserResources>,
 }
 
 impl GetUserUseCase {
-    pub fn new(user_repository: Arc<dyn UserRepository>) -> Self {
-        Self { user_repository }
-    }
-
-    pub async fn execute(&self, user_id: i32, caller: &CallerContext) -> AppResult<UserResponse> {
-        // Authorization: admin can view any user, regular users only their own
-        if !caller.can_access_user(user_id) {
-            return Err(ApplicationError::Forbidden(
-                "You can only view your own profile".to_string(),
-            ));
+    pub fn new(
+        organization_repository: Arc<dyn OrganizationRepository>,
+        policy: Arc<Policy>,
+        user_resources: Arc<UserResources>,
+    ) -> Self {
+        Self {
+            organization_repository,
+            policy,
+            user_resources,
         }
+    }
 
-        let user_id = UserId::from(user_id);
-
-        let user = self
-            .user_repository
-            .find_by_id(user_id)
-            .await?
-            .ok_or(ApplicationError::UserNotFound)?;
+    pub async fn execute(
+        &self,
+        user_id: PublicUserId,
+        caller: &CallerContext,
+    ) -> AppResult<UserResponse> {
+        let (user, resource) = self.user_resources.find(user_id, caller).await?;
+        self.policy.authorize(caller, Action::UserRead, &resource)?;
 
-        Ok(UserResponse::from_domain(&user))
+        let user = user.ok_or(ApplicationError::UserNotFound)?;
+        present_user(&user, caller, self.organization_repository.as_ref()).await
     }
 }
@@ -0,0 +1,67 @@
+use super::UserResponse;
+use crate::app::caller_context::CallerContext;
+use crate::app::errors::{AppResult, ApplicationError};
+use crate::app::policy::{Action, Policy, UserResources};
+use crate::domain::organization::OrganizationRepository;
+use crate::domain::shared::PublicUserId;
+use crate::domain::user::{Role, UserRepository};
+use std::sync::Arc;
+
+/// GrantRoleUseCase - adds a role to a user (admin only)
+///
+/// Within an organization the role is granted in that organization only.
+pub struct GrantRoleUseCase {
+    user_repository: Arc<dyn UserRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+    policy: Arc<Policy>,
+    user_resources: Arc<UserResources>,
+}
+
+impl GrantRoleUseCase {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        organization_repository: Arc<dyn OrganizationRepository>,
+        policy: Arc<Policy>,
+        user_resources: Arc<UserResources>,
+    ) -> Self {
+        Self {
+            user_repository,
+            organization_repository,
+            policy,
+            user_resources,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: PublicUserId,
+        role: Role,
+        caller: &CallerContext,
+    ) -> AppResult<UserResponse> {
+        let (user, resource) = self.user_resources.find(user_id, caller).await?;
+        self.policy
+            .authorize(caller, Action::UserGrantRole, &resource)?;
+        let mut user = user.ok_or(ApplicationError::UserNotFound)?;
+
+        // Domain logic: granting a held role is a no-op
+        if let Some(organization_id) = caller.organization {
+            let internal_id = user.id().ok_or(ApplicationError::UserNotFound)?;
+            let mut membership = self
+                .organization_repository
+                .find_membership(organization_id, internal_id)
+                .await?
+                .ok_or(ApplicationError::MembershipNotFound)?;
+            membership.grant(role);
+            self.organization_repository
+                .save_membership(&membership)
+                .await?;
+            return Ok(UserResponse::from_membership(&user, &membership));
+        }
+
+        user.add_role(role);
+
+        self.user_repository.save(&mut user).await?;
+
+        Ok(UserResponse::from_domain(&user))
+    }
+}
@@ -1,17 +1,33 @@
+use super::user_response::present_users;
 use super::{ListUsersQuery, UserResponse};
 use crate::app::caller_context::CallerContext;
-use crate::app::errors::{AppResult, ApplicationError};
+use crate::app::errors::AppResult;
+use crate::app::policy::{Action, Policy, Resource};
+use crate::domain::organization::OrganizationRepository;
 use crate::domain::user::UserRepository;
 use std::sync::Arc;
 
 /// ListUsersUseCase - handles listing users with pagination
+///
+/// Callers the policy keeps from listing everyone in scope may still be
+/// allowed to list the members of their teams.
 pub struct ListUsersUseCase {
     user_repository: Arc<dyn UserRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+    policy: Arc<Policy>,
 }
 
 impl ListUsersUseCase {
-    pub fn new(user_repository: Arc<dyn UserRepository>) -> Self {
-        Self { user_repository }
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        organization_repository: Arc<dyn OrganizationRepository>,
+        policy: Arc<Policy>,
+    ) -> Self {
+        Self {
+            user_repository,
+            organization_repository,
+            policy,
+        }
     }
 
     pub async fn execute(
@@ -19,20 +35,29 @@ impl ListUsersUseCase {
         query: ListUsersQuery,
         caller: &CallerContext,
     ) -> AppResult<(Vec<UserResponse>, u64)> {
-        // Authorization: only admins can list all users
-        if !caller.is_admin() {
-            return Err(ApplicationError::Forbidden(
-                "Only administrators can list all users".to_string(),
-            ));
-        }
-
-        let (users, total) = self
-            .user_repository
-            .list(query.page, query.rows_per_page)
-            .await?;
+        // Callers who may not list everyone in scope may still list their teammates
+        let (users, total) = if self
+            .policy
+            .allows(caller, Action::UserList, &Resource::users())
+        {
+            self.user_repository
+                .list(caller.scope(), query.page, query.rows_per_page)
+                .await?
+        } else {
+            self.policy
+                .authorize(caller, Action::UserListTeammates, &Resource::users())?;
+            self.user_repository
+                .list_in_groups(
+                    &caller.team_ids(),
+                    caller.scope(),
+                    query.page,
+                    query.rows_per_page,
+                )
+                .await?
+        };
 
-        let user_responses: Vec<UserResponse> =
-            users.iter().map(UserResponse::from_domain).collect();
+        let user_responses =
+            present_users(&users, caller, self.organization_repository.as_ref()).await?;
 
         Ok((user_responses, total))
     }
@@ -1,32 +1,60 @@
 pub mod create_user_use_case;
 pub mod get_user_use_case;
+pub mod grant_role_use_case;
 pub mod list_users_use_case;
+pub mod reset_password_use_case;
+pub mod revoke_role_use_case;
 pub mod update_user_use_case;
 pub mod user_response;
 
 pub use create_user_use_case::CreateUserUseCase;
 pub use get_user_use_case::GetUserUseCase;
+pub use grant_role_use_case::GrantRoleUseCase;
 pub use list_users_use_case::ListUsersUseCase;
+pub use reset_password_use_case::ResetPasswordUseCase;
+pub use revoke_role_use_case::RevokeRoleUseCase;
 pub use update_user_use_case::UpdateUserUseCase;
 pub use user_response::UserResponse;
 
+use crate::app::errors::{AppResult, ApplicationError};
+use crate::domain::user::DateOfBirth;
+use chrono::{NaiveDate, Utc};
 use serde::Deserialize;
 use utoipa::ToSchema;
 use validator::Validate;
 
 /// Command for creating a user
-#[derive(Debug, Clone, Deserialize, ToSchema, Validate)]
+#[derive(Clone, Deserialize, ToSchema, Validate)]
 pub struct CreateUserCommand {
     #[validate(email)]
     pub email: String,
-    #[validate(length(min = 8))]
+    /// Checked against the password policy
     pub password: String,
     #[validate(length(min = 1))]
     pub first_name: String,
     #[validate(length(min = 1))]
     pub last_name: String,
+    /// Date of birth (YYYY-MM-DD)
+    #[schema(value_type = Option<String>, format = Date, example = "1990-04-21")]
+    pub date_of_birth: Option<NaiveDate>,
+    /// Deprecated: send `date_of_birth` instead
+    #[schema(deprecated)]
     #[validate(range(min = 18, max = 150))]
-    pub age: u8,
+    pub age: Option<u8>,
+}
+
+// Note: Debug is implemented by hand so the raw password never reaches the logs
+impl std::fmt::Debug for CreateUserCommand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CreateUserCommand")
+            .field("email", &self.email)
+            .field("password", &"[REDACTED]")
+            .field("first_name", &self.first_name)
+            .field("last_name", &self.last_name)
+            .field("date_of_birth", &self.date_of_birth)
+            .field("age", &self.age)
+            .finish()
+    }
 }
 
 /// Command for updating a user
@@ -38,8 +66,13 @@ pub struct UpdateUserCommand {
     pub first_name: String,
     #[validate(length(min = 1))]
     pub last_name: String,
+    /// Date of birth (YYYY-MM-DD)
+    #[schema(value_type = Option<String>, format = Date, example = "1990-04-21")]
+    pub date_of_birth: Option<NaiveDate>,
+    /// Deprecated: send `date_of_birth` instead
+    #[schema(deprecated)]
     #[validate(range(min = 18, max = 150))]
-    pub age: u8,
+    pub age: Option<u8>,
 }
 
 /// Query for listing users
@@ -48,3 +81,43 @@ pub struct ListUsersQuery {
     pub page: u64,
     pub rows_per_page: u64,
 }
+
+/// Date of birth given by a command
+///
+/// Commands still accept the deprecated `age`, which maps to the latest date
+/// of birth giving that age today. Sending both is ambiguous and rejected.
+pub(crate) fn date_of_birth_from(
+    date_of_birth: Option<NaiveDate>,
+    age: Option<u8>,
+) -> AppResult<DateOfBirth> {
+    match (date_of_birth, age) {
+        (Some(date), None) => Ok(DateOfBirth::from(date)),
+        (None, Some(age)) => Ok(DateOfBirth::from_age(age, Utc::now().date_naive())),
+        (Some(_), Some(_)) => Err(ApplicationError::ValidationError(
+            "Send either date_of_birth or age, not both".to_string(),
+        )),
+        (None, None) => Err(ApplicationError::ValidationError(
+            "date_of_birth is required".to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_date_of_birth_accepts_either_form() {
+        let date = NaiveDate::from_ymd_opt(1990, 4, 21).unwrap();
+        assert_eq!(date_of_birth_from(Some(date), None).unwrap().value(), date);
+        assert_eq!(date_of_birth_from(None, Some(30)).unwrap().age(), 30);
+        assert!(matches!(
+            date_of_birth_from(Some(date), Some(30)),
+            Err(ApplicationError::ValidationError(_))
+        ));
+        assert!(matches!(
+            date_of_birth_from(None, None),
+            Err(ApplicationError::ValidationError(_))
+        ));
+    }
+}
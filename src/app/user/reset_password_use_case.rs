@@ -0,0 +1,80 @@
+use super::UserResponse;
+use super::user_response::present_user;
+use crate::app::auth::{HashingPool, PasswordChecker};
+use crate::app::caller_context::CallerContext;
+use crate::app::errors::{AppResult, ApplicationError};
+use crate::app::policy::{Action, Policy, UserResources};
+use crate::app::ports::TokenRevocationStore;
+use crate::domain::organization::OrganizationRepository;
+use crate::domain::shared::PublicUserId;
+use crate::domain::user::UserRepository;
+use std::sync::Arc;
+
+/// ResetPasswordUseCase - sets a new password for a user (admin only)
+///
+/// Tokens issued before the reset are revoked, so sessions opened with
+/// the old password end with it.
+pub struct ResetPasswordUseCase {
+    user_repository: Arc<dyn UserRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+    revocation_store: Arc<dyn TokenRevocationStore>,
+    password_checker: Arc<PasswordChecker>,
+    hashing_pool: Arc<HashingPool>,
+    policy: Arc<Policy>,
+    user_resources: Arc<UserResources>,
+}
+
+impl ResetPasswordUseCase {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        organization_repository: Arc<dyn OrganizationRepository>,
+        revocation_store: Arc<dyn TokenRevocationStore>,
+        password_checker: Arc<PasswordChecker>,
+        hashing_pool: Arc<HashingPool>,
+        policy: Arc<Policy>,
+        user_resources: Arc<UserResources>,
+    ) -> Self {
+        Self {
+            user_repository,
+            organization_repository,
+            revocation_store,
+            password_checker,
+            hashing_pool,
+            policy,
+            user_resources,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: PublicUserId,
+        password: String,
+        caller: &CallerContext,
+    ) -> AppResult<UserResponse> {
+        let (user, resource) = self.user_resources.find(user_id, caller).await?;
+        self.policy
+            .authorize(caller, Action::UserResetPassword, &resource)?;
+        let mut user = user.ok_or(ApplicationError::UserNotFound)?;
+
+        // Policy, breached list and the user's recent passwords
+        self.password_checker.check_change(&password, &user).await?;
+
+        // Domain logic: enforces the policy's own rules; hashing runs on the pool
+        let policy = self.password_checker.policy().clone();
+        let hasher = self.password_checker.hasher().clone();
+        let mut user = self
+            .hashing_pool
+            .run(move || {
+                user.change_password(password, &policy, &hasher)
+                    .map(|()| user)
+            })
+            .await??;
+
+        self.user_repository.save(&mut user).await?;
+        self.password_checker.remember(&user).await?;
+        let internal_id = user.id().ok_or(ApplicationError::UserNotFound)?.value();
+        self.revocation_store.revoke_all(internal_id).await?;
+
+        present_user(&user, caller, self.organization_repository.as_ref()).await
+    }
+}
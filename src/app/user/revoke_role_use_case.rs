@@ -0,0 +1,74 @@
+use super::UserResponse;
+use crate::app::caller_context::CallerContext;
+use crate::app::errors::{AppResult, ApplicationError};
+use crate::app::policy::{Action, Policy, UserResources};
+use crate::domain::organization::OrganizationRepository;
+use crate::domain::shared::PublicUserId;
+use crate::domain::user::{Role, UserRepository};
+use std::sync::Arc;
+
+/// RevokeRoleUseCase - removes a role from a user (admin only)
+///
+/// Within an organization the role is revoked in that organization only.
+pub struct RevokeRoleUseCase {
+    user_repository: Arc<dyn UserRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+    policy: Arc<Policy>,
+    user_resources: Arc<UserResources>,
+}
+
+impl RevokeRoleUseCase {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        organization_repository: Arc<dyn OrganizationRepository>,
+        policy: Arc<Policy>,
+        user_resources: Arc<UserResources>,
+    ) -> Self {
+        Self {
+            user_repository,
+            organization_repository,
+            policy,
+            user_resources,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: PublicUserId,
+        role: Role,
+        caller: &CallerContext,
+    ) -> AppResult<UserResponse> {
+        let (user, resource) = self.user_resources.find(user_id, caller).await?;
+        self.policy
+            .authorize(caller, Action::UserRevokeRole, &resource)?;
+
+        // An admin dropping their own admin role could leave nobody to undo it
+        if role == Role::Admin && caller.is_owner(user_id) {
+            return Err(ApplicationError::Forbidden(
+                "Administrators cannot revoke their own admin role".to_string(),
+            ));
+        }
+        let mut user = user.ok_or(ApplicationError::UserNotFound)?;
+
+        // Domain logic: revoking a role the user lacks is a no-op
+        if let Some(organization_id) = caller.organization {
+            let internal_id = user.id().ok_or(ApplicationError::UserNotFound)?;
+            let mut membership = self
+                .organization_repository
+                .find_membership(organization_id, internal_id)
+                .await?
+                .ok_or(ApplicationError::MembershipNotFound)?;
+            membership.revoke(&role);
+            self.organization_repository
+                .save_membership(&membership)
+                .await?;
+            return Ok(UserResponse::from_membership(&user, &membership));
+        }
+
+        user.remove_role(&role);
+
+        self.user_repository.save(&mut user).await?;
+
+        Ok(UserResponse::from_domain(&user))
+    }
+}
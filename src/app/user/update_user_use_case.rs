@@ -1,55 +1,67 @@
-use super::{UpdateUserCommand, UserResponse};
+use super::user_response::present_user;
+use super::{UpdateUserCommand, UserResponse, date_of_birth_from};
 use crate::app::caller_context::CallerContext;
 use crate::app::errors::{AppResult, ApplicationError};
-use crate::domain::shared::UserId;
+use crate::app::policy::{Action, Policy, UserResources};
+use crate::domain::organization::OrganizationRepository;
+use crate::domain::shared::PublicUserId;
 use crate::domain::user::{Email, UserRepository};
 use std::sync::Arc;
 
 /// UpdateUserUseCase - handles updating an existing user
 pub struct UpdateUserUseCase {
     user_repository: Arc<dyn UserRepository>,
+    organization_repository: Arc<dyn OrganizationRepository>,
+    policy: Arc<Policy>,
+    user_resources: Arc<UserResources>,
 }
 
 impl UpdateUserUseCase {
-    pub fn new(user_repository: Arc<dyn UserRepository>) -> Self {
-        Self { user_repository }
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        organization_repository: Arc<dyn OrganizationRepository>,
+        policy: Arc<Policy>,
+        user_resources: Arc<UserResources>,
+    ) -> Self {
+        Self {
+            user_repository,
+            organization_repository,
+            policy,
+            user_resources,
+        }
     }
 
     pub async fn execute(
         &self,
-        user_id: i32,
+        user_id: PublicUserId,
         command: UpdateUserCommand,
         caller: &CallerContext,
     ) -> AppResult<UserResponse> {
-        // Authorization: admin can update any user, regular users only their own
-        if !caller.can_access_user(user_id) {
-            return Err(ApplicationError::Forbidden(
-                "You can only update your own profile".to_string(),
-            ));
-        }
-
-        let user_id = UserId::from(user_id);
-
         // Find the user
-        let mut user = self
-            .user_repository
-            .find_by_id(user_id)
-            .await?
-            .ok_or(ApplicationError::UserNotFound)?;
+        let (user, resource) = self.user_resources.find(user_id, caller).await?;
+        self.policy
+            .authorize(caller, Action::UserUpdate, &resource)?;
+        let mut user = user.ok_or(ApplicationError::UserNotFound)?;
 
         // Parse and validate email (domain validation)
         let new_email = Email::try_from(command.email)?;
+        // The email signs the user in, so changing it is authorized apart
+        if *user.email() != new_email {
+            self.policy
+                .authorize(caller, Action::UserChangeEmail, &resource)?;
+        }
 
         // Domain logic: update email if different
         user.change_email(new_email)?;
 
         // Domain logic: update profile
-        user.update_profile(command.first_name, command.last_name, command.age)?;
+        let date_of_birth = date_of_birth_from(command.date_of_birth, command.age)?;
+        user.update_profile(command.first_name, command.last_name, date_of_birth)?;
 
         // Persist changes
         self.user_repository.save(&mut user).await?;
 
         // Convert to response DTO
-        Ok(UserResponse::from_domain(&user))
+        present_user(&user, caller, self.organization_repository.as_ref()).await
     }
 }
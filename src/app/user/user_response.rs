@@ -1,16 +1,25 @@
+use crate::app::caller_context::CallerContext;
+use crate::app::errors::AppResult;
+use crate::domain::organization::{Membership, OrganizationRepository, TenantScope};
 use crate::domain::user::User;
 use serde::Serialize;
+use std::collections::HashMap;
 use utoipa::ToSchema;
 
 /// UserResponse DTO - for API responses
 #[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct UserResponse {
-    pub id: i32,
+    /// Public user ID (UUID)
+    pub id: String,
     pub email: String,
     pub first_name: String,
     pub last_name: String,
+    /// Date of birth (YYYY-MM-DD)
+    pub date_of_birth: String,
+    /// Age today, derived from the date of birth
     pub age: u8,
     pub created_at: String,
+    /// Roles held platform-wide, or within the caller's organization
     pub roles: Vec<String>,
 }
 
@@ -18,13 +27,66 @@ impl UserResponse {
     /// Convert from domain User entity
     pub fn from_domain(user: &User) -> Self {
         Self {
-            id: user.id().expect("User must have an ID").value(),
+            id: user.public_id().to_string(),
             email: user.email().as_ref().to_string(),
             first_name: user.profile().first_name().to_string(),
             last_name: user.profile().last_name().to_string(),
+            date_of_birth: user.profile().date_of_birth().to_string(),
             age: user.profile().age(),
             created_at: user.created_at().to_string(),
             roles: user.roles().iter().map(|r| r.to_string()).collect(),
         }
     }
+
+    /// Convert from a User acting in an organization, with the roles they hold there
+    pub fn from_membership(user: &User, membership: &Membership) -> Self {
+        Self {
+            roles: membership.roles().iter().map(|r| r.to_string()).collect(),
+            ..Self::from_domain(user)
+        }
+    }
+}
+
+/// Users as the caller sees them: within an organization, each with the
+/// roles held there rather than their own
+pub(crate) async fn present_users(
+    users: &[User],
+    caller: &CallerContext,
+    organizations: &dyn OrganizationRepository,
+) -> AppResult<Vec<UserResponse>> {
+    let TenantScope::Organization(organization_id) = caller.scope() else {
+        return Ok(users.iter().map(UserResponse::from_domain).collect());
+    };
+
+    let user_ids: Vec<_> = users.iter().filter_map(|user| user.id()).collect();
+    let memberships: HashMap<_, _> = organizations
+        .find_memberships(organization_id, &user_ids)
+        .await?
+        .into_iter()
+        .map(|membership| (membership.user_id(), membership))
+        .collect();
+
+    Ok(users
+        .iter()
+        .map(|user| {
+            match user.id().and_then(|id| memberships.get(&id)) {
+                Some(membership) => UserResponse::from_membership(user, membership),
+                // Only members are visible in an organization, so this is a race with removal
+                None => UserResponse {
+                    roles: Vec::new(),
+                    ..UserResponse::from_domain(user)
+                },
+            }
+        })
+        .collect())
+}
+
+/// A single user as the caller sees them; see [`present_users`]
+pub(crate) async fn present_user(
+    user: &User,
+    caller: &CallerContext,
+    organizations: &dyn OrganizationRepository,
+) -> AppResult<UserResponse> {
+    let mut responses = present_users(std::slice::from_ref(user), caller, organizations).await?;
+    Ok(responses.remove(0))
 }
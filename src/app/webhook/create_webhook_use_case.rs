@@ -0,0 +1,69 @@
+use super::{CreateWebhookCommand, WebhookResponse};
+use crate::app::caller_context::CallerContext;
+use crate::app::errors::{AppResult, ApplicationError};
+use crate::app::policy::{Action, Policy, Resource};
+use crate::app::ports::{NewWebhook, Webhook, WebhookStore};
+use crate::domain::user::UserEventKind;
+use std::sync::Arc;
+
+/// CreateWebhookUseCase - subscribes a partner endpoint to events (admin only)
+pub struct CreateWebhookUseCase {
+    webhook_store: Arc<dyn WebhookStore>,
+    policy: Arc<Policy>,
+}
+
+impl CreateWebhookUseCase {
+    pub fn new(webhook_store: Arc<dyn WebhookStore>, policy: Arc<Policy>) -> Self {
+        Self {
+            webhook_store,
+            policy,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        command: CreateWebhookCommand,
+        caller: &CallerContext,
+    ) -> AppResult<WebhookResponse> {
+        // Authorization: only admins manage webhooks
+        self.policy
+            .authorize(caller, Action::WebhookCreate, &Resource::of_type("webhook"))?;
+
+        // A filter matching nothing, such as the unsupported `user.deleted`,
+        // would never deliver anything
+        if let Some(filter) = command
+            .events
+            .iter()
+            .find(|filter| !Webhook::is_known_filter(filter))
+        {
+            return Err(ApplicationError::ValidationError(format!(
+                "{} matches no event; events are {}",
+                filter,
+                UserEventKind::NAMES.join(", ")
+            )));
+        }
+
+        let secret = command.secret.unwrap_or_else(|| {
+            format!(
+                "whsec_{}{}",
+                uuid::Uuid::new_v4().simple(),
+                uuid::Uuid::new_v4().simple()
+            )
+        });
+
+        let webhook = self
+            .webhook_store
+            .create(NewWebhook {
+                url: command.url,
+                events: command.events,
+                secret,
+            })
+            .await?;
+
+        // The secret is shown once, so the partner can verify signatures
+        Ok(WebhookResponse {
+            secret: Some(webhook.secret.clone()),
+            ..WebhookResponse::from_webhook(&webhook)
+        })
+    }
+}
@@ -0,0 +1,32 @@
+use crate::app::caller_context::CallerContext;
+use crate::app::errors::{AppResult, ApplicationError};
+use crate::app::policy::{Action, Policy, Resource};
+use crate::app::ports::WebhookStore;
+use std::sync::Arc;
+
+/// DeleteWebhookUseCase - removes a subscription and its delivery log (admin only)
+pub struct DeleteWebhookUseCase {
+    webhook_store: Arc<dyn WebhookStore>,
+    policy: Arc<Policy>,
+}
+
+impl DeleteWebhookUseCase {
+    pub fn new(webhook_store: Arc<dyn WebhookStore>, policy: Arc<Policy>) -> Self {
+        Self {
+            webhook_store,
+            policy,
+        }
+    }
+
+    pub async fn execute(&self, webhook_id: i32, caller: &CallerContext) -> AppResult<()> {
+        // Authorization: only admins manage webhooks
+        self.policy
+            .authorize(caller, Action::WebhookDelete, &Resource::of_type("webhook"))?;
+
+        if !self.webhook_store.delete(webhook_id).await? {
+            return Err(ApplicationError::WebhookNotFound);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use super::{ListWebhookDeliveriesQuery, WebhookDeliveryResponse};
+use crate::app::caller_context::CallerContext;
+use crate::app::errors::{AppResult, ApplicationError};
+use crate::app::policy::{Action, Policy, Resource};
+use crate::app::ports::WebhookStore;
+use std::sync::Arc;
+
+/// ListWebhookDeliveriesUseCase - the delivery log of a webhook (admin only)
+pub struct ListWebhookDeliveriesUseCase {
+    webhook_store: Arc<dyn WebhookStore>,
+    policy: Arc<Policy>,
+}
+
+impl ListWebhookDeliveriesUseCase {
+    pub fn new(webhook_store: Arc<dyn WebhookStore>, policy: Arc<Policy>) -> Self {
+        Self {
+            webhook_store,
+            policy,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        query: ListWebhookDeliveriesQuery,
+        caller: &CallerContext,
+    ) -> AppResult<(Vec<WebhookDeliveryResponse>, u64)> {
+        // Authorization: only admins manage webhooks
+        self.policy.authorize(
+            caller,
+            Action::WebhookListDeliveries,
+            &Resource::of_type("webhook"),
+        )?;
+
+        let (deliveries, total) = self
+            .webhook_store
+            .deliveries(query.webhook_id, query.page, query.rows_per_page)
+            .await?
+            .ok_or(ApplicationError::WebhookNotFound)?;
+
+        Ok((
+            deliveries
+                .iter()
+                .map(WebhookDeliveryResponse::from_delivery)
+                .collect(),
+            total,
+        ))
+    }
+}
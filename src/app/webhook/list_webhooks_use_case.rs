@@ -0,0 +1,31 @@
+use super::WebhookResponse;
+use crate::app::caller_context::CallerContext;
+use crate::app::errors::AppResult;
+use crate::app::policy::{Action, Policy, Resource};
+use crate::app::ports::WebhookStore;
+use std::sync::Arc;
+
+/// ListWebhooksUseCase - lists webhook subscriptions (admin only)
+pub struct ListWebhooksUseCase {
+    webhook_store: Arc<dyn WebhookStore>,
+    policy: Arc<Policy>,
+}
+
+impl ListWebhooksUseCase {
+    pub fn new(webhook_store: Arc<dyn WebhookStore>, policy: Arc<Policy>) -> Self {
+        Self {
+            webhook_store,
+            policy,
+        }
+    }
+
+    pub async fn execute(&self, caller: &CallerContext) -> AppResult<Vec<WebhookResponse>> {
+        // Authorization: only admins manage webhooks
+        self.policy
+            .authorize(caller, Action::WebhookList, &Resource::of_type("webhook"))?;
+
+        let webhooks = self.webhook_store.list().await?;
+
+        Ok(webhooks.iter().map(WebhookResponse::from_webhook).collect())
+    }
+}
@@ -0,0 +1,43 @@
+use super::WebhookDeliveryResponse;
+use crate::app::caller_context::CallerContext;
+use crate::app::errors::{AppResult, ApplicationError};
+use crate::app::policy::{Action, Policy, Resource};
+use crate::app::ports::WebhookStore;
+use std::sync::Arc;
+
+/// RedeliverWebhookUseCase - queues a delivery again, e.g. after a dead letter (admin only)
+pub struct RedeliverWebhookUseCase {
+    webhook_store: Arc<dyn WebhookStore>,
+    policy: Arc<Policy>,
+}
+
+impl RedeliverWebhookUseCase {
+    pub fn new(webhook_store: Arc<dyn WebhookStore>, policy: Arc<Policy>) -> Self {
+        Self {
+            webhook_store,
+            policy,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        webhook_id: i32,
+        delivery_id: i64,
+        caller: &CallerContext,
+    ) -> AppResult<WebhookDeliveryResponse> {
+        // Authorization: only admins manage webhooks
+        self.policy.authorize(
+            caller,
+            Action::WebhookRedeliver,
+            &Resource::of_type("webhook"),
+        )?;
+
+        let delivery = self
+            .webhook_store
+            .redeliver(webhook_id, delivery_id)
+            .await?
+            .ok_or(ApplicationError::WebhookDeliveryNotFound)?;
+
+        Ok(WebhookDeliveryResponse::from_delivery(&delivery))
+    }
+}
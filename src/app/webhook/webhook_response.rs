@@ -0,0 +1,66 @@
+use crate::app::ports::{Webhook, WebhookDelivery};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// WebhookResponse DTO - for API responses
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct WebhookResponse {
+    pub id: i32,
+    pub url: String,
+    pub events: Vec<String>,
+    /// Only returned when the webhook is created
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+    pub created_at: String,
+}
+
+impl WebhookResponse {
+    /// Convert from a stored webhook, leaving out the secret
+    pub fn from_webhook(webhook: &Webhook) -> Self {
+        Self {
+            id: webhook.id,
+            url: webhook.url.clone(),
+            events: webhook.events.clone(),
+            secret: None,
+            created_at: webhook.created_at.to_rfc3339(),
+        }
+    }
+}
+
+/// WebhookDeliveryResponse DTO - an entry of the delivery log
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct WebhookDeliveryResponse {
+    pub id: i64,
+    pub webhook_id: i32,
+    pub event_id: String,
+    pub event_type: String,
+    /// pending, delivered or dead
+    pub status: String,
+    pub attempts: i32,
+    pub last_status_code: Option<u16>,
+    pub last_error: Option<String>,
+    pub next_attempt_at: String,
+    pub created_at: String,
+    pub delivered_at: Option<String>,
+    #[schema(value_type = Object)]
+    pub payload: serde_json::Value,
+}
+
+impl WebhookDeliveryResponse {
+    pub fn from_delivery(delivery: &WebhookDelivery) -> Self {
+        Self {
+            id: delivery.id,
+            webhook_id: delivery.webhook_id,
+            event_id: delivery.event_id.to_string(),
+            event_type: delivery.event_type.clone(),
+            status: delivery.status.as_str().to_string(),
+            attempts: delivery.attempts,
+            last_status_code: delivery.last_status_code,
+            last_error: delivery.last_error.clone(),
+            next_attempt_at: delivery.next_attempt_at.to_rfc3339(),
+            created_at: delivery.created_at.to_rfc3339(),
+            delivered_at: delivery.delivered_at.map(|at| at.to_rfc3339()),
+            payload: delivery.payload.clone(),
+        }
+    }
+}
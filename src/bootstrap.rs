@@ -6,14 +6,71 @@
 
 use std::sync::Arc;
 
-use crate::app::auth::{LoginUseCase, RegisterUseCase};
-use crate::app::ports::TokenService;
-use crate::app::user::{CreateUserUseCase, GetUserUseCase, ListUsersUseCase, UpdateUserUseCase};
-use crate::domain::user::UserRepository;
-use crate::infra::auth::JwtTokenService;
+use crate::app::auth::{
+    HashingPool, LoginUseCase, PasswordChecker, RegisterUseCase, RevokeTokensUseCase,
+};
+use crate::app::cache::GetCacheStatsUseCase;
+use crate::app::events::EventDispatcher;
+use crate::app::group::{
+    AddGroupMemberUseCase, CreateGroupUseCase, DeleteGroupUseCase, ListGroupMembersUseCase,
+    ListGroupsUseCase, ListUserGroupsUseCase, RemoveGroupMemberUseCase, UpdateGroupUseCase,
+};
+use crate::app::health::CheckReadinessUseCase;
+use crate::app::invitation::{
+    AcceptInvitationUseCase, CreateInvitationUseCase, InvitationSender, ListInvitationsUseCase,
+    ResendInvitationUseCase, RevokeInvitationUseCase, SendInvitation, SendInvitationHandler,
+};
+use crate::app::jobs::{GetJobUseCase, JobRegistry, ListJobsUseCase, RetryJobUseCase};
+use crate::app::mail::{SendMail, SendMailHandler};
+use crate::app::organization::{
+    AddMemberUseCase, CreateOrganizationUseCase, ListOrganizationsUseCase, RemoveMemberUseCase,
+};
+use crate::app::policy::{ExplainDecisionUseCase, UserResources};
+use crate::app::ports::{
+    BreachedPasswordList, CacheMetrics, CacheStore, HealthCheck, IdempotencyStore,
+    InvitationSigner, InvitationStore, JobQueue, Mailer, MessagePublisher, RateLimitStore,
+    TokenRevocationStore, TokenService, WebhookStore,
+};
+use crate::app::user::{
+    CreateUserUseCase, GetUserUseCase, GrantRoleUseCase, ListUsersUseCase, ResetPasswordUseCase,
+    RevokeRoleUseCase, UpdateUserUseCase,
+};
+use crate::app::webhook::{
+    CreateWebhookUseCase, DeleteWebhookUseCase, ListWebhookDeliveriesUseCase, ListWebhooksUseCase,
+    RedeliverWebhookUseCase,
+};
+use crate::domain::group::GroupRepository;
+use crate::domain::organization::OrganizationRepository;
+use crate::domain::user::{PasswordHasher, PasswordPolicy, UserRepository};
+use crate::infra::auth::{
+    BreachedPasswordDirectory, HmacInvitationSigner, JwtTokenService, SeaOrmInvitationStore,
+    SeaOrmPasswordHistoryStore, SeaOrmTokenRevocationStore, load_policy,
+};
+use crate::infra::cache::{
+    CachingGroupRepository, CachingOrganizationRepository, CachingTokenRevocationStore,
+    CachingUserRepository, MemoryCacheStore, RedisCacheStore,
+};
+use crate::infra::config::app_config::{
+    CacheBackend, MailTransport, OutboxSink, PasswordHashAlgorithm, RateLimitBackend,
+};
 use crate::infra::config::{self, Config};
-use crate::infra::persistence::SeaOrmUserRepository;
+use crate::infra::events::AuditLogSubscriber;
+use crate::infra::health::{
+    DatabaseHealthCheck, DrainingHealthCheck, MigrationsHealthCheck, SigningKeysHealthCheck,
+};
+use crate::infra::idempotency::{self, SeaOrmIdempotencyStore};
+use crate::infra::jobs::{self, JobWorker, SeaOrmJobQueue};
+use crate::infra::lifecycle::Lifecycle;
+use crate::infra::mail::{self, MboxMailer, MemoryMailer, SmtpMailer};
+use crate::infra::outbox::{self, AmqpPublisher, JsonLinesPublisher, OutboxRelay};
+use crate::infra::persistence::{
+    SeaOrmGroupRepository, SeaOrmOrganizationRepository, SeaOrmUserRepository, Seed, schema,
+};
+use crate::infra::rate_limit::{MemoryRateLimitStore, RedisRateLimitStore};
+use crate::infra::tls::ServiceIdentities;
+use crate::infra::webhooks::{self, SeaOrmWebhookStore, WebhookDeliveryWorker};
 use crate::presentation::AppState;
+use crate::presentation::middleware::{IdempotencyGuard, RateLimiter};
 
 /// Bootstrap error type
 #[derive(Debug)]
@@ -30,7 +87,7 @@ impl std::error::Error for BootstrapError {}
 /// Bootstrap the application and return the configured AppState
 ///
 /// This function:
-/// 1. Connects to the database
+/// 1. Connects to the database and checks (or migrates) its schema
 /// 2. Creates infrastructure implementations (repositories, services)
 /// 3. Injects them into application use cases
 /// 4. Returns a fully configured AppState
@@ -42,31 +99,542 @@ pub async fn create_app_state(config: Config) -> Result<AppState, BootstrapError
             .map_err(|e| BootstrapError(format!("Failed to connect to database: {}", e)))?,
     );
 
+    // Infrastructure layer: Bring the schema up to date, or refuse to run on a stale one
+    if config.database.auto_migrate {
+        let applied = schema::migrate(&db)
+            .await
+            .map_err(|e| BootstrapError(format!("Failed to apply migrations: {}", e)))?;
+        if !applied.is_empty() {
+            tracing::info!(migrations = ?applied, "Applied migrations");
+        }
+    }
+    schema::check(&db).await.map_err(|e| {
+        BootstrapError(format!(
+            "Database schema does not match this build ({}); run `mini-rust-api admin migrate` or set DATABASE__AUTO_MIGRATE=true",
+            e
+        ))
+    })?;
+
+    // Infrastructure layer: Shutdown coordination (owns the pool for closing)
+    let lifecycle = Arc::new(Lifecycle::new(db.clone()));
+
+    // Infrastructure layer: Create readiness checks
+    let health_checks: Vec<Arc<dyn HealthCheck>> = vec![
+        Arc::new(DrainingHealthCheck::new(lifecycle.clone())),
+        Arc::new(DatabaseHealthCheck::new(db.clone())),
+        Arc::new(MigrationsHealthCheck::new(db.clone())),
+        Arc::new(SigningKeysHealthCheck),
+    ];
+
+    // Infrastructure layer: Idempotency keys, purged in the background
+    let idempotency_store: Arc<dyn IdempotencyStore> = Arc::new(SeaOrmIdempotencyStore::new(
+        db.clone(),
+        config.idempotency.lock_timeout,
+    ));
+    if config.server.background_workers {
+        idempotency::spawn_sweeper(
+            idempotency_store.clone(),
+            config.idempotency.sweep_interval,
+            &lifecycle,
+        );
+    }
+    let idempotency_guard = Arc::new(IdempotencyGuard::new(
+        idempotency_store,
+        config.idempotency.clone(),
+    ));
+
+    // Infrastructure layer: Outbox relay publishing domain events to other services
+    let message_publisher: Arc<dyn MessagePublisher> = match config.outbox.sink {
+        OutboxSink::Stdout => Arc::new(JsonLinesPublisher::stdout()),
+        OutboxSink::File => Arc::new(
+            JsonLinesPublisher::open(&config.outbox.file_path)
+                .await
+                .map_err(|e| BootstrapError(format!("Failed to open outbox file: {}", e)))?,
+        ),
+        OutboxSink::Amqp => Arc::new(AmqpPublisher::new(
+            &config.amqp.url,
+            &config.outbox.amqp_exchange,
+        )),
+    };
+    if config.server.background_workers {
+        outbox::spawn_relay(
+            Arc::new(OutboxRelay::new(
+                db.clone(),
+                message_publisher,
+                config.outbox.clone(),
+            )),
+            &lifecycle,
+        );
+    }
+
+    // Infrastructure layer: Webhook subscriptions, delivered in the background
+    let webhook_store: Arc<dyn WebhookStore> = Arc::new(SeaOrmWebhookStore::new(db.clone()));
+    if config.server.background_workers {
+        webhooks::spawn_delivery_worker(
+            Arc::new(WebhookDeliveryWorker::new(
+                db.clone(),
+                config.webhooks.clone(),
+            )),
+            &lifecycle,
+        );
+    }
+
+    // Infrastructure layer: Outbound email and its templates
+    let mailer: Arc<dyn Mailer> = match config.mail.transport {
+        MailTransport::Smtp => {
+            Arc::new(SmtpMailer::new(config.mail.smtp.clone(), &config.mail.from))
+        }
+        MailTransport::File => Arc::new(
+            MboxMailer::open(&config.mail.file_path, &config.mail.from)
+                .await
+                .map_err(|e| BootstrapError(format!("Failed to open mail file: {}", e)))?,
+        ),
+        MailTransport::Memory => Arc::new(MemoryMailer::new()),
+    };
+    let mail_templates = Arc::new(
+        mail::load_templates(&config.mail.templates_dir, &config.mail.default_locale)
+            .map_err(|e| BootstrapError(format!("Failed to load mail templates: {}", e)))?,
+    );
+
+    // Infrastructure layer: Background job queue; its workers start once the
+    // stores their handlers need are wired below
+    let job_queue: Arc<dyn JobQueue> = Arc::new(SeaOrmJobQueue::new(db.clone()));
+
+    // Application layer: Domain event subscribers
+    let event_dispatcher = Arc::new(EventDispatcher::new(vec![Arc::new(AuditLogSubscriber)]));
+
+    // Infrastructure layer: Per-user cut-offs for revoked tokens
+    let token_revocation_store: Arc<dyn TokenRevocationStore> =
+        Arc::new(SeaOrmTokenRevocationStore::new(db.clone()));
+
     // Infrastructure layer: Create repository implementation
-    let user_repository: Arc<dyn UserRepository> = Arc::new(SeaOrmUserRepository::new(db));
+    let user_repository: Arc<dyn UserRepository> = Arc::new(SeaOrmUserRepository::new(
+        db.clone(),
+        event_dispatcher.clone(),
+    ));
+    let organization_repository: Arc<dyn OrganizationRepository> =
+        Arc::new(SeaOrmOrganizationRepository::new(db.clone()));
+    let group_repository: Arc<dyn GroupRepository> =
+        Arc::new(SeaOrmGroupRepository::new(db.clone()));
+
+    // Application layer: Password policy, breached list and reuse history
+    let passwords = &config.passwords;
+    let password_policy = PasswordPolicy {
+        min_length: passwords.min_length,
+        max_length: passwords.max_length,
+        require_uppercase: passwords.require_uppercase,
+        require_lowercase: passwords.require_lowercase,
+        require_digit: passwords.require_digit,
+        require_symbol: passwords.require_symbol,
+        min_entropy_bits: passwords.min_entropy_bits,
+        disallow_personal_info: passwords.disallow_personal_info,
+        history_size: passwords.history_size,
+    };
+    let password_hasher = match passwords.hash_algorithm {
+        PasswordHashAlgorithm::Argon2id => PasswordHasher::argon2id(
+            passwords.argon2_memory_kib,
+            passwords.argon2_iterations,
+            passwords.argon2_parallelism,
+        ),
+        PasswordHashAlgorithm::Bcrypt => PasswordHasher::bcrypt(passwords.bcrypt_cost),
+    }
+    .map_err(|e| BootstrapError(e.to_string()))?;
+    let hashing_pool = Arc::new(HashingPool::new(
+        passwords.hashing_concurrency,
+        passwords.hashing_queue_size,
+    ));
+    let breached_passwords: Option<Arc<dyn BreachedPasswordList>> = passwords
+        .breached_list_dir
+        .clone()
+        .map(|dir| Arc::new(BreachedPasswordDirectory::new(dir)) as _);
+    let password_checker = Arc::new(PasswordChecker::new(
+        password_policy,
+        password_hasher.clone(),
+        hashing_pool.clone(),
+        breached_passwords,
+        Arc::new(SeaOrmPasswordHistoryStore::new(db.clone())),
+    ));
+
+    // Infrastructure layer: Declarative seed data (roles, initial admin)
+    if let Some(path) = &config.database.seed_file {
+        let outcome = Seed::load(path)
+            .map_err(|e| BootstrapError(format!("Failed to load seed file: {}", e)))?
+            .apply(
+                &db,
+                user_repository.as_ref(),
+                &event_dispatcher,
+                password_checker.policy(),
+                password_checker.hasher(),
+            )
+            .await
+            .map_err(|e| BootstrapError(format!("Failed to apply seed file: {}", e)))?;
+        tracing::info!(?outcome, "Applied seed file");
+    }
+
+    // Infrastructure layer: Read-through cache of the lookups made by every
+    // authenticated request
+    let mut caches: Vec<Arc<dyn CacheMetrics>> = Vec::new();
+    let (user_repository, token_revocation_store, organization_repository, group_repository) =
+        if config.cache.enabled {
+            let cache_store: Arc<dyn CacheStore> = match config.cache.backend {
+                CacheBackend::Memory => Arc::new(MemoryCacheStore::new(config.cache.max_entries)),
+                CacheBackend::Redis => Arc::new(
+                    RedisCacheStore::connect(&config.redis.url)
+                        .await
+                        .map_err(|e| {
+                            BootstrapError(format!("Failed to connect to Redis: {}", e))
+                        })?,
+                ),
+            };
+            let ttl = config.cache.ttl;
+            let users = Arc::new(CachingUserRepository::new(
+                user_repository,
+                cache_store.clone(),
+                ttl,
+            ));
+            let revocations = Arc::new(CachingTokenRevocationStore::new(
+                token_revocation_store,
+                cache_store.clone(),
+                ttl,
+            ));
+            let organizations = Arc::new(CachingOrganizationRepository::new(
+                organization_repository,
+                cache_store.clone(),
+                ttl,
+            ));
+            let groups = Arc::new(CachingGroupRepository::new(
+                group_repository,
+                cache_store,
+                ttl,
+            ));
+            caches.push(users.clone());
+            caches.push(revocations.clone());
+            caches.push(organizations.clone());
+            caches.push(groups.clone());
+            (
+                users as Arc<dyn UserRepository>,
+                revocations as Arc<dyn TokenRevocationStore>,
+                organizations as Arc<dyn OrganizationRepository>,
+                groups as Arc<dyn GroupRepository>,
+            )
+        } else {
+            (
+                user_repository,
+                token_revocation_store,
+                organization_repository,
+                group_repository,
+            )
+        };
 
     // Infrastructure layer: Create token service
     let token_service: Arc<dyn TokenService> = Arc::new(JwtTokenService::new());
 
+    // Infrastructure layer: Invitations and the signer of their links
+    let invitation_store: Arc<dyn InvitationStore> =
+        Arc::new(SeaOrmInvitationStore::new(db.clone(), event_dispatcher));
+    let invitation_signer: Arc<dyn InvitationSigner> = Arc::new(HmacInvitationSigner::new(
+        config.invitations.signing_key.as_deref().ok_or_else(|| {
+            BootstrapError(
+                "INVITATIONS__SIGNING_KEY or JWT_SECRET must be set to sign invitations"
+                    .to_string(),
+            )
+        })?,
+    ));
+    let invitation_sender = Arc::new(InvitationSender::new(job_queue.clone()));
+
+    // Infrastructure layer: Background job workers
+    let send_mail = Arc::new(SendMailHandler::new(mail_templates, mailer));
+    let job_registry = Arc::new(
+        JobRegistry::new()
+            .register::<SendMail>(send_mail.clone())
+            .register::<SendInvitation>(Arc::new(SendInvitationHandler::new(
+                invitation_store.clone(),
+                invitation_signer.clone(),
+                organization_repository.clone(),
+                send_mail,
+                config.invitations.accept_url.clone(),
+            ))),
+    );
+    if config.server.background_workers {
+        jobs::spawn_workers(
+            Arc::new(JobWorker::new(
+                db.clone(),
+                job_registry,
+                config.jobs.clone(),
+            )),
+            &lifecycle,
+        );
+    }
+
+    // Infrastructure layer: Service identities for mutual TLS callers
+    let service_identities = Arc::new(
+        config
+            .tls
+            .as_ref()
+            .map(|tls| ServiceIdentities::new(&tls.client_identities))
+            .unwrap_or_default(),
+    );
+
+    // Infrastructure layer: Rate limit counters, shared through Redis when configured
+    if config.rate_limit.enabled {
+        config
+            .rate_limit
+            .validate()
+            .map_err(|e| BootstrapError(format!("Invalid rate limit configuration: {}", e)))?;
+    }
+    let rate_limit_store: Arc<dyn RateLimitStore> = match config.rate_limit.backend {
+        RateLimitBackend::Memory => Arc::new(MemoryRateLimitStore::new()),
+        RateLimitBackend::Redis => Arc::new(
+            RedisRateLimitStore::connect(&config.redis.url)
+                .await
+                .map_err(|e| BootstrapError(format!("Failed to connect to Redis: {}", e)))?,
+        ),
+    };
+    let rate_limiter = Arc::new(RateLimiter::new(
+        rate_limit_store,
+        config.rate_limit.clone(),
+    ));
+
+    // Application layer: Authorization policy and the user attributes it tests
+    let policy = Arc::new(
+        load_policy(&config.authorization.policy_file)
+            .map_err(|e| BootstrapError(format!("Failed to load policy file: {}", e)))?,
+    );
+    let user_resources = Arc::new(UserResources::new(
+        user_repository.clone(),
+        group_repository.clone(),
+        organization_repository.clone(),
+    ));
+
     // Application layer: Create use cases
     let login_use_case = Arc::new(LoginUseCase::new(
         user_repository.clone(),
+        organization_repository.clone(),
         token_service.clone(),
+        password_hasher,
+        hashing_pool.clone(),
+    ));
+    let register_use_case = Arc::new(RegisterUseCase::new(
+        user_repository.clone(),
+        password_checker.clone(),
+        hashing_pool.clone(),
+    ));
+    let revoke_tokens_use_case = Arc::new(RevokeTokensUseCase::new(
+        token_revocation_store.clone(),
+        policy.clone(),
+        user_resources.clone(),
+    ));
+    let create_user_use_case = Arc::new(CreateUserUseCase::new(
+        user_repository.clone(),
+        organization_repository.clone(),
+        password_checker.clone(),
+        hashing_pool.clone(),
+        policy.clone(),
+    ));
+    let get_user_use_case = Arc::new(GetUserUseCase::new(
+        organization_repository.clone(),
+        policy.clone(),
+        user_resources.clone(),
+    ));
+    let list_users_use_case = Arc::new(ListUsersUseCase::new(
+        user_repository.clone(),
+        organization_repository.clone(),
+        policy.clone(),
+    ));
+    let update_user_use_case = Arc::new(UpdateUserUseCase::new(
+        user_repository.clone(),
+        organization_repository.clone(),
+        policy.clone(),
+        user_resources.clone(),
+    ));
+    let grant_role_use_case = Arc::new(GrantRoleUseCase::new(
+        user_repository.clone(),
+        organization_repository.clone(),
+        policy.clone(),
+        user_resources.clone(),
+    ));
+    let revoke_role_use_case = Arc::new(RevokeRoleUseCase::new(
+        user_repository.clone(),
+        organization_repository.clone(),
+        policy.clone(),
+        user_resources.clone(),
+    ));
+    let reset_password_use_case = Arc::new(ResetPasswordUseCase::new(
+        user_repository.clone(),
+        organization_repository.clone(),
+        token_revocation_store.clone(),
+        password_checker.clone(),
+        hashing_pool.clone(),
+        policy.clone(),
+        user_resources.clone(),
+    ));
+    let create_organization_use_case = Arc::new(CreateOrganizationUseCase::new(
+        organization_repository.clone(),
+        policy.clone(),
+    ));
+    let list_organizations_use_case = Arc::new(ListOrganizationsUseCase::new(
+        user_repository.clone(),
+        organization_repository.clone(),
+        policy.clone(),
+    ));
+    let add_member_use_case = Arc::new(AddMemberUseCase::new(
+        user_repository.clone(),
+        organization_repository.clone(),
+        policy.clone(),
+    ));
+    let remove_member_use_case = Arc::new(RemoveMemberUseCase::new(
+        user_repository.clone(),
+        organization_repository.clone(),
+        policy.clone(),
+    ));
+    let create_invitation_use_case = Arc::new(CreateInvitationUseCase::new(
+        invitation_store.clone(),
+        user_repository.clone(),
+        organization_repository.clone(),
+        invitation_sender.clone(),
+        config.invitations.ttl,
+        policy.clone(),
+    ));
+    let list_invitations_use_case = Arc::new(ListInvitationsUseCase::new(
+        invitation_store.clone(),
+        organization_repository.clone(),
+        policy.clone(),
+    ));
+    let resend_invitation_use_case = Arc::new(ResendInvitationUseCase::new(
+        invitation_store.clone(),
+        organization_repository.clone(),
+        invitation_sender,
+        config.invitations.ttl,
+        policy.clone(),
+    ));
+    let revoke_invitation_use_case = Arc::new(RevokeInvitationUseCase::new(
+        invitation_store.clone(),
+        policy.clone(),
+    ));
+    let accept_invitation_use_case = Arc::new(AcceptInvitationUseCase::new(
+        invitation_store,
+        invitation_signer,
+        user_repository.clone(),
+        password_checker,
+        hashing_pool,
+    ));
+    let create_group_use_case = Arc::new(CreateGroupUseCase::new(
+        group_repository.clone(),
+        organization_repository.clone(),
+        policy.clone(),
+    ));
+    let list_groups_use_case = Arc::new(ListGroupsUseCase::new(
+        group_repository.clone(),
+        organization_repository.clone(),
+        policy.clone(),
+    ));
+    let update_group_use_case = Arc::new(UpdateGroupUseCase::new(
+        group_repository.clone(),
+        organization_repository.clone(),
+        policy.clone(),
+    ));
+    let delete_group_use_case = Arc::new(DeleteGroupUseCase::new(
+        group_repository.clone(),
+        policy.clone(),
+    ));
+    let add_group_member_use_case = Arc::new(AddGroupMemberUseCase::new(
+        group_repository.clone(),
+        user_repository.clone(),
+        policy.clone(),
+    ));
+    let remove_group_member_use_case = Arc::new(RemoveGroupMemberUseCase::new(
+        group_repository.clone(),
+        user_repository.clone(),
+        policy.clone(),
+    ));
+    let list_group_members_use_case = Arc::new(ListGroupMembersUseCase::new(
+        group_repository.clone(),
+        user_repository.clone(),
+        organization_repository.clone(),
+        policy.clone(),
+    ));
+    let list_user_groups_use_case = Arc::new(ListUserGroupsUseCase::new(
+        group_repository.clone(),
+        organization_repository.clone(),
+        policy.clone(),
+        user_resources.clone(),
+    ));
+    let explain_decision_use_case =
+        Arc::new(ExplainDecisionUseCase::new(policy.clone(), user_resources));
+    let create_webhook_use_case = Arc::new(CreateWebhookUseCase::new(
+        webhook_store.clone(),
+        policy.clone(),
+    ));
+    let list_webhooks_use_case = Arc::new(ListWebhooksUseCase::new(
+        webhook_store.clone(),
+        policy.clone(),
+    ));
+    let delete_webhook_use_case = Arc::new(DeleteWebhookUseCase::new(
+        webhook_store.clone(),
+        policy.clone(),
+    ));
+    let list_webhook_deliveries_use_case = Arc::new(ListWebhookDeliveriesUseCase::new(
+        webhook_store.clone(),
+        policy.clone(),
+    ));
+    let redeliver_webhook_use_case =
+        Arc::new(RedeliverWebhookUseCase::new(webhook_store, policy.clone()));
+    let list_jobs_use_case = Arc::new(ListJobsUseCase::new(job_queue.clone(), policy.clone()));
+    let get_job_use_case = Arc::new(GetJobUseCase::new(job_queue.clone(), policy.clone()));
+    let retry_job_use_case = Arc::new(RetryJobUseCase::new(job_queue, policy.clone()));
+    let get_cache_stats_use_case = Arc::new(GetCacheStatsUseCase::new(caches, policy));
+    let check_readiness_use_case = Arc::new(CheckReadinessUseCase::new(
+        health_checks,
+        config.health.check_timeout,
     ));
-    let register_use_case = Arc::new(RegisterUseCase::new(user_repository.clone()));
-    let create_user_use_case = Arc::new(CreateUserUseCase::new(user_repository.clone()));
-    let get_user_use_case = Arc::new(GetUserUseCase::new(user_repository.clone()));
-    let list_users_use_case = Arc::new(ListUsersUseCase::new(user_repository.clone()));
-    let update_user_use_case = Arc::new(UpdateUserUseCase::new(user_repository.clone()));
 
     Ok(AppState {
         config,
+        lifecycle,
         user_repository,
+        organization_repository,
+        group_repository,
+        token_revocation_store,
+        service_identities,
+        rate_limiter,
+        idempotency_guard,
         login_use_case,
         register_use_case,
+        revoke_tokens_use_case,
         create_user_use_case,
         get_user_use_case,
         list_users_use_case,
         update_user_use_case,
+        grant_role_use_case,
+        revoke_role_use_case,
+        reset_password_use_case,
+        create_organization_use_case,
+        list_organizations_use_case,
+        add_member_use_case,
+        remove_member_use_case,
+        create_invitation_use_case,
+        list_invitations_use_case,
+        resend_invitation_use_case,
+        revoke_invitation_use_case,
+        accept_invitation_use_case,
+        create_group_use_case,
+        list_groups_use_case,
+        update_group_use_case,
+        delete_group_use_case,
+        add_group_member_use_case,
+        remove_group_member_use_case,
+        list_group_members_use_case,
+        list_user_groups_use_case,
+        explain_decision_use_case,
+        create_webhook_use_case,
+        list_webhooks_use_case,
+        delete_webhook_use_case,
+        list_webhook_deliveries_use_case,
+        redeliver_webhook_use_case,
+        list_jobs_use_case,
+        get_job_use_case,
+        retry_job_use_case,
+        get_cache_stats_use_case,
+        check_readiness_use_case,
     })
 }
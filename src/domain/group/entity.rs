@@ -0,0 +1,121 @@
+use super::GroupGrant;
+use crate::domain::shared::{GroupId, OrganizationId};
+use crate::domain::user::DomainError;
+use chrono::NaiveDate;
+use std::collections::HashSet;
+
+/// Group aggregate root - a team of users within one tenant
+///
+/// A group belongs to an organization, or to the platform when created
+/// outside of one; only users of that tenant can join it. Its grants give
+/// members access to each other.
+#[derive(Clone, Debug)]
+pub struct Group {
+    id: GroupId,
+    organization_id: Option<OrganizationId>,
+    name: String,
+    grants: HashSet<GroupGrant>,
+    created_at: NaiveDate,
+}
+
+impl Group {
+    /// Create a new group (factory method)
+    pub fn create(
+        organization_id: Option<OrganizationId>,
+        name: String,
+        grants: HashSet<GroupGrant>,
+    ) -> Result<Self, DomainError> {
+        Ok(Self {
+            id: GroupId::generate(),
+            organization_id,
+            name: Self::validate_name(name)?,
+            grants,
+            created_at: chrono::Utc::now().naive_utc().date(),
+        })
+    }
+
+    /// Reconstitute a Group from persistence (not a business operation)
+    pub fn reconstitute(
+        id: GroupId,
+        organization_id: Option<OrganizationId>,
+        name: String,
+        grants: HashSet<GroupGrant>,
+        created_at: NaiveDate,
+    ) -> Self {
+        Self {
+            id,
+            organization_id,
+            name,
+            grants,
+            created_at,
+        }
+    }
+
+    pub fn id(&self) -> GroupId {
+        self.id
+    }
+
+    pub fn organization_id(&self) -> Option<OrganizationId> {
+        self.organization_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn grants(&self) -> &HashSet<GroupGrant> {
+        &self.grants
+    }
+
+    pub fn has_grant(&self, grant: &GroupGrant) -> bool {
+        self.grants.contains(grant)
+    }
+
+    pub fn created_at(&self) -> NaiveDate {
+        self.created_at
+    }
+
+    /// Rename the group
+    pub fn rename(&mut self, name: String) -> Result<(), DomainError> {
+        self.name = Self::validate_name(name)?;
+        Ok(())
+    }
+
+    /// Replace what membership of the group grants
+    pub fn set_grants(&mut self, grants: HashSet<GroupGrant>) {
+        self.grants = grants;
+    }
+
+    fn validate_name(name: String) -> Result<String, DomainError> {
+        let name = name.trim().to_string();
+        if name.is_empty() {
+            return Err(DomainError::EmptyGroupName);
+        }
+        Ok(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_and_rename_group() {
+        let mut group = Group::create(
+            Some(OrganizationId::from(1)),
+            " Support ".to_string(),
+            HashSet::from([GroupGrant::ViewMembers]),
+        )
+        .unwrap();
+        assert_eq!(group.name(), "Support");
+        assert!(group.has_grant(&GroupGrant::ViewMembers));
+
+        group.rename("Customer Support".to_string()).unwrap();
+        assert_eq!(group.name(), "Customer Support");
+        assert!(matches!(
+            group.rename(" ".to_string()),
+            Err(DomainError::EmptyGroupName)
+        ));
+        assert_eq!(group.name(), "Customer Support");
+    }
+}
@@ -0,0 +1,35 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Group grant - what belonging to a group entitles its members to
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GroupGrant {
+    /// Members can view the profiles of their fellow members
+    ViewMembers,
+}
+
+impl GroupGrant {
+    /// Returns the string representation used for persistence and the API
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GroupGrant::ViewMembers => "view_members",
+        }
+    }
+}
+
+impl fmt::Display for GroupGrant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for GroupGrant {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "view_members" => Ok(GroupGrant::ViewMembers),
+            other => Err(format!("Unknown group grant: {}", other)),
+        }
+    }
+}
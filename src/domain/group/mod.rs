@@ -0,0 +1,7 @@
+pub mod entity;
+pub mod grant;
+pub mod repository;
+
+pub use entity::Group;
+pub use grant::GroupGrant;
+pub use repository::GroupRepository;
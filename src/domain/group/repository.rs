@@ -0,0 +1,62 @@
+use super::{Group, GroupGrant};
+use crate::domain::organization::TenantScope;
+use crate::domain::shared::{GroupId, OrganizationId, UserId};
+use crate::domain::user::repository::RepositoryError;
+use async_trait::async_trait;
+
+pub type GroupRepositoryResult<T> = Result<T, RepositoryError>;
+
+/// GroupRepository trait - defines the contract for group persistence
+/// This trait lives in the domain layer, implementations are in infrastructure
+#[async_trait]
+pub trait GroupRepository: Send + Sync {
+    /// Find a group by its identifier
+    async fn find_by_id(&self, id: GroupId) -> GroupRepositoryResult<Option<Group>>;
+
+    /// Check if the tenant already has a group with the given name
+    async fn exists_with_name(
+        &self,
+        organization_id: Option<OrganizationId>,
+        name: &str,
+    ) -> GroupRepositoryResult<bool>;
+
+    /// Save a group (insert if new, update if existing)
+    async fn save(&self, group: &Group) -> GroupRepositoryResult<()>;
+
+    /// Delete a group and its memberships; returns whether it existed
+    async fn delete(&self, id: GroupId) -> GroupRepositoryResult<bool>;
+
+    /// List the groups in the scope by name, with the total count; the
+    /// platform scope sees every group
+    async fn list(
+        &self,
+        scope: TenantScope,
+        page: u64,
+        rows_per_page: u64,
+    ) -> GroupRepositoryResult<(Vec<Group>, u64)>;
+
+    /// List the groups in the scope a user belongs to, by name
+    async fn list_for_user(
+        &self,
+        user_id: UserId,
+        scope: TenantScope,
+    ) -> GroupRepositoryResult<Vec<Group>>;
+
+    /// The groups of the tenant a user belongs to that carry the grant
+    async fn granting(
+        &self,
+        user_id: UserId,
+        organization_id: Option<OrganizationId>,
+        grant: GroupGrant,
+    ) -> GroupRepositoryResult<Vec<GroupId>>;
+
+    /// Add a user to a group; returns whether they were not a member yet
+    async fn add_member(&self, group_id: GroupId, user_id: UserId) -> GroupRepositoryResult<bool>;
+
+    /// Remove a user from a group; returns whether they were a member
+    async fn remove_member(
+        &self,
+        group_id: GroupId,
+        user_id: UserId,
+    ) -> GroupRepositoryResult<bool>;
+}
@@ -1,2 +1,4 @@
+pub mod group;
+pub mod organization;
 pub mod shared;
 pub mod user;
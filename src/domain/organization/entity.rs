@@ -0,0 +1,94 @@
+use super::OrganizationSlug;
+use crate::domain::shared::OrganizationId;
+use crate::domain::user::DomainError;
+use chrono::NaiveDate;
+
+/// Organization aggregate root - a customer company (tenant)
+///
+/// Users belong to organizations through `Membership`s, which carry the
+/// roles a user holds inside the organization.
+#[derive(Clone, Debug)]
+pub struct Organization {
+    id: Option<OrganizationId>,
+    slug: OrganizationSlug,
+    name: String,
+    created_at: NaiveDate,
+}
+
+impl Organization {
+    /// Create a new organization (factory method)
+    pub fn create(slug: OrganizationSlug, name: String) -> Result<Self, DomainError> {
+        let name = name.trim().to_string();
+        if name.is_empty() {
+            return Err(DomainError::EmptyOrganizationName);
+        }
+
+        Ok(Self {
+            id: None,
+            slug,
+            name,
+            created_at: chrono::Utc::now().naive_utc().date(),
+        })
+    }
+
+    /// Reconstitute an Organization from persistence (not a business operation)
+    pub fn reconstitute(
+        id: OrganizationId,
+        slug: OrganizationSlug,
+        name: String,
+        created_at: NaiveDate,
+    ) -> Self {
+        Self {
+            id: Some(id),
+            slug,
+            name,
+            created_at,
+        }
+    }
+
+    pub fn id(&self) -> Option<OrganizationId> {
+        self.id
+    }
+
+    pub fn slug(&self) -> &OrganizationSlug {
+        &self.slug
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn created_at(&self) -> NaiveDate {
+        self.created_at
+    }
+
+    /// Set the ID (used by repository after insert)
+    pub(crate) fn set_id(&mut self, id: OrganizationId) {
+        self.id = Some(id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slug(value: &str) -> OrganizationSlug {
+        OrganizationSlug::try_from(value.to_string()).unwrap()
+    }
+
+    #[test]
+    fn test_create_organization() {
+        let organization = Organization::create(slug("acme"), " Acme Corp ".to_string()).unwrap();
+        assert!(organization.id().is_none());
+        assert_eq!(organization.slug().as_ref(), "acme");
+        assert_eq!(organization.name(), "Acme Corp");
+    }
+
+    #[test]
+    fn test_name_is_required() {
+        assert!(matches!(
+            Organization::create(slug("acme"), "  ".to_string()),
+            Err(DomainError::EmptyOrganizationName)
+        ));
+    }
+}
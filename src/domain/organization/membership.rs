@@ -0,0 +1,95 @@
+use crate::domain::shared::{OrganizationId, UserId};
+use crate::domain::user::Role;
+use std::collections::HashSet;
+
+/// Membership - a user's place in an organization and their roles there
+///
+/// Roles held in an organization are independent of the user's own roles:
+/// an organization admin administers that organization's members only.
+#[derive(Clone, Debug)]
+pub struct Membership {
+    organization_id: OrganizationId,
+    user_id: UserId,
+    roles: HashSet<Role>,
+}
+
+impl Membership {
+    /// Add a user to an organization; new members hold the `User` role
+    pub fn new(organization_id: OrganizationId, user_id: UserId) -> Self {
+        Self {
+            organization_id,
+            user_id,
+            roles: HashSet::from([Role::User]),
+        }
+    }
+
+    /// Add a user to an organization holding `roles` besides `User`
+    pub fn with_roles(
+        organization_id: OrganizationId,
+        user_id: UserId,
+        roles: impl IntoIterator<Item = Role>,
+    ) -> Self {
+        let mut membership = Self::new(organization_id, user_id);
+        for role in roles {
+            membership.grant(role);
+        }
+        membership
+    }
+
+    /// Reconstitute a Membership from persistence (not a business operation)
+    pub fn reconstitute(
+        organization_id: OrganizationId,
+        user_id: UserId,
+        roles: HashSet<Role>,
+    ) -> Self {
+        Self {
+            organization_id,
+            user_id,
+            roles,
+        }
+    }
+
+    pub fn organization_id(&self) -> OrganizationId {
+        self.organization_id
+    }
+
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+
+    pub fn roles(&self) -> &HashSet<Role> {
+        &self.roles
+    }
+
+    pub fn has_role(&self, role: &Role) -> bool {
+        self.roles.contains(role)
+    }
+
+    /// Grant a role within the organization; granting a held role is a no-op
+    pub fn grant(&mut self, role: Role) {
+        self.roles.insert(role);
+    }
+
+    /// Revoke a role within the organization; the user stays a member
+    pub fn revoke(&mut self, role: &Role) {
+        self.roles.remove(role);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_members_start_as_users() {
+        let mut membership = Membership::new(OrganizationId::from(1), UserId::from(2));
+        assert_eq!(membership.roles(), &HashSet::from([Role::User]));
+
+        membership.grant(Role::Admin);
+        membership.grant(Role::Admin);
+        assert_eq!(membership.roles().len(), 2);
+
+        membership.revoke(&Role::User);
+        assert_eq!(membership.roles(), &HashSet::from([Role::Admin]));
+    }
+}
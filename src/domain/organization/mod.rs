@@ -0,0 +1,11 @@
+pub mod entity;
+pub mod membership;
+pub mod repository;
+pub mod slug;
+pub mod tenant_scope;
+
+pub use entity::Organization;
+pub use membership::Membership;
+pub use repository::OrganizationRepository;
+pub use slug::OrganizationSlug;
+pub use tenant_scope::TenantScope;
@@ -0,0 +1,69 @@
+use super::{Membership, Organization, OrganizationSlug};
+use crate::domain::shared::{OrganizationId, UserId};
+use crate::domain::user::repository::RepositoryError;
+use async_trait::async_trait;
+
+pub type OrganizationRepositoryResult<T> = Result<T, RepositoryError>;
+
+/// OrganizationRepository trait - defines the contract for organization persistence
+/// This trait lives in the domain layer, implementations are in infrastructure
+#[async_trait]
+pub trait OrganizationRepository: Send + Sync {
+    /// Find an organization by its identifier
+    async fn find_by_id(
+        &self,
+        id: OrganizationId,
+    ) -> OrganizationRepositoryResult<Option<Organization>>;
+
+    /// Find an organization by its slug
+    async fn find_by_slug(
+        &self,
+        slug: &OrganizationSlug,
+    ) -> OrganizationRepositoryResult<Option<Organization>>;
+
+    /// Find just the identifier of the organization with the given slug
+    async fn find_id_by_slug(
+        &self,
+        slug: &OrganizationSlug,
+    ) -> OrganizationRepositoryResult<Option<OrganizationId>>;
+
+    /// Check if an organization exists with the given slug
+    async fn exists_with_slug(&self, slug: &OrganizationSlug)
+    -> OrganizationRepositoryResult<bool>;
+
+    /// Save an organization (insert if new, update if existing)
+    async fn save(&self, organization: &mut Organization) -> OrganizationRepositoryResult<()>;
+
+    /// List every organization, oldest first
+    async fn list(&self) -> OrganizationRepositoryResult<Vec<Organization>>;
+
+    /// List the organizations a user is a member of, oldest first
+    async fn list_for_user(
+        &self,
+        user_id: UserId,
+    ) -> OrganizationRepositoryResult<Vec<Organization>>;
+
+    /// Find a user's membership of an organization
+    async fn find_membership(
+        &self,
+        organization_id: OrganizationId,
+        user_id: UserId,
+    ) -> OrganizationRepositoryResult<Option<Membership>>;
+
+    /// Find the memberships of several users of one organization
+    async fn find_memberships(
+        &self,
+        organization_id: OrganizationId,
+        user_ids: &[UserId],
+    ) -> OrganizationRepositoryResult<Vec<Membership>>;
+
+    /// Save a membership and its roles (insert if new, update if existing)
+    async fn save_membership(&self, membership: &Membership) -> OrganizationRepositoryResult<()>;
+
+    /// Remove a user from an organization; returns whether they were a member
+    async fn remove_membership(
+        &self,
+        organization_id: OrganizationId,
+        user_id: UserId,
+    ) -> OrganizationRepositoryResult<bool>;
+}
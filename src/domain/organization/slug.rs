@@ -0,0 +1,69 @@
+use crate::domain::user::DomainError;
+use std::fmt::Display;
+
+/// Shortest and longest slug; the upper bound is a DNS label
+const MIN_LEN: usize = 3;
+const MAX_LEN: usize = 63;
+
+/// OrganizationSlug value object - the name an organization is addressed by
+///
+/// Used in the `X-Organization` header, as a subdomain and in token claims,
+/// so it is restricted to what a DNS label allows: lowercase letters, digits
+/// and inner hyphens.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct OrganizationSlug(String);
+
+impl TryFrom<String> for OrganizationSlug {
+    type Error = DomainError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let slug = value.trim().to_lowercase();
+
+        let valid = (MIN_LEN..=MAX_LEN).contains(&slug.len())
+            && slug
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+            && !slug.starts_with('-')
+            && !slug.ends_with('-');
+        if !valid {
+            return Err(DomainError::InvalidOrganizationSlug(value));
+        }
+
+        Ok(Self(slug))
+    }
+}
+
+impl Display for OrganizationSlug {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl AsRef<str> for OrganizationSlug {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_slugs_are_normalized() {
+        let slug = OrganizationSlug::try_from("  Acme-Corp ".to_string()).unwrap();
+        assert_eq!(slug.as_ref(), "acme-corp");
+        assert!(OrganizationSlug::try_from("a1b".to_string()).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_slugs() {
+        for slug in ["ab", "-acme", "acme-", "acme corp", "acme.corp", "ácme"] {
+            assert!(
+                OrganizationSlug::try_from(slug.to_string()).is_err(),
+                "{slug} should be rejected"
+            );
+        }
+        assert!(OrganizationSlug::try_from("a".repeat(64)).is_err());
+    }
+}
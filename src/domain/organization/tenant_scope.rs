@@ -0,0 +1,20 @@
+use crate::domain::shared::OrganizationId;
+
+/// TenantScope - which users a query may see
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TenantScope {
+    /// Every user; for platform operators and the service itself
+    Platform,
+    /// Only members of the organization
+    Organization(OrganizationId),
+}
+
+impl TenantScope {
+    /// The organization the scope is limited to, if any
+    pub fn organization(&self) -> Option<OrganizationId> {
+        match self {
+            Self::Platform => None,
+            Self::Organization(id) => Some(*id),
+        }
+    }
+}
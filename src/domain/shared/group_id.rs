@@ -0,0 +1,39 @@
+use std::fmt;
+use uuid::Uuid;
+
+/// GroupId value object - the identifier of a group of users
+///
+/// A UUIDv7 assigned when the group is created; it is also the identifier
+/// groups are known by outside the service.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GroupId(Uuid);
+
+impl GroupId {
+    /// Generate the identifier of a new group
+    pub fn generate() -> Self {
+        Self(Uuid::now_v7())
+    }
+
+    /// Get the inner UUID
+    pub fn value(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl From<Uuid> for GroupId {
+    fn from(id: Uuid) -> Self {
+        Self(id)
+    }
+}
+
+impl From<GroupId> for Uuid {
+    fn from(id: GroupId) -> Self {
+        id.0
+    }
+}
+
+impl fmt::Display for GroupId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
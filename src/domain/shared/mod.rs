@@ -1,3 +1,9 @@
+pub mod group_id;
+pub mod organization_id;
+pub mod public_user_id;
 pub mod user_id;
 
+pub use group_id::GroupId;
+pub use organization_id::OrganizationId;
+pub use public_user_id::PublicUserId;
 pub use user_id::UserId;
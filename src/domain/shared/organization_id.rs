@@ -0,0 +1,24 @@
+/// OrganizationId value object - the internal (database) organization identifier
+///
+/// Organizations are known outside the service by their slug.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OrganizationId(i32);
+
+impl OrganizationId {
+    /// Get the inner i32 value
+    pub fn value(&self) -> i32 {
+        self.0
+    }
+}
+
+impl From<i32> for OrganizationId {
+    fn from(id: i32) -> Self {
+        Self(id)
+    }
+}
+
+impl From<OrganizationId> for i32 {
+    fn from(id: OrganizationId) -> Self {
+        id.0
+    }
+}
@@ -0,0 +1,70 @@
+use std::fmt;
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// PublicUserId value object - the identifier a user is known by outside the service
+///
+/// A UUIDv7 assigned at registration. Unlike the integer `UserId` it cannot
+/// be enumerated and does not reveal how many users have signed up.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PublicUserId(Uuid);
+
+impl PublicUserId {
+    /// Generate the identifier of a new user
+    pub fn generate() -> Self {
+        Self(Uuid::now_v7())
+    }
+
+    /// Get the inner UUID
+    pub fn value(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl From<Uuid> for PublicUserId {
+    fn from(id: Uuid) -> Self {
+        Self(id)
+    }
+}
+
+impl From<PublicUserId> for Uuid {
+    fn from(id: PublicUserId) -> Self {
+        id.0
+    }
+}
+
+impl fmt::Display for PublicUserId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl FromStr for PublicUserId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Uuid::parse_str(s)
+            .map(Self)
+            .map_err(|_| format!("Invalid user ID: {}", s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generated_ids_are_unique_v7() {
+        let first = PublicUserId::generate();
+        let second = PublicUserId::generate();
+        assert_ne!(first, second);
+        assert_eq!(first.value().get_version_num(), 7);
+    }
+
+    #[test]
+    fn test_round_trips_through_strings() {
+        let id = PublicUserId::generate();
+        assert_eq!(id.to_string().parse::<PublicUserId>(), Ok(id));
+        assert!("42".parse::<PublicUserId>().is_err());
+    }
+}
@@ -1,5 +1,7 @@
-/// UserId value object - represents a unique user identifier
-#[derive(Clone, Copy, Debug)]
+/// UserId value object - the internal (database) user identifier
+///
+/// Never exposed outside the service; see `PublicUserId`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct UserId(i32);
 
 impl UserId {
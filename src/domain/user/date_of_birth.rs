@@ -0,0 +1,86 @@
+use chrono::{Datelike, Months, NaiveDate, Utc};
+use std::fmt::Display;
+
+/// DateOfBirth value object - the age is derived from it on demand
+///
+/// Range rules live in `UserProfile`, which knows what a valid user is.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DateOfBirth(NaiveDate);
+
+impl DateOfBirth {
+    /// Latest date of birth for someone who is `age` years old on `today`
+    ///
+    /// Used for clients still sending an age instead of a date of birth.
+    pub fn from_age(age: u8, today: NaiveDate) -> Self {
+        let date = today
+            .checked_sub_months(Months::new(u32::from(age) * 12))
+            .unwrap_or(NaiveDate::MIN);
+        Self(date)
+    }
+
+    /// Get the date
+    pub fn value(&self) -> NaiveDate {
+        self.0
+    }
+
+    /// Whole years completed on `today`; negative for dates in the future
+    pub fn age_on(&self, today: NaiveDate) -> i32 {
+        let years = today.year() - self.0.year();
+        if (today.month(), today.day()) < (self.0.month(), self.0.day()) {
+            years - 1
+        } else {
+            years
+        }
+    }
+
+    /// Whole years completed today (UTC)
+    pub fn age(&self) -> i32 {
+        self.age_on(Utc::now().date_naive())
+    }
+}
+
+impl From<NaiveDate> for DateOfBirth {
+    fn from(date: NaiveDate) -> Self {
+        Self(date)
+    }
+}
+
+impl Display for DateOfBirth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_age_turns_on_birthday() {
+        let dob = DateOfBirth::from(date(2000, 6, 15));
+        assert_eq!(dob.age_on(date(2018, 6, 14)), 17);
+        assert_eq!(dob.age_on(date(2018, 6, 15)), 18);
+        assert_eq!(dob.age_on(date(2019, 1, 1)), 18);
+        assert_eq!(dob.age_on(date(1999, 12, 31)), -1);
+    }
+
+    #[test]
+    fn test_leap_day_birthday() {
+        let dob = DateOfBirth::from(date(2004, 2, 29));
+        assert_eq!(dob.age_on(date(2022, 2, 28)), 17);
+        assert_eq!(dob.age_on(date(2022, 3, 1)), 18);
+    }
+
+    #[test]
+    fn test_from_age_round_trips() {
+        let today = date(2025, 3, 10);
+        let dob = DateOfBirth::from_age(30, today);
+        assert_eq!(dob.value(), date(1995, 3, 10));
+        assert_eq!(dob.age_on(today), 30);
+        assert_eq!(dob.age_on(today.pred_opt().unwrap()), 29);
+    }
+}
@@ -1,18 +1,24 @@
 use std::collections::HashSet;
 
-use super::{DomainError, Email, Password, Role, UserProfile};
-use crate::domain::shared::UserId;
-use chrono::NaiveDate;
+use super::{
+    DateOfBirth, DomainError, Email, Password, PasswordHasher, PasswordPolicy, Role, UserEvent,
+    UserEventKind, UserProfile,
+};
+use crate::domain::shared::{PublicUserId, UserId};
+use chrono::{DateTime, NaiveDate, Utc};
 
 /// User aggregate root - rich domain entity with business logic
 #[derive(Clone)]
 pub struct User {
     id: Option<UserId>,
+    public_id: PublicUserId,
     email: Email,
     password: Password,
     profile: UserProfile,
     created_at: NaiveDate,
     roles: HashSet<Role>,
+    // Events recorded by behaviors, drained by the repository after saving
+    pending_events: Vec<(DateTime<Utc>, UserEventKind)>,
 }
 
 impl User {
@@ -23,25 +29,37 @@ impl User {
         raw_password: String,
         first_name: String,
         last_name: String,
-        age: u8,
+        date_of_birth: DateOfBirth,
+        policy: &PasswordPolicy,
+        hasher: &PasswordHasher,
     ) -> Result<Self, DomainError> {
-        let password = Password::hash(raw_password)?;
-        let profile = UserProfile::new(first_name, last_name, age)?;
-
-        Ok(Self {
+        let password = Password::hash(
+            raw_password,
+            policy,
+            &[email.as_ref(), &first_name, &last_name],
+            hasher,
+        )?;
+        let profile = UserProfile::new(first_name, last_name, date_of_birth)?;
+
+        let mut user = Self {
             id: None,
-            email,
+            public_id: PublicUserId::generate(),
+            email: email.clone(),
             password,
             profile,
             created_at: chrono::Utc::now().naive_utc().date(),
             roles: HashSet::from([Role::User]),
-        })
+            pending_events: Vec::new(),
+        };
+        user.record(UserEventKind::Registered { email });
+        Ok(user)
     }
 
     /// Reconstitute a User from persistence (not a business operation)
     /// This is used when loading from the database
     pub fn reconstitute(
         id: UserId,
+        public_id: PublicUserId,
         email: Email,
         password: Password,
         profile: UserProfile,
@@ -50,11 +68,13 @@ impl User {
     ) -> Self {
         Self {
             id: Some(id),
+            public_id,
             email,
             password,
             profile,
             created_at,
             roles,
+            pending_events: Vec::new(),
         }
     }
 
@@ -67,6 +87,22 @@ impl User {
         Ok(())
     }
 
+    /// Re-hash the password with `hasher` if its stored hash is outdated
+    ///
+    /// `raw_password` must already have passed `authenticate`. Returns whether
+    /// the hash changed; this is not a password change, so no event is recorded.
+    pub fn upgrade_password_hash(
+        &mut self,
+        raw_password: &str,
+        hasher: &PasswordHasher,
+    ) -> Result<bool, DomainError> {
+        if !self.password.needs_rehash(hasher) {
+            return Ok(false);
+        }
+        self.password = Password::rehash(raw_password, hasher)?;
+        Ok(true)
+    }
+
     /// Change the user's email
     /// This is a domain behavior with business rules
     pub fn change_email(&mut self, new_email: Email) -> Result<(), DomainError> {
@@ -76,7 +112,11 @@ impl User {
             return Ok(());
         }
 
-        self.email = new_email;
+        let previous = std::mem::replace(&mut self.email, new_email);
+        self.record(UserEventKind::EmailChanged {
+            previous,
+            email: self.email.clone(),
+        });
         Ok(())
     }
 
@@ -85,15 +125,36 @@ impl User {
         &mut self,
         first_name: String,
         last_name: String,
-        age: u8,
+        date_of_birth: DateOfBirth,
     ) -> Result<(), DomainError> {
-        self.profile.update(first_name, last_name, age)?;
+        let unchanged = self.profile.first_name() == first_name
+            && self.profile.last_name() == last_name
+            && self.profile.date_of_birth() == date_of_birth;
+        self.profile.update(first_name, last_name, date_of_birth)?;
+        if !unchanged {
+            self.record(UserEventKind::ProfileUpdated);
+        }
         Ok(())
     }
 
     /// Change password
-    pub fn change_password(&mut self, raw_password: String) -> Result<(), DomainError> {
-        self.password = Password::hash(raw_password)?;
+    pub fn change_password(
+        &mut self,
+        raw_password: String,
+        policy: &PasswordPolicy,
+        hasher: &PasswordHasher,
+    ) -> Result<(), DomainError> {
+        self.password = Password::hash(
+            raw_password,
+            policy,
+            &[
+                self.email.as_ref(),
+                self.profile.first_name(),
+                self.profile.last_name(),
+            ],
+            hasher,
+        )?;
+        self.record(UserEventKind::PasswordChanged);
         Ok(())
     }
 
@@ -103,6 +164,10 @@ impl User {
         self.id
     }
 
+    pub fn public_id(&self) -> PublicUserId {
+        self.public_id
+    }
+
     pub fn email(&self) -> &Email {
         &self.email
     }
@@ -130,11 +195,38 @@ impl User {
     }
 
     pub fn add_role(&mut self, role: Role) {
-        self.roles.insert(role);
+        if self.roles.insert(role) {
+            self.record(UserEventKind::RoleGranted { role });
+        }
     }
 
     pub fn remove_role(&mut self, role: &Role) {
-        self.roles.remove(role);
+        if self.roles.remove(role) {
+            self.record(UserEventKind::RoleRevoked { role: *role });
+        }
+    }
+
+    // Domain events
+
+    fn record(&mut self, kind: UserEventKind) {
+        self.pending_events.push((Utc::now(), kind));
+    }
+
+    /// Drain the events recorded since the last save
+    /// Events stay pending until the user has an ID (i.e. has been persisted)
+    pub fn take_events(&mut self) -> Vec<UserEvent> {
+        if self.id.is_none() {
+            return Vec::new();
+        }
+        let user_id = self.public_id;
+        self.pending_events
+            .drain(..)
+            .map(|(occurred_at, kind)| UserEvent {
+                user_id,
+                occurred_at,
+                kind,
+            })
+            .collect()
     }
 
     /// Set the ID (used after persistence)
@@ -148,11 +240,13 @@ impl std::fmt::Debug for User {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("User")
             .field("id", &self.id)
+            .field("public_id", &self.public_id)
             .field("email", &self.email)
             .field("password", &"[REDACTED]")
             .field("profile", &self.profile)
             .field("created_at", &self.created_at)
             .field("roles", &self.roles)
+            .field("pending_events", &self.pending_events.len())
             .finish()
     }
 }
@@ -160,6 +254,11 @@ impl std::fmt::Debug for User {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::user::HashScheme;
+
+    fn born(age: u8) -> DateOfBirth {
+        DateOfBirth::from_age(age, Utc::now().date_naive())
+    }
 
     #[test]
     fn test_user_registration() {
@@ -169,7 +268,9 @@ mod tests {
             "SecurePass123".to_string(),
             "John".to_string(),
             "Doe".to_string(),
-            25,
+            born(25),
+            &PasswordPolicy::default(),
+            &PasswordHasher::default(),
         )
         .unwrap();
 
@@ -186,7 +287,9 @@ mod tests {
             "SecurePass123".to_string(),
             "John".to_string(),
             "Doe".to_string(),
-            25,
+            born(25),
+            &PasswordPolicy::default(),
+            &PasswordHasher::default(),
         )
         .unwrap();
 
@@ -194,6 +297,43 @@ mod tests {
         assert!(user.authenticate("WrongPassword").is_err());
     }
 
+    #[test]
+    fn test_upgrade_password_hash() {
+        let bcrypt = PasswordHasher::bcrypt(4).unwrap();
+        let mut user = User::register(
+            Email::try_from("test@example.com".to_string()).unwrap(),
+            "SecurePass123".to_string(),
+            "John".to_string(),
+            "Doe".to_string(),
+            born(25),
+            &PasswordPolicy::default(),
+            &bcrypt,
+        )
+        .unwrap();
+        assert!(
+            !user
+                .upgrade_password_hash("SecurePass123", &bcrypt)
+                .unwrap()
+        );
+
+        let argon2 = PasswordHasher::argon2id(1024, 1, 1).unwrap();
+        assert!(
+            user.upgrade_password_hash("SecurePass123", &argon2)
+                .unwrap()
+        );
+        assert_eq!(user.password().scheme(), HashScheme::Argon2id);
+        assert!(user.authenticate("SecurePass123").is_ok());
+        assert!(
+            !user
+                .upgrade_password_hash("SecurePass123", &argon2)
+                .unwrap()
+        );
+
+        // Not a password change: only the registration is recorded
+        user.set_id(UserId::from(1));
+        assert_eq!(user.take_events().len(), 1);
+    }
+
     #[test]
     fn test_user_update_profile() {
         let email = Email::try_from("test@example.com".to_string()).unwrap();
@@ -202,11 +342,13 @@ mod tests {
             "SecurePass123".to_string(),
             "John".to_string(),
             "Doe".to_string(),
-            25,
+            born(25),
+            &PasswordPolicy::default(),
+            &PasswordHasher::default(),
         )
         .unwrap();
 
-        user.update_profile("Jane".to_string(), "Smith".to_string(), 30)
+        user.update_profile("Jane".to_string(), "Smith".to_string(), born(30))
             .unwrap();
 
         assert_eq!(user.profile().first_name(), "Jane");
@@ -222,7 +364,9 @@ mod tests {
             "SecurePass123".to_string(),
             "John".to_string(),
             "Doe".to_string(),
-            25,
+            born(25),
+            &PasswordPolicy::default(),
+            &PasswordHasher::default(),
         )
         .unwrap();
 
@@ -231,4 +375,47 @@ mod tests {
 
         assert_eq!(user.email().as_ref(), "newemail@example.com");
     }
+
+    #[test]
+    fn test_behaviors_record_events() {
+        let email = Email::try_from("test@example.com".to_string()).unwrap();
+        let mut user = User::register(
+            email,
+            "SecurePass123".to_string(),
+            "John".to_string(),
+            "Doe".to_string(),
+            born(25),
+            &PasswordPolicy::default(),
+            &PasswordHasher::default(),
+        )
+        .unwrap();
+
+        user.change_email(Email::try_from("new@example.com".to_string()).unwrap())
+            .unwrap();
+        user.change_email(Email::try_from("new@example.com".to_string()).unwrap())
+            .unwrap();
+        user.update_profile("John".to_string(), "Doe".to_string(), born(25))
+            .unwrap();
+        user.add_role(Role::Admin);
+        user.add_role(Role::Admin);
+        user.remove_role(&Role::User);
+
+        // Nothing is drained before the user has been persisted
+        assert!(user.take_events().is_empty());
+        user.set_id(UserId::from(7));
+
+        let events = user.take_events();
+        let names: Vec<_> = events.iter().map(|e| e.kind.name()).collect();
+        assert_eq!(
+            names,
+            [
+                "user.registered",
+                "user.email_changed",
+                "user.role_granted",
+                "user.role_revoked"
+            ]
+        );
+        assert!(events.iter().all(|e| e.user_id == user.public_id()));
+        assert!(user.take_events().is_empty());
+    }
 }
@@ -1,3 +1,4 @@
+use super::password_policy::PasswordViolation;
 use thiserror::Error;
 
 #[derive(Error, Debug, Clone)]
@@ -5,17 +6,15 @@ pub enum DomainError {
     #[error("Invalid email format: {0}")]
     InvalidEmail(String),
 
-    #[error("Password must be at least 8 characters long")]
-    PasswordTooShort,
-
-    #[error(
-        "Password must contain at least one uppercase letter, one lowercase letter, and one number"
-    )]
-    PasswordTooWeak,
+    #[error("{}", .0.iter().map(ToString::to_string).collect::<Vec<_>>().join("; "))]
+    WeakPassword(Vec<PasswordViolation>),
 
     #[error("Failed to hash password")]
     PasswordHashingFailed,
 
+    #[error("Invalid password hashing parameters: {0}")]
+    InvalidHasherParameters(String),
+
     #[error("Invalid credentials")]
     InvalidCredentials,
 
@@ -33,4 +32,13 @@ pub enum DomainError {
 
     #[error("User with email {0} already exists")]
     EmailAlreadyExists(String),
+
+    #[error("Invalid organization slug: {0}")]
+    InvalidOrganizationSlug(String),
+
+    #[error("Organization name cannot be empty")]
+    EmptyOrganizationName,
+
+    #[error("Group name cannot be empty")]
+    EmptyGroupName,
 }
@@ -0,0 +1,48 @@
+use super::{Email, Role};
+use crate::domain::shared::PublicUserId;
+use chrono::{DateTime, Utc};
+
+/// Something that happened to a User aggregate
+#[derive(Clone, Debug, PartialEq)]
+pub enum UserEventKind {
+    Registered { email: Email },
+    EmailChanged { previous: Email, email: Email },
+    ProfileUpdated,
+    PasswordChanged,
+    RoleGranted { role: Role },
+    RoleRevoked { role: Role },
+}
+
+impl UserEventKind {
+    /// Every event name. Users are never deleted, so there is no
+    /// `user.deleted`
+    pub const NAMES: [&'static str; 6] = [
+        "user.registered",
+        "user.email_changed",
+        "user.profile_updated",
+        "user.password_changed",
+        "user.role_granted",
+        "user.role_revoked",
+    ];
+
+    /// Stable event name used by subscribers (logs, messages, webhooks)
+    pub fn name(&self) -> &'static str {
+        match self {
+            UserEventKind::Registered { .. } => "user.registered",
+            UserEventKind::EmailChanged { .. } => "user.email_changed",
+            UserEventKind::ProfileUpdated => "user.profile_updated",
+            UserEventKind::PasswordChanged => "user.password_changed",
+            UserEventKind::RoleGranted { .. } => "user.role_granted",
+            UserEventKind::RoleRevoked { .. } => "user.role_revoked",
+        }
+    }
+}
+
+/// Domain event raised by the User aggregate, drained once it is persisted
+#[derive(Clone, Debug)]
+pub struct UserEvent {
+    /// Events leave the service, so they carry the public identifier
+    pub user_id: PublicUserId,
+    pub occurred_at: DateTime<Utc>,
+    pub kind: UserEventKind,
+}
@@ -1,15 +1,23 @@
+pub mod date_of_birth;
 pub mod email;
 pub mod entity;
 pub mod errors;
+pub mod events;
 pub mod password;
+pub mod password_hasher;
+pub mod password_policy;
 pub mod repository;
 pub mod role;
 pub mod user_profile;
 
+pub use date_of_birth::DateOfBirth;
 pub use email::Email;
 pub use entity::User;
 pub use errors::DomainError;
+pub use events::{UserEvent, UserEventKind};
 pub use password::Password;
+pub use password_hasher::{HashScheme, PasswordHasher};
+pub use password_policy::{PasswordPolicy, PasswordViolation};
 pub use repository::UserRepository;
 pub use role::Role;
 pub use user_profile::UserProfile;
@@ -1,53 +1,76 @@
 use super::errors::DomainError;
-use bcrypt::{DEFAULT_COST, hash, verify};
+use super::password_hasher::{HashScheme, PasswordHasher};
+use super::password_policy::PasswordPolicy;
 
 /// Password value object - handles hashing and verification
 #[derive(Clone)]
 pub struct Password {
     hashed: String,
+    scheme: HashScheme,
 }
 
 impl Password {
-    /// Create a new Password by hashing a raw password
-    pub fn hash(raw: String) -> Result<Self, DomainError> {
-        // Validate password strength
-        Self::validate_strength(&raw)?;
+    /// Create a new Password by hashing a raw password that satisfies `policy`
+    ///
+    /// `personal_info` holds the owner's email and names, which the password
+    /// may not contain.
+    pub fn hash(
+        raw: String,
+        policy: &PasswordPolicy,
+        personal_info: &[&str],
+        hasher: &PasswordHasher,
+    ) -> Result<Self, DomainError> {
+        let violations = policy.check(&raw, personal_info);
+        if !violations.is_empty() {
+            return Err(DomainError::WeakPassword(violations));
+        }
 
-        let hashed = hash(raw, DEFAULT_COST).map_err(|_| DomainError::PasswordHashingFailed)?;
+        Self::rehash(&raw, hasher)
+    }
 
-        Ok(Self { hashed })
+    /// Hash a raw password that was already accepted, e.g. to upgrade its hash
+    pub fn rehash(raw: &str, hasher: &PasswordHasher) -> Result<Self, DomainError> {
+        Ok(Self::from_hash(hasher.hash(raw)?))
     }
 
     /// Create a Password from an already hashed value (e.g., from database)
+    ///
+    /// The scheme is detected from the hash's prefix (`$argon2id$`, `$2b$`, ...).
     pub fn from_hash(hashed: String) -> Self {
-        Self { hashed }
+        let scheme = HashScheme::detect(&hashed);
+        Self { hashed, scheme }
     }
 
-    /// Verify a raw password against this hashed password
-    pub fn verify(&self, raw_password: &str) -> bool {
-        verify(raw_password, &self.hashed).unwrap_or(false)
+    /// A password whose hash was not loaded, e.g. for a user read from a cache
+    ///
+    /// It verifies no password, and saving the user keeps the stored hash.
+    pub fn withheld() -> Self {
+        Self::from_hash(String::new())
     }
 
-    /// Get the hashed password string
-    pub fn hashed(&self) -> &str {
-        &self.hashed
+    /// Whether this is a `withheld` password
+    pub fn is_withheld(&self) -> bool {
+        self.hashed.is_empty()
     }
 
-    /// Validate password strength rules
-    fn validate_strength(password: &str) -> Result<(), DomainError> {
-        if password.len() < 8 {
-            return Err(DomainError::PasswordTooShort);
-        }
+    /// Verify a raw password against this hashed password
+    pub fn verify(&self, raw_password: &str) -> bool {
+        self.scheme.verify(raw_password, &self.hashed)
+    }
 
-        let has_uppercase = password.chars().any(|c| c.is_uppercase());
-        let has_lowercase = password.chars().any(|c| c.is_lowercase());
-        let has_digit = password.chars().any(|c| c.is_ascii_digit());
+    /// Whether the hash should be replaced to match `hasher`
+    pub fn needs_rehash(&self, hasher: &PasswordHasher) -> bool {
+        !hasher.is_current(&self.hashed)
+    }
 
-        if !has_uppercase || !has_lowercase || !has_digit {
-            return Err(DomainError::PasswordTooWeak);
-        }
+    /// The scheme the hash was made with
+    pub fn scheme(&self) -> HashScheme {
+        self.scheme
+    }
 
-        Ok(())
+    /// Get the hashed password string
+    pub fn hashed(&self) -> &str {
+        &self.hashed
     }
 }
 
@@ -61,34 +84,64 @@ impl std::fmt::Debug for Password {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::user::PasswordViolation;
+
+    fn hash(raw: &str) -> Result<Password, DomainError> {
+        Password::hash(
+            raw.to_string(),
+            &PasswordPolicy::default(),
+            &[],
+            &PasswordHasher::default(),
+        )
+    }
 
     #[test]
     fn test_password_hashing() {
         let raw = "SecurePass123".to_string();
-        let password = Password::hash(raw.clone()).unwrap();
+        let password = hash(&raw).unwrap();
         assert!(password.verify(&raw));
         assert!(!password.verify("WrongPassword"));
     }
 
     #[test]
     fn test_password_too_short() {
-        let result = Password::hash("Short1".to_string());
-        assert!(matches!(result, Err(DomainError::PasswordTooShort)));
+        let result = hash("Short1");
+        assert!(matches!(
+            result,
+            Err(DomainError::WeakPassword(v)) if v == [PasswordViolation::TooShort { min: 8 }]
+        ));
     }
 
     #[test]
     fn test_password_too_weak() {
-        let result = Password::hash("alllowercase".to_string());
-        assert!(matches!(result, Err(DomainError::PasswordTooWeak)));
+        let result = hash("alllowercase");
+        assert!(matches!(
+            result,
+            Err(DomainError::WeakPassword(v)) if v.contains(&PasswordViolation::MissingUppercase)
+        ));
     }
 
     #[test]
     fn test_password_from_hash() {
         let raw = "ValidPass123".to_string();
-        let password = Password::hash(raw.clone()).unwrap();
+        let password = hash(&raw).unwrap();
         let hash_string = password.hashed().to_string();
 
         let password_from_hash = Password::from_hash(hash_string);
+        assert_eq!(password_from_hash.scheme(), HashScheme::Argon2id);
         assert!(password_from_hash.verify(&raw));
     }
+
+    #[test]
+    fn test_bcrypt_hash_needs_rehash() {
+        let raw = "ValidPass123";
+        let legacy = Password::from_hash(bcrypt::hash(raw, 4).unwrap());
+        assert_eq!(legacy.scheme(), HashScheme::Bcrypt);
+        assert!(legacy.verify(raw));
+        assert!(legacy.needs_rehash(&PasswordHasher::default()));
+
+        let upgraded = Password::rehash(raw, &PasswordHasher::default()).unwrap();
+        assert!(upgraded.verify(raw));
+        assert!(!upgraded.needs_rehash(&PasswordHasher::default()));
+    }
 }
@@ -0,0 +1,218 @@
+use super::errors::DomainError;
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::{PasswordHash, PasswordHasher as _, PasswordVerifier, SaltString};
+use argon2::{Algorithm, Argon2, Params, Version};
+
+/// Scheme of a stored hash, read from its PHC or modular-crypt prefix
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HashScheme {
+    Argon2id,
+    Bcrypt,
+    /// Nothing this service can verify; every password is rejected
+    Unknown,
+}
+
+impl HashScheme {
+    /// Detect the scheme of `hashed`
+    pub fn detect(hashed: &str) -> Self {
+        if hashed.starts_with("$argon2id$") {
+            Self::Argon2id
+        } else if ["$2a$", "$2b$", "$2x$", "$2y$"]
+            .iter()
+            .any(|prefix| hashed.starts_with(prefix))
+        {
+            Self::Bcrypt
+        } else {
+            Self::Unknown
+        }
+    }
+
+    /// Verify `raw` against `hashed`, which must be of this scheme
+    pub(super) fn verify(self, raw: &str, hashed: &str) -> bool {
+        match self {
+            // Parameters come from the hash itself, so any Argon2 instance will do
+            Self::Argon2id => PasswordHash::new(hashed)
+                .map(|hash| {
+                    Argon2::default()
+                        .verify_password(raw.as_bytes(), &hash)
+                        .is_ok()
+                })
+                .unwrap_or(false),
+            Self::Bcrypt => bcrypt::verify(raw, hashed).unwrap_or(false),
+            Self::Unknown => false,
+        }
+    }
+}
+
+/// PasswordHasher - the algorithm and parameters new hashes are made with
+///
+/// Hashes made with another algorithm or weaker parameters still verify,
+/// and are replaced on the next successful login.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PasswordHasher {
+    Argon2id {
+        memory_kib: u32,
+        iterations: u32,
+        parallelism: u32,
+    },
+    Bcrypt {
+        cost: u32,
+    },
+}
+
+impl Default for PasswordHasher {
+    /// Argon2id with the OWASP minimum (19 MiB, 2 iterations, 1 lane)
+    fn default() -> Self {
+        Self::Argon2id {
+            memory_kib: 19_456,
+            iterations: 2,
+            parallelism: 1,
+        }
+    }
+}
+
+impl PasswordHasher {
+    /// Argon2id hasher, rejecting parameters the algorithm does not allow
+    pub fn argon2id(
+        memory_kib: u32,
+        iterations: u32,
+        parallelism: u32,
+    ) -> Result<Self, DomainError> {
+        Params::new(memory_kib, iterations, parallelism, None)
+            .map_err(|e| DomainError::InvalidHasherParameters(e.to_string()))?;
+        Ok(Self::Argon2id {
+            memory_kib,
+            iterations,
+            parallelism,
+        })
+    }
+
+    /// bcrypt hasher; `cost` must be between 4 and 31
+    pub fn bcrypt(cost: u32) -> Result<Self, DomainError> {
+        if !(4..=31).contains(&cost) {
+            return Err(DomainError::InvalidHasherParameters(format!(
+                "bcrypt cost must be between 4 and 31, got {}",
+                cost
+            )));
+        }
+        Ok(Self::Bcrypt { cost })
+    }
+
+    /// Hash `raw` with a fresh salt
+    pub(super) fn hash(&self, raw: &str) -> Result<String, DomainError> {
+        match self {
+            Self::Argon2id {
+                memory_kib,
+                iterations,
+                parallelism,
+            } => {
+                let salt = SaltString::generate(&mut OsRng);
+                Self::argon2(*memory_kib, *iterations, *parallelism)?
+                    .hash_password(raw.as_bytes(), &salt)
+                    .map(|hash| hash.to_string())
+                    .map_err(|_| DomainError::PasswordHashingFailed)
+            }
+            Self::Bcrypt { cost } => {
+                bcrypt::hash(raw, *cost).map_err(|_| DomainError::PasswordHashingFailed)
+            }
+        }
+    }
+
+    /// Whether `hashed` was made with this algorithm and these parameters
+    pub(super) fn is_current(&self, hashed: &str) -> bool {
+        match self {
+            Self::Argon2id {
+                memory_kib,
+                iterations,
+                parallelism,
+            } => PasswordHash::new(hashed)
+                .ok()
+                .filter(|hash| {
+                    hash.algorithm == Algorithm::Argon2id.ident()
+                        && hash.version == Some(Version::V0x13.into())
+                })
+                .and_then(|hash| Params::try_from(&hash).ok())
+                .is_some_and(|params| {
+                    params.m_cost() == *memory_kib
+                        && params.t_cost() == *iterations
+                        && params.p_cost() == *parallelism
+                }),
+            Self::Bcrypt { cost } => {
+                HashScheme::detect(hashed) == HashScheme::Bcrypt
+                    && hashed
+                        .parse::<bcrypt::HashParts>()
+                        .is_ok_and(|parts| parts.get_cost() == *cost)
+            }
+        }
+    }
+
+    fn argon2(
+        memory_kib: u32,
+        iterations: u32,
+        parallelism: u32,
+    ) -> Result<Argon2<'static>, DomainError> {
+        let params = Params::new(memory_kib, iterations, parallelism, None)
+            .map_err(|e| DomainError::InvalidHasherParameters(e.to_string()))?;
+        Ok(Argon2::new(Algorithm::Argon2id, Version::V0x13, params))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cheap_argon2() -> PasswordHasher {
+        PasswordHasher::argon2id(1024, 1, 1).unwrap()
+    }
+
+    #[test]
+    fn test_detects_scheme_from_prefix() {
+        let argon = cheap_argon2().hash("SecurePass123").unwrap();
+        let bcrypt = PasswordHasher::bcrypt(4)
+            .unwrap()
+            .hash("SecurePass123")
+            .unwrap();
+
+        assert!(argon.starts_with("$argon2id$v=19$m=1024,t=1,p=1$"));
+        assert_eq!(HashScheme::detect(&argon), HashScheme::Argon2id);
+        assert_eq!(HashScheme::detect(&bcrypt), HashScheme::Bcrypt);
+        assert_eq!(HashScheme::detect("plaintext"), HashScheme::Unknown);
+        assert!(!HashScheme::Unknown.verify("plaintext", "plaintext"));
+    }
+
+    #[test]
+    fn test_verifies_either_scheme() {
+        for hasher in [cheap_argon2(), PasswordHasher::bcrypt(4).unwrap()] {
+            let hashed = hasher.hash("SecurePass123").unwrap();
+            let scheme = HashScheme::detect(&hashed);
+            assert!(scheme.verify("SecurePass123", &hashed));
+            assert!(!scheme.verify("WrongPass123", &hashed));
+        }
+    }
+
+    #[test]
+    fn test_outdated_hashes_are_not_current() {
+        let argon = cheap_argon2();
+        let stronger = PasswordHasher::argon2id(2048, 1, 1).unwrap();
+        let bcrypt = PasswordHasher::bcrypt(4).unwrap();
+        let argon_hash = argon.hash("SecurePass123").unwrap();
+        let bcrypt_hash = bcrypt.hash("SecurePass123").unwrap();
+
+        assert!(argon.is_current(&argon_hash));
+        assert!(!stronger.is_current(&argon_hash));
+        assert!(!argon.is_current(&bcrypt_hash));
+        assert!(bcrypt.is_current(&bcrypt_hash));
+        assert!(!PasswordHasher::bcrypt(5).unwrap().is_current(&bcrypt_hash));
+        assert!(!bcrypt.is_current(&argon_hash));
+    }
+
+    #[test]
+    fn test_rejects_invalid_parameters() {
+        assert!(matches!(
+            PasswordHasher::argon2id(1, 1, 1),
+            Err(DomainError::InvalidHasherParameters(_))
+        ));
+        assert!(PasswordHasher::bcrypt(3).is_err());
+        assert!(PasswordHasher::bcrypt(32).is_err());
+    }
+}
@@ -0,0 +1,278 @@
+use std::fmt::Display;
+
+/// A rule a new password breaks
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PasswordViolation {
+    TooShort {
+        min: usize,
+    },
+    TooLong {
+        max: usize,
+    },
+    MissingUppercase,
+    MissingLowercase,
+    MissingDigit,
+    MissingSymbol,
+    /// Estimated entropy below the configured minimum
+    TooPredictable {
+        min_bits: u32,
+    },
+    /// Contains the user's email or name
+    ContainsPersonalInfo,
+    /// Matches one of the user's recent passwords
+    RecentlyUsed {
+        window: usize,
+    },
+    /// Appears in the breached password list
+    Breached,
+}
+
+impl Display for PasswordViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TooShort { min } => {
+                write!(f, "Password must be at least {} characters long", min)
+            }
+            Self::TooLong { max } => write!(f, "Password must be at most {} characters long", max),
+            Self::MissingUppercase => f.write_str("Password must contain an uppercase letter"),
+            Self::MissingLowercase => f.write_str("Password must contain a lowercase letter"),
+            Self::MissingDigit => f.write_str("Password must contain a number"),
+            Self::MissingSymbol => f.write_str("Password must contain a symbol"),
+            Self::TooPredictable { min_bits } => write!(
+                f,
+                "Password is too predictable; use a longer or more varied one (at least {} bits)",
+                min_bits
+            ),
+            Self::ContainsPersonalInfo => {
+                f.write_str("Password must not contain your email address or name")
+            }
+            Self::RecentlyUsed { window } => write!(
+                f,
+                "Password must differ from your last {} passwords",
+                window
+            ),
+            Self::Breached => f.write_str("Password has appeared in a data breach"),
+        }
+    }
+}
+
+/// PasswordPolicy - rules every new password must satisfy
+///
+/// `check` covers the rules that need nothing but the password and the
+/// user's details; history and breach lookups are done by the application,
+/// which reports them alongside.
+#[derive(Clone, Debug)]
+pub struct PasswordPolicy {
+    pub min_length: usize,
+    /// bcrypt ignores everything past 72 bytes
+    pub max_length: usize,
+    pub require_uppercase: bool,
+    pub require_lowercase: bool,
+    pub require_digit: bool,
+    pub require_symbol: bool,
+    /// Minimum estimated entropy in bits; 0 disables the check
+    pub min_entropy_bits: u32,
+    /// Reject passwords containing the email's local part or a name
+    pub disallow_personal_info: bool,
+    /// Number of previous passwords that may not be reused; 0 disables the check
+    pub history_size: usize,
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        Self {
+            min_length: 8,
+            max_length: 72,
+            require_uppercase: true,
+            require_lowercase: true,
+            require_digit: true,
+            require_symbol: false,
+            min_entropy_bits: 0,
+            disallow_personal_info: true,
+            history_size: 0,
+        }
+    }
+}
+
+/// Shortest email part or name that counts as personal information
+const MIN_PERSONAL_LEN: usize = 3;
+
+impl PasswordPolicy {
+    /// Every rule `password` breaks, given the user's email and names
+    pub fn check(&self, password: &str, personal_info: &[&str]) -> Vec<PasswordViolation> {
+        let mut violations = Vec::new();
+        let length = password.chars().count();
+
+        if length < self.min_length {
+            violations.push(PasswordViolation::TooShort {
+                min: self.min_length,
+            });
+        }
+        if length > self.max_length {
+            violations.push(PasswordViolation::TooLong {
+                max: self.max_length,
+            });
+        }
+
+        let classes = CharClasses::of(password);
+        if self.require_uppercase && !classes.upper {
+            violations.push(PasswordViolation::MissingUppercase);
+        }
+        if self.require_lowercase && !classes.lower {
+            violations.push(PasswordViolation::MissingLowercase);
+        }
+        if self.require_digit && !classes.digit {
+            violations.push(PasswordViolation::MissingDigit);
+        }
+        if self.require_symbol && !classes.symbol {
+            violations.push(PasswordViolation::MissingSymbol);
+        }
+
+        if self.min_entropy_bits > 0 && entropy_bits(password) < f64::from(self.min_entropy_bits) {
+            violations.push(PasswordViolation::TooPredictable {
+                min_bits: self.min_entropy_bits,
+            });
+        }
+
+        if self.disallow_personal_info && contains_personal_info(password, personal_info) {
+            violations.push(PasswordViolation::ContainsPersonalInfo);
+        }
+
+        violations
+    }
+}
+
+#[derive(Default)]
+struct CharClasses {
+    upper: bool,
+    lower: bool,
+    digit: bool,
+    symbol: bool,
+    other: bool,
+}
+
+impl CharClasses {
+    fn of(password: &str) -> Self {
+        let mut classes = Self::default();
+        for c in password.chars() {
+            if c.is_uppercase() {
+                classes.upper = true;
+            } else if c.is_lowercase() {
+                classes.lower = true;
+            } else if c.is_ascii_digit() {
+                classes.digit = true;
+            } else if c.is_ascii_punctuation() || c == ' ' {
+                classes.symbol = true;
+            } else {
+                classes.other = true;
+            }
+        }
+        classes
+    }
+}
+
+/// Entropy estimate: length times the bits per character of the classes used,
+/// after collapsing runs of a repeated character
+fn entropy_bits(password: &str) -> f64 {
+    let classes = CharClasses::of(password);
+    let pool = [
+        (classes.upper, 26),
+        (classes.lower, 26),
+        (classes.digit, 10),
+        (classes.symbol, 33),
+        (classes.other, 100),
+    ]
+    .iter()
+    .filter(|(used, _)| *used)
+    .map(|(_, size)| size)
+    .sum::<u32>();
+
+    let mut chars: Vec<char> = password.chars().collect();
+    chars.dedup();
+
+    if pool == 0 {
+        0.0
+    } else {
+        chars.len() as f64 * f64::from(pool).log2()
+    }
+}
+
+fn contains_personal_info(password: &str, personal_info: &[&str]) -> bool {
+    let password = password.to_lowercase();
+    personal_info
+        .iter()
+        // An email counts by its local part, which is what people reuse
+        .map(|info| info.split('@').next().unwrap_or(info).trim().to_lowercase())
+        .filter(|info| info.chars().count() >= MIN_PERSONAL_LEN)
+        .any(|info| password.contains(&info))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_policy_matches_previous_rules() {
+        let policy = PasswordPolicy::default();
+        assert!(policy.check("SecurePass123", &[]).is_empty());
+        assert_eq!(
+            policy.check("Short1", &[]),
+            vec![PasswordViolation::TooShort { min: 8 }]
+        );
+        assert_eq!(
+            policy.check("alllowercase", &[]),
+            vec![
+                PasswordViolation::MissingUppercase,
+                PasswordViolation::MissingDigit
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reports_every_violation() {
+        let policy = PasswordPolicy {
+            require_symbol: true,
+            min_entropy_bits: 60,
+            ..PasswordPolicy::default()
+        };
+
+        assert_eq!(
+            policy.check("aaaa", &[]),
+            vec![
+                PasswordViolation::TooShort { min: 8 },
+                PasswordViolation::MissingUppercase,
+                PasswordViolation::MissingDigit,
+                PasswordViolation::MissingSymbol,
+                PasswordViolation::TooPredictable { min_bits: 60 },
+            ]
+        );
+        assert!(policy.check("c0rrect-Horse-battery", &[]).is_empty());
+    }
+
+    #[test]
+    fn test_rejects_personal_info() {
+        let policy = PasswordPolicy::default();
+        let info = ["jane.doe@example.com", "Jane", "Do"];
+
+        assert_eq!(
+            policy.check("Jane.doe2024", &info),
+            vec![PasswordViolation::ContainsPersonalInfo]
+        );
+        assert_eq!(
+            policy.check("xxJANExx9", &info),
+            vec![PasswordViolation::ContainsPersonalInfo]
+        );
+        // Names shorter than three characters are too common to reject
+        assert!(policy.check("Dolphin99", &info).is_empty());
+    }
+
+    #[test]
+    fn test_max_length() {
+        let policy = PasswordPolicy::default();
+        let long = format!("Aa1{}", "x".repeat(70));
+        assert_eq!(
+            policy.check(&long, &[]),
+            vec![PasswordViolation::TooLong { max: 72 }]
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use super::{Email, Role, User};
-use crate::domain::shared::UserId;
+use crate::domain::organization::TenantScope;
+use crate::domain::shared::{GroupId, PublicUserId, UserId};
 use async_trait::async_trait;
 use std::collections::HashSet;
 use thiserror::Error;
@@ -29,17 +30,53 @@ pub trait UserRepository: Send + Sync {
     /// Find a user by their unique identifier
     async fn find_by_id(&self, id: UserId) -> UserRepositoryResult<Option<User>>;
 
+    /// Find a user by the identifier exposed outside the service, among the
+    /// users visible in `scope`
+    async fn find_by_public_id(
+        &self,
+        id: PublicUserId,
+        scope: TenantScope,
+    ) -> UserRepositoryResult<Option<User>>;
+
+    /// Find the internal ID of the user with the given public ID, platform-wide
+    async fn find_id_by_public_id(&self, id: PublicUserId) -> UserRepositoryResult<Option<UserId>>;
+
     /// Find a user by their email address
     async fn find_by_email(&self, email: &Email) -> UserRepositoryResult<Option<User>>;
 
     /// Save a user (insert if new, update if existing)
+    /// Drains the user's pending domain events and dispatches them once saved
     async fn save(&self, user: &mut User) -> UserRepositoryResult<()>;
 
+    /// Replace the user's password hash if it is still `current`, leaving
+    /// everything else untouched; returns whether it was replaced
+    async fn update_password_hash(
+        &self,
+        id: UserId,
+        current: &str,
+        upgraded: &str,
+    ) -> UserRepositoryResult<bool>;
+
     /// Check if a user exists with the given email
     async fn exists_with_email(&self, email: &Email) -> UserRepositoryResult<bool>;
 
-    /// List users with pagination
-    async fn list(&self, page: u64, rows_per_page: u64) -> UserRepositoryResult<(Vec<User>, u64)>;
+    /// List the users visible in `scope` with pagination
+    async fn list(
+        &self,
+        scope: TenantScope,
+        page: u64,
+        rows_per_page: u64,
+    ) -> UserRepositoryResult<(Vec<User>, u64)>;
+
+    /// List the users visible in `scope` who belong to any of the groups,
+    /// with pagination
+    async fn list_in_groups(
+        &self,
+        groups: &[GroupId],
+        scope: TenantScope,
+        page: u64,
+        rows_per_page: u64,
+    ) -> UserRepositoryResult<(Vec<User>, u64)>;
 
     /// Find the roles assigned to a user by their ID
     async fn find_roles_by_user_id(&self, id: UserId) -> UserRepositoryResult<HashSet<Role>>;
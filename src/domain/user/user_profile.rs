@@ -1,19 +1,22 @@
+use super::date_of_birth::DateOfBirth;
 use super::errors::DomainError;
+use chrono::{NaiveDate, Utc};
 
 /// UserProfile value object - encapsulates user profile information
 #[derive(Debug, Clone)]
 pub struct UserProfile {
     first_name: String,
     last_name: String,
-    age: u8,
+    date_of_birth: DateOfBirth,
 }
 
 impl UserProfile {
     fn validate_and_normalize(
         first_name: String,
         last_name: String,
-        age: u8,
-    ) -> Result<(String, String, u8), DomainError> {
+        date_of_birth: DateOfBirth,
+        today: NaiveDate,
+    ) -> Result<(String, String, DateOfBirth), DomainError> {
         let first_name = first_name.trim().to_string();
         let last_name = last_name.trim().to_string();
 
@@ -25,6 +28,8 @@ impl UserProfile {
             return Err(DomainError::EmptyLastName);
         }
 
+        let age = date_of_birth.age_on(today);
+
         if age < 18 {
             return Err(DomainError::UserTooYoung);
         }
@@ -33,18 +38,26 @@ impl UserProfile {
             return Err(DomainError::InvalidAge);
         }
 
-        Ok((first_name, last_name, age))
+        Ok((first_name, last_name, date_of_birth))
     }
 
     /// Create a new UserProfile with validation
-    pub fn new(first_name: String, last_name: String, age: u8) -> Result<Self, DomainError> {
-        let (first_name, last_name, age) =
-            Self::validate_and_normalize(first_name, last_name, age)?;
+    pub fn new(
+        first_name: String,
+        last_name: String,
+        date_of_birth: DateOfBirth,
+    ) -> Result<Self, DomainError> {
+        let (first_name, last_name, date_of_birth) = Self::validate_and_normalize(
+            first_name,
+            last_name,
+            date_of_birth,
+            Utc::now().date_naive(),
+        )?;
 
         Ok(Self {
             first_name,
             last_name,
-            age,
+            date_of_birth,
         })
     }
 
@@ -58,9 +71,14 @@ impl UserProfile {
         &self.last_name
     }
 
-    /// Get the age
+    /// Get the date of birth
+    pub fn date_of_birth(&self) -> DateOfBirth {
+        self.date_of_birth
+    }
+
+    /// Get the age today, derived from the date of birth
     pub fn age(&self) -> u8 {
-        self.age
+        self.date_of_birth.age().clamp(0, u8::MAX as i32) as u8
     }
 
     /// Get the full name
@@ -73,14 +91,18 @@ impl UserProfile {
         &mut self,
         first_name: String,
         last_name: String,
-        age: u8,
+        date_of_birth: DateOfBirth,
     ) -> Result<(), DomainError> {
-        let (first_name, last_name, age) =
-            Self::validate_and_normalize(first_name, last_name, age)?;
+        let (first_name, last_name, date_of_birth) = Self::validate_and_normalize(
+            first_name,
+            last_name,
+            date_of_birth,
+            Utc::now().date_naive(),
+        )?;
 
         self.first_name = first_name;
         self.last_name = last_name;
-        self.age = age;
+        self.date_of_birth = date_of_birth;
 
         Ok(())
     }
@@ -90,9 +112,14 @@ impl UserProfile {
 mod tests {
     use super::*;
 
+    fn years_ago(age: u8) -> DateOfBirth {
+        DateOfBirth::from_age(age, Utc::now().date_naive())
+    }
+
     #[test]
     fn test_valid_profile() {
-        let profile = UserProfile::new("John".to_string(), "Doe".to_string(), 25).unwrap();
+        let profile =
+            UserProfile::new("John".to_string(), "Doe".to_string(), years_ago(25)).unwrap();
         assert_eq!(profile.first_name(), "John");
         assert_eq!(profile.last_name(), "Doe");
         assert_eq!(profile.age(), 25);
@@ -101,23 +128,49 @@ mod tests {
 
     #[test]
     fn test_empty_first_name() {
-        let result = UserProfile::new("".to_string(), "Doe".to_string(), 25);
+        let result = UserProfile::new("".to_string(), "Doe".to_string(), years_ago(25));
         assert!(matches!(result, Err(DomainError::EmptyFirstName)));
     }
 
     #[test]
     fn test_user_too_young() {
-        let result = UserProfile::new("John".to_string(), "Doe".to_string(), 17);
+        let result = UserProfile::new("John".to_string(), "Doe".to_string(), years_ago(17));
         assert!(matches!(result, Err(DomainError::UserTooYoung)));
     }
 
+    #[test]
+    fn test_age_boundaries_follow_the_birthday() {
+        let today = NaiveDate::from_ymd_opt(2025, 6, 15).unwrap();
+        let validate = |y, m, d| {
+            UserProfile::validate_and_normalize(
+                "John".to_string(),
+                "Doe".to_string(),
+                DateOfBirth::from(NaiveDate::from_ymd_opt(y, m, d).unwrap()),
+                today,
+            )
+        };
+
+        assert!(validate(2007, 6, 15).is_ok());
+        assert!(matches!(
+            validate(2007, 6, 16),
+            Err(DomainError::UserTooYoung)
+        ));
+        assert!(matches!(
+            validate(2030, 1, 1),
+            Err(DomainError::UserTooYoung)
+        ));
+        assert!(matches!(validate(1870, 1, 1), Err(DomainError::InvalidAge)));
+    }
+
     #[test]
     fn test_profile_update() {
-        let mut profile = UserProfile::new("John".to_string(), "Doe".to_string(), 25).unwrap();
+        let mut profile =
+            UserProfile::new("John".to_string(), "Doe".to_string(), years_ago(25)).unwrap();
         profile
-            .update("Jane".to_string(), "Smith".to_string(), 30)
+            .update("Jane".to_string(), "Smith".to_string(), years_ago(30))
             .unwrap();
         assert_eq!(profile.first_name(), "Jane");
         assert_eq!(profile.full_name(), "Jane Smith");
+        assert_eq!(profile.age(), 30);
     }
 }
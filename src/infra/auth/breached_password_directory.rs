@@ -0,0 +1,69 @@
+use crate::app::ports::BreachedPasswordList;
+use async_trait::async_trait;
+use sha1::{Digest, Sha1};
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+/// Breached passwords stored locally as SHA-1 range files
+///
+/// Uses the layout of the Pwned Passwords k-anonymity ranges: one file per
+/// five-character hash prefix (`21BD1.txt`), each line the remaining 35
+/// characters and a count (`0018A45C4D1DEF81644B54AB7F969B88D65:10`). A
+/// lookup reads only the file for the password's prefix, and no plaintext
+/// password is ever stored.
+pub struct BreachedPasswordDirectory {
+    dir: PathBuf,
+}
+
+impl BreachedPasswordDirectory {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+}
+
+#[async_trait]
+impl BreachedPasswordList for BreachedPasswordDirectory {
+    async fn contains(&self, password: &str) -> Result<bool, String> {
+        let hash = format!("{:X}", Sha1::digest(password.as_bytes()));
+        let (prefix, suffix) = hash.split_at(5);
+
+        let path = self.dir.join(format!("{}.txt", prefix));
+        let range = match tokio::fs::read_to_string(&path).await {
+            Ok(range) => range,
+            // No file means no breached password has this prefix
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(false),
+            Err(e) => return Err(format!("{}: {}", path.display(), e)),
+        };
+
+        Ok(range.lines().any(|line| {
+            line.split(':')
+                .next()
+                .is_some_and(|candidate| candidate.trim().eq_ignore_ascii_case(suffix))
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_looks_up_the_prefix_range() {
+        let dir = std::env::temp_dir().join(format!("breached-{}", uuid::Uuid::new_v4()));
+        tokio::fs::create_dir_all(&dir).await.unwrap();
+        // SHA-1("Password1") = 70CCD9007338D6D81DD3B6271621B9CF9A97EA00
+        tokio::fs::write(
+            dir.join("70CCD.txt"),
+            "0018A45C4D1DEF81644B54AB7F969B88D65:3\r\n9007338d6d81dd3b6271621b9cf9a97ea00:111\r\n",
+        )
+        .await
+        .unwrap();
+        let list = BreachedPasswordDirectory::new(dir.clone());
+
+        assert!(list.contains("Password1").await.unwrap());
+        assert!(!list.contains("Password2").await.unwrap());
+        assert!(!list.contains("c0rrect-Horse-battery").await.unwrap());
+
+        tokio::fs::remove_dir_all(&dir).await.unwrap();
+    }
+}
@@ -0,0 +1,79 @@
+use crate::app::ports::InvitationSigner;
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use uuid::Uuid;
+
+/// HMAC-SHA256 implementation of InvitationSigner
+///
+/// Tokens read `<invitation id>.<expiry unix seconds>.<hex signature>`, so
+/// they fit in a URL query without escaping.
+pub struct HmacInvitationSigner {
+    key: Vec<u8>,
+}
+
+impl HmacInvitationSigner {
+    pub fn new(key: &str) -> Self {
+        Self {
+            key: key.as_bytes().to_vec(),
+        }
+    }
+
+    fn mac(&self, payload: &str) -> Hmac<Sha256> {
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(&self.key).expect("HMAC accepts any key length");
+        mac.update(payload.as_bytes());
+        mac
+    }
+}
+
+impl InvitationSigner for HmacInvitationSigner {
+    fn sign(&self, invitation_id: Uuid, expires_at: DateTime<Utc>) -> String {
+        let payload = format!("{}.{}", invitation_id.simple(), expires_at.timestamp());
+        let signature = self.mac(&payload).finalize().into_bytes();
+        format!("{}.{:x}", payload, signature)
+    }
+
+    fn verify(&self, token: &str) -> Option<(Uuid, DateTime<Utc>)> {
+        let (payload, signature) = token.rsplit_once('.')?;
+        let signature = decode_hex(signature)?;
+        // Constant-time comparison
+        self.mac(payload).verify_slice(&signature).ok()?;
+
+        let (id, expires_at) = payload.split_once('.')?;
+        let id = Uuid::try_parse(id).ok()?;
+        let expires_at = DateTime::from_timestamp(expires_at.parse().ok()?, 0)?;
+        Some((id, expires_at))
+    }
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verifies_only_untampered_tokens() {
+        let signer = HmacInvitationSigner::new("secret");
+        let id = Uuid::now_v7();
+        let expires_at = DateTime::from_timestamp(1_800_000_000, 0).unwrap();
+        let token = signer.sign(id, expires_at);
+
+        assert_eq!(signer.verify(&token), Some((id, expires_at)));
+        assert_eq!(HmacInvitationSigner::new("other").verify(&token), None);
+
+        let extended = token.replacen("1800000000", "1900000000", 1);
+        assert_eq!(signer.verify(&extended), None);
+        assert_eq!(signer.verify("not-a-token"), None);
+        assert_eq!(signer.verify(&format!("{}0", token)), None);
+    }
+}
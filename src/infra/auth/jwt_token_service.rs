@@ -1,10 +1,12 @@
 use crate::app::errors::ApplicationError;
 use crate::app::ports::TokenService;
+use crate::domain::shared::PublicUserId;
 use async_trait::async_trait;
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, Utc};
 use jsonwebtoken::{DecodingKey, EncodingKey, Header, encode};
 use serde::{Deserialize, Serialize};
 use std::sync::LazyLock;
+use uuid::Uuid;
 
 /// JWT Claims structure
 ///
@@ -12,9 +14,23 @@ use std::sync::LazyLock;
 /// database per request to ensure they are always up-to-date.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Claims {
-    pub sub: String,  // Subject (user email)
-    pub user_id: i32, // User ID
-    pub exp: usize,   // Expiration time
+    pub sub: String, // Subject (user email)
+    /// Public user ID; tokens carrying the old integer ID no longer decode
+    pub user_id: Uuid,
+    pub exp: usize, // Expiration time
+    /// Issue time; tokens minted before this claim existed read as 0
+    #[serde(default)]
+    pub iat: usize,
+    /// Slug of the organization the token is bound to, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub org: Option<String>,
+}
+
+impl Claims {
+    /// Whether the token was issued at or before `cutoff` (second precision)
+    pub fn issued_by(&self, cutoff: DateTime<Utc>) -> bool {
+        self.iat as i64 <= cutoff.timestamp()
+    }
 }
 
 /// Keys for JWT encoding/decoding
@@ -48,6 +64,11 @@ impl JwtTokenService {
     pub fn get_decoding_key() -> &'static DecodingKey {
         &KEYS.decoding
     }
+
+    /// Check that the signing secret is available without panicking on first use
+    pub fn signing_key_configured() -> bool {
+        std::env::var("JWT_SECRET").is_ok_and(|secret| !secret.is_empty())
+    }
 }
 
 impl Default for JwtTokenService {
@@ -60,17 +81,43 @@ impl Default for JwtTokenService {
 impl TokenService for JwtTokenService {
     async fn generate_token(
         &self,
-        user_id: i32,
+        user_id: PublicUserId,
         user_email: &str,
+        organization: Option<&str>,
     ) -> Result<String, ApplicationError> {
-        let exp = (Utc::now() + Duration::days(7)).timestamp() as usize;
+        let now = Utc::now();
         let claims = Claims {
             sub: user_email.to_string(),
-            user_id,
-            exp,
+            user_id: user_id.value(),
+            exp: (now + Duration::days(7)).timestamp() as usize,
+            iat: now.timestamp() as usize,
+            org: organization.map(str::to_string),
         };
 
         encode(&Header::default(), &claims, &KEYS.encoding)
             .map_err(|e| ApplicationError::TokenGenerationFailed(e.to_string()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_issued_by_compares_whole_seconds() {
+        let cutoff = DateTime::from_timestamp(1_700_000_000, 500_000_000).unwrap();
+        let claims = |iat| Claims {
+            sub: "ann@example.com".to_string(),
+            user_id: Uuid::nil(),
+            exp: 1_800_000_000,
+            iat,
+            org: None,
+        };
+
+        assert!(claims(1_699_999_999).issued_by(cutoff));
+        assert!(claims(1_700_000_000).issued_by(cutoff));
+        assert!(!claims(1_700_000_001).issued_by(cutoff));
+        // Tokens without `iat` predate every revocation
+        assert!(claims(0).issued_by(cutoff));
+    }
+}
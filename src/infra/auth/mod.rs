@@ -1,3 +1,15 @@
+pub mod breached_password_directory;
+pub mod hmac_invitation_signer;
 pub mod jwt_token_service;
+pub mod policy_file;
+pub mod sea_orm_invitation_store;
+pub mod sea_orm_password_history_store;
+pub mod sea_orm_token_revocation_store;
 
+pub use breached_password_directory::BreachedPasswordDirectory;
+pub use hmac_invitation_signer::HmacInvitationSigner;
 pub use jwt_token_service::{Claims, JwtTokenService};
+pub use policy_file::load_policy;
+pub use sea_orm_invitation_store::SeaOrmInvitationStore;
+pub use sea_orm_password_history_store::SeaOrmPasswordHistoryStore;
+pub use sea_orm_token_revocation_store::SeaOrmTokenRevocationStore;
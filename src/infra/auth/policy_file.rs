@@ -0,0 +1,9 @@
+use crate::app::policy::Policy;
+use std::path::Path;
+
+/// Read and validate the authorization policy file
+pub fn load_policy(path: &Path) -> Result<Policy, String> {
+    let content =
+        std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    Policy::parse(&content).map_err(|e| format!("{}: {}", path.display(), e))
+}
@@ -0,0 +1,365 @@
+use crate::app::events::EventDispatcher;
+use crate::app::ports::{Invitation, InvitationStatus, InvitationStore, NewInvitation};
+use crate::domain::organization::{Membership, TenantScope};
+use crate::domain::shared::OrganizationId;
+use crate::domain::user::repository::RepositoryError;
+use crate::domain::user::{Role, User};
+use crate::infra::persistence::entities::invitations::{self, Entity as InvitationsEntity};
+use crate::infra::persistence::{SeaOrmOrganizationRepository, SeaOrmUserRepository};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::sea_query::{Expr, SimpleExpr};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+use std::str::FromStr;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// SeaORM implementation of InvitationStore
+///
+/// State changes only apply to pending rows, so concurrent revokes and
+/// acceptances of the same invitation cannot both succeed. Accepting saves
+/// the invitee in the same transaction, dispatching their events once it
+/// commits as the user repository does.
+pub struct SeaOrmInvitationStore {
+    db: Arc<sea_orm::DatabaseConnection>,
+    events: Arc<EventDispatcher>,
+}
+
+impl SeaOrmInvitationStore {
+    pub fn new(db: Arc<sea_orm::DatabaseConnection>, events: Arc<EventDispatcher>) -> Self {
+        Self { db, events }
+    }
+
+    /// Update the pending invitation `id`; `false` if there was none
+    async fn update_pending(
+        db: &impl ConnectionTrait,
+        id: Uuid,
+        changes: Vec<(invitations::Column, SimpleExpr)>,
+    ) -> Result<bool, RepositoryError> {
+        let mut update = InvitationsEntity::update_many();
+        for (column, value) in changes {
+            update = update.col_expr(column, value);
+        }
+        let result = update
+            .filter(invitations::Column::Id.eq(id))
+            .filter(invitations::Column::Status.eq(InvitationStatus::Pending.as_str()))
+            .exec(db)
+            .await
+            .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
+
+/// Convert a SeaORM model to a stored invitation
+fn to_invitation(model: invitations::Model) -> Result<Invitation, RepositoryError> {
+    let roles = serde_json::from_value(model.roles)
+        .map_err(|e| RepositoryError::PersistenceFailure(format!("Invalid roles: {}", e)))?;
+    let status =
+        InvitationStatus::from_str(&model.status).map_err(RepositoryError::PersistenceFailure)?;
+
+    Ok(Invitation {
+        id: model.id,
+        email: model.email,
+        roles,
+        organization_id: model.organization_id.map(OrganizationId::from),
+        invited_by: model.invited_by,
+        locale: model.locale,
+        status,
+        expires_at: model.expires_at.to_utc(),
+        created_at: model.created_at.to_utc(),
+        accepted_at: model.accepted_at.map(|at| at.to_utc()),
+    })
+}
+
+#[async_trait]
+impl InvitationStore for SeaOrmInvitationStore {
+    async fn create(&self, invitation: NewInvitation) -> Result<Invitation, RepositoryError> {
+        let model = invitations::ActiveModel {
+            id: Set(Uuid::now_v7()),
+            email: Set(invitation.email),
+            roles: Set(serde_json::json!(invitation.roles)),
+            organization_id: Set(invitation.organization_id.map(|id| id.value())),
+            invited_by: Set(invitation.invited_by),
+            locale: Set(invitation.locale),
+            status: Set(InvitationStatus::Pending.as_str().to_string()),
+            expires_at: Set(invitation.expires_at.into()),
+            created_at: Set(Utc::now().into()),
+            accepted_at: Set(None),
+        }
+        .insert(self.db.as_ref())
+        .await
+        .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?;
+
+        to_invitation(model)
+    }
+
+    async fn find(&self, id: Uuid) -> Result<Option<Invitation>, RepositoryError> {
+        InvitationsEntity::find_by_id(id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?
+            .map(to_invitation)
+            .transpose()
+    }
+
+    async fn find_pending(
+        &self,
+        email: &str,
+        organization_id: Option<OrganizationId>,
+    ) -> Result<Option<Invitation>, RepositoryError> {
+        let organization = match organization_id {
+            Some(id) => invitations::Column::OrganizationId.eq(id.value()),
+            None => invitations::Column::OrganizationId.is_null(),
+        };
+
+        InvitationsEntity::find()
+            .filter(invitations::Column::Email.eq(email))
+            .filter(organization)
+            .filter(invitations::Column::Status.eq(InvitationStatus::Pending.as_str()))
+            .one(self.db.as_ref())
+            .await
+            .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?
+            .map(to_invitation)
+            .transpose()
+    }
+
+    async fn list(
+        &self,
+        scope: TenantScope,
+        page: u64,
+        rows_per_page: u64,
+    ) -> Result<(Vec<Invitation>, u64), RepositoryError> {
+        let mut query = InvitationsEntity::find();
+        if let Some(organization_id) = scope.organization() {
+            query = query.filter(invitations::Column::OrganizationId.eq(organization_id.value()));
+        }
+
+        let total = query
+            .clone()
+            .count(self.db.as_ref())
+            .await
+            .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?;
+
+        let invitations = query
+            .order_by_desc(invitations::Column::CreatedAt)
+            .offset(page.saturating_sub(1) * rows_per_page)
+            .limit(rows_per_page)
+            .all(self.db.as_ref())
+            .await
+            .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?
+            .into_iter()
+            .map(to_invitation)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok((invitations, total))
+    }
+
+    async fn renew(
+        &self,
+        id: Uuid,
+        expires_at: DateTime<Utc>,
+    ) -> Result<Option<Invitation>, RepositoryError> {
+        if !Self::update_pending(
+            self.db.as_ref(),
+            id,
+            vec![(invitations::Column::ExpiresAt, Expr::value(expires_at))],
+        )
+        .await?
+        {
+            return Ok(None);
+        }
+        self.find(id).await
+    }
+
+    async fn revoke(&self, id: Uuid) -> Result<bool, RepositoryError> {
+        Self::update_pending(
+            self.db.as_ref(),
+            id,
+            vec![(
+                invitations::Column::Status,
+                Expr::value(InvitationStatus::Revoked.as_str()),
+            )],
+        )
+        .await
+    }
+
+    async fn accept(
+        &self,
+        id: Uuid,
+        invitee: &mut User,
+        membership: Option<(OrganizationId, &[Role])>,
+    ) -> Result<bool, RepositoryError> {
+        let persistence_failure =
+            |e: sea_orm::DbErr| RepositoryError::PersistenceFailure(e.to_string());
+        let txn = self.db.begin().await.map_err(persistence_failure)?;
+
+        // Claim first, so a concurrent revoke or acceptance wins outright
+        let claimed = Self::update_pending(
+            &txn,
+            id,
+            vec![
+                (
+                    invitations::Column::Status,
+                    Expr::value(InvitationStatus::Accepted.as_str()),
+                ),
+                (
+                    invitations::Column::AcceptedAt,
+                    Expr::current_timestamp().into(),
+                ),
+            ],
+        )
+        .await?;
+        if !claimed {
+            return Ok(false);
+        }
+
+        let events = SeaOrmUserRepository::save_in(&txn, invitee).await?;
+        if let Some((organization_id, roles)) = membership {
+            let user_id = invitee.id().ok_or(RepositoryError::NotFound)?;
+            let membership =
+                Membership::with_roles(organization_id, user_id, roles.iter().copied());
+            SeaOrmOrganizationRepository::save_membership_in(&txn, &membership).await?;
+        }
+
+        txn.commit().await.map_err(persistence_failure)?;
+        self.events.dispatch(events).await;
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::user::{DateOfBirth, Email, PasswordHasher, PasswordPolicy};
+    use crate::infra::persistence::entities::outbox::{self, Entity as OutboxEntity};
+    use crate::infra::persistence::entities::users::{self, Entity as UsersEntity};
+    use chrono::{Duration, NaiveDate};
+
+    fn invitee(email: &str) -> User {
+        User::register(
+            Email::try_from(email.to_string()).unwrap(),
+            "Secure-Pass-2024".to_string(),
+            "Ivy".to_string(),
+            "Tee".to_string(),
+            DateOfBirth::from(NaiveDate::from_ymd_opt(1990, 1, 1).unwrap()),
+            &PasswordPolicy::default(),
+            &PasswordHasher::default(),
+        )
+        .unwrap()
+    }
+
+    async fn invite(store: &SeaOrmInvitationStore, email: &str) -> Invitation {
+        store
+            .create(NewInvitation {
+                email: email.to_string(),
+                roles: vec!["admin".to_string()],
+                organization_id: None,
+                invited_by: None,
+                locale: Some("de".to_string()),
+                expires_at: Utc::now() + Duration::days(1),
+            })
+            .await
+            .unwrap()
+    }
+
+    async fn delete_user(db: &sea_orm::DatabaseConnection, user: &User) {
+        OutboxEntity::delete_many()
+            .filter(outbox::Column::AggregateId.eq(user.public_id().value()))
+            .exec(db)
+            .await
+            .unwrap();
+        UsersEntity::delete_many()
+            .filter(users::Column::PublicId.eq(user.public_id().value()))
+            .exec(db)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated database (docker-compose up postgresql)"]
+    async fn test_state_changes_apply_to_pending_invitations_only() {
+        let db = Arc::new(crate::infra::config::database::connect().await.unwrap());
+        let store = SeaOrmInvitationStore::new(db.clone(), Arc::new(EventDispatcher::new(vec![])));
+        let email = format!("{}@invitations.test", Uuid::new_v4().simple());
+
+        let invitation = invite(&store, &email).await;
+        assert_eq!(
+            store.find(invitation.id).await.unwrap(),
+            Some(invitation.clone())
+        );
+        assert_eq!(
+            store
+                .find_pending(&email, None)
+                .await
+                .unwrap()
+                .map(|i| i.id),
+            Some(invitation.id)
+        );
+
+        let later = Utc::now() + Duration::days(2);
+        let renewed = store.renew(invitation.id, later).await.unwrap().unwrap();
+        assert_eq!(renewed.expires_at.timestamp(), later.timestamp());
+
+        let mut user = invitee(&email);
+        assert!(store.accept(invitation.id, &mut user, None).await.unwrap());
+        assert!(user.id().is_some());
+        assert!(!store.revoke(invitation.id).await.unwrap());
+        let mut late = invitee(&email);
+        assert!(!store.accept(invitation.id, &mut late, None).await.unwrap());
+        assert!(late.id().is_none());
+        assert!(store.renew(invitation.id, later).await.unwrap().is_none());
+        let accepted = store.find(invitation.id).await.unwrap().unwrap();
+        assert_eq!(accepted.status, InvitationStatus::Accepted);
+        assert!(accepted.accepted_at.is_some());
+        assert!(store.find_pending(&email, None).await.unwrap().is_none());
+
+        delete_user(&db, &user).await;
+        InvitationsEntity::delete_by_id(invitation.id)
+            .exec(db.as_ref())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated database (docker-compose up postgresql)"]
+    async fn test_failed_acceptance_saves_nothing() {
+        let db = Arc::new(crate::infra::config::database::connect().await.unwrap());
+        let store = SeaOrmInvitationStore::new(db.clone(), Arc::new(EventDispatcher::new(vec![])));
+        let email = format!("{}@invitations.test", Uuid::new_v4().simple());
+        let invitation = invite(&store, &email).await;
+
+        // No such organization: the membership fails after the invitee is written
+        let mut user = invitee(&email);
+        let missing = OrganizationId::from(i32::MAX);
+        assert!(
+            store
+                .accept(invitation.id, &mut user, Some((missing, &[Role::Admin])))
+                .await
+                .is_err()
+        );
+
+        let pending = store.find(invitation.id).await.unwrap().unwrap();
+        assert_eq!(pending.status, InvitationStatus::Pending);
+        let saved = UsersEntity::find()
+            .filter(users::Column::Email.eq(email.clone()))
+            .count(db.as_ref())
+            .await
+            .unwrap();
+        assert_eq!(saved, 0);
+
+        // The invitation is still open to a retry
+        let mut user = invitee(&email);
+        assert!(store.accept(invitation.id, &mut user, None).await.unwrap());
+
+        delete_user(&db, &user).await;
+        InvitationsEntity::delete_by_id(invitation.id)
+            .exec(db.as_ref())
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,105 @@
+use crate::app::ports::PasswordHistoryStore;
+use crate::domain::user::repository::RepositoryError;
+use crate::infra::persistence::entities::password_history::{
+    self, Entity as PasswordHistoryEntity,
+};
+use async_trait::async_trait;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DbBackend, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+    Statement,
+};
+use std::sync::Arc;
+
+/// Record a hash and drop those older than the newest `keep`
+const RECORD_SQL: &str = r#"
+WITH inserted AS (
+    INSERT INTO password_history (user_id, password_hash) VALUES ($1, $2)
+    RETURNING id
+)
+DELETE FROM password_history
+WHERE user_id = $1
+  AND id NOT IN (
+    SELECT id FROM password_history WHERE user_id = $1 ORDER BY id DESC LIMIT $3
+  )
+"#;
+
+/// SeaORM implementation of PasswordHistoryStore
+pub struct SeaOrmPasswordHistoryStore {
+    db: Arc<sea_orm::DatabaseConnection>,
+}
+
+impl SeaOrmPasswordHistoryStore {
+    pub fn new(db: Arc<sea_orm::DatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl PasswordHistoryStore for SeaOrmPasswordHistoryStore {
+    async fn recent(&self, user_id: i32, limit: usize) -> Result<Vec<String>, RepositoryError> {
+        let rows = PasswordHistoryEntity::find()
+            .filter(password_history::Column::UserId.eq(user_id))
+            .order_by_desc(password_history::Column::Id)
+            .limit(limit as u64)
+            .all(self.db.as_ref())
+            .await
+            .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|row| row.password_hash).collect())
+    }
+
+    async fn record(
+        &self,
+        user_id: i32,
+        password_hash: &str,
+        keep: usize,
+    ) -> Result<(), RepositoryError> {
+        // The new row is invisible to the statement's own subqueries, so the
+        // older rows kept are one fewer
+        self.db
+            .execute(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                RECORD_SQL,
+                [
+                    user_id.into(),
+                    password_hash.into(),
+                    (keep.saturating_sub(1) as i64).into(),
+                ],
+            ))
+            .await
+            .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    #[ignore = "requires a migrated database (docker-compose up postgresql)"]
+    async fn test_keeps_only_the_latest_hashes() {
+        let db = Arc::new(crate::infra::config::database::connect().await.unwrap());
+        let user_id = db
+            .query_one(Statement::from_string(
+                DbBackend::Postgres,
+                "SELECT min(id) AS id FROM users",
+            ))
+            .await
+            .unwrap()
+            .and_then(|row| row.try_get::<Option<i32>>("", "id").unwrap())
+            .expect("a user to attach history to");
+        let store = SeaOrmPasswordHistoryStore::new(db.clone());
+
+        for hash in ["h1", "h2", "h3"] {
+            store.record(user_id, hash, 2).await.unwrap();
+        }
+        assert_eq!(store.recent(user_id, 5).await.unwrap(), ["h3", "h2"]);
+
+        PasswordHistoryEntity::delete_many()
+            .filter(password_history::Column::UserId.eq(user_id))
+            .exec(db.as_ref())
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,61 @@
+use crate::app::ports::TokenRevocationStore;
+use crate::domain::user::repository::RepositoryError;
+use crate::infra::persistence::entities::token_revocations::Entity as TokenRevocationsEntity;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::prelude::DateTimeWithTimeZone;
+use sea_orm::{ConnectionTrait, DbBackend, EntityTrait, Statement};
+use std::sync::Arc;
+
+/// Move the user's cut-off to the current time
+const REVOKE_SQL: &str = r#"
+INSERT INTO token_revocations (user_id, revoked_at)
+VALUES ($1, now())
+ON CONFLICT (user_id) DO UPDATE SET revoked_at = EXCLUDED.revoked_at
+RETURNING revoked_at
+"#;
+
+/// SeaORM implementation of TokenRevocationStore
+///
+/// Cut-offs are read on every authenticated request; behind the cache (see
+/// `CachingTokenRevocationStore`) a revocation reaches other instances only
+/// through a shared Redis cache.
+pub struct SeaOrmTokenRevocationStore {
+    db: Arc<sea_orm::DatabaseConnection>,
+}
+
+impl SeaOrmTokenRevocationStore {
+    pub fn new(db: Arc<sea_orm::DatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl TokenRevocationStore for SeaOrmTokenRevocationStore {
+    async fn revoke_all(&self, user_id: i32) -> Result<DateTime<Utc>, RepositoryError> {
+        let row = self
+            .db
+            .query_one(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                REVOKE_SQL,
+                [user_id.into()],
+            ))
+            .await
+            .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?
+            .ok_or_else(|| RepositoryError::Unexpected("Revocation not recorded".to_string()))?;
+
+        let revoked_at: DateTimeWithTimeZone = row
+            .try_get("", "revoked_at")
+            .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?;
+        Ok(revoked_at.with_timezone(&Utc))
+    }
+
+    async fn revoked_at(&self, user_id: i32) -> Result<Option<DateTime<Utc>>, RepositoryError> {
+        let revocation = TokenRevocationsEntity::find_by_id(user_id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?;
+
+        Ok(revocation.map(|model| model.revoked_at.with_timezone(&Utc)))
+    }
+}
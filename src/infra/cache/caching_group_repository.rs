@@ -0,0 +1,298 @@
+use super::{Counters, ReadThrough};
+use crate::app::ports::{CacheMetrics, CacheStats, CacheStore};
+use crate::domain::group::repository::GroupRepositoryResult;
+use crate::domain::group::{Group, GroupGrant, GroupRepository};
+use crate::domain::organization::TenantScope;
+use crate::domain::shared::{GroupId, OrganizationId, UserId};
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
+
+/// Read-through cache in front of another GroupRepository
+///
+/// Caches `granting`, looked up on every authenticated request, for `ttl`.
+/// Entries are keyed by a generation of their tenant that every change to
+/// one of its groups or their members replaces, so a changed grant or a
+/// deleted group drops the entries of all members at once.
+pub struct CachingGroupRepository {
+    inner: Arc<dyn GroupRepository>,
+    cache: ReadThrough,
+    generations: Counters,
+    grants: Counters,
+}
+
+impl CachingGroupRepository {
+    pub fn new(inner: Arc<dyn GroupRepository>, store: Arc<dyn CacheStore>, ttl: Duration) -> Self {
+        Self {
+            inner,
+            cache: ReadThrough::new(store, ttl),
+            generations: Counters::default(),
+            grants: Counters::default(),
+        }
+    }
+
+    fn generation_key(organization_id: Option<OrganizationId>) -> String {
+        match organization_id {
+            Some(id) => format!("group_generation:{}", id.value()),
+            None => "group_generation:platform".to_string(),
+        }
+    }
+
+    /// The current generation of the tenant's group entries, started if missing
+    async fn generation(&self, organization_id: Option<OrganizationId>) -> String {
+        let key = Self::generation_key(organization_id);
+        match self.cache.read::<String>(&self.generations, &key).await {
+            Some(generation) => generation,
+            None => self.next_generation(organization_id).await,
+        }
+    }
+
+    /// Start a new generation, leaving the tenant's entries to expire unread
+    async fn next_generation(&self, organization_id: Option<OrganizationId>) -> String {
+        let generation = Uuid::new_v4().simple().to_string();
+        self.cache
+            .write(&Self::generation_key(organization_id), &generation)
+            .await;
+        generation
+    }
+
+    /// Start a new generation for the tenant of the group, if it exists
+    async fn changed(&self, group_id: GroupId) -> GroupRepositoryResult<()> {
+        if let Some(group) = self.inner.find_by_id(group_id).await? {
+            self.next_generation(group.organization_id()).await;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl GroupRepository for CachingGroupRepository {
+    async fn find_by_id(&self, id: GroupId) -> GroupRepositoryResult<Option<Group>> {
+        self.inner.find_by_id(id).await
+    }
+
+    async fn exists_with_name(
+        &self,
+        organization_id: Option<OrganizationId>,
+        name: &str,
+    ) -> GroupRepositoryResult<bool> {
+        self.inner.exists_with_name(organization_id, name).await
+    }
+
+    async fn save(&self, group: &Group) -> GroupRepositoryResult<()> {
+        self.inner.save(group).await?;
+        self.next_generation(group.organization_id()).await;
+        Ok(())
+    }
+
+    async fn delete(&self, id: GroupId) -> GroupRepositoryResult<bool> {
+        // Looked up first; the tenant is gone with the group
+        let group = self.inner.find_by_id(id).await?;
+        let deleted = self.inner.delete(id).await?;
+        if let Some(group) = group {
+            self.next_generation(group.organization_id()).await;
+        }
+        Ok(deleted)
+    }
+
+    async fn list(
+        &self,
+        scope: TenantScope,
+        page: u64,
+        rows_per_page: u64,
+    ) -> GroupRepositoryResult<(Vec<Group>, u64)> {
+        self.inner.list(scope, page, rows_per_page).await
+    }
+
+    async fn list_for_user(
+        &self,
+        user_id: UserId,
+        scope: TenantScope,
+    ) -> GroupRepositoryResult<Vec<Group>> {
+        self.inner.list_for_user(user_id, scope).await
+    }
+
+    async fn granting(
+        &self,
+        user_id: UserId,
+        organization_id: Option<OrganizationId>,
+        grant: GroupGrant,
+    ) -> GroupRepositoryResult<Vec<GroupId>> {
+        let generation = self.generation(organization_id).await;
+        let key = format!(
+            "group_grants:{}:{}:{}",
+            generation,
+            user_id.value(),
+            grant.as_str()
+        );
+        if let Some(ids) = self.cache.read::<Vec<Uuid>>(&self.grants, &key).await {
+            return Ok(ids.into_iter().map(GroupId::from).collect());
+        }
+
+        let groups = self.inner.granting(user_id, organization_id, grant).await?;
+        let ids: Vec<Uuid> = groups.iter().map(|id| id.value()).collect();
+        self.cache.write(&key, &ids).await;
+        Ok(groups)
+    }
+
+    async fn add_member(&self, group_id: GroupId, user_id: UserId) -> GroupRepositoryResult<bool> {
+        let added = self.inner.add_member(group_id, user_id).await?;
+        self.changed(group_id).await?;
+        Ok(added)
+    }
+
+    async fn remove_member(
+        &self,
+        group_id: GroupId,
+        user_id: UserId,
+    ) -> GroupRepositoryResult<bool> {
+        let removed = self.inner.remove_member(group_id, user_id).await?;
+        self.changed(group_id).await?;
+        Ok(removed)
+    }
+}
+
+impl CacheMetrics for CachingGroupRepository {
+    fn stats(&self) -> Vec<CacheStats> {
+        vec![
+            self.generations.stats("group_generations"),
+            self.grants.stats("group_grants"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infra::cache::{Lookups, MemoryCacheStore};
+    use std::collections::{HashMap, HashSet};
+    use std::sync::Mutex;
+
+    /// Repository of groups and members, counting the grant lookups that reach it
+    #[derive(Default)]
+    struct CountingRepository {
+        groups: Mutex<HashMap<GroupId, Group>>,
+        members: Mutex<HashSet<(GroupId, UserId)>>,
+        lookups: Lookups,
+    }
+
+    #[async_trait]
+    impl GroupRepository for CountingRepository {
+        async fn find_by_id(&self, id: GroupId) -> GroupRepositoryResult<Option<Group>> {
+            Ok(self.groups.lock().unwrap().get(&id).cloned())
+        }
+
+        async fn exists_with_name(
+            &self,
+            _organization_id: Option<OrganizationId>,
+            _name: &str,
+        ) -> GroupRepositoryResult<bool> {
+            Ok(false)
+        }
+
+        async fn save(&self, group: &Group) -> GroupRepositoryResult<()> {
+            self.groups
+                .lock()
+                .unwrap()
+                .insert(group.id(), group.clone());
+            Ok(())
+        }
+
+        async fn delete(&self, id: GroupId) -> GroupRepositoryResult<bool> {
+            Ok(self.groups.lock().unwrap().remove(&id).is_some())
+        }
+
+        async fn list(
+            &self,
+            _scope: TenantScope,
+            _page: u64,
+            _rows_per_page: u64,
+        ) -> GroupRepositoryResult<(Vec<Group>, u64)> {
+            Ok((vec![], 0))
+        }
+
+        async fn list_for_user(
+            &self,
+            _user_id: UserId,
+            _scope: TenantScope,
+        ) -> GroupRepositoryResult<Vec<Group>> {
+            Ok(vec![])
+        }
+
+        async fn granting(
+            &self,
+            user_id: UserId,
+            organization_id: Option<OrganizationId>,
+            grant: GroupGrant,
+        ) -> GroupRepositoryResult<Vec<GroupId>> {
+            self.lookups.record();
+            let members = self.members.lock().unwrap();
+            Ok(self
+                .groups
+                .lock()
+                .unwrap()
+                .values()
+                .filter(|group| group.organization_id() == organization_id)
+                .filter(|group| group.has_grant(&grant))
+                .filter(|group| members.contains(&(group.id(), user_id)))
+                .map(|group| group.id())
+                .collect())
+        }
+
+        async fn add_member(
+            &self,
+            group_id: GroupId,
+            user_id: UserId,
+        ) -> GroupRepositoryResult<bool> {
+            Ok(self.members.lock().unwrap().insert((group_id, user_id)))
+        }
+
+        async fn remove_member(
+            &self,
+            group_id: GroupId,
+            user_id: UserId,
+        ) -> GroupRepositoryResult<bool> {
+            Ok(self.members.lock().unwrap().remove(&(group_id, user_id)))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_caches_grants_until_the_tenant_changes() {
+        let inner = Arc::new(CountingRepository::default());
+        let cache = CachingGroupRepository::new(
+            inner.clone(),
+            Arc::new(MemoryCacheStore::new(100)),
+            Duration::from_secs(60),
+        );
+        let acme = Some(OrganizationId::from(3));
+        let (ann, bob) = (UserId::from(7), UserId::from(8));
+        let granting = |user_id| cache.granting(user_id, acme, GroupGrant::ViewMembers);
+
+        let mut team = Group::create(
+            acme,
+            "Support".to_string(),
+            HashSet::from([GroupGrant::ViewMembers]),
+        )
+        .unwrap();
+        cache.save(&team).await.unwrap();
+        assert!(granting(ann).await.unwrap().is_empty());
+        assert!(granting(ann).await.unwrap().is_empty());
+        assert_eq!(inner.lookups.count(), 1);
+
+        // Joining a team shows at once
+        cache.add_member(team.id(), ann).await.unwrap();
+        cache.add_member(team.id(), bob).await.unwrap();
+        assert_eq!(granting(ann).await.unwrap(), [team.id()]);
+        assert_eq!(granting(bob).await.unwrap(), [team.id()]);
+        granting(ann).await.unwrap();
+        assert_eq!(inner.lookups.count(), 3);
+
+        // So does a grant taken from the team, for every member
+        team.set_grants(HashSet::new());
+        cache.save(&team).await.unwrap();
+        assert!(granting(ann).await.unwrap().is_empty());
+        assert!(granting(bob).await.unwrap().is_empty());
+        assert_eq!(inner.lookups.count(), 5);
+    }
+}
@@ -0,0 +1,342 @@
+use super::{Counters, ReadThrough, parse_roles, role_names};
+use crate::app::ports::{CacheMetrics, CacheStats, CacheStore};
+use crate::domain::organization::repository::OrganizationRepositoryResult;
+use crate::domain::organization::{
+    Membership, Organization, OrganizationRepository, OrganizationSlug,
+};
+use crate::domain::shared::{OrganizationId, UserId};
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Read-through cache in front of another OrganizationRepository
+///
+/// Caches the lookups made when a request selects an organization,
+/// `find_id_by_slug` and `find_membership`, for `ttl`. A membership entry,
+/// including the answer that the user is not a member, is dropped when the
+/// membership is saved or removed through this repository. Memberships
+/// written inside another transaction (accepting an invitation) belong to
+/// users created there, who have no entries yet.
+pub struct CachingOrganizationRepository {
+    inner: Arc<dyn OrganizationRepository>,
+    cache: ReadThrough,
+    ids: Counters,
+    memberships: Counters,
+}
+
+impl CachingOrganizationRepository {
+    pub fn new(
+        inner: Arc<dyn OrganizationRepository>,
+        store: Arc<dyn CacheStore>,
+        ttl: Duration,
+    ) -> Self {
+        Self {
+            inner,
+            cache: ReadThrough::new(store, ttl),
+            ids: Counters::default(),
+            memberships: Counters::default(),
+        }
+    }
+
+    fn slug_key(slug: &OrganizationSlug) -> String {
+        format!("organization_slug:{}", slug)
+    }
+
+    fn membership_key(organization_id: OrganizationId, user_id: UserId) -> String {
+        format!(
+            "membership_roles:{}:{}",
+            organization_id.value(),
+            user_id.value()
+        )
+    }
+}
+
+#[async_trait]
+impl OrganizationRepository for CachingOrganizationRepository {
+    async fn find_by_id(
+        &self,
+        id: OrganizationId,
+    ) -> OrganizationRepositoryResult<Option<Organization>> {
+        self.inner.find_by_id(id).await
+    }
+
+    async fn find_by_slug(
+        &self,
+        slug: &OrganizationSlug,
+    ) -> OrganizationRepositoryResult<Option<Organization>> {
+        self.inner.find_by_slug(slug).await
+    }
+
+    async fn find_id_by_slug(
+        &self,
+        slug: &OrganizationSlug,
+    ) -> OrganizationRepositoryResult<Option<OrganizationId>> {
+        let key = Self::slug_key(slug);
+        if let Some(id) = self.cache.read::<i32>(&self.ids, &key).await {
+            return Ok(Some(OrganizationId::from(id)));
+        }
+
+        // Unknown slugs are not cached, so a new organization is found at once
+        let id = self.inner.find_id_by_slug(slug).await?;
+        if let Some(id) = id {
+            self.cache.write(&key, &id.value()).await;
+        }
+        Ok(id)
+    }
+
+    async fn exists_with_slug(
+        &self,
+        slug: &OrganizationSlug,
+    ) -> OrganizationRepositoryResult<bool> {
+        self.inner.exists_with_slug(slug).await
+    }
+
+    async fn save(&self, organization: &mut Organization) -> OrganizationRepositoryResult<()> {
+        self.inner.save(organization).await?;
+        self.cache
+            .invalidate(&[Self::slug_key(organization.slug())])
+            .await;
+        Ok(())
+    }
+
+    async fn list(&self) -> OrganizationRepositoryResult<Vec<Organization>> {
+        self.inner.list().await
+    }
+
+    async fn list_for_user(
+        &self,
+        user_id: UserId,
+    ) -> OrganizationRepositoryResult<Vec<Organization>> {
+        self.inner.list_for_user(user_id).await
+    }
+
+    async fn find_membership(
+        &self,
+        organization_id: OrganizationId,
+        user_id: UserId,
+    ) -> OrganizationRepositoryResult<Option<Membership>> {
+        let key = Self::membership_key(organization_id, user_id);
+        if let Some(names) = self
+            .cache
+            .read::<Option<Vec<String>>>(&self.memberships, &key)
+            .await
+        {
+            match names.as_deref().map(parse_roles).transpose() {
+                Ok(roles) => {
+                    return Ok(roles
+                        .map(|roles| Membership::reconstitute(organization_id, user_id, roles)));
+                }
+                Err(e) => tracing::warn!(key, error = %e, "Discarding invalid cached roles"),
+            }
+        }
+
+        let membership = self.inner.find_membership(organization_id, user_id).await?;
+        let names = membership
+            .as_ref()
+            .map(|membership| role_names(membership.roles()));
+        self.cache.write(&key, &names).await;
+        Ok(membership)
+    }
+
+    async fn find_memberships(
+        &self,
+        organization_id: OrganizationId,
+        user_ids: &[UserId],
+    ) -> OrganizationRepositoryResult<Vec<Membership>> {
+        self.inner.find_memberships(organization_id, user_ids).await
+    }
+
+    async fn save_membership(&self, membership: &Membership) -> OrganizationRepositoryResult<()> {
+        self.inner.save_membership(membership).await?;
+        self.cache
+            .invalidate(&[Self::membership_key(
+                membership.organization_id(),
+                membership.user_id(),
+            )])
+            .await;
+        Ok(())
+    }
+
+    async fn remove_membership(
+        &self,
+        organization_id: OrganizationId,
+        user_id: UserId,
+    ) -> OrganizationRepositoryResult<bool> {
+        let removed = self
+            .inner
+            .remove_membership(organization_id, user_id)
+            .await?;
+        self.cache
+            .invalidate(&[Self::membership_key(organization_id, user_id)])
+            .await;
+        Ok(removed)
+    }
+}
+
+impl CacheMetrics for CachingOrganizationRepository {
+    fn stats(&self) -> Vec<CacheStats> {
+        vec![
+            self.ids.stats("organization_ids"),
+            self.memberships.stats("memberships"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::user::Role;
+    use crate::infra::cache::{Lookups, MemoryCacheStore};
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    /// Repository of organization 3, counting the lookups that reach it
+    #[derive(Default)]
+    struct CountingRepository {
+        memberships: Mutex<HashMap<UserId, Membership>>,
+        lookups: Lookups,
+    }
+
+    #[async_trait]
+    impl OrganizationRepository for CountingRepository {
+        async fn find_by_id(
+            &self,
+            _id: OrganizationId,
+        ) -> OrganizationRepositoryResult<Option<Organization>> {
+            Ok(None)
+        }
+
+        async fn find_by_slug(
+            &self,
+            _slug: &OrganizationSlug,
+        ) -> OrganizationRepositoryResult<Option<Organization>> {
+            Ok(None)
+        }
+
+        async fn find_id_by_slug(
+            &self,
+            slug: &OrganizationSlug,
+        ) -> OrganizationRepositoryResult<Option<OrganizationId>> {
+            self.lookups.record();
+            Ok((slug.as_ref() == "acme").then(|| OrganizationId::from(3)))
+        }
+
+        async fn exists_with_slug(
+            &self,
+            _slug: &OrganizationSlug,
+        ) -> OrganizationRepositoryResult<bool> {
+            Ok(false)
+        }
+
+        async fn save(&self, _organization: &mut Organization) -> OrganizationRepositoryResult<()> {
+            Ok(())
+        }
+
+        async fn list(&self) -> OrganizationRepositoryResult<Vec<Organization>> {
+            Ok(vec![])
+        }
+
+        async fn list_for_user(
+            &self,
+            _user_id: UserId,
+        ) -> OrganizationRepositoryResult<Vec<Organization>> {
+            Ok(vec![])
+        }
+
+        async fn find_membership(
+            &self,
+            _organization_id: OrganizationId,
+            user_id: UserId,
+        ) -> OrganizationRepositoryResult<Option<Membership>> {
+            self.lookups.record();
+            Ok(self.memberships.lock().unwrap().get(&user_id).cloned())
+        }
+
+        async fn find_memberships(
+            &self,
+            _organization_id: OrganizationId,
+            _user_ids: &[UserId],
+        ) -> OrganizationRepositoryResult<Vec<Membership>> {
+            Ok(vec![])
+        }
+
+        async fn save_membership(
+            &self,
+            membership: &Membership,
+        ) -> OrganizationRepositoryResult<()> {
+            self.memberships
+                .lock()
+                .unwrap()
+                .insert(membership.user_id(), membership.clone());
+            Ok(())
+        }
+
+        async fn remove_membership(
+            &self,
+            _organization_id: OrganizationId,
+            user_id: UserId,
+        ) -> OrganizationRepositoryResult<bool> {
+            Ok(self.memberships.lock().unwrap().remove(&user_id).is_some())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_caches_ids_and_memberships_until_changed() {
+        let inner = Arc::new(CountingRepository::default());
+        let cache = CachingOrganizationRepository::new(
+            inner.clone(),
+            Arc::new(MemoryCacheStore::new(100)),
+            Duration::from_secs(60),
+        );
+        let acme = OrganizationSlug::try_from("acme".to_string()).unwrap();
+        let (organization_id, user_id) = (OrganizationId::from(3), UserId::from(7));
+
+        assert_eq!(
+            cache.find_id_by_slug(&acme).await.unwrap(),
+            Some(organization_id)
+        );
+        cache.find_id_by_slug(&acme).await.unwrap();
+        assert_eq!(inner.lookups.count(), 1);
+
+        // Not being a member is cached, until the user joins
+        assert!(
+            cache
+                .find_membership(organization_id, user_id)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        cache
+            .find_membership(organization_id, user_id)
+            .await
+            .unwrap();
+        assert_eq!(inner.lookups.count(), 2);
+
+        let membership = Membership::with_roles(organization_id, user_id, [Role::Admin]);
+        cache.save_membership(&membership).await.unwrap();
+        let found = cache
+            .find_membership(organization_id, user_id)
+            .await
+            .unwrap();
+        let cached = cache
+            .find_membership(organization_id, user_id)
+            .await
+            .unwrap();
+        assert_eq!(found.unwrap().roles(), membership.roles());
+        assert_eq!(cached.unwrap().roles(), membership.roles());
+        assert_eq!(inner.lookups.count(), 3);
+
+        cache
+            .remove_membership(organization_id, user_id)
+            .await
+            .unwrap();
+        assert!(
+            cache
+                .find_membership(organization_id, user_id)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert_eq!(inner.lookups.count(), 4);
+    }
+}
@@ -0,0 +1,187 @@
+use super::{Counters, ReadThrough};
+use crate::app::ports::{CacheMetrics, CacheStats, CacheStore, TokenRevocationStore};
+use crate::domain::user::repository::RepositoryError;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
+
+/// Read-through cache in front of another TokenRevocationStore
+///
+/// Caches `revoked_at`, including the common answer that the user never
+/// revoked their tokens, for `ttl`. Entries are keyed by a generation of the
+/// user that revoking replaces once committed, so a lookup that missed
+/// before the revocation cannot cache its stale answer where later lookups
+/// read. Cut-offs are stored as microseconds since the epoch.
+pub struct CachingTokenRevocationStore {
+    inner: Arc<dyn TokenRevocationStore>,
+    cache: ReadThrough,
+    generations: Counters,
+    cut_offs: Counters,
+}
+
+impl CachingTokenRevocationStore {
+    pub fn new(
+        inner: Arc<dyn TokenRevocationStore>,
+        store: Arc<dyn CacheStore>,
+        ttl: Duration,
+    ) -> Self {
+        Self {
+            inner,
+            cache: ReadThrough::new(store, ttl),
+            generations: Counters::default(),
+            cut_offs: Counters::default(),
+        }
+    }
+
+    fn generation_key(user_id: i32) -> String {
+        format!("revocation_generation:{}", user_id)
+    }
+
+    /// The current generation of the user's entry, started if missing
+    async fn generation(&self, user_id: i32) -> String {
+        let key = Self::generation_key(user_id);
+        match self.cache.read::<String>(&self.generations, &key).await {
+            Some(generation) => generation,
+            None => self.next_generation(user_id).await,
+        }
+    }
+
+    /// Start a new generation, leaving the user's entry to expire unread
+    async fn next_generation(&self, user_id: i32) -> String {
+        let generation = Uuid::new_v4().simple().to_string();
+        self.cache
+            .write(&Self::generation_key(user_id), &generation)
+            .await;
+        generation
+    }
+}
+
+#[async_trait]
+impl TokenRevocationStore for CachingTokenRevocationStore {
+    async fn revoke_all(&self, user_id: i32) -> Result<DateTime<Utc>, RepositoryError> {
+        let revoked_at = self.inner.revoke_all(user_id).await?;
+        self.next_generation(user_id).await;
+        Ok(revoked_at)
+    }
+
+    async fn revoked_at(&self, user_id: i32) -> Result<Option<DateTime<Utc>>, RepositoryError> {
+        // Read before the source, so a revocation committed meanwhile moves
+        // the entry written below out of reach
+        let generation = self.generation(user_id).await;
+        let key = format!("revoked_at:{}:{}", generation, user_id);
+        if let Some(micros) = self.cache.read::<Option<i64>>(&self.cut_offs, &key).await {
+            match micros.map(DateTime::from_timestamp_micros) {
+                None => return Ok(None),
+                Some(Some(revoked_at)) => return Ok(Some(revoked_at)),
+                Some(None) => tracing::warn!(key, "Discarding invalid cached cut-off"),
+            }
+        }
+
+        let revoked_at = self.inner.revoked_at(user_id).await?;
+        self.cache
+            .write(&key, &revoked_at.map(|at| at.timestamp_micros()))
+            .await;
+        Ok(revoked_at)
+    }
+}
+
+impl CacheMetrics for CachingTokenRevocationStore {
+    fn stats(&self) -> Vec<CacheStats> {
+        vec![
+            self.generations.stats("token_revocation_generations"),
+            self.cut_offs.stats("token_revocations"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infra::cache::{Lookups, MemoryCacheStore};
+    use std::sync::Mutex;
+    use tokio::sync::{Notify, oneshot};
+
+    /// Store with one cut-off, counting the lookups that reach it
+    ///
+    /// A lookup can be held after reading the cut-off, until released.
+    #[derive(Default)]
+    struct CountingStore {
+        cut_off: Mutex<Option<DateTime<Utc>>>,
+        lookups: Lookups,
+        held: Mutex<Option<oneshot::Receiver<()>>>,
+        holding: Notify,
+    }
+
+    #[async_trait]
+    impl TokenRevocationStore for CountingStore {
+        async fn revoke_all(&self, _user_id: i32) -> Result<DateTime<Utc>, RepositoryError> {
+            // To the microsecond, like the database
+            let now = DateTime::from_timestamp_micros(Utc::now().timestamp_micros()).unwrap();
+            *self.cut_off.lock().unwrap() = Some(now);
+            Ok(now)
+        }
+
+        async fn revoked_at(
+            &self,
+            _user_id: i32,
+        ) -> Result<Option<DateTime<Utc>>, RepositoryError> {
+            self.lookups.record();
+            let cut_off = *self.cut_off.lock().unwrap();
+            let held = self.held.lock().unwrap().take();
+            if let Some(release) = held {
+                self.holding.notify_one();
+                release.await.ok();
+            }
+            Ok(cut_off)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_caches_cut_offs_until_revoked() {
+        let inner = Arc::new(CountingStore::default());
+        let cache = CachingTokenRevocationStore::new(
+            inner.clone(),
+            Arc::new(MemoryCacheStore::new(100)),
+            Duration::from_secs(60),
+        );
+
+        // Never revoked is cached too
+        assert_eq!(cache.revoked_at(7).await.unwrap(), None);
+        assert_eq!(cache.revoked_at(7).await.unwrap(), None);
+        assert_eq!(inner.lookups.count(), 1);
+
+        let cut_off = cache.revoke_all(7).await.unwrap();
+        assert_eq!(cache.revoked_at(7).await.unwrap(), Some(cut_off));
+        assert_eq!(cache.revoked_at(7).await.unwrap(), Some(cut_off));
+        assert_eq!(inner.lookups.count(), 2);
+
+        let stats = cache.stats();
+        assert_eq!((stats[1].hits, stats[1].misses), (2, 2));
+    }
+
+    #[tokio::test]
+    async fn test_lookups_racing_a_revocation_do_not_cache_the_old_cut_off() {
+        let inner = Arc::new(CountingStore::default());
+        let cache = CachingTokenRevocationStore::new(
+            inner.clone(),
+            Arc::new(MemoryCacheStore::new(100)),
+            Duration::from_secs(60),
+        );
+        let (release, held) = oneshot::channel();
+        *inner.held.lock().unwrap() = Some(held);
+
+        // The lookup reads "never revoked", then the revocation commits
+        // before the lookup writes its answer to the cache
+        let (stale, cut_off) = tokio::join!(cache.revoked_at(7), async {
+            inner.holding.notified().await;
+            let cut_off = cache.revoke_all(7).await.unwrap();
+            release.send(()).unwrap();
+            cut_off
+        });
+        assert_eq!(stale.unwrap(), None);
+
+        assert_eq!(cache.revoked_at(7).await.unwrap(), Some(cut_off));
+    }
+}
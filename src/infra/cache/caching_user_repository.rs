@@ -0,0 +1,381 @@
+use super::{Counters, ReadThrough, parse_roles, role_names};
+use crate::app::ports::{CacheMetrics, CacheStats, CacheStore};
+use crate::domain::organization::TenantScope;
+use crate::domain::shared::{GroupId, PublicUserId, UserId};
+use crate::domain::user::entity::User;
+use crate::domain::user::repository::{UserRepository, UserRepositoryResult};
+use crate::domain::user::{DateOfBirth, Email, Password, Role, UserProfile};
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
+
+/// Read-through cache in front of another UserRepository
+///
+/// Caches the lookups made on every authenticated request,
+/// `find_id_by_public_id` and `find_roles_by_user_id`, and `find_by_id` for
+/// `ttl`, and drops the user's entries when they are saved. Users are stored
+/// without their password hash, which is never cached: those read from the
+/// cache have a withheld password. Cache failures are logged and the lookup
+/// falls through to the inner repository, so the cache never fails a request.
+pub struct CachingUserRepository {
+    inner: Arc<dyn UserRepository>,
+    cache: ReadThrough,
+    users: Counters,
+    ids: Counters,
+    roles: Counters,
+}
+
+/// A user as cached, without their password hash
+#[derive(Serialize, Deserialize)]
+struct CachedUser {
+    public_id: Uuid,
+    email: String,
+    first_name: String,
+    last_name: String,
+    date_of_birth: NaiveDate,
+    created_at: NaiveDate,
+    roles: Vec<String>,
+}
+
+impl CachedUser {
+    fn of(user: &User) -> Self {
+        Self {
+            public_id: user.public_id().value(),
+            email: user.email().to_string(),
+            first_name: user.profile().first_name().to_string(),
+            last_name: user.profile().last_name().to_string(),
+            date_of_birth: user.profile().date_of_birth().value(),
+            created_at: user.created_at(),
+            roles: role_names(user.roles()),
+        }
+    }
+
+    fn into_user(self, id: UserId) -> Result<User, String> {
+        let profile = UserProfile::new(
+            self.first_name,
+            self.last_name,
+            DateOfBirth::from(self.date_of_birth),
+        )
+        .map_err(|e| e.to_string())?;
+        Ok(User::reconstitute(
+            id,
+            PublicUserId::from(self.public_id),
+            Email::try_from(self.email).map_err(|e| e.to_string())?,
+            Password::withheld(),
+            profile,
+            self.created_at,
+            parse_roles(&self.roles)?,
+        ))
+    }
+}
+
+impl CachingUserRepository {
+    pub fn new(inner: Arc<dyn UserRepository>, store: Arc<dyn CacheStore>, ttl: Duration) -> Self {
+        Self {
+            inner,
+            cache: ReadThrough::new(store, ttl),
+            users: Counters::default(),
+            ids: Counters::default(),
+            roles: Counters::default(),
+        }
+    }
+
+    fn user_key(id: UserId) -> String {
+        format!("user:{}", id.value())
+    }
+
+    fn public_key(id: PublicUserId) -> String {
+        format!("user_public:{}", id)
+    }
+
+    fn roles_key(id: UserId) -> String {
+        format!("user_roles:{}", id.value())
+    }
+}
+
+#[async_trait]
+impl UserRepository for CachingUserRepository {
+    async fn find_by_id(&self, id: UserId) -> UserRepositoryResult<Option<User>> {
+        let key = Self::user_key(id);
+        if let Some(cached) = self.cache.read::<CachedUser>(&self.users, &key).await {
+            match cached.into_user(id) {
+                Ok(user) => return Ok(Some(user)),
+                Err(e) => tracing::warn!(key, error = %e, "Discarding invalid cached user"),
+            }
+        }
+
+        let user = self.inner.find_by_id(id).await?;
+        if let Some(user) = &user {
+            self.cache.write(&key, &CachedUser::of(user)).await;
+        }
+        Ok(user)
+    }
+
+    async fn find_by_public_id(
+        &self,
+        id: PublicUserId,
+        scope: TenantScope,
+    ) -> UserRepositoryResult<Option<User>> {
+        self.inner.find_by_public_id(id, scope).await
+    }
+
+    async fn find_id_by_public_id(&self, id: PublicUserId) -> UserRepositoryResult<Option<UserId>> {
+        let key = Self::public_key(id);
+        if let Some(user_id) = self.cache.read::<i32>(&self.ids, &key).await {
+            return Ok(Some(UserId::from(user_id)));
+        }
+
+        let user_id = self.inner.find_id_by_public_id(id).await?;
+        if let Some(user_id) = user_id {
+            self.cache.write(&key, &user_id.value()).await;
+        }
+        Ok(user_id)
+    }
+
+    async fn find_by_email(&self, email: &Email) -> UserRepositoryResult<Option<User>> {
+        self.inner.find_by_email(email).await
+    }
+
+    async fn save(&self, user: &mut User) -> UserRepositoryResult<()> {
+        self.inner.save(user).await?;
+
+        if let Some(id) = user.id() {
+            self.cache
+                .invalidate(&[
+                    Self::user_key(id),
+                    Self::public_key(user.public_id()),
+                    Self::roles_key(id),
+                ])
+                .await;
+        }
+        Ok(())
+    }
+
+    async fn update_password_hash(
+        &self,
+        id: UserId,
+        current: &str,
+        upgraded: &str,
+    ) -> UserRepositoryResult<bool> {
+        // Password hashes are never cached
+        self.inner.update_password_hash(id, current, upgraded).await
+    }
+
+    async fn exists_with_email(&self, email: &Email) -> UserRepositoryResult<bool> {
+        self.inner.exists_with_email(email).await
+    }
+
+    async fn list(
+        &self,
+        scope: TenantScope,
+        page: u64,
+        rows_per_page: u64,
+    ) -> UserRepositoryResult<(Vec<User>, u64)> {
+        self.inner.list(scope, page, rows_per_page).await
+    }
+
+    async fn list_in_groups(
+        &self,
+        groups: &[GroupId],
+        scope: TenantScope,
+        page: u64,
+        rows_per_page: u64,
+    ) -> UserRepositoryResult<(Vec<User>, u64)> {
+        self.inner
+            .list_in_groups(groups, scope, page, rows_per_page)
+            .await
+    }
+
+    async fn find_roles_by_user_id(&self, id: UserId) -> UserRepositoryResult<HashSet<Role>> {
+        let key = Self::roles_key(id);
+        if let Some(names) = self.cache.read::<Vec<String>>(&self.roles, &key).await {
+            match parse_roles(&names) {
+                Ok(roles) => return Ok(roles),
+                Err(e) => tracing::warn!(key, error = %e, "Discarding invalid cached roles"),
+            }
+        }
+
+        let roles = self.inner.find_roles_by_user_id(id).await?;
+        self.cache.write(&key, &role_names(&roles)).await;
+        Ok(roles)
+    }
+}
+
+impl CacheMetrics for CachingUserRepository {
+    fn stats(&self) -> Vec<CacheStats> {
+        vec![
+            self.users.stats("users"),
+            self.ids.stats("user_ids"),
+            self.roles.stats("user_roles"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infra::cache::{Lookups, MemoryCacheStore};
+
+    /// Repository holding one admin user, counting the lookups that reach it
+    struct CountingRepository {
+        user: User,
+        lookups: Lookups,
+    }
+
+    #[async_trait]
+    impl UserRepository for CountingRepository {
+        async fn find_by_id(&self, _id: UserId) -> UserRepositoryResult<Option<User>> {
+            self.lookups.record();
+            Ok(Some(self.user.clone()))
+        }
+
+        async fn find_by_public_id(
+            &self,
+            _id: PublicUserId,
+            _scope: TenantScope,
+        ) -> UserRepositoryResult<Option<User>> {
+            self.lookups.record();
+            Ok(Some(self.user.clone()))
+        }
+
+        async fn find_id_by_public_id(
+            &self,
+            _id: PublicUserId,
+        ) -> UserRepositoryResult<Option<UserId>> {
+            self.lookups.record();
+            Ok(self.user.id())
+        }
+
+        async fn find_by_email(&self, _email: &Email) -> UserRepositoryResult<Option<User>> {
+            Ok(None)
+        }
+
+        async fn save(&self, _user: &mut User) -> UserRepositoryResult<()> {
+            Ok(())
+        }
+
+        async fn update_password_hash(
+            &self,
+            _id: UserId,
+            _current: &str,
+            _upgraded: &str,
+        ) -> UserRepositoryResult<bool> {
+            Ok(false)
+        }
+
+        async fn exists_with_email(&self, _email: &Email) -> UserRepositoryResult<bool> {
+            Ok(false)
+        }
+
+        async fn list(
+            &self,
+            _scope: TenantScope,
+            _page: u64,
+            _rows: u64,
+        ) -> UserRepositoryResult<(Vec<User>, u64)> {
+            Ok((vec![], 0))
+        }
+
+        async fn list_in_groups(
+            &self,
+            _groups: &[GroupId],
+            _scope: TenantScope,
+            _page: u64,
+            _rows: u64,
+        ) -> UserRepositoryResult<(Vec<User>, u64)> {
+            Ok((vec![], 0))
+        }
+
+        async fn find_roles_by_user_id(&self, _id: UserId) -> UserRepositoryResult<HashSet<Role>> {
+            self.lookups.record();
+            Ok(self.user.roles().clone())
+        }
+    }
+
+    fn repository() -> (Arc<CountingRepository>, CachingUserRepository) {
+        let user = User::reconstitute(
+            UserId::from(7),
+            PublicUserId::generate(),
+            Email::try_from("ann@example.com".to_string()).unwrap(),
+            Password::from_hash("$2b$04$hash".to_string()),
+            UserProfile::new(
+                "Ann".to_string(),
+                "Lee".to_string(),
+                DateOfBirth::from(NaiveDate::from_ymd_opt(1995, 3, 10).unwrap()),
+            )
+            .unwrap(),
+            NaiveDate::from_ymd_opt(2025, 1, 2).unwrap(),
+            HashSet::from([Role::Admin, Role::User]),
+        );
+        let inner = Arc::new(CountingRepository {
+            user,
+            lookups: Lookups::default(),
+        });
+        let cache = CachingUserRepository::new(
+            inner.clone(),
+            Arc::new(MemoryCacheStore::new(100)),
+            Duration::from_secs(60),
+        );
+        (inner, cache)
+    }
+
+    #[tokio::test]
+    async fn test_reads_through_and_invalidates_on_save() {
+        let (inner, cache) = repository();
+        let public_id = inner.user.public_id();
+        let id = UserId::from(7);
+
+        assert_eq!(
+            cache.find_id_by_public_id(public_id).await.unwrap(),
+            Some(id)
+        );
+        assert_eq!(
+            cache.find_id_by_public_id(public_id).await.unwrap(),
+            Some(id)
+        );
+        assert_eq!(inner.lookups.count(), 1);
+
+        let roles = cache.find_roles_by_user_id(id).await.unwrap();
+        cache.find_roles_by_user_id(id).await.unwrap();
+        assert_eq!(roles, HashSet::from([Role::Admin, Role::User]));
+        assert_eq!(inner.lookups.count(), 2);
+
+        // Users are cached without their password hash
+        let found = cache.find_by_id(id).await.unwrap().unwrap();
+        let cached = cache.find_by_id(id).await.unwrap().unwrap();
+        assert_eq!(inner.lookups.count(), 3);
+        assert!(!found.password().is_withheld());
+        assert!(cached.password().is_withheld());
+        assert_eq!(cached.public_id(), public_id);
+        assert_eq!(cached.email(), inner.user.email());
+        assert_eq!(cached.profile().first_name(), "Ann");
+        assert_eq!(cached.created_at(), inner.user.created_at());
+        assert_eq!(cached.roles(), inner.user.roles());
+
+        // Saving drops every entry
+        let mut user = inner.user.clone();
+        cache.save(&mut user).await.unwrap();
+        cache.find_by_id(id).await.unwrap();
+        cache.find_id_by_public_id(public_id).await.unwrap();
+        cache.find_roles_by_user_id(id).await.unwrap();
+        assert_eq!(inner.lookups.count(), 6);
+
+        let stats = cache.stats();
+        assert_eq!(
+            (stats[0].name, stats[0].hits, stats[0].misses),
+            ("users", 1, 2)
+        );
+        assert_eq!(
+            (stats[1].name, stats[1].hits, stats[1].misses),
+            ("user_ids", 1, 2)
+        );
+        assert_eq!(
+            (stats[2].name, stats[2].hits, stats[2].misses),
+            ("user_roles", 1, 2)
+        );
+    }
+}
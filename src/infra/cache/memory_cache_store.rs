@@ -0,0 +1,152 @@
+use crate::app::ports::CacheStore;
+use async_trait::async_trait;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+struct Entry {
+    value: String,
+    expires_at: Instant,
+    /// Position in the recency order; higher is more recent
+    used: u64,
+}
+
+#[derive(Default)]
+struct Inner {
+    entries: HashMap<String, Entry>,
+    /// Keys by `Entry::used`, least recently used first
+    recency: BTreeMap<u64, String>,
+    clock: u64,
+}
+
+impl Inner {
+    fn touch(&mut self, key: &str) {
+        self.clock += 1;
+        let clock = self.clock;
+        if let Some(entry) = self.entries.get_mut(key) {
+            self.recency.remove(&entry.used);
+            entry.used = clock;
+            self.recency.insert(clock, key.to_string());
+        }
+    }
+
+    fn remove(&mut self, key: &str) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.recency.remove(&entry.used);
+        }
+    }
+}
+
+/// In-memory implementation of CacheStore
+///
+/// Holds at most `capacity` entries, evicting the least recently used.
+/// Entries are local to the process, so `delete` only reaches this instance.
+pub struct MemoryCacheStore {
+    inner: Mutex<Inner>,
+    capacity: usize,
+}
+
+impl MemoryCacheStore {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            inner: Mutex::new(Inner::default()),
+            capacity: capacity.max(1),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.lock().unwrap().entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[async_trait]
+impl CacheStore for MemoryCacheStore {
+    async fn get(&self, key: &str) -> Result<Option<String>, String> {
+        let mut inner = self.inner.lock().unwrap();
+
+        match inner.entries.get(key) {
+            Some(entry) if entry.expires_at > Instant::now() => {
+                let value = entry.value.clone();
+                inner.touch(key);
+                Ok(Some(value))
+            }
+            Some(_) => {
+                inner.remove(key);
+                Ok(None)
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn set(&self, key: &str, value: &str, ttl: Duration) -> Result<(), String> {
+        let mut inner = self.inner.lock().unwrap();
+
+        inner.remove(key);
+        while inner.entries.len() >= self.capacity {
+            let Some((_, oldest)) = inner.recency.pop_first() else {
+                break;
+            };
+            inner.entries.remove(&oldest);
+        }
+
+        inner.clock += 1;
+        let used = inner.clock;
+        inner.entries.insert(
+            key.to_string(),
+            Entry {
+                value: value.to_string(),
+                expires_at: Instant::now() + ttl,
+                used,
+            },
+        );
+        inner.recency.insert(used, key.to_string());
+        Ok(())
+    }
+
+    async fn delete(&self, keys: &[String]) -> Result<(), String> {
+        let mut inner = self.inner.lock().unwrap();
+        for key in keys {
+            inner.remove(key);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TTL: Duration = Duration::from_secs(60);
+
+    #[tokio::test]
+    async fn test_evicts_least_recently_used() {
+        let store = MemoryCacheStore::new(2);
+        store.set("a", "1", TTL).await.unwrap();
+        store.set("b", "2", TTL).await.unwrap();
+
+        // Reading "a" makes "b" the eviction candidate
+        assert_eq!(store.get("a").await.unwrap().as_deref(), Some("1"));
+        store.set("c", "3", TTL).await.unwrap();
+
+        assert_eq!(store.len(), 2);
+        assert!(store.get("b").await.unwrap().is_none());
+        assert_eq!(store.get("a").await.unwrap().as_deref(), Some("1"));
+        assert_eq!(store.get("c").await.unwrap().as_deref(), Some("3"));
+
+        store.delete(&["a".to_string()]).await.unwrap();
+        assert!(store.get("a").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_expired_entries_are_misses() {
+        let store = MemoryCacheStore::new(10);
+        store.set("a", "1", Duration::ZERO).await.unwrap();
+
+        assert!(store.get("a").await.unwrap().is_none());
+        assert!(store.is_empty());
+    }
+}
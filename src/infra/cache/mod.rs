@@ -0,0 +1,132 @@
+//! Caching
+//!
+//! Caching decorators wrap the SeaORM repositories and stores so the lookups
+//! made by every authenticated request are served from a `CacheStore`: a
+//! per-process LRU, or Redis when instances should share entries and
+//! invalidations.
+//!
+//! - `CachingUserRepository`: users without their password hash, IDs and roles
+//! - `CachingTokenRevocationStore`: token revocation cut-offs
+//! - `CachingOrganizationRepository`: organization IDs and membership roles
+//! - `CachingGroupRepository`: the groups granting a user something
+
+pub mod caching_group_repository;
+pub mod caching_organization_repository;
+pub mod caching_token_revocation_store;
+pub mod caching_user_repository;
+pub mod memory_cache_store;
+pub mod redis_cache_store;
+
+pub use caching_group_repository::CachingGroupRepository;
+pub use caching_organization_repository::CachingOrganizationRepository;
+pub use caching_token_revocation_store::CachingTokenRevocationStore;
+pub use caching_user_repository::CachingUserRepository;
+pub use memory_cache_store::MemoryCacheStore;
+pub use redis_cache_store::RedisCacheStore;
+
+use crate::app::ports::{CacheStats, CacheStore};
+use crate::domain::user::Role;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::collections::HashSet;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+fn role_names(roles: &HashSet<Role>) -> Vec<String> {
+    roles.iter().map(|role| role.as_str().to_string()).collect()
+}
+
+fn parse_roles(names: &[String]) -> Result<HashSet<Role>, String> {
+    names.iter().map(|name| Role::from_str(name)).collect()
+}
+
+/// Hit, miss and error counts of one cached lookup
+#[derive(Default)]
+struct Counters {
+    hits: AtomicU64,
+    misses: AtomicU64,
+    errors: AtomicU64,
+}
+
+impl Counters {
+    fn stats(&self, name: &'static str) -> CacheStats {
+        CacheStats {
+            name,
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            errors: self.errors.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// JSON entries in a CacheStore, kept for `ttl`
+///
+/// Cache failures are logged and reported as misses, so callers fall through
+/// to their source and the cache never fails a request. A failed
+/// invalidation leaves stale entries until they expire.
+struct ReadThrough {
+    store: Arc<dyn CacheStore>,
+    ttl: Duration,
+}
+
+impl ReadThrough {
+    fn new(store: Arc<dyn CacheStore>, ttl: Duration) -> Self {
+        Self { store, ttl }
+    }
+
+    async fn read<T: DeserializeOwned>(&self, counters: &Counters, key: &str) -> Option<T> {
+        match self.store.get(key).await {
+            Ok(Some(value)) => match serde_json::from_str(&value) {
+                Ok(value) => {
+                    counters.hits.fetch_add(1, Ordering::Relaxed);
+                    return Some(value);
+                }
+                Err(e) => tracing::warn!(key, error = %e, "Discarding unreadable cache entry"),
+            },
+            Ok(None) => {}
+            Err(e) => {
+                tracing::warn!(key, error = %e, "Cache lookup failed");
+                counters.errors.fetch_add(1, Ordering::Relaxed);
+                return None;
+            }
+        }
+
+        counters.misses.fetch_add(1, Ordering::Relaxed);
+        None
+    }
+
+    async fn write<T: Serialize>(&self, key: &str, value: &T) {
+        let result = match serde_json::to_string(value) {
+            Ok(value) => self.store.set(key, &value, self.ttl).await,
+            Err(e) => Err(e.to_string()),
+        };
+        if let Err(e) = result {
+            tracing::warn!(key, error = %e, "Cache write failed");
+        }
+    }
+
+    async fn invalidate(&self, keys: &[String]) {
+        if let Err(e) = self.store.delete(keys).await {
+            tracing::warn!(?keys, error = %e, "Cache invalidation failed");
+        }
+    }
+}
+
+/// Counts the lookups that reach the source behind a cache, for the fakes
+/// standing in for that source in tests
+#[cfg(test)]
+#[derive(Default)]
+struct Lookups(std::sync::atomic::AtomicU32);
+
+#[cfg(test)]
+impl Lookups {
+    fn record(&self) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+
+    fn count(&self) -> u32 {
+        self.0.load(Ordering::SeqCst)
+    }
+}
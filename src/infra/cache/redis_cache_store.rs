@@ -0,0 +1,87 @@
+use crate::app::ports::CacheStore;
+use async_trait::async_trait;
+use redis::AsyncCommands;
+use redis::aio::ConnectionManager;
+use std::time::Duration;
+
+/// Redis implementation of CacheStore
+///
+/// Entries are shared by every instance, so an invalidation on one instance
+/// is seen by all of them. Redis evicts by its own `maxmemory-policy`.
+pub struct RedisCacheStore {
+    connection: ConnectionManager,
+    key_prefix: String,
+}
+
+impl RedisCacheStore {
+    /// Connect to Redis at `url` (e.g. `redis://:password@localhost:6379`)
+    pub async fn connect(url: &str) -> Result<Self, String> {
+        let client = redis::Client::open(url).map_err(|e| e.to_string())?;
+        let connection = ConnectionManager::new(client)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(Self {
+            connection,
+            key_prefix: "cache:".to_string(),
+        })
+    }
+
+    fn key(&self, key: &str) -> String {
+        format!("{}{}", self.key_prefix, key)
+    }
+}
+
+#[async_trait]
+impl CacheStore for RedisCacheStore {
+    async fn get(&self, key: &str) -> Result<Option<String>, String> {
+        let mut connection = self.connection.clone();
+        connection
+            .get(self.key(key))
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn set(&self, key: &str, value: &str, ttl: Duration) -> Result<(), String> {
+        let mut connection = self.connection.clone();
+        connection
+            .pset_ex(self.key(key), value, ttl.as_millis().max(1) as u64)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn delete(&self, keys: &[String]) -> Result<(), String> {
+        if keys.is_empty() {
+            return Ok(());
+        }
+
+        let keys: Vec<String> = keys.iter().map(|key| self.key(key)).collect();
+        let mut connection = self.connection.clone();
+        connection.del(keys).await.map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn redis_url() -> String {
+        std::env::var("REDIS__URL").unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string())
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a local Redis (docker-compose up redis)"]
+    async fn test_set_get_delete() {
+        let store = RedisCacheStore::connect(&redis_url()).await.unwrap();
+        let key = format!("test:{}", uuid::Uuid::new_v4());
+
+        store
+            .set(&key, "value", Duration::from_secs(10))
+            .await
+            .unwrap();
+        assert_eq!(store.get(&key).await.unwrap().as_deref(), Some("value"));
+
+        store.delete(std::slice::from_ref(&key)).await.unwrap();
+        assert!(store.get(&key).await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,25 @@
+use crate::app::ports::EventSubscriber;
+use crate::domain::user::UserEvent;
+use async_trait::async_trait;
+
+/// Writes every domain event to the `audit` log target
+pub struct AuditLogSubscriber;
+
+#[async_trait]
+impl EventSubscriber for AuditLogSubscriber {
+    fn name(&self) -> &'static str {
+        "audit_log"
+    }
+
+    async fn handle(&self, event: &UserEvent) -> Result<(), String> {
+        // Only names and IDs: event payloads may carry personal data
+        tracing::info!(
+            target: "audit",
+            event = event.kind.name(),
+            user_id = %event.user_id,
+            occurred_at = %event.occurred_at.to_rfc3339(),
+            "Domain event"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,8 @@
+//! Domain event subscribers
+//!
+//! Implementations of the `EventSubscriber` port registered with the
+//! event dispatcher at bootstrap.
+
+pub mod audit_log_subscriber;
+
+pub use audit_log_subscriber::AuditLogSubscriber;
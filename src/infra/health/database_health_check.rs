@@ -0,0 +1,26 @@
+use crate::app::ports::HealthCheck;
+use async_trait::async_trait;
+use sea_orm::DatabaseConnection;
+use std::sync::Arc;
+
+/// Pings the database over a pooled connection
+pub struct DatabaseHealthCheck {
+    db: Arc<DatabaseConnection>,
+}
+
+impl DatabaseHealthCheck {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl HealthCheck for DatabaseHealthCheck {
+    fn name(&self) -> &'static str {
+        "database"
+    }
+
+    async fn check(&self) -> Result<(), String> {
+        self.db.ping().await.map_err(|e| e.to_string())
+    }
+}
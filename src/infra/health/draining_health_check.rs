@@ -0,0 +1,30 @@
+use crate::app::ports::HealthCheck;
+use crate::infra::lifecycle::Lifecycle;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Reports down once graceful shutdown has started so no new traffic arrives
+pub struct DrainingHealthCheck {
+    lifecycle: Arc<Lifecycle>,
+}
+
+impl DrainingHealthCheck {
+    pub fn new(lifecycle: Arc<Lifecycle>) -> Self {
+        Self { lifecycle }
+    }
+}
+
+#[async_trait]
+impl HealthCheck for DrainingHealthCheck {
+    fn name(&self) -> &'static str {
+        "shutdown"
+    }
+
+    async fn check(&self) -> Result<(), String> {
+        if self.lifecycle.is_draining() {
+            Err("server is draining".to_string())
+        } else {
+            Ok(())
+        }
+    }
+}
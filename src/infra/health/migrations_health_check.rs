@@ -0,0 +1,27 @@
+use crate::app::ports::HealthCheck;
+use crate::infra::persistence::schema;
+use async_trait::async_trait;
+use sea_orm::DatabaseConnection;
+use std::sync::Arc;
+
+/// Reports down while the schema does not match the migrations of this build
+pub struct MigrationsHealthCheck {
+    db: Arc<DatabaseConnection>,
+}
+
+impl MigrationsHealthCheck {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl HealthCheck for MigrationsHealthCheck {
+    fn name(&self) -> &'static str {
+        "migrations"
+    }
+
+    async fn check(&self) -> Result<(), String> {
+        schema::check(self.db.as_ref()).await
+    }
+}
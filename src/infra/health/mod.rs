@@ -0,0 +1,13 @@
+//! Health check implementations
+//!
+//! Infrastructure probes registered with the readiness use case.
+
+pub mod database_health_check;
+pub mod draining_health_check;
+pub mod migrations_health_check;
+pub mod signing_keys_health_check;
+
+pub use database_health_check::DatabaseHealthCheck;
+pub use draining_health_check::DrainingHealthCheck;
+pub use migrations_health_check::MigrationsHealthCheck;
+pub use signing_keys_health_check::SigningKeysHealthCheck;
@@ -0,0 +1,21 @@
+use crate::app::ports::HealthCheck;
+use crate::infra::auth::JwtTokenService;
+use async_trait::async_trait;
+
+/// Verifies the JWT signing secret is configured
+pub struct SigningKeysHealthCheck;
+
+#[async_trait]
+impl HealthCheck for SigningKeysHealthCheck {
+    fn name(&self) -> &'static str {
+        "signing_keys"
+    }
+
+    async fn check(&self) -> Result<(), String> {
+        if JwtTokenService::signing_key_configured() {
+            Ok(())
+        } else {
+            Err("JWT_SECRET is not set".to_string())
+        }
+    }
+}
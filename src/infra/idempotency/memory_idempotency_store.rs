@@ -0,0 +1,186 @@
+use crate::app::ports::{IdempotencyClaim, IdempotencyStore, StoredResponse};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+struct Entry {
+    fingerprint: String,
+    response: Option<StoredResponse>,
+    locked_at: Instant,
+    expires_at: Instant,
+}
+
+/// In-memory implementation of IdempotencyStore
+///
+/// Keys are local to the process; intended for tests and single-instance setups.
+pub struct MemoryIdempotencyStore {
+    entries: Mutex<HashMap<String, Entry>>,
+    lock_timeout: Duration,
+}
+
+impl MemoryIdempotencyStore {
+    pub fn new(lock_timeout: Duration) -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+            lock_timeout,
+        }
+    }
+
+    fn claim_at(
+        &self,
+        key: &str,
+        fingerprint: &str,
+        ttl: Duration,
+        now: Instant,
+    ) -> IdempotencyClaim {
+        let mut entries = self.entries.lock().expect("idempotency store poisoned");
+
+        if let Some(entry) = entries.get(key) {
+            let expired = entry.expires_at <= now;
+            let abandoned = entry.response.is_none() && entry.locked_at + self.lock_timeout <= now;
+
+            if !expired && !abandoned {
+                return match &entry.response {
+                    Some(response) => IdempotencyClaim::Completed {
+                        fingerprint: entry.fingerprint.clone(),
+                        response: response.clone(),
+                    },
+                    None => IdempotencyClaim::InProgress {
+                        fingerprint: entry.fingerprint.clone(),
+                    },
+                };
+            }
+        }
+
+        entries.insert(
+            key.to_string(),
+            Entry {
+                fingerprint: fingerprint.to_string(),
+                response: None,
+                locked_at: now,
+                expires_at: now + ttl,
+            },
+        );
+        IdempotencyClaim::Claimed
+    }
+}
+
+#[async_trait]
+impl IdempotencyStore for MemoryIdempotencyStore {
+    async fn claim(
+        &self,
+        key: &str,
+        fingerprint: &str,
+        ttl: Duration,
+    ) -> Result<IdempotencyClaim, String> {
+        Ok(self.claim_at(key, fingerprint, ttl, Instant::now()))
+    }
+
+    async fn complete(&self, key: &str, response: &StoredResponse) -> Result<(), String> {
+        let mut entries = self.entries.lock().expect("idempotency store poisoned");
+        match entries.get_mut(key) {
+            Some(entry) => {
+                entry.response = Some(response.clone());
+                Ok(())
+            }
+            None => Err(format!("Idempotency key '{}' is not claimed", key)),
+        }
+    }
+
+    async fn release(&self, key: &str) -> Result<(), String> {
+        let mut entries = self.entries.lock().expect("idempotency store poisoned");
+        if entries
+            .get(key)
+            .is_some_and(|entry| entry.response.is_none())
+        {
+            entries.remove(key);
+        }
+        Ok(())
+    }
+
+    async fn purge_expired(&self) -> Result<u64, String> {
+        let now = Instant::now();
+        let mut entries = self.entries.lock().expect("idempotency store poisoned");
+        let before = entries.len();
+        entries.retain(|_, entry| entry.expires_at > now);
+        Ok((before - entries.len()) as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TTL: Duration = Duration::from_secs(3600);
+
+    fn response() -> StoredResponse {
+        StoredResponse {
+            status: 201,
+            headers: vec![("content-type".to_string(), "application/json".to_string())],
+            body: b"{}".to_vec(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_claim_complete_and_replay() {
+        let store = MemoryIdempotencyStore::new(Duration::from_secs(60));
+
+        assert_eq!(
+            store.claim("k", "fp", TTL).await.unwrap(),
+            IdempotencyClaim::Claimed
+        );
+        assert_eq!(
+            store.claim("k", "fp", TTL).await.unwrap(),
+            IdempotencyClaim::InProgress {
+                fingerprint: "fp".to_string()
+            }
+        );
+
+        store.complete("k", &response()).await.unwrap();
+        assert_eq!(
+            store.claim("k", "other", TTL).await.unwrap(),
+            IdempotencyClaim::Completed {
+                fingerprint: "fp".to_string(),
+                response: response(),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_release_frees_key() {
+        let store = MemoryIdempotencyStore::new(Duration::from_secs(60));
+
+        store.claim("k", "fp", TTL).await.unwrap();
+        store.release("k").await.unwrap();
+        assert_eq!(
+            store.claim("k", "fp", TTL).await.unwrap(),
+            IdempotencyClaim::Claimed
+        );
+    }
+
+    #[test]
+    fn test_abandoned_and_expired_keys_can_be_reclaimed() {
+        let store = MemoryIdempotencyStore::new(Duration::from_secs(60));
+        let now = Instant::now();
+
+        store.claim_at("k", "fp", TTL, now);
+        assert_eq!(
+            store.claim_at("k", "fp", TTL, now + Duration::from_secs(61)),
+            IdempotencyClaim::Claimed
+        );
+
+        store.claim_at("short", "fp", Duration::from_secs(1), now);
+        store
+            .entries
+            .lock()
+            .unwrap()
+            .get_mut("short")
+            .unwrap()
+            .response = Some(response());
+        assert_eq!(
+            store.claim_at("short", "fp", TTL, now + Duration::from_secs(2)),
+            IdempotencyClaim::Claimed
+        );
+    }
+}
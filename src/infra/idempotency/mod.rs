@@ -0,0 +1,39 @@
+//! Idempotency key stores
+//!
+//! Implementations of the `IdempotencyStore` port, and the background
+//! worker that purges expired keys.
+
+pub mod memory_idempotency_store;
+pub mod sea_orm_idempotency_store;
+
+pub use memory_idempotency_store::MemoryIdempotencyStore;
+pub use sea_orm_idempotency_store::SeaOrmIdempotencyStore;
+
+use crate::app::ports::IdempotencyStore;
+use crate::infra::lifecycle::Lifecycle;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Periodically remove expired idempotency keys until shutdown
+pub fn spawn_sweeper(store: Arc<dyn IdempotencyStore>, every: Duration, lifecycle: &Lifecycle) {
+    let shutdown = lifecycle.shutdown_token();
+
+    let handle = tokio::spawn(async move {
+        let mut interval = tokio::time::interval(every);
+
+        loop {
+            tokio::select! {
+                _ = shutdown.cancelled() => break,
+                _ = interval.tick() => {}
+            }
+
+            match store.purge_expired().await {
+                Ok(0) => {}
+                Ok(purged) => tracing::debug!(purged, "Expired idempotency keys purged"),
+                Err(e) => tracing::warn!(error = %e, "Failed to purge idempotency keys"),
+            }
+        }
+    });
+
+    lifecycle.register_worker("idempotency_sweeper", handle);
+}
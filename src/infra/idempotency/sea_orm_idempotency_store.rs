@@ -0,0 +1,212 @@
+use crate::app::ports::{IdempotencyClaim, IdempotencyStore, StoredResponse};
+use crate::infra::persistence::entities::idempotency_keys::{
+    self, Entity as IdempotencyKeysEntity,
+};
+use async_trait::async_trait;
+use sea_orm::sea_query::Expr;
+use sea_orm::{ColumnTrait, ConnectionTrait, DbBackend, EntityTrait, QueryFilter, Statement};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Insert the key, or take over one that expired or whose request was abandoned
+const CLAIM_SQL: &str = r#"
+INSERT INTO idempotency_keys (key, fingerprint, locked_at, expires_at)
+VALUES ($1, $2, now(), now() + make_interval(secs => $3))
+ON CONFLICT (key) DO UPDATE SET
+    fingerprint = EXCLUDED.fingerprint,
+    response_status = NULL,
+    response_headers = NULL,
+    response_body = NULL,
+    locked_at = EXCLUDED.locked_at,
+    expires_at = EXCLUDED.expires_at
+WHERE idempotency_keys.expires_at <= now()
+   OR (idempotency_keys.response_status IS NULL
+       AND idempotency_keys.locked_at <= now() - make_interval(secs => $4))
+RETURNING key
+"#;
+
+/// SeaORM implementation of IdempotencyStore
+///
+/// Keys are shared by every instance using the same database, so a retry
+/// landing on another instance still sees the original response.
+pub struct SeaOrmIdempotencyStore {
+    db: Arc<sea_orm::DatabaseConnection>,
+    lock_timeout: Duration,
+}
+
+impl SeaOrmIdempotencyStore {
+    pub fn new(db: Arc<sea_orm::DatabaseConnection>, lock_timeout: Duration) -> Self {
+        Self { db, lock_timeout }
+    }
+
+    async fn try_claim(
+        &self,
+        key: &str,
+        fingerprint: &str,
+        ttl: Duration,
+    ) -> Result<Option<IdempotencyClaim>, String> {
+        let claimed = self
+            .db
+            .query_one(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                CLAIM_SQL,
+                [
+                    key.into(),
+                    fingerprint.into(),
+                    ttl.as_secs_f64().into(),
+                    self.lock_timeout.as_secs_f64().into(),
+                ],
+            ))
+            .await
+            .map_err(|e| e.to_string())?;
+
+        if claimed.is_some() {
+            return Ok(Some(IdempotencyClaim::Claimed));
+        }
+
+        let existing = IdempotencyKeysEntity::find_by_id(key)
+            .one(self.db.as_ref())
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(existing.map(|model| match model.response_status {
+            Some(status) => IdempotencyClaim::Completed {
+                fingerprint: model.fingerprint,
+                response: StoredResponse {
+                    status: status as u16,
+                    headers: model
+                        .response_headers
+                        .and_then(|headers| serde_json::from_value(headers).ok())
+                        .unwrap_or_default(),
+                    body: model.response_body.unwrap_or_default(),
+                },
+            },
+            None => IdempotencyClaim::InProgress {
+                fingerprint: model.fingerprint,
+            },
+        }))
+    }
+}
+
+#[async_trait]
+impl IdempotencyStore for SeaOrmIdempotencyStore {
+    async fn claim(
+        &self,
+        key: &str,
+        fingerprint: &str,
+        ttl: Duration,
+    ) -> Result<IdempotencyClaim, String> {
+        // The row can vanish between the insert and the lookup (released or
+        // purged concurrently); claiming again resolves that race
+        for _ in 0..2 {
+            if let Some(claim) = self.try_claim(key, fingerprint, ttl).await? {
+                return Ok(claim);
+            }
+        }
+        Err(format!("Idempotency key '{}' could not be claimed", key))
+    }
+
+    async fn complete(&self, key: &str, response: &StoredResponse) -> Result<(), String> {
+        let headers = serde_json::to_value(&response.headers).map_err(|e| e.to_string())?;
+
+        IdempotencyKeysEntity::update_many()
+            .col_expr(
+                idempotency_keys::Column::ResponseStatus,
+                Expr::value(response.status as i32),
+            )
+            .col_expr(
+                idempotency_keys::Column::ResponseHeaders,
+                Expr::value(headers),
+            )
+            .col_expr(
+                idempotency_keys::Column::ResponseBody,
+                Expr::value(response.body.clone()),
+            )
+            .filter(idempotency_keys::Column::Key.eq(key))
+            .exec(self.db.as_ref())
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+
+    async fn release(&self, key: &str) -> Result<(), String> {
+        IdempotencyKeysEntity::delete_many()
+            .filter(idempotency_keys::Column::Key.eq(key))
+            .filter(idempotency_keys::Column::ResponseStatus.is_null())
+            .exec(self.db.as_ref())
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+
+    async fn purge_expired(&self) -> Result<u64, String> {
+        let result = IdempotencyKeysEntity::delete_many()
+            .filter(Expr::col(idempotency_keys::Column::ExpiresAt).lte(Expr::current_timestamp()))
+            .exec(self.db.as_ref())
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(result.rows_affected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TTL: Duration = Duration::from_secs(3600);
+
+    async fn store() -> SeaOrmIdempotencyStore {
+        let db = crate::infra::config::database::connect().await.unwrap();
+        SeaOrmIdempotencyStore::new(Arc::new(db), Duration::from_secs(60))
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated database (docker-compose up postgresql)"]
+    async fn test_claim_complete_release() {
+        let store = store().await;
+        let key = format!("test:{}", uuid::Uuid::new_v4());
+
+        assert_eq!(
+            store.claim(&key, "fp", TTL).await.unwrap(),
+            IdempotencyClaim::Claimed
+        );
+        assert_eq!(
+            store.claim(&key, "fp", TTL).await.unwrap(),
+            IdempotencyClaim::InProgress {
+                fingerprint: "fp".to_string()
+            }
+        );
+
+        let response = StoredResponse {
+            status: 201,
+            headers: vec![("content-type".to_string(), "application/json".to_string())],
+            body: b"{\"data\":{}}".to_vec(),
+        };
+        store.complete(&key, &response).await.unwrap();
+        assert_eq!(
+            store.claim(&key, "fp", TTL).await.unwrap(),
+            IdempotencyClaim::Completed {
+                fingerprint: "fp".to_string(),
+                response,
+            }
+        );
+
+        // Completed keys are kept until they expire
+        store.release(&key).await.unwrap();
+        assert!(matches!(
+            store.claim(&key, "fp", TTL).await.unwrap(),
+            IdempotencyClaim::Completed { .. }
+        ));
+
+        let expiring = format!("test:{}", uuid::Uuid::new_v4());
+        store.claim(&expiring, "fp", Duration::ZERO).await.unwrap();
+        assert!(store.purge_expired().await.unwrap() >= 1);
+        assert_eq!(
+            store.claim(&expiring, "fp", TTL).await.unwrap(),
+            IdempotencyClaim::Claimed
+        );
+    }
+}
@@ -0,0 +1,278 @@
+use super::sea_orm_job_queue::to_job;
+use crate::app::jobs::JobRegistry;
+use crate::app::ports::{JobStatus, QueuedJob};
+use crate::infra::config::app_config::Jobs;
+use crate::infra::persistence::entities::jobs::{self, Entity as JobsEntity};
+use crate::infra::retry::{backoff, truncate_error};
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DbBackend, EntityTrait, QueryFilter, Set, Statement, Value,
+};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Longest error text kept on a job
+const MAX_ERROR_LEN: usize = 500;
+
+/// Lease the oldest due job of the registered kinds; `{kinds}` is replaced
+/// by one placeholder per kind. Running jobs whose lease expired belonged
+/// to a worker that died and are claimed again.
+const CLAIM_SQL: &str = r#"
+UPDATE jobs
+SET status = 'running', attempts = attempts + 1, locked_until = now() + make_interval(secs => $1)
+WHERE id = (
+    SELECT id FROM jobs
+    WHERE kind IN ({kinds})
+      AND ((status = 'pending' AND run_at <= now())
+        OR (status = 'running' AND locked_until < now()))
+    ORDER BY run_at, id
+    LIMIT 1
+    FOR UPDATE SKIP LOCKED
+)
+RETURNING *
+"#;
+
+/// Runs queued jobs through their registered handlers
+///
+/// A job is completed when its handler returns `Ok`. Errors, panics and
+/// timeouts are retried with exponential backoff until the job's
+/// `max_attempts`, after which it is marked failed and waits for a manual
+/// retry.
+pub struct JobWorker {
+    db: Arc<sea_orm::DatabaseConnection>,
+    registry: Arc<JobRegistry>,
+    claim_sql: String,
+    config: Jobs,
+}
+
+impl JobWorker {
+    pub fn new(
+        db: Arc<sea_orm::DatabaseConnection>,
+        registry: Arc<JobRegistry>,
+        config: Jobs,
+    ) -> Self {
+        let placeholders: Vec<String> = (0..registry.kinds().len())
+            .map(|i| format!("${}", i + 2))
+            .collect();
+        let claim_sql = CLAIM_SQL.replace("{kinds}", &placeholders.join(", "));
+
+        Self {
+            db,
+            registry,
+            claim_sql,
+            config,
+        }
+    }
+
+    pub fn config(&self) -> &Jobs {
+        &self.config
+    }
+
+    /// Claim and run one due job; `false` when there was none
+    pub async fn run_next(&self) -> Result<bool, String> {
+        let kinds = self.registry.kinds();
+        if kinds.is_empty() {
+            return Ok(false);
+        }
+
+        // Keep the lease a little longer than the job may run
+        let lease = self.config.timeout + Duration::from_secs(30);
+        let mut values: Vec<Value> = vec![lease.as_secs_f64().into()];
+        values.extend(kinds.into_iter().map(Value::from));
+
+        let Some(model) = JobsEntity::find()
+            .from_raw_sql(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                &self.claim_sql,
+                values,
+            ))
+            .one(self.db.as_ref())
+            .await
+            .map_err(|e| e.to_string())?
+        else {
+            return Ok(false);
+        };
+
+        let job = to_job(model.clone()).map_err(|e| e.to_string())?;
+        let result = self.run(&job).await;
+        self.record(model, result).await?;
+        Ok(true)
+    }
+
+    /// Run the handler in its own task so a panic or timeout fails only the job
+    async fn run(&self, job: &QueuedJob) -> Result<(), String> {
+        let registry = self.registry.clone();
+        let kind = job.kind.clone();
+        let payload = job.payload.clone();
+        let mut task = tokio::spawn(async move { registry.run(&kind, payload).await });
+
+        match tokio::time::timeout(self.config.timeout, &mut task).await {
+            Ok(Ok(result)) => result,
+            Ok(Err(e)) => Err(format!("Job panicked: {}", e)),
+            Err(_) => {
+                task.abort();
+                Err(format!("Job timed out after {:?}", self.config.timeout))
+            }
+        }
+    }
+
+    async fn record(&self, model: jobs::Model, result: Result<(), String>) -> Result<(), String> {
+        let (id, kind, attempts, max_attempts) = (
+            model.id,
+            model.kind.clone(),
+            model.attempts,
+            model.max_attempts,
+        );
+        let mut update: jobs::ActiveModel = model.into();
+        update.locked_until = Set(None);
+
+        match result {
+            Ok(()) => {
+                update.status = Set(JobStatus::Completed.as_str().to_string());
+                update.last_error = Set(None);
+                update.finished_at = Set(Some(Utc::now().into()));
+            }
+            Err(mut error) => {
+                truncate_error(&mut error, MAX_ERROR_LEN);
+                if attempts >= max_attempts {
+                    tracing::warn!(id, kind, attempts, error = %error, "Job failed");
+                    update.status = Set(JobStatus::Failed.as_str().to_string());
+                    update.finished_at = Set(Some(Utc::now().into()));
+                } else {
+                    let delay = backoff(
+                        attempts as u32,
+                        self.config.retry_base,
+                        self.config.retry_max,
+                    );
+                    tracing::debug!(id, kind, attempts, error = %error, "Job will be retried");
+                    update.status = Set(JobStatus::Pending.as_str().to_string());
+                    update.run_at = Set((Utc::now() + delay).into());
+                }
+                update.last_error = Set(Some(error));
+            }
+        }
+
+        update
+            .update(self.db.as_ref())
+            .await
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+
+    /// Delete completed jobs older than the retention period
+    pub async fn purge_completed(&self) -> Result<u64, String> {
+        let cutoff = Utc::now() - self.config.retention;
+        let result = JobsEntity::delete_many()
+            .filter(jobs::Column::Status.eq(JobStatus::Completed.as_str()))
+            .filter(jobs::Column::FinishedAt.lt(cutoff))
+            .exec(self.db.as_ref())
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(result.rows_affected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::jobs::JobHandler;
+    use crate::app::ports::{Job, JobQueue};
+    use crate::infra::jobs::SeaOrmJobQueue;
+    use async_trait::async_trait;
+    use serde::{Deserialize, Serialize};
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[derive(Serialize, Deserialize)]
+    struct Flaky {
+        /// Runs that fail before one succeeds
+        failures: u32,
+    }
+
+    impl Job for Flaky {
+        const KIND: &'static str = "test.flaky";
+        const MAX_ATTEMPTS: u32 = 2;
+    }
+
+    #[derive(Default)]
+    struct FlakyHandler {
+        runs: AtomicU32,
+    }
+
+    #[async_trait]
+    impl JobHandler<Flaky> for FlakyHandler {
+        async fn handle(&self, job: Flaky) -> Result<(), String> {
+            let run = self.runs.fetch_add(1, Ordering::SeqCst);
+            if run < job.failures {
+                return Err(format!("run {} failed", run + 1));
+            }
+            Ok(())
+        }
+    }
+
+    fn config() -> Jobs {
+        Jobs {
+            workers: 1,
+            poll_interval: Duration::from_millis(100),
+            timeout: Duration::from_secs(5),
+            retry_base: Duration::ZERO,
+            retry_max: Duration::ZERO,
+            retention: Duration::from_secs(3600),
+        }
+    }
+
+    async fn drain(worker: &JobWorker) {
+        while worker.run_next().await.unwrap() {}
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated database (docker-compose up postgresql)"]
+    async fn test_retry_failure_and_manual_retry() {
+        let db = Arc::new(crate::infra::config::database::connect().await.unwrap());
+        let queue: Arc<dyn JobQueue> = Arc::new(SeaOrmJobQueue::new(db.clone()));
+
+        // Succeeds on the second attempt
+        let handler = Arc::new(FlakyHandler::default());
+        let registry = Arc::new(JobRegistry::new().register::<Flaky>(handler.clone()));
+        let worker = JobWorker::new(db.clone(), registry, config());
+        let id = queue.enqueue(&Flaky { failures: 1 }).await.unwrap();
+        drain(&worker).await;
+
+        let job = queue.find(id).await.unwrap().unwrap();
+        assert_eq!(job.status, JobStatus::Completed);
+        assert_eq!(job.attempts, 2);
+        assert!(job.last_error.is_none());
+
+        // Runs out of attempts, then succeeds after a manual retry
+        let handler = Arc::new(FlakyHandler::default());
+        let registry = Arc::new(JobRegistry::new().register::<Flaky>(handler.clone()));
+        let worker = JobWorker::new(db.clone(), registry, config());
+        let id = queue.enqueue(&Flaky { failures: 2 }).await.unwrap();
+        drain(&worker).await;
+
+        let job = queue.find(id).await.unwrap().unwrap();
+        assert_eq!(job.status, JobStatus::Failed);
+        assert_eq!(job.last_error.as_deref(), Some("run 2 failed"));
+        assert!(queue.retry(id).await.unwrap().is_some());
+        // Only failed jobs can be retried
+        assert!(queue.retry(id).await.unwrap().is_none());
+        drain(&worker).await;
+
+        let job = queue.find(id).await.unwrap().unwrap();
+        assert_eq!(job.status, JobStatus::Completed);
+        assert_eq!(job.attempts, 1);
+
+        // Scheduled jobs wait for their time
+        let later = Utc::now() + chrono::Duration::hours(1);
+        let id = queue.schedule(&Flaky { failures: 0 }, later).await.unwrap();
+        assert!(!worker.run_next().await.unwrap());
+        assert_eq!(
+            queue.find(id).await.unwrap().unwrap().status,
+            JobStatus::Pending
+        );
+        JobsEntity::delete_by_id(id)
+            .exec(db.as_ref())
+            .await
+            .unwrap();
+    }
+}
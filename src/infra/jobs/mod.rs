@@ -0,0 +1,71 @@
+//! Background job queue
+//!
+//! Jobs are rows of the `jobs` table. Each worker claims one due job at a
+//! time with `FOR UPDATE SKIP LOCKED`, so any number of workers, in this
+//! process or others, can share the queue without running a job twice.
+
+pub mod job_worker;
+pub mod sea_orm_job_queue;
+
+pub use job_worker::JobWorker;
+pub use sea_orm_job_queue::SeaOrmJobQueue;
+
+use crate::infra::lifecycle::Lifecycle;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// How often completed jobs past their retention are purged
+const PURGE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Start `config.workers` job workers and the purge of completed jobs
+pub fn spawn_workers(worker: Arc<JobWorker>, lifecycle: &Lifecycle) {
+    if worker.config().workers == 0 {
+        return;
+    }
+
+    for _ in 0..worker.config().workers {
+        let worker = worker.clone();
+        let shutdown = lifecycle.shutdown_token();
+
+        let handle = tokio::spawn(async move {
+            loop {
+                // Keep going while there is work; sleep once the queue is empty
+                let idle = match worker.run_next().await {
+                    Ok(ran) => !ran,
+                    Err(e) => {
+                        tracing::warn!(error = %e, "Job worker failed");
+                        true
+                    }
+                };
+
+                if !idle && !shutdown.is_cancelled() {
+                    continue;
+                }
+                tokio::select! {
+                    _ = shutdown.cancelled() => break,
+                    _ = tokio::time::sleep(worker.config().poll_interval) => {}
+                }
+            }
+        });
+
+        lifecycle.register_worker("job_worker", handle);
+    }
+
+    let shutdown = lifecycle.shutdown_token();
+    let handle = tokio::spawn(async move {
+        let mut interval = tokio::time::interval(PURGE_INTERVAL);
+
+        loop {
+            tokio::select! {
+                _ = shutdown.cancelled() => break,
+                _ = interval.tick() => {}
+            }
+
+            if let Err(e) = worker.purge_completed().await {
+                tracing::warn!(error = %e, "Failed to purge completed jobs");
+            }
+        }
+    });
+
+    lifecycle.register_worker("job_purge", handle);
+}
@@ -0,0 +1,121 @@
+use crate::app::ports::{JobQueue, JobQueueError, JobStatus, NewJob, QueuedJob};
+use crate::infra::persistence::entities::jobs::{self, Entity as JobsEntity};
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    QuerySelect, Set,
+};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// SeaORM implementation of JobQueue
+pub struct SeaOrmJobQueue {
+    db: Arc<sea_orm::DatabaseConnection>,
+}
+
+impl SeaOrmJobQueue {
+    pub fn new(db: Arc<sea_orm::DatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+/// Convert a SeaORM model to a queued job
+pub(super) fn to_job(model: jobs::Model) -> Result<QueuedJob, JobQueueError> {
+    let status = JobStatus::from_str(&model.status).map_err(JobQueueError::QueueFailure)?;
+
+    Ok(QueuedJob {
+        id: model.id,
+        kind: model.kind,
+        payload: model.payload,
+        status,
+        attempts: model.attempts,
+        max_attempts: model.max_attempts,
+        run_at: model.run_at.to_utc(),
+        last_error: model.last_error,
+        created_at: model.created_at.to_utc(),
+        finished_at: model.finished_at.map(|at| at.to_utc()),
+    })
+}
+
+#[async_trait]
+impl JobQueue for SeaOrmJobQueue {
+    async fn push(&self, job: NewJob) -> Result<i64, JobQueueError> {
+        let model = jobs::ActiveModel {
+            kind: Set(job.kind),
+            payload: Set(job.payload),
+            status: Set(JobStatus::Pending.as_str().to_string()),
+            max_attempts: Set(job.max_attempts as i32),
+            run_at: Set(job.run_at.into()),
+            created_at: Set(Utc::now().into()),
+            ..Default::default()
+        }
+        .insert(self.db.as_ref())
+        .await
+        .map_err(|e| JobQueueError::QueueFailure(e.to_string()))?;
+
+        Ok(model.id)
+    }
+
+    async fn find(&self, id: i64) -> Result<Option<QueuedJob>, JobQueueError> {
+        JobsEntity::find_by_id(id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(|e| JobQueueError::QueueFailure(e.to_string()))?
+            .map(to_job)
+            .transpose()
+    }
+
+    async fn list(
+        &self,
+        status: Option<JobStatus>,
+        page: u64,
+        rows_per_page: u64,
+    ) -> Result<(Vec<QueuedJob>, u64), JobQueueError> {
+        let mut query = JobsEntity::find();
+        if let Some(status) = status {
+            query = query.filter(jobs::Column::Status.eq(status.as_str()));
+        }
+
+        let total = query
+            .clone()
+            .count(self.db.as_ref())
+            .await
+            .map_err(|e| JobQueueError::QueueFailure(e.to_string()))?;
+
+        let jobs = query
+            .order_by_desc(jobs::Column::Id)
+            .offset(page.saturating_sub(1) * rows_per_page)
+            .limit(rows_per_page)
+            .all(self.db.as_ref())
+            .await
+            .map_err(|e| JobQueueError::QueueFailure(e.to_string()))?
+            .into_iter()
+            .map(to_job)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok((jobs, total))
+    }
+
+    async fn retry(&self, id: i64) -> Result<Option<QueuedJob>, JobQueueError> {
+        let retried = JobsEntity::update_many()
+            .col_expr(
+                jobs::Column::Status,
+                Expr::value(JobStatus::Pending.as_str()),
+            )
+            .col_expr(jobs::Column::Attempts, Expr::value(0))
+            .col_expr(jobs::Column::RunAt, Expr::current_timestamp().into())
+            .col_expr(
+                jobs::Column::FinishedAt,
+                Expr::value(Option::<chrono::DateTime<Utc>>::None),
+            )
+            .filter(jobs::Column::Id.eq(id))
+            .filter(jobs::Column::Status.eq(JobStatus::Failed.as_str()))
+            .exec_with_returning(self.db.as_ref())
+            .await
+            .map_err(|e| JobQueueError::QueueFailure(e.to_string()))?;
+
+        retried.into_iter().next().map(to_job).transpose()
+    }
+}
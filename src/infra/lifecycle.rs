@@ -0,0 +1,164 @@
+//! Process lifecycle and graceful shutdown
+//!
+//! Tracks whether the server is draining, owns the handles of background
+//! workers, and closes the database pool once everything else has stopped.
+//! Shutdown happens in two phases:
+//!
+//! 1. **Drain** - triggered by SIGTERM/SIGINT. Readiness reports down and the
+//!    server keeps accepting requests for the pre-drain delay, long enough for
+//!    the load balancer to notice and stop routing new traffic. It then stops
+//!    accepting connections and lets in-flight HTTP requests finish (bounded
+//!    by the drain timeout).
+//! 2. **Close** - background workers are told to stop and awaited, then the
+//!    SeaORM pool is closed.
+
+use sea_orm::DatabaseConnection;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+
+/// Shared lifecycle state for the running process
+pub struct Lifecycle {
+    draining: CancellationToken,
+    shutdown: CancellationToken,
+    workers: Mutex<Vec<(&'static str, JoinHandle<()>)>>,
+    db: Arc<DatabaseConnection>,
+}
+
+impl Lifecycle {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self {
+            draining: CancellationToken::new(),
+            shutdown: CancellationToken::new(),
+            workers: Mutex::new(Vec::new()),
+            db,
+        }
+    }
+
+    /// Mark the process as draining; readiness reports down from now on
+    pub fn begin_drain(&self) {
+        if !self.draining.is_cancelled() {
+            tracing::info!("Draining: readiness now reports unavailable");
+        }
+        self.draining.cancel();
+    }
+
+    /// Whether the process is shutting down
+    pub fn is_draining(&self) -> bool {
+        self.draining.is_cancelled()
+    }
+
+    /// Resolves once draining has started
+    pub async fn draining(&self) {
+        self.draining.cancelled().await
+    }
+
+    /// Token cancelled when background workers must stop
+    pub fn shutdown_token(&self) -> CancellationToken {
+        self.shutdown.clone()
+    }
+
+    /// Register a background worker to be awaited during shutdown
+    ///
+    /// Workers are expected to watch [`Lifecycle::shutdown_token`] and return
+    /// once it is cancelled.
+    pub fn register_worker(&self, name: &'static str, handle: JoinHandle<()>) {
+        self.workers
+            .lock()
+            .expect("worker registry poisoned")
+            .push((name, handle));
+    }
+
+    /// Stop background workers and close the database pool
+    ///
+    /// All workers share one `timeout`; those still running when it elapses
+    /// are aborted.
+    pub async fn close(&self, timeout: Duration) {
+        self.begin_drain();
+        self.shutdown.cancel();
+
+        let deadline = tokio::time::Instant::now() + timeout;
+        let workers = std::mem::take(&mut *self.workers.lock().expect("worker registry poisoned"));
+        for (name, mut handle) in workers {
+            match tokio::time::timeout_at(deadline, &mut handle).await {
+                Ok(Ok(())) => tracing::info!(worker = name, "Background worker stopped"),
+                Ok(Err(e)) => {
+                    tracing::error!(worker = name, error = %e, "Background worker failed")
+                }
+                Err(_) => {
+                    tracing::warn!(worker = name, "Background worker did not stop in time");
+                    handle.abort();
+                }
+            }
+        }
+
+        match self.db.close_by_ref().await {
+            Ok(()) => tracing::info!("Database pool closed"),
+            Err(e) => tracing::error!(error = %e, "Failed to close database pool"),
+        }
+    }
+}
+
+/// Resolves on SIGINT (Ctrl+C) or, on Unix, SIGTERM
+pub async fn shutdown_signal() {
+    let ctrl_c = async {
+        tokio::signal::ctrl_c()
+            .await
+            .expect("Failed to install Ctrl+C handler");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("Failed to install SIGTERM handler")
+            .recv()
+            .await;
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => tracing::info!("Received SIGINT"),
+        _ = terminate => tracing::info!("Received SIGTERM"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_close_stops_workers_and_drains() {
+        let lifecycle = Lifecycle::new(Arc::new(DatabaseConnection::Disconnected));
+        let token = lifecycle.shutdown_token();
+        lifecycle.register_worker("test", tokio::spawn(async move { token.cancelled().await }));
+
+        assert!(!lifecycle.is_draining());
+        lifecycle.close(Duration::from_secs(1)).await;
+        assert!(lifecycle.is_draining());
+        assert!(lifecycle.workers.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_close_aborts_stuck_workers() {
+        let lifecycle = Lifecycle::new(Arc::new(DatabaseConnection::Disconnected));
+        lifecycle.register_worker("stuck", tokio::spawn(std::future::pending::<()>()));
+
+        lifecycle.close(Duration::from_millis(20)).await;
+        assert!(lifecycle.workers.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_close_bounds_the_total_wait() {
+        let lifecycle = Lifecycle::new(Arc::new(DatabaseConnection::Disconnected));
+        for name in ["first", "second", "third"] {
+            lifecycle.register_worker(name, tokio::spawn(std::future::pending::<()>()));
+        }
+
+        let started = std::time::Instant::now();
+        lifecycle.close(Duration::from_millis(100)).await;
+        assert!(started.elapsed() < Duration::from_millis(200));
+    }
+}
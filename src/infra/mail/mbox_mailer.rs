@@ -0,0 +1,92 @@
+use super::{address, format_message};
+use crate::app::ports::{MailMessage, Mailer};
+use async_trait::async_trait;
+use chrono::Utc;
+use std::path::Path;
+use tokio::fs::File;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+
+/// Appends each message to an mbox file, readable by most mail clients
+pub struct MboxMailer {
+    from: String,
+    file: Mutex<File>,
+}
+
+impl MboxMailer {
+    /// Append to `path`, creating the file if needed
+    pub async fn open(path: &Path, from: &str) -> std::io::Result<Self> {
+        let file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .await?;
+
+        Ok(Self {
+            from: from.to_string(),
+            file: Mutex::new(file),
+        })
+    }
+}
+
+#[async_trait]
+impl Mailer for MboxMailer {
+    async fn send(&self, message: &MailMessage) -> Result<(), String> {
+        let formatted = format_message(&self.from, message)?;
+
+        // mboxrd: a separator line per message, body lines starting with
+        // "From " (after any '>') get one more '>'
+        let mut entry = format!(
+            "From {} {}\n",
+            address(&self.from),
+            Utc::now().format("%a %b %e %H:%M:%S %Y")
+        );
+        for line in formatted.split("\r\n") {
+            if line.trim_start_matches('>').starts_with("From ") {
+                entry.push('>');
+            }
+            entry.push_str(line);
+            entry.push('\n');
+        }
+
+        let mut file = self.file.lock().await;
+        file.write_all(entry.as_bytes())
+            .await
+            .map_err(|e| e.to_string())?;
+        file.flush().await.map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_appends_mbox_entries() {
+        let path = std::env::temp_dir().join(format!("mail-{}.mbox", uuid::Uuid::new_v4()));
+        let mailer = MboxMailer::open(&path, "Team <team@example.com>")
+            .await
+            .unwrap();
+
+        for to in ["ann@example.com", "bob@example.com"] {
+            let message = MailMessage {
+                to: to.to_string(),
+                subject: "Hello".to_string(),
+                html: "<p>Hello</p>".to_string(),
+                text: "Hello".to_string(),
+            };
+            mailer.send(&message).await.unwrap();
+        }
+
+        let contents = tokio::fs::read_to_string(&path).await.unwrap();
+        tokio::fs::remove_file(&path).await.unwrap();
+        let separators: Vec<_> = contents
+            .lines()
+            .filter(|line| line.starts_with("From "))
+            .collect();
+        assert_eq!(separators.len(), 2);
+        assert!(separators[0].starts_with("From team@example.com "));
+        assert!(contents.contains("\nTo: bob@example.com\n"));
+        assert!(!contents.contains('\r'));
+    }
+}
@@ -0,0 +1,30 @@
+use crate::app::ports::{MailMessage, Mailer};
+use async_trait::async_trait;
+use std::sync::Mutex;
+
+/// In-memory implementation of Mailer
+///
+/// Keeps every message instead of sending it, for tests to inspect.
+#[derive(Default)]
+pub struct MemoryMailer {
+    sent: Mutex<Vec<MailMessage>>,
+}
+
+impl MemoryMailer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Messages sent so far, oldest first
+    pub fn sent(&self) -> Vec<MailMessage> {
+        self.sent.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl Mailer for MemoryMailer {
+    async fn send(&self, message: &MailMessage) -> Result<(), String> {
+        self.sent.lock().unwrap().push(message.clone());
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+//! Outbound email
+//!
+//! `Mailer` implementations: SMTP for real delivery (or a local sink such as
+//! Mailpit), an mbox file for development and an in-memory capture for
+//! tests. Templates are read from `<dir>/<name>/<locale>.{subject,html,txt}`.
+
+pub mod mbox_mailer;
+pub mod memory_mailer;
+pub mod smtp_mailer;
+pub mod template_loader;
+
+pub use mbox_mailer::MboxMailer;
+pub use memory_mailer::MemoryMailer;
+pub use smtp_mailer::SmtpMailer;
+pub use template_loader::load_templates;
+
+use crate::app::ports::MailMessage;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use chrono::Utc;
+
+/// Bare address of a mailbox such as `Team <team@example.com>`
+pub fn address(mailbox: &str) -> &str {
+    match (mailbox.rfind('<'), mailbox.rfind('>')) {
+        (Some(start), Some(end)) if start < end => &mailbox[start + 1..end],
+        _ => mailbox.trim(),
+    }
+}
+
+/// RFC 5322 message with text and HTML alternatives, lines ending in CRLF
+pub fn format_message(from: &str, message: &MailMessage) -> Result<String, String> {
+    for (header, value) in [
+        ("From", from),
+        ("To", &message.to),
+        ("Subject", &message.subject),
+    ] {
+        if value.contains(['\r', '\n']) {
+            return Err(format!("{} header contains a line break", header));
+        }
+    }
+
+    let id = uuid::Uuid::new_v4().simple();
+    let domain = address(from)
+        .rsplit_once('@')
+        .map_or("localhost", |(_, d)| d);
+    let boundary = format!("=_{}", id);
+
+    let mut out = String::new();
+    out.push_str(&format!("From: {}\r\n", from));
+    out.push_str(&format!("To: {}\r\n", message.to));
+    out.push_str(&format!("Subject: {}\r\n", encode_header(&message.subject)));
+    out.push_str(&format!("Date: {}\r\n", Utc::now().to_rfc2822()));
+    out.push_str(&format!("Message-ID: <{}@{}>\r\n", id, domain));
+    out.push_str("MIME-Version: 1.0\r\n");
+    out.push_str(&format!(
+        "Content-Type: multipart/alternative; boundary=\"{}\"\r\n\r\n",
+        boundary
+    ));
+    for (content_type, body) in [("text/plain", &message.text), ("text/html", &message.html)] {
+        out.push_str(&format!("--{}\r\n", boundary));
+        out.push_str(&format!(
+            "Content-Type: {}; charset=utf-8\r\n",
+            content_type
+        ));
+        out.push_str("Content-Transfer-Encoding: base64\r\n\r\n");
+        out.push_str(&encode_body(body));
+    }
+    out.push_str(&format!("--{}--\r\n", boundary));
+
+    Ok(out)
+}
+
+/// RFC 2047 encoded-word for non-ASCII header values
+fn encode_header(value: &str) -> String {
+    if value.is_ascii() {
+        value.to_string()
+    } else {
+        format!("=?utf-8?B?{}?=", STANDARD.encode(value))
+    }
+}
+
+/// Base64 body wrapped at 76 characters
+fn encode_body(body: &str) -> String {
+    let encoded = STANDARD.encode(body);
+    let mut out = String::with_capacity(encoded.len() + encoded.len() / 38 + 2);
+    for line in encoded.as_bytes().chunks(76) {
+        // Base64 output is ASCII
+        out.push_str(std::str::from_utf8(line).unwrap());
+        out.push_str("\r\n");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_message() {
+        let message = MailMessage {
+            to: "ann@example.com".to_string(),
+            subject: "Olá".to_string(),
+            html: "<p>Hi</p>".to_string(),
+            text: "Hi".to_string(),
+        };
+
+        let formatted = format_message("Team <team@example.com>", &message).unwrap();
+        assert!(formatted.starts_with("From: Team <team@example.com>\r\nTo: ann@example.com\r\n"));
+        assert!(formatted.contains("Subject: =?utf-8?B?T2zDoQ==?=\r\n"));
+        assert!(formatted.contains("@example.com>\r\n"));
+        // "Hi" and "<p>Hi</p>"
+        assert!(formatted.contains("\r\n\r\nSGk=\r\n"));
+        assert!(formatted.contains("\r\n\r\nPHA+SGk8L3A+\r\n"));
+
+        let injected = MailMessage {
+            subject: "Hi\r\nBcc: eve@example.com".to_string(),
+            ..message
+        };
+        assert_eq!(
+            format_message("team@example.com", &injected).unwrap_err(),
+            "Subject header contains a line break"
+        );
+        assert_eq!(address("Team <team@example.com>"), "team@example.com");
+        assert_eq!(address("team@example.com"), "team@example.com");
+    }
+}
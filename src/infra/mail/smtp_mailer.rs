@@ -0,0 +1,399 @@
+use super::{address, format_message};
+use crate::app::ports::{MailMessage, Mailer};
+use crate::infra::config::app_config::{Smtp, SmtpTls};
+use async_trait::async_trait;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use rustls::crypto::ring;
+use rustls::{ClientConfig, RootCertStore};
+use rustls_pki_types::ServerName;
+use std::sync::Arc;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufStream};
+use tokio::net::TcpStream;
+use tokio_rustls::TlsConnector;
+
+/// Byte stream of an SMTP session, before or after the TLS handshake
+trait Stream: AsyncRead + AsyncWrite + Unpin + Send {}
+
+impl<S: AsyncRead + AsyncWrite + Unpin + Send> Stream for S {}
+
+/// Sends each message in its own SMTP session
+///
+/// Any rejection fails the send, so a job queued through `SendMail` is
+/// retried; the message is only accepted once the server has replied `250`
+/// to the end of `DATA`.
+pub struct SmtpMailer {
+    config: Smtp,
+    from: String,
+    tls: TlsConnector,
+}
+
+impl SmtpMailer {
+    pub fn new(config: Smtp, from: &str) -> Self {
+        let roots = RootCertStore {
+            roots: webpki_roots::TLS_SERVER_ROOTS.to_vec(),
+        };
+        Self::with_roots(config, from, roots)
+    }
+
+    /// A mailer trusting only the given certificate authorities
+    fn with_roots(config: Smtp, from: &str, roots: RootCertStore) -> Self {
+        let tls = ClientConfig::builder_with_provider(Arc::new(ring::default_provider()))
+            .with_safe_default_protocol_versions()
+            .expect("ring supports the default TLS versions")
+            .with_root_certificates(roots)
+            .with_no_client_auth();
+
+        Self {
+            config,
+            from: from.to_string(),
+            tls: TlsConnector::from(Arc::new(tls)),
+        }
+    }
+
+    async fn deliver(&self, message: &MailMessage) -> Result<(), String> {
+        let data = format_message(&self.from, message)?;
+
+        let tcp = TcpStream::connect((self.config.host.as_str(), self.config.port))
+            .await
+            .map_err(|e| format!("Failed to connect to SMTP server: {}", e))?;
+        let stream: Box<dyn Stream> = match self.config.tls {
+            SmtpTls::Tls => Box::new(self.handshake(tcp).await?),
+            SmtpTls::None | SmtpTls::StartTls => Box::new(tcp),
+        };
+        let mut session = Session::new(stream);
+
+        session.reply(220).await?;
+        let hello = format!("EHLO {}", self.config.hello_name);
+        session.command(&hello, 250).await?;
+
+        if self.config.tls == SmtpTls::StartTls {
+            session.command("STARTTLS", 220).await?;
+            session = Session::new(Box::new(self.handshake(session.into_inner()).await?));
+            session.command(&hello, 250).await?;
+        }
+
+        if let Some(username) = &self.config.username {
+            let password = self.config.password.as_deref().unwrap_or_default();
+            let credentials = STANDARD.encode(format!("\0{}\0{}", username, password));
+            session
+                .command(&format!("AUTH PLAIN {}", credentials), 235)
+                .await?;
+        }
+
+        session
+            .command(&format!("MAIL FROM:<{}>", address(&self.from)), 250)
+            .await?;
+        session
+            .command(&format!("RCPT TO:<{}>", address(&message.to)), 250)
+            .await?;
+        session.command("DATA", 354).await?;
+        session.data(&data).await?;
+
+        // The message is accepted; a failed goodbye or close does not matter
+        let _ = session.command("QUIT", 221).await;
+        let _ = session.stream.shutdown().await;
+        Ok(())
+    }
+
+    async fn handshake<S: Stream + 'static>(
+        &self,
+        stream: S,
+    ) -> Result<tokio_rustls::client::TlsStream<S>, String> {
+        let name = ServerName::try_from(self.config.host.clone())
+            .map_err(|e| format!("Invalid SMTP host name: {}", e))?;
+        self.tls
+            .connect(name, stream)
+            .await
+            .map_err(|e| format!("SMTP TLS handshake failed: {}", e))
+    }
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+    async fn send(&self, message: &MailMessage) -> Result<(), String> {
+        tokio::time::timeout(self.config.timeout, self.deliver(message))
+            .await
+            .map_err(|_| format!("SMTP session timed out after {:?}", self.config.timeout))?
+    }
+}
+
+/// Command/reply exchange with an SMTP server
+struct Session {
+    stream: BufStream<Box<dyn Stream>>,
+}
+
+impl Session {
+    fn new(stream: Box<dyn Stream>) -> Self {
+        Self {
+            stream: BufStream::new(stream),
+        }
+    }
+
+    fn into_inner(self) -> Box<dyn Stream> {
+        self.stream.into_inner()
+    }
+
+    async fn command(&mut self, line: &str, expected: u16) -> Result<(), String> {
+        self.write(format!("{}\r\n", line).as_bytes()).await?;
+        self.reply(expected).await.map_err(|e| {
+            // Never log credentials
+            let verb = line.split(' ').next().unwrap_or_default();
+            format!("{} failed: {}", verb, e)
+        })
+    }
+
+    /// Send the message, dot-stuffed, and its terminating `.` line
+    async fn data(&mut self, data: &str) -> Result<(), String> {
+        let mut body = String::with_capacity(data.len() + 8);
+        for line in data.trim_end_matches("\r\n").split("\r\n") {
+            if line.starts_with('.') {
+                body.push('.');
+            }
+            body.push_str(line);
+            body.push_str("\r\n");
+        }
+        body.push_str(".\r\n");
+
+        self.write(body.as_bytes()).await?;
+        self.reply(250)
+            .await
+            .map_err(|e| format!("Message rejected: {}", e))
+    }
+
+    async fn write(&mut self, bytes: &[u8]) -> Result<(), String> {
+        self.stream
+            .write_all(bytes)
+            .await
+            .map_err(|e| e.to_string())?;
+        self.stream.flush().await.map_err(|e| e.to_string())
+    }
+
+    /// Read a possibly multi-line reply and check its code
+    async fn reply(&mut self, expected: u16) -> Result<(), String> {
+        let mut text = Vec::new();
+        loop {
+            let mut line = String::new();
+            if self
+                .stream
+                .read_line(&mut line)
+                .await
+                .map_err(|e| e.to_string())?
+                == 0
+            {
+                return Err("connection closed".to_string());
+            }
+            let line = line.trim_end();
+            let code = line
+                .get(..3)
+                .and_then(|code| code.parse::<u16>().ok())
+                .ok_or_else(|| format!("malformed reply: {}", line))?;
+            text.push(line.get(4..).unwrap_or_default().to_string());
+
+            // "250-" continues the reply, "250 " ends it
+            if line.as_bytes().get(3) != Some(&b'-') {
+                return if code == expected {
+                    Ok(())
+                } else {
+                    Err(format!("{} {}", code, text.join(" ")))
+                };
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustls::ServerConfig;
+    use rustls_pki_types::PrivateKeyDer;
+    use std::time::Duration;
+    use tokio::io::{AsyncBufRead, BufReader};
+    use tokio::net::TcpListener;
+    use tokio_rustls::TlsAcceptor;
+
+    /// How the sink answers
+    #[derive(Clone, Default)]
+    struct Script {
+        reject_rcpt: bool,
+        /// Offer STARTTLS, upgrading with this acceptor
+        starttls: Option<TlsAcceptor>,
+    }
+
+    /// Accept one SMTP session, recording the commands and message data
+    async fn sink(listener: TcpListener, script: Script) -> (Vec<String>, String) {
+        let (socket, _) = listener.accept().await.unwrap();
+        let mut socket = BufReader::new(socket);
+        let mut commands = Vec::new();
+        let mut data = String::new();
+
+        socket
+            .write_all(b"220-sink ESMTP\r\n220 ready\r\n")
+            .await
+            .unwrap();
+        let upgrade = converse(&mut socket, &script, &mut commands, &mut data).await;
+        if upgrade {
+            let acceptor = script.starttls.clone().unwrap();
+            let tls = acceptor.accept(socket.into_inner()).await.unwrap();
+            let script = Script {
+                starttls: None,
+                ..script
+            };
+            converse(&mut BufReader::new(tls), &script, &mut commands, &mut data).await;
+        }
+
+        (commands, data)
+    }
+
+    /// Answer commands until the client quits; `true` if it asked for STARTTLS
+    async fn converse<S: AsyncBufRead + AsyncWrite + Unpin>(
+        socket: &mut S,
+        script: &Script,
+        commands: &mut Vec<String>,
+        data: &mut String,
+    ) -> bool {
+        loop {
+            let mut line = String::new();
+            if socket.read_line(&mut line).await.unwrap() == 0 {
+                return false;
+            }
+            let line = line.trim_end().to_string();
+            let reply: &[u8] = match line.split(' ').next().unwrap() {
+                "EHLO" if script.starttls.is_some() => b"250-sink\r\n250 STARTTLS\r\n",
+                "EHLO" => b"250-sink\r\n250 AUTH PLAIN\r\n",
+                "STARTTLS" => {
+                    commands.push(line);
+                    socket.write_all(b"220 go ahead\r\n").await.unwrap();
+                    return true;
+                }
+                "AUTH" => b"235 ok\r\n",
+                "RCPT" if script.reject_rcpt => b"550-no such user\r\n550 mailbox unavailable\r\n",
+                "DATA" => {
+                    socket.write_all(b"354 go ahead\r\n").await.unwrap();
+                    loop {
+                        let mut line = String::new();
+                        socket.read_line(&mut line).await.unwrap();
+                        if line == ".\r\n" {
+                            break;
+                        }
+                        data.push_str(&line);
+                    }
+                    b"250 queued\r\n"
+                }
+                "QUIT" => b"221 bye\r\n",
+                _ => b"250 ok\r\n",
+            };
+            commands.push(line);
+            socket.write_all(reply).await.unwrap();
+        }
+    }
+
+    fn config(listener: &TcpListener, tls: SmtpTls) -> Smtp {
+        Smtp {
+            host: "localhost".to_string(),
+            port: listener.local_addr().unwrap().port(),
+            tls,
+            username: Some("user".to_string()),
+            password: Some("secret".to_string()),
+            hello_name: "test.local".to_string(),
+            timeout: Duration::from_secs(5),
+        }
+    }
+
+    fn mailer(listener: &TcpListener) -> SmtpMailer {
+        SmtpMailer::new(config(listener, SmtpTls::None), "Team <team@example.com>")
+    }
+
+    fn message() -> MailMessage {
+        MailMessage {
+            to: "Ann <ann@example.com>".to_string(),
+            subject: "Hello".to_string(),
+            html: "<p>Hello</p>".to_string(),
+            text: "Hello".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sends_message_to_smtp_sink() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let mailer = mailer(&listener);
+        let server = tokio::spawn(sink(listener, Script::default()));
+
+        mailer.send(&message()).await.unwrap();
+        let (commands, data) = server.await.unwrap();
+
+        assert_eq!(
+            commands,
+            vec![
+                "EHLO test.local",
+                "AUTH PLAIN AHVzZXIAc2VjcmV0",
+                "MAIL FROM:<team@example.com>",
+                "RCPT TO:<ann@example.com>",
+                "DATA",
+                "QUIT",
+            ]
+        );
+        assert!(data.starts_with("From: Team <team@example.com>\r\nTo: Ann <ann@example.com>\r\n"));
+        assert!(data.contains("Content-Type: multipart/alternative;"));
+    }
+
+    #[tokio::test]
+    async fn test_upgrades_with_starttls_before_authenticating() {
+        let cert = rcgen::generate_simple_self_signed(vec!["localhost".to_string()]).unwrap();
+        let server_config = ServerConfig::builder_with_provider(Arc::new(ring::default_provider()))
+            .with_safe_default_protocol_versions()
+            .unwrap()
+            .with_no_client_auth()
+            .with_single_cert(
+                vec![cert.cert.der().clone()],
+                PrivateKeyDer::Pkcs8(cert.key_pair.serialize_der().into()),
+            )
+            .unwrap();
+        let mut roots = RootCertStore::empty();
+        roots.add(cert.cert.der().clone()).unwrap();
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let mailer = SmtpMailer::with_roots(
+            config(&listener, SmtpTls::StartTls),
+            "Team <team@example.com>",
+            roots,
+        );
+        let script = Script {
+            starttls: Some(TlsAcceptor::from(Arc::new(server_config))),
+            ..Script::default()
+        };
+        let server = tokio::spawn(sink(listener, script));
+
+        mailer.send(&message()).await.unwrap();
+        let (commands, data) = server.await.unwrap();
+
+        // Credentials only cross the encrypted session, after a fresh EHLO
+        assert_eq!(
+            commands[..4],
+            [
+                "EHLO test.local",
+                "STARTTLS",
+                "EHLO test.local",
+                "AUTH PLAIN AHVzZXIAc2VjcmV0",
+            ]
+        );
+        assert_eq!(commands.last().unwrap(), "QUIT");
+        assert!(data.contains("Subject: Hello\r\n"));
+    }
+
+    #[tokio::test]
+    async fn test_rejected_recipient_fails_send() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let mailer = mailer(&listener);
+        let script = Script {
+            reject_rcpt: true,
+            ..Script::default()
+        };
+        let server = tokio::spawn(sink(listener, script));
+
+        // Every line of a multi-line reply is kept
+        let error = mailer.send(&message()).await.unwrap_err();
+        assert_eq!(error, "RCPT failed: 550 no such user mailbox unavailable");
+        server.abort();
+    }
+}
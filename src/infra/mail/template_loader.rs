@@ -0,0 +1,66 @@
+use crate::app::mail::{MailTemplate, MailTemplates};
+use std::io;
+use std::path::Path;
+
+/// Load every `<dir>/<name>/<locale>.subject` with its `.html` and `.txt` bodies
+pub fn load_templates(dir: &Path, default_locale: &str) -> io::Result<MailTemplates> {
+    let mut templates = MailTemplates::new(default_locale);
+
+    for entry in std::fs::read_dir(dir)? {
+        let template_dir = entry?.path();
+        if !template_dir.is_dir() {
+            continue;
+        }
+        let Some(name) = template_dir.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+
+        for file in std::fs::read_dir(&template_dir)? {
+            let subject_path = file?.path();
+            if subject_path.extension().is_none_or(|ext| ext != "subject") {
+                continue;
+            }
+            let Some(locale) = subject_path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+
+            let read = |extension: &str| {
+                let path = subject_path.with_extension(extension);
+                std::fs::read_to_string(&path)
+                    .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))
+            };
+            let template = MailTemplate {
+                subject: read("subject")?.trim().to_string(),
+                html: read("html")?,
+                text: read("txt")?,
+            };
+            templates.insert(name, locale, template);
+        }
+    }
+
+    Ok(templates)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_loads_shipped_templates() {
+        let templates = load_templates(Path::new("templates/mail"), "en").unwrap();
+        let vars = serde_json::json!({ "first_name": "Ann" });
+
+        let message = templates
+            .render(
+                "welcome",
+                Some("de-AT"),
+                "ann@example.com",
+                vars.as_object().unwrap(),
+            )
+            .unwrap();
+        assert_eq!(message.subject, "Willkommen, Ann");
+        assert!(message.html.contains("Ann"));
+        assert!(message.text.contains("Ann"));
+        assert_eq!(templates.locales("welcome"), vec!["de", "en"]);
+    }
+}
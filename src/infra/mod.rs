@@ -1,5 +1,18 @@
 pub mod auth;
+pub mod cache;
 pub mod config;
+pub mod events;
+pub mod health;
+pub mod idempotency;
+pub mod jobs;
+pub mod lifecycle;
+pub mod mail;
+pub mod outbox;
 pub mod persistence;
+pub mod rate_limit;
+pub mod retry;
+pub mod telemetry;
+pub mod tls;
+pub mod webhooks;
 
 pub use config::Config;
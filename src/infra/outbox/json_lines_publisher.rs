@@ -0,0 +1,79 @@
+use crate::app::ports::{MessagePublisher, OutboundMessage};
+use async_trait::async_trait;
+use std::path::Path;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use tokio::sync::Mutex;
+
+/// Publishes each message as one JSON line to stdout or an append-only file
+pub struct JsonLinesPublisher {
+    out: Mutex<Box<dyn AsyncWrite + Send + Unpin>>,
+}
+
+impl JsonLinesPublisher {
+    pub fn stdout() -> Self {
+        Self {
+            out: Mutex::new(Box::new(tokio::io::stdout())),
+        }
+    }
+
+    /// Append to `path`, creating the file if needed
+    pub async fn open(path: &Path) -> std::io::Result<Self> {
+        let file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .await?;
+
+        Ok(Self {
+            out: Mutex::new(Box::new(file)),
+        })
+    }
+}
+
+#[async_trait]
+impl MessagePublisher for JsonLinesPublisher {
+    async fn publish(&self, message: &OutboundMessage) -> Result<(), String> {
+        let mut line = serde_json::to_vec(message).map_err(|e| e.to_string())?;
+        line.push(b'\n');
+
+        let mut out = self.out.lock().await;
+        out.write_all(&line).await.map_err(|e| e.to_string())?;
+        out.flush().await.map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_appends_json_lines() {
+        let path = std::env::temp_dir().join(format!("outbox-{}.jsonl", uuid::Uuid::new_v4()));
+        let publisher = JsonLinesPublisher::open(&path).await.unwrap();
+
+        let aggregate_id = uuid::Uuid::new_v4();
+        for id in 1..=2 {
+            let message = OutboundMessage {
+                id,
+                event_type: "user.registered".to_string(),
+                aggregate_id,
+                occurred_at: chrono::Utc::now(),
+                data: json!({ "email": "a@example.com" }),
+            };
+            publisher.publish(&message).await.unwrap();
+        }
+
+        let contents = tokio::fs::read_to_string(&path).await.unwrap();
+        tokio::fs::remove_file(&path).await.unwrap();
+        let lines: Vec<serde_json::Value> = contents
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[1]["id"], 2);
+        assert_eq!(lines[1]["type"], "user.registered");
+        assert_eq!(lines[1]["aggregateId"], aggregate_id.to_string());
+        assert_eq!(lines[1]["data"]["email"], "a@example.com");
+    }
+}
@@ -0,0 +1,104 @@
+//! Transactional outbox
+//!
+//! Domain events are written to the `outbox` table in the same transaction
+//! as the aggregate change (see `SeaOrmUserRepository::save`). A background
+//! relay publishes them through a `MessagePublisher`, queues their webhook
+//! deliveries and marks them published, giving at-least-once delivery in
+//! per-user order.
+
+pub mod amqp_publisher;
+pub mod json_lines_publisher;
+pub mod outbox_relay;
+
+pub use amqp_publisher::AmqpPublisher;
+pub use json_lines_publisher::JsonLinesPublisher;
+pub use outbox_relay::OutboxRelay;
+
+use crate::domain::user::{UserEvent, UserEventKind};
+use crate::infra::lifecycle::Lifecycle;
+use crate::infra::persistence::entities::outbox;
+use sea_orm::Set;
+use serde_json::json;
+use std::sync::Arc;
+
+/// Outbox row for a domain event, ready to insert alongside the change
+pub fn outbox_row(event: &UserEvent) -> outbox::ActiveModel {
+    outbox::ActiveModel {
+        aggregate_id: Set(event.user_id.value()),
+        event_type: Set(event.kind.name().to_string()),
+        payload: Set(event_data(&event.kind)),
+        occurred_at: Set(event.occurred_at.into()),
+        next_attempt_at: Set(event.occurred_at.into()),
+        ..Default::default()
+    }
+}
+
+/// Event-specific fields of the published message
+pub fn event_data(kind: &UserEventKind) -> serde_json::Value {
+    match kind {
+        UserEventKind::Registered { email } => json!({ "email": email.as_ref() }),
+        UserEventKind::EmailChanged { previous, email } => json!({
+            "previousEmail": previous.as_ref(),
+            "email": email.as_ref(),
+        }),
+        UserEventKind::ProfileUpdated | UserEventKind::PasswordChanged => json!({}),
+        UserEventKind::RoleGranted { role } | UserEventKind::RoleRevoked { role } => {
+            json!({ "role": role.as_str() })
+        }
+    }
+}
+
+/// Relay outbox events every poll interval until shutdown
+pub fn spawn_relay(relay: Arc<OutboxRelay>, lifecycle: &Lifecycle) {
+    let shutdown = lifecycle.shutdown_token();
+
+    let handle = tokio::spawn(async move {
+        let mut interval = tokio::time::interval(relay.poll_interval());
+
+        loop {
+            tokio::select! {
+                _ = shutdown.cancelled() => break,
+                _ = interval.tick() => {}
+            }
+
+            if let Err(e) = relay.run_once().await {
+                tracing::warn!(error = %e, "Outbox relay failed");
+            }
+        }
+    });
+
+    lifecycle.register_worker("outbox_relay", handle);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::shared::PublicUserId;
+    use crate::domain::user::{Email, Role};
+
+    #[test]
+    fn test_outbox_row_payload() {
+        let user_id = PublicUserId::generate();
+        let event = UserEvent {
+            user_id,
+            occurred_at: chrono::Utc::now(),
+            kind: UserEventKind::EmailChanged {
+                previous: Email::try_from("old@example.com".to_string()).unwrap(),
+                email: Email::try_from("new@example.com".to_string()).unwrap(),
+            },
+        };
+
+        let row = outbox_row(&event);
+        assert_eq!(row.aggregate_id.unwrap(), user_id.value());
+        assert_eq!(row.event_type.unwrap(), "user.email_changed");
+        assert_eq!(
+            row.payload.unwrap(),
+            json!({ "previousEmail": "old@example.com", "email": "new@example.com" })
+        );
+
+        assert_eq!(
+            event_data(&UserEventKind::RoleGranted { role: Role::Admin }),
+            json!({ "role": "admin" })
+        );
+    }
+}
@@ -0,0 +1,408 @@
+use crate::app::ports::{MessagePublisher, OutboundMessage};
+use crate::infra::config::app_config::Outbox;
+use crate::infra::persistence::entities::outbox::{self, Entity as OutboxEntity};
+use crate::infra::retry::backoff;
+use crate::infra::webhooks::SeaOrmWebhookStore;
+use chrono::Utc;
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DbBackend, EntityTrait, QueryFilter,
+    QueryOrder, QuerySelect, Set, Statement, TransactionTrait,
+};
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Advisory lock held while relaying, so one instance publishes at a time
+/// and per-user order survives running several replicas
+const RELAY_LOCK_KEY: i64 = 0x6f7574626f78; // "outbox"
+
+/// Excludes rows queued behind an earlier event of their user that is
+/// waiting out a retry; `$1` is the current time
+const NOT_HELD_BACK: &str = "NOT EXISTS (SELECT 1 FROM outbox AS earlier \
+     WHERE earlier.aggregate_id = outbox.aggregate_id \
+     AND earlier.published_at IS NULL AND earlier.id < outbox.id \
+     AND earlier.next_attempt_at > $1)";
+
+/// Publishes pending outbox rows, oldest first, and queues their webhook
+/// deliveries
+///
+/// A failed publish is retried with exponential backoff. Until it succeeds,
+/// later events of the same user are held back so consumers never see them
+/// out of order; other users' events keep flowing.
+pub struct OutboxRelay {
+    db: Arc<sea_orm::DatabaseConnection>,
+    publisher: Arc<dyn MessagePublisher>,
+    config: Outbox,
+}
+
+impl OutboxRelay {
+    pub fn new(
+        db: Arc<sea_orm::DatabaseConnection>,
+        publisher: Arc<dyn MessagePublisher>,
+        config: Outbox,
+    ) -> Self {
+        Self {
+            db,
+            publisher,
+            config,
+        }
+    }
+
+    pub fn poll_interval(&self) -> Duration {
+        self.config.poll_interval
+    }
+
+    /// Relay every ready batch, then purge events past their retention
+    pub async fn run_once(&self) -> Result<(), String> {
+        while self.relay_batch().await? {}
+        self.purge_published().await?;
+        Ok(())
+    }
+
+    /// Publish one batch of pending rows; `true` if more may be ready
+    async fn relay_batch(&self) -> Result<bool, String> {
+        let txn = self.db.begin().await.map_err(|e| e.to_string())?;
+
+        let locked = txn
+            .query_one(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                "SELECT pg_try_advisory_xact_lock($1) AS locked",
+                [RELAY_LOCK_KEY.into()],
+            ))
+            .await
+            .map_err(|e| e.to_string())?
+            .and_then(|row| row.try_get::<bool>("", "locked").ok())
+            .unwrap_or(false);
+        if !locked {
+            // Another instance is relaying
+            return Ok(false);
+        }
+
+        // Only due rows, so a user stuck in backoff cannot fill every batch
+        // and starve everyone else
+        let now = Utc::now();
+        let rows = OutboxEntity::find()
+            .filter(outbox::Column::PublishedAt.is_null())
+            .filter(outbox::Column::NextAttemptAt.lte(now))
+            .filter(Expr::cust_with_values(NOT_HELD_BACK, [now]))
+            .order_by_asc(outbox::Column::Id)
+            .limit(self.config.batch_size)
+            .all(&txn)
+            .await
+            .map_err(|e| e.to_string())?;
+        let full = rows.len() as u64 == self.config.batch_size;
+        let mut published = 0;
+
+        // Users whose earlier event failed in this batch
+        let mut held_back = HashSet::new();
+        for row in rows {
+            if held_back.contains(&row.aggregate_id) {
+                continue;
+            }
+
+            let message = OutboundMessage {
+                id: row.id,
+                event_type: row.event_type.clone(),
+                aggregate_id: row.aggregate_id,
+                occurred_at: row.occurred_at.to_utc(),
+                data: row.payload.clone(),
+            };
+            let attempts = row.attempts + 1;
+            let mut update: outbox::ActiveModel = row.into();
+
+            match self.publisher.publish(&message).await {
+                Ok(()) => {
+                    // Queued in this transaction, so a published event always
+                    // has its webhook deliveries; a failure rolls back the batch
+                    let mut data = message.data.clone();
+                    data["userId"] = message.aggregate_id.to_string().into();
+                    data["occurredAt"] = message.occurred_at.to_rfc3339().into();
+                    SeaOrmWebhookStore::enqueue_in(&txn, &message.event_type, data)
+                        .await
+                        .map_err(|e| e.to_string())?;
+
+                    update.published_at = Set(Some(Utc::now().into()));
+                    published += 1;
+                }
+                Err(e) => {
+                    let delay = backoff(
+                        attempts as u32,
+                        self.config.retry_base,
+                        self.config.retry_max,
+                    );
+                    tracing::warn!(
+                        id = message.id,
+                        event = %message.event_type,
+                        attempts,
+                        retry_in_ms = delay.as_millis() as u64,
+                        error = %e,
+                        "Failed to publish outbox event"
+                    );
+                    held_back.insert(message.aggregate_id);
+                    update.attempts = Set(attempts);
+                    update.last_error = Set(Some(e));
+                    update.next_attempt_at = Set((Utc::now() + delay).into());
+                }
+            }
+            update.update(&txn).await.map_err(|e| e.to_string())?;
+        }
+
+        txn.commit().await.map_err(|e| e.to_string())?;
+        Ok(full && published > 0)
+    }
+
+    async fn purge_published(&self) -> Result<(), String> {
+        let cutoff = Utc::now() - self.config.retention;
+        let result = OutboxEntity::delete_many()
+            .filter(Expr::col(outbox::Column::PublishedAt).lt(cutoff))
+            .exec(self.db.as_ref())
+            .await
+            .map_err(|e| e.to_string())?;
+
+        if result.rows_affected > 0 {
+            tracing::debug!(
+                purged = result.rows_affected,
+                "Published outbox events purged"
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infra::config::app_config::OutboxSink;
+    use async_trait::async_trait;
+    use sea_orm::PaginatorTrait;
+    use serde_json::json;
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    /// Fails every publish for one aggregate, records the rest
+    struct Flaky {
+        failing_aggregate: Uuid,
+        published: Mutex<Vec<(Uuid, String)>>,
+    }
+
+    #[async_trait]
+    impl MessagePublisher for Flaky {
+        async fn publish(&self, message: &OutboundMessage) -> Result<(), String> {
+            if message.aggregate_id == self.failing_aggregate {
+                return Err("broker unavailable".to_string());
+            }
+            self.published
+                .lock()
+                .unwrap()
+                .push((message.aggregate_id, message.event_type.clone()));
+            Ok(())
+        }
+    }
+
+    fn relay(
+        db: Arc<sea_orm::DatabaseConnection>,
+        publisher: Arc<Flaky>,
+        batch_size: u64,
+    ) -> OutboxRelay {
+        OutboxRelay::new(
+            db,
+            publisher,
+            Outbox {
+                sink: OutboxSink::Stdout,
+                file_path: "outbox.jsonl".into(),
+                amqp_exchange: "users".to_string(),
+                poll_interval: Duration::from_secs(1),
+                batch_size,
+                retry_base: Duration::from_secs(60),
+                retry_max: Duration::from_secs(300),
+                retention: Duration::from_secs(3600),
+            },
+        )
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated database (docker-compose up postgresql)"]
+    async fn test_holds_back_failed_user_and_relays_others() {
+        let db = Arc::new(crate::infra::config::database::connect().await.unwrap());
+        // Aggregate IDs no real user will have
+        let failing = Uuid::new_v4();
+        let healthy = Uuid::new_v4();
+
+        for (aggregate_id, event_type) in [
+            (failing, "user.registered"),
+            (healthy, "user.registered"),
+            (failing, "user.email_changed"),
+            (healthy, "user.role_granted"),
+        ] {
+            outbox::ActiveModel {
+                aggregate_id: Set(aggregate_id),
+                event_type: Set(event_type.to_string()),
+                payload: Set(json!({})),
+                occurred_at: Set(Utc::now().into()),
+                next_attempt_at: Set(Utc::now().into()),
+                ..Default::default()
+            }
+            .insert(db.as_ref())
+            .await
+            .unwrap();
+        }
+
+        let publisher = Arc::new(Flaky {
+            failing_aggregate: failing,
+            published: Mutex::new(Vec::new()),
+        });
+        let relay = relay(db.clone(), publisher.clone(), 1000);
+        relay.run_once().await.unwrap();
+        relay.run_once().await.unwrap();
+
+        let published: Vec<_> = publisher
+            .published
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(aggregate_id, _)| *aggregate_id == healthy)
+            .map(|(_, event_type)| event_type.clone())
+            .collect();
+        assert_eq!(published, ["user.registered", "user.role_granted"]);
+
+        let pending = OutboxEntity::find()
+            .filter(outbox::Column::AggregateId.eq(failing))
+            .order_by_asc(outbox::Column::Id)
+            .all(db.as_ref())
+            .await
+            .unwrap();
+        // Only the first event was attempted; the second waits behind it
+        assert_eq!(pending[0].attempts, 1);
+        assert_eq!(pending[0].last_error.as_deref(), Some("broker unavailable"));
+        assert_eq!(pending[1].attempts, 0);
+        assert!(pending.iter().all(|row| row.published_at.is_none()));
+
+        OutboxEntity::delete_many()
+            .filter(outbox::Column::AggregateId.is_in([failing, healthy]))
+            .exec(db.as_ref())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated database (docker-compose up postgresql)"]
+    async fn test_backlog_of_failed_user_does_not_fill_batches() {
+        let db = Arc::new(crate::infra::config::database::connect().await.unwrap());
+        let failing = Uuid::new_v4();
+        let healthy = Uuid::new_v4();
+
+        // The failing user's first event is waiting out a retry, with more
+        // events behind it than fit in a batch
+        for (aggregate_id, next_attempt_in) in [
+            (failing, chrono::Duration::minutes(5)),
+            (failing, chrono::Duration::zero()),
+            (failing, chrono::Duration::zero()),
+            (failing, chrono::Duration::zero()),
+            (healthy, chrono::Duration::zero()),
+        ] {
+            outbox::ActiveModel {
+                aggregate_id: Set(aggregate_id),
+                event_type: Set("user.profile_updated".to_string()),
+                payload: Set(json!({})),
+                occurred_at: Set(Utc::now().into()),
+                next_attempt_at: Set((Utc::now() + next_attempt_in).into()),
+                ..Default::default()
+            }
+            .insert(db.as_ref())
+            .await
+            .unwrap();
+        }
+
+        let publisher = Arc::new(Flaky {
+            failing_aggregate: failing,
+            published: Mutex::new(Vec::new()),
+        });
+        relay(db.clone(), publisher.clone(), 2)
+            .run_once()
+            .await
+            .unwrap();
+
+        let published: Vec<Uuid> = publisher
+            .published
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(aggregate_id, _)| *aggregate_id)
+            .collect();
+        assert!(published.contains(&healthy));
+
+        let attempted = OutboxEntity::find()
+            .filter(outbox::Column::AggregateId.eq(failing))
+            .filter(outbox::Column::Attempts.gt(0))
+            .count(db.as_ref())
+            .await
+            .unwrap();
+        // Nothing of the failing user was retried early or out of order
+        assert_eq!(attempted, 0);
+
+        OutboxEntity::delete_many()
+            .filter(outbox::Column::AggregateId.is_in([failing, healthy]))
+            .exec(db.as_ref())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated database (docker-compose up postgresql)"]
+    async fn test_queues_webhook_deliveries_with_the_published_event() {
+        use crate::app::ports::{NewWebhook, WebhookStore};
+        use crate::infra::persistence::entities::webhook_deliveries::{
+            self, Entity as WebhookDeliveriesEntity,
+        };
+
+        let db = Arc::new(crate::infra::config::database::connect().await.unwrap());
+        let aggregate_id = Uuid::new_v4();
+        // An event type no other webhook listens for by name
+        let event_type = format!("user.relay_test_{}", Uuid::new_v4().simple());
+        let store = SeaOrmWebhookStore::new(db.clone());
+        let webhook = store
+            .create(NewWebhook {
+                url: "https://partner.example/hooks".to_string(),
+                events: vec![event_type.clone()],
+                secret: "whsec_test".to_string(),
+            })
+            .await
+            .unwrap();
+
+        outbox::ActiveModel {
+            aggregate_id: Set(aggregate_id),
+            event_type: Set(event_type.clone()),
+            payload: Set(json!({ "role": "admin" })),
+            occurred_at: Set(Utc::now().into()),
+            next_attempt_at: Set(Utc::now().into()),
+            ..Default::default()
+        }
+        .insert(db.as_ref())
+        .await
+        .unwrap();
+
+        let publisher = Arc::new(Flaky {
+            failing_aggregate: Uuid::new_v4(),
+            published: Mutex::new(Vec::new()),
+        });
+        relay(db.clone(), publisher, 1000).run_once().await.unwrap();
+
+        let deliveries = WebhookDeliveriesEntity::find()
+            .filter(webhook_deliveries::Column::WebhookId.eq(webhook.id))
+            .all(db.as_ref())
+            .await
+            .unwrap();
+        assert_eq!(deliveries.len(), 1);
+        assert_eq!(deliveries[0].event_type, event_type);
+        assert_eq!(deliveries[0].payload["role"], "admin");
+        assert_eq!(deliveries[0].payload["userId"], aggregate_id.to_string());
+
+        store.delete(webhook.id).await.unwrap();
+        OutboxEntity::delete_many()
+            .filter(outbox::Column::AggregateId.eq(aggregate_id))
+            .exec(db.as_ref())
+            .await
+            .unwrap();
+    }
+}
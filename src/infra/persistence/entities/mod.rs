@@ -3,9 +3,22 @@
 //! These are database entities generated by sea-orm-codegen.
 //! They belong in the infrastructure layer as they are persistence concerns.
 
+pub mod group_members;
+pub mod groups;
+pub mod idempotency_keys;
+pub mod invitations;
+pub mod jobs;
+pub mod organization_member_roles;
+pub mod organization_members;
+pub mod organizations;
+pub mod outbox;
+pub mod password_history;
 pub mod prelude;
 pub mod roles;
+pub mod token_revocations;
 pub mod user_roles;
 pub mod users;
+pub mod webhook_deliveries;
+pub mod webhooks;
 
 pub use prelude::*;
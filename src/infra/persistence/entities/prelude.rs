@@ -1,5 +1,18 @@
 //! `SeaORM` Entity prelude
 
+pub use super::group_members::Entity as GroupMembers;
+pub use super::groups::Entity as Groups;
+pub use super::idempotency_keys::Entity as IdempotencyKeys;
+pub use super::invitations::Entity as Invitations;
+pub use super::jobs::Entity as Jobs;
+pub use super::organization_member_roles::Entity as OrganizationMemberRoles;
+pub use super::organization_members::Entity as OrganizationMembers;
+pub use super::organizations::Entity as Organizations;
+pub use super::outbox::Entity as Outbox;
+pub use super::password_history::Entity as PasswordHistory;
 pub use super::roles::Entity as Roles;
+pub use super::token_revocations::Entity as TokenRevocations;
 pub use super::user_roles::Entity as UserRoles;
 pub use super::users::Entity as Users;
+pub use super::webhook_deliveries::Entity as WebhookDeliveries;
+pub use super::webhooks::Entity as Webhooks;
@@ -7,9 +7,11 @@ use sea_orm::entity::prelude::*;
 pub struct Model {
     #[sea_orm(primary_key)]
     pub id: i32,
+    #[sea_orm(unique)]
+    pub public_id: Uuid,
     pub first_name: String,
     pub last_name: String,
-    pub age: i32,
+    pub date_of_birth: Date,
     #[sea_orm(unique)]
     pub email: String,
     pub password_hash: String,
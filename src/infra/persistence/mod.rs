@@ -1,4 +1,11 @@
 pub mod entities;
+pub mod schema;
+pub mod sea_orm_group_repository;
+pub mod sea_orm_organization_repository;
 pub mod sea_orm_user_repository;
+pub mod seed;
 
+pub use sea_orm_group_repository::SeaOrmGroupRepository;
+pub use sea_orm_organization_repository::SeaOrmOrganizationRepository;
 pub use sea_orm_user_repository::SeaOrmUserRepository;
+pub use seed::{Seed, SeedOutcome};
@@ -0,0 +1,73 @@
+//! Schema migrations at startup
+//!
+//! Applies the `migration` crate's migrations from the application itself
+//! and refuses to run against a schema that does not match the binary.
+
+use migration::{Migrator, MigratorTrait};
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbBackend, Statement, TransactionTrait};
+
+/// Advisory lock key held while migrating ("minirust" in ASCII)
+const MIGRATION_LOCK_KEY: i64 = 0x6d69_6e69_7275_7374;
+
+/// Apply pending migrations, returning the names of those applied
+///
+/// Runs in one transaction holding an advisory lock, so instances starting
+/// together migrate one at a time and the others find nothing left to do.
+pub async fn migrate(db: &DatabaseConnection) -> Result<Vec<String>, String> {
+    let txn = db.begin().await.map_err(|e| e.to_string())?;
+    lock(&txn).await?;
+
+    let pending: Vec<String> = Migrator::get_pending_migrations(&txn)
+        .await
+        .map_err(|e| e.to_string())?
+        .iter()
+        .map(|migration| migration.name().to_string())
+        .collect();
+    Migrator::up(&txn, None).await.map_err(|e| e.to_string())?;
+
+    txn.commit().await.map_err(|e| e.to_string())?;
+    Ok(pending)
+}
+
+/// Take the migration lock until `txn` ends, so schema and seed changes
+/// made by instances starting together run one at a time
+pub(crate) async fn lock(txn: &impl ConnectionTrait) -> Result<(), String> {
+    txn.execute(Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        "SELECT pg_advisory_xact_lock($1)",
+        [MIGRATION_LOCK_KEY.into()],
+    ))
+    .await
+    .map(|_| ())
+    .map_err(|e| e.to_string())
+}
+
+/// Fail unless every migration is applied and the database has none unknown
+/// to this build (i.e. it was migrated by a newer release)
+pub async fn check(db: &DatabaseConnection) -> Result<(), String> {
+    let pending = Migrator::get_pending_migrations(db)
+        .await
+        .map_err(|e| format!("schema drift: {}", e))?;
+
+    if pending.is_empty() {
+        Ok(())
+    } else {
+        let names: Vec<&str> = pending.iter().map(|m| m.name()).collect();
+        Err(format!("pending migrations: {}", names.join(", ")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    #[ignore = "requires a migrated database (docker-compose up postgresql)"]
+    async fn test_migrate_is_idempotent() {
+        let db = crate::infra::config::database::connect().await.unwrap();
+
+        migrate(&db).await.unwrap();
+        assert!(migrate(&db).await.unwrap().is_empty());
+        check(&db).await.unwrap();
+    }
+}
@@ -0,0 +1,301 @@
+use super::entities::group_members::{self, Entity as GroupMembersEntity};
+use super::entities::groups::{self, Entity as GroupsEntity};
+use crate::domain::group::repository::GroupRepositoryResult;
+use crate::domain::group::{Group, GroupGrant, GroupRepository};
+use crate::domain::organization::TenantScope;
+use crate::domain::shared::{GroupId, OrganizationId, UserId};
+use crate::domain::user::repository::RepositoryError;
+use async_trait::async_trait;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{
+    ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Select, Set,
+};
+use std::collections::HashSet;
+use std::str::FromStr;
+use std::sync::Arc;
+
+fn persistence_failure(e: impl ToString) -> RepositoryError {
+    RepositoryError::PersistenceFailure(e.to_string())
+}
+
+/// SeaORM implementation of GroupRepository
+pub struct SeaOrmGroupRepository {
+    db: Arc<sea_orm::DatabaseConnection>,
+}
+
+impl SeaOrmGroupRepository {
+    pub fn new(db: Arc<sea_orm::DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    /// Restrict a group query to the groups of the tenant
+    fn in_tenant(
+        query: Select<GroupsEntity>,
+        organization_id: Option<OrganizationId>,
+    ) -> Select<GroupsEntity> {
+        match organization_id {
+            Some(organization_id) => {
+                query.filter(groups::Column::OrganizationId.eq(organization_id.value()))
+            }
+            None => query.filter(groups::Column::OrganizationId.is_null()),
+        }
+    }
+
+    /// Restrict a group query to the groups visible in `scope`
+    fn scoped(query: Select<GroupsEntity>, scope: TenantScope) -> Select<GroupsEntity> {
+        match scope {
+            TenantScope::Platform => query,
+            TenantScope::Organization(organization_id) => {
+                Self::in_tenant(query, Some(organization_id))
+            }
+        }
+    }
+
+    fn to_domain(model: groups::Model) -> Group {
+        // Grants no longer known to the service are dropped
+        let grants = serde_json::from_value::<Vec<String>>(model.grants)
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|grant| GroupGrant::from_str(grant).ok())
+            .collect();
+
+        Group::reconstitute(
+            GroupId::from(model.id),
+            model.organization_id.map(OrganizationId::from),
+            model.name,
+            grants,
+            model.created_at,
+        )
+    }
+
+    fn grant_names(grants: &HashSet<GroupGrant>) -> serde_json::Value {
+        let mut names: Vec<&str> = grants.iter().map(GroupGrant::as_str).collect();
+        names.sort_unstable();
+        serde_json::json!(names)
+    }
+
+    /// The groups of a user, restricted by `filter`, by name
+    async fn of_user(
+        &self,
+        user_id: UserId,
+        filter: impl FnOnce(Select<GroupsEntity>) -> Select<GroupsEntity>,
+    ) -> GroupRepositoryResult<Vec<Group>> {
+        let query = GroupsEntity::find()
+            .inner_join(GroupMembersEntity)
+            .filter(group_members::Column::UserId.eq(user_id.value()));
+        let models = filter(query)
+            .order_by_asc(groups::Column::Name)
+            .order_by_asc(groups::Column::Id)
+            .all(self.db.as_ref())
+            .await
+            .map_err(persistence_failure)?;
+
+        Ok(models.into_iter().map(Self::to_domain).collect())
+    }
+}
+
+#[async_trait]
+impl GroupRepository for SeaOrmGroupRepository {
+    async fn find_by_id(&self, id: GroupId) -> GroupRepositoryResult<Option<Group>> {
+        Ok(GroupsEntity::find_by_id(id.value())
+            .one(self.db.as_ref())
+            .await
+            .map_err(persistence_failure)?
+            .map(Self::to_domain))
+    }
+
+    async fn exists_with_name(
+        &self,
+        organization_id: Option<OrganizationId>,
+        name: &str,
+    ) -> GroupRepositoryResult<bool> {
+        let count = Self::in_tenant(GroupsEntity::find(), organization_id)
+            .filter(groups::Column::Name.eq(name))
+            .count(self.db.as_ref())
+            .await
+            .map_err(persistence_failure)?;
+
+        Ok(count > 0)
+    }
+
+    async fn save(&self, group: &Group) -> GroupRepositoryResult<()> {
+        GroupsEntity::insert(groups::ActiveModel {
+            id: Set(group.id().value()),
+            organization_id: Set(group.organization_id().map(|id| id.value())),
+            name: Set(group.name().to_string()),
+            grants: Set(Self::grant_names(group.grants())),
+            created_at: Set(group.created_at()),
+        })
+        .on_conflict(
+            OnConflict::column(groups::Column::Id)
+                .update_columns([groups::Column::Name, groups::Column::Grants])
+                .to_owned(),
+        )
+        .exec_without_returning(self.db.as_ref())
+        .await
+        .map_err(persistence_failure)?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: GroupId) -> GroupRepositoryResult<bool> {
+        // Memberships go with the group through the cascading key
+        let result = GroupsEntity::delete_by_id(id.value())
+            .exec(self.db.as_ref())
+            .await
+            .map_err(persistence_failure)?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn list(
+        &self,
+        scope: TenantScope,
+        page: u64,
+        rows_per_page: u64,
+    ) -> GroupRepositoryResult<(Vec<Group>, u64)> {
+        let offset = page.saturating_sub(1) * rows_per_page;
+
+        let models = Self::scoped(GroupsEntity::find(), scope)
+            .order_by_asc(groups::Column::Name)
+            .order_by_asc(groups::Column::Id)
+            .offset(offset)
+            .limit(rows_per_page)
+            .all(self.db.as_ref())
+            .await
+            .map_err(persistence_failure)?;
+
+        let total = Self::scoped(GroupsEntity::find(), scope)
+            .count(self.db.as_ref())
+            .await
+            .map_err(persistence_failure)?;
+
+        Ok((models.into_iter().map(Self::to_domain).collect(), total))
+    }
+
+    async fn list_for_user(
+        &self,
+        user_id: UserId,
+        scope: TenantScope,
+    ) -> GroupRepositoryResult<Vec<Group>> {
+        self.of_user(user_id, |query| Self::scoped(query, scope))
+            .await
+    }
+
+    async fn granting(
+        &self,
+        user_id: UserId,
+        organization_id: Option<OrganizationId>,
+        grant: GroupGrant,
+    ) -> GroupRepositoryResult<Vec<GroupId>> {
+        // Users belong to a handful of groups, so grants are checked here
+        Ok(self
+            .of_user(user_id, |query| Self::in_tenant(query, organization_id))
+            .await?
+            .into_iter()
+            .filter(|group| group.has_grant(&grant))
+            .map(|group| group.id())
+            .collect())
+    }
+
+    async fn add_member(&self, group_id: GroupId, user_id: UserId) -> GroupRepositoryResult<bool> {
+        let inserted = GroupMembersEntity::insert(group_members::ActiveModel {
+            group_id: Set(group_id.value()),
+            user_id: Set(user_id.value()),
+        })
+        .on_conflict(
+            OnConflict::columns([
+                group_members::Column::GroupId,
+                group_members::Column::UserId,
+            ])
+            .do_nothing()
+            .to_owned(),
+        )
+        .exec_without_returning(self.db.as_ref())
+        .await
+        .map_err(persistence_failure)?;
+
+        Ok(inserted > 0)
+    }
+
+    async fn remove_member(
+        &self,
+        group_id: GroupId,
+        user_id: UserId,
+    ) -> GroupRepositoryResult<bool> {
+        let result = GroupMembersEntity::delete_many()
+            .filter(group_members::Column::GroupId.eq(group_id.value()))
+            .filter(group_members::Column::UserId.eq(user_id.value()))
+            .exec(self.db.as_ref())
+            .await
+            .map_err(persistence_failure)?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{ConnectionTrait, DbBackend, Statement};
+
+    #[tokio::test]
+    #[ignore = "requires a migrated database (docker-compose up postgresql)"]
+    async fn test_members_are_granted_their_groups() {
+        let db = Arc::new(crate::infra::config::database::connect().await.unwrap());
+        let user_id = db
+            .query_one(Statement::from_string(
+                DbBackend::Postgres,
+                "SELECT min(id) AS id FROM users",
+            ))
+            .await
+            .unwrap()
+            .and_then(|row| row.try_get::<Option<i32>>("", "id").unwrap())
+            .map(UserId::from)
+            .expect("a user to add as a member");
+        let repository = SeaOrmGroupRepository::new(db.clone());
+
+        let name = format!("test-{}", uuid::Uuid::now_v7());
+        let team =
+            Group::create(None, name.clone(), HashSet::from([GroupGrant::ViewMembers])).unwrap();
+        let mailing_list = Group::create(None, format!("{} list", name), HashSet::new()).unwrap();
+        repository.save(&team).await.unwrap();
+        repository.save(&mailing_list).await.unwrap();
+        assert!(repository.exists_with_name(None, &name).await.unwrap());
+
+        assert!(repository.add_member(team.id(), user_id).await.unwrap());
+        assert!(!repository.add_member(team.id(), user_id).await.unwrap());
+        assert!(
+            repository
+                .add_member(mailing_list.id(), user_id)
+                .await
+                .unwrap()
+        );
+
+        let granting = repository
+            .granting(user_id, None, GroupGrant::ViewMembers)
+            .await
+            .unwrap();
+        assert!(granting.contains(&team.id()));
+        assert!(!granting.contains(&mailing_list.id()));
+        let is_member = |groups: Vec<Group>| groups.iter().any(|group| group.id() == team.id());
+        assert!(is_member(
+            repository
+                .list_for_user(user_id, TenantScope::Platform)
+                .await
+                .unwrap()
+        ));
+
+        assert!(repository.remove_member(team.id(), user_id).await.unwrap());
+        assert!(!is_member(
+            repository
+                .list_for_user(user_id, TenantScope::Platform)
+                .await
+                .unwrap()
+        ));
+
+        assert!(repository.delete(team.id()).await.unwrap());
+        assert!(repository.delete(mailing_list.id()).await.unwrap());
+        assert!(repository.find_by_id(team.id()).await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,374 @@
+use super::entities::organization_member_roles::{self, Entity as OrganizationMemberRolesEntity};
+use super::entities::organization_members::{self, Entity as OrganizationMembersEntity};
+use super::entities::organizations::{self, Entity as OrganizationsEntity};
+use super::entities::roles::{self, Entity as RolesEntity};
+use crate::domain::organization::repository::OrganizationRepositoryResult;
+use crate::domain::organization::{
+    Membership, Organization, OrganizationRepository, OrganizationSlug,
+};
+use crate::domain::shared::{OrganizationId, UserId};
+use crate::domain::user::Role;
+use crate::domain::user::repository::RepositoryError;
+use async_trait::async_trait;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect, Set, TransactionTrait,
+};
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use std::sync::Arc;
+
+fn persistence_failure(e: impl ToString) -> RepositoryError {
+    RepositoryError::PersistenceFailure(e.to_string())
+}
+
+/// SeaORM implementation of OrganizationRepository
+pub struct SeaOrmOrganizationRepository {
+    db: Arc<sea_orm::DatabaseConnection>,
+}
+
+impl SeaOrmOrganizationRepository {
+    pub fn new(db: Arc<sea_orm::DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    fn to_domain(model: organizations::Model) -> Result<Organization, RepositoryError> {
+        let slug = OrganizationSlug::try_from(model.slug)
+            .map_err(|e| RepositoryError::PersistenceFailure(format!("Invalid slug: {}", e)))?;
+
+        Ok(Organization::reconstitute(
+            OrganizationId::from(model.id),
+            slug,
+            model.name,
+            model.created_at,
+        ))
+    }
+
+    fn to_domain_list(
+        models: Vec<organizations::Model>,
+    ) -> Result<Vec<Organization>, RepositoryError> {
+        models.into_iter().map(Self::to_domain).collect()
+    }
+
+    /// Load the organization roles of each given member, keyed by user ID
+    async fn load_roles(
+        &self,
+        organization_id: i32,
+        user_ids: &[i32],
+    ) -> Result<HashMap<i32, HashSet<Role>>, RepositoryError> {
+        let rows = OrganizationMemberRolesEntity::find()
+            .filter(organization_member_roles::Column::OrganizationId.eq(organization_id))
+            .filter(organization_member_roles::Column::UserId.is_in(user_ids.iter().copied()))
+            .find_also_related(RolesEntity)
+            .all(self.db.as_ref())
+            .await
+            .map_err(persistence_failure)?;
+
+        let mut roles: HashMap<i32, HashSet<Role>> = HashMap::new();
+        for (member_role, role_opt) in rows {
+            if let Some(role_model) = role_opt
+                && let Ok(role) = Role::from_str(&role_model.name)
+            {
+                roles.entry(member_role.user_id).or_default().insert(role);
+            }
+        }
+
+        Ok(roles)
+    }
+
+    /// Write the membership and its roles within `txn`
+    pub(crate) async fn save_membership_in(
+        txn: &impl ConnectionTrait,
+        membership: &Membership,
+    ) -> Result<(), RepositoryError> {
+        OrganizationMembersEntity::insert(organization_members::ActiveModel {
+            organization_id: Set(membership.organization_id().value()),
+            user_id: Set(membership.user_id().value()),
+        })
+        .on_conflict(
+            OnConflict::columns([
+                organization_members::Column::OrganizationId,
+                organization_members::Column::UserId,
+            ])
+            .do_nothing()
+            .to_owned(),
+        )
+        .exec_without_returning(txn)
+        .await
+        .map_err(persistence_failure)?;
+
+        Self::save_roles(txn, membership).await
+    }
+
+    /// Replace a member's organization roles
+    async fn save_roles(
+        db: &impl ConnectionTrait,
+        membership: &Membership,
+    ) -> Result<(), RepositoryError> {
+        let organization_id = membership.organization_id().value();
+        let user_id = membership.user_id().value();
+
+        OrganizationMemberRolesEntity::delete_many()
+            .filter(organization_member_roles::Column::OrganizationId.eq(organization_id))
+            .filter(organization_member_roles::Column::UserId.eq(user_id))
+            .exec(db)
+            .await
+            .map_err(persistence_failure)?;
+
+        for role in membership.roles() {
+            let role_name = role.to_string();
+            let role_model = RolesEntity::find()
+                .filter(roles::Column::Name.eq(&role_name))
+                .one(db)
+                .await
+                .map_err(persistence_failure)?
+                .ok_or_else(|| {
+                    RepositoryError::PersistenceFailure(format!(
+                        "Role '{}' not found in database",
+                        role_name
+                    ))
+                })?;
+
+            organization_member_roles::ActiveModel {
+                organization_id: Set(organization_id),
+                user_id: Set(user_id),
+                role_id: Set(role_model.id),
+            }
+            .insert(db)
+            .await
+            .map_err(persistence_failure)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl OrganizationRepository for SeaOrmOrganizationRepository {
+    async fn find_by_id(
+        &self,
+        id: OrganizationId,
+    ) -> OrganizationRepositoryResult<Option<Organization>> {
+        OrganizationsEntity::find_by_id(id.value())
+            .one(self.db.as_ref())
+            .await
+            .map_err(persistence_failure)?
+            .map(Self::to_domain)
+            .transpose()
+    }
+
+    async fn find_by_slug(
+        &self,
+        slug: &OrganizationSlug,
+    ) -> OrganizationRepositoryResult<Option<Organization>> {
+        OrganizationsEntity::find()
+            .filter(organizations::Column::Slug.eq(slug.as_ref()))
+            .one(self.db.as_ref())
+            .await
+            .map_err(persistence_failure)?
+            .map(Self::to_domain)
+            .transpose()
+    }
+
+    async fn find_id_by_slug(
+        &self,
+        slug: &OrganizationSlug,
+    ) -> OrganizationRepositoryResult<Option<OrganizationId>> {
+        let id: Option<i32> = OrganizationsEntity::find()
+            .select_only()
+            .column(organizations::Column::Id)
+            .filter(organizations::Column::Slug.eq(slug.as_ref()))
+            .into_tuple()
+            .one(self.db.as_ref())
+            .await
+            .map_err(persistence_failure)?;
+
+        Ok(id.map(OrganizationId::from))
+    }
+
+    async fn exists_with_slug(
+        &self,
+        slug: &OrganizationSlug,
+    ) -> OrganizationRepositoryResult<bool> {
+        Ok(self.find_id_by_slug(slug).await?.is_some())
+    }
+
+    async fn save(&self, organization: &mut Organization) -> OrganizationRepositoryResult<()> {
+        let mut active_model = organizations::ActiveModel {
+            slug: Set(organization.slug().to_string()),
+            name: Set(organization.name().to_string()),
+            created_at: Set(organization.created_at()),
+            ..Default::default()
+        };
+
+        match organization.id() {
+            Some(id) => {
+                active_model.id = Set(id.value());
+                active_model
+                    .update(self.db.as_ref())
+                    .await
+                    .map_err(persistence_failure)?;
+            }
+            None => {
+                let inserted = active_model
+                    .insert(self.db.as_ref())
+                    .await
+                    .map_err(persistence_failure)?;
+                organization.set_id(OrganizationId::from(inserted.id));
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn list(&self) -> OrganizationRepositoryResult<Vec<Organization>> {
+        let models = OrganizationsEntity::find()
+            .order_by_asc(organizations::Column::Id)
+            .all(self.db.as_ref())
+            .await
+            .map_err(persistence_failure)?;
+
+        Self::to_domain_list(models)
+    }
+
+    async fn list_for_user(
+        &self,
+        user_id: UserId,
+    ) -> OrganizationRepositoryResult<Vec<Organization>> {
+        let models = OrganizationsEntity::find()
+            .inner_join(OrganizationMembersEntity)
+            .filter(organization_members::Column::UserId.eq(user_id.value()))
+            .order_by_asc(organizations::Column::Id)
+            .all(self.db.as_ref())
+            .await
+            .map_err(persistence_failure)?;
+
+        Self::to_domain_list(models)
+    }
+
+    async fn find_membership(
+        &self,
+        organization_id: OrganizationId,
+        user_id: UserId,
+    ) -> OrganizationRepositoryResult<Option<Membership>> {
+        Ok(self
+            .find_memberships(organization_id, &[user_id])
+            .await?
+            .pop())
+    }
+
+    async fn find_memberships(
+        &self,
+        organization_id: OrganizationId,
+        user_ids: &[UserId],
+    ) -> OrganizationRepositoryResult<Vec<Membership>> {
+        let ids: Vec<i32> = user_ids.iter().map(|id| id.value()).collect();
+        let members = OrganizationMembersEntity::find()
+            .filter(organization_members::Column::OrganizationId.eq(organization_id.value()))
+            .filter(organization_members::Column::UserId.is_in(ids.iter().copied()))
+            .all(self.db.as_ref())
+            .await
+            .map_err(persistence_failure)?;
+
+        let mut roles = self.load_roles(organization_id.value(), &ids).await?;
+        Ok(members
+            .into_iter()
+            .map(|member| {
+                Membership::reconstitute(
+                    organization_id,
+                    UserId::from(member.user_id),
+                    roles.remove(&member.user_id).unwrap_or_default(),
+                )
+            })
+            .collect())
+    }
+
+    async fn save_membership(&self, membership: &Membership) -> OrganizationRepositoryResult<()> {
+        let txn = self.db.begin().await.map_err(persistence_failure)?;
+        Self::save_membership_in(&txn, membership).await?;
+        txn.commit().await.map_err(persistence_failure)
+    }
+
+    async fn remove_membership(
+        &self,
+        organization_id: OrganizationId,
+        user_id: UserId,
+    ) -> OrganizationRepositoryResult<bool> {
+        // Member roles go with the membership through the cascading key
+        let result = OrganizationMembersEntity::delete_many()
+            .filter(organization_members::Column::OrganizationId.eq(organization_id.value()))
+            .filter(organization_members::Column::UserId.eq(user_id.value()))
+            .exec(self.db.as_ref())
+            .await
+            .map_err(persistence_failure)?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{DbBackend, Statement};
+
+    #[tokio::test]
+    #[ignore = "requires a migrated database (docker-compose up postgresql)"]
+    async fn test_memberships_carry_organization_roles() {
+        let db = Arc::new(crate::infra::config::database::connect().await.unwrap());
+        let user_id = db
+            .query_one(Statement::from_string(
+                DbBackend::Postgres,
+                "SELECT min(id) AS id FROM users",
+            ))
+            .await
+            .unwrap()
+            .and_then(|row| row.try_get::<Option<i32>>("", "id").unwrap())
+            .map(UserId::from)
+            .expect("a user to add as a member");
+        let repository = SeaOrmOrganizationRepository::new(db.clone());
+
+        let slug = OrganizationSlug::try_from(format!("test-{}", uuid::Uuid::now_v7())).unwrap();
+        let mut organization = Organization::create(slug.clone(), "Test".to_string()).unwrap();
+        repository.save(&mut organization).await.unwrap();
+        let organization_id = organization.id().unwrap();
+
+        let mut membership = Membership::new(organization_id, user_id);
+        membership.grant(Role::Admin);
+        repository.save_membership(&membership).await.unwrap();
+
+        let found = repository
+            .find_membership(organization_id, user_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.roles(), membership.roles());
+        assert!(
+            repository
+                .list_for_user(user_id)
+                .await
+                .unwrap()
+                .iter()
+                .any(|o| o.slug() == &slug)
+        );
+
+        assert!(
+            repository
+                .remove_membership(organization_id, user_id)
+                .await
+                .unwrap()
+        );
+        assert!(
+            repository
+                .find_membership(organization_id, user_id)
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        OrganizationsEntity::delete_by_id(organization_id.value())
+            .exec(db.as_ref())
+            .await
+            .unwrap();
+    }
+}
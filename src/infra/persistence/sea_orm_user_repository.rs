@@ -1,27 +1,133 @@
+use super::entities::group_members::{self, Entity as GroupMembersEntity};
+use super::entities::organization_members::{self, Entity as OrganizationMembersEntity};
+use super::entities::outbox::Entity as OutboxEntity;
 use super::entities::roles::{self, Entity as RolesEntity};
 use super::entities::user_roles::{self, Entity as UserRolesEntity};
 use super::entities::users::{self, Entity as UsersEntity};
-use crate::domain::shared::UserId;
+use crate::app::events::EventDispatcher;
+use crate::domain::organization::TenantScope;
+use crate::domain::shared::{GroupId, PublicUserId, UserId};
 use crate::domain::user::entity::User;
 use crate::domain::user::repository::{RepositoryError, UserRepository};
-use crate::domain::user::{Email, Password, Role, UserProfile};
+use crate::domain::user::{DateOfBirth, Email, Password, Role, UserEvent, UserProfile};
+use crate::infra::outbox::outbox_row;
 use async_trait::async_trait;
+use sea_orm::sea_query::{Expr, Query};
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
-    QuerySelect, Set,
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, NotSet, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect, Select, Set, TransactionTrait,
 };
 use std::collections::HashSet;
 use std::str::FromStr;
 use std::sync::Arc;
 
 /// SeaORM implementation of UserRepository
+///
+/// Domain events recorded on a user are written to the outbox in the same
+/// transaction as the user, then dispatched in-process once committed.
 pub struct SeaOrmUserRepository {
     db: Arc<sea_orm::DatabaseConnection>,
+    events: Arc<EventDispatcher>,
 }
 
 impl SeaOrmUserRepository {
-    pub fn new(db: Arc<sea_orm::DatabaseConnection>) -> Self {
-        Self { db }
+    pub fn new(db: Arc<sea_orm::DatabaseConnection>, events: Arc<EventDispatcher>) -> Self {
+        Self { db, events }
+    }
+
+    /// Write the user, their roles and their events' outbox rows within
+    /// `txn`, returning the events to dispatch once it commits
+    pub(crate) async fn save_in(
+        txn: &impl ConnectionTrait,
+        user: &mut User,
+    ) -> Result<Vec<UserEvent>, RepositoryError> {
+        if user.id().is_none() {
+            // Insert new user
+            let active_model = Self::to_active_model_insert(user);
+            let inserted = active_model
+                .insert(txn)
+                .await
+                .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?;
+
+            // Set the ID on the user entity
+            let user_id = inserted.id;
+            user.set_id(UserId::from(user_id));
+
+            // Save roles for the new user
+            Self::save_roles(txn, user_id, user.roles()).await?;
+        } else {
+            // Update existing user
+            let active_model = Self::to_active_model_update(user);
+            active_model
+                .update(txn)
+                .await
+                .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?;
+
+            // Sync roles
+            let user_id = user.id().unwrap().value();
+            Self::save_roles(txn, user_id, user.roles()).await?;
+        }
+
+        // Record events in the outbox so they are published only if the change commits
+        let events = user.take_events();
+        if !events.is_empty() {
+            OutboxEntity::insert_many(events.iter().map(outbox_row))
+                .exec(txn)
+                .await
+                .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?;
+        }
+
+        Ok(events)
+    }
+
+    /// Restrict a user query to the users visible in `scope`
+    fn scoped(query: Select<UsersEntity>, scope: TenantScope) -> Select<UsersEntity> {
+        match scope {
+            TenantScope::Platform => query,
+            TenantScope::Organization(organization_id) => query.filter(
+                users::Column::Id.in_subquery(
+                    Query::select()
+                        .column(organization_members::Column::UserId)
+                        .from(OrganizationMembersEntity)
+                        .and_where(
+                            organization_members::Column::OrganizationId
+                                .eq(organization_id.value()),
+                        )
+                        .to_owned(),
+                ),
+            ),
+        }
+    }
+
+    /// One page of the users matched by `query`, newest first, with the total count
+    async fn list_page(
+        &self,
+        query: Select<UsersEntity>,
+        page: u64,
+        rows_per_page: u64,
+    ) -> Result<(Vec<User>, u64), RepositoryError> {
+        let offset = (page.saturating_sub(1)) * rows_per_page;
+
+        let models = query
+            .clone()
+            .order_by_desc(users::Column::Id)
+            .offset(offset)
+            .limit(rows_per_page)
+            .all(self.db.as_ref())
+            .await
+            .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?;
+
+        let total = query
+            .count(self.db.as_ref())
+            .await
+            .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?;
+
+        let mut users = Vec::with_capacity(models.len());
+        for model in models {
+            users.push(self.to_domain(model).await?);
+        }
+
+        Ok((users, total))
     }
 
     /// Load roles for a given user ID from the junction table
@@ -46,11 +152,15 @@ impl SeaOrmUserRepository {
     }
 
     /// Save roles for a user by syncing the junction table
-    async fn save_roles(&self, user_id: i32, roles: &HashSet<Role>) -> Result<(), RepositoryError> {
+    async fn save_roles(
+        db: &impl ConnectionTrait,
+        user_id: i32,
+        roles: &HashSet<Role>,
+    ) -> Result<(), RepositoryError> {
         // Delete existing role assignments
         UserRolesEntity::delete_many()
             .filter(user_roles::Column::UserId.eq(user_id))
-            .exec(self.db.as_ref())
+            .exec(db)
             .await
             .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?;
 
@@ -61,7 +171,7 @@ impl SeaOrmUserRepository {
             // Look up the role ID by name
             let role_model = RolesEntity::find()
                 .filter(roles::Column::Name.eq(&role_name))
-                .one(self.db.as_ref())
+                .one(db)
                 .await
                 .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?
                 .ok_or_else(|| {
@@ -77,7 +187,7 @@ impl SeaOrmUserRepository {
             };
 
             user_role
-                .insert(self.db.as_ref())
+                .insert(db)
                 .await
                 .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?;
         }
@@ -94,13 +204,18 @@ impl SeaOrmUserRepository {
 
         let password = Password::from_hash(model.password_hash);
 
-        let profile = UserProfile::new(model.first_name, model.last_name, model.age as u8)
-            .map_err(|e| RepositoryError::PersistenceFailure(format!("Invalid profile: {}", e)))?;
+        let profile = UserProfile::new(
+            model.first_name,
+            model.last_name,
+            DateOfBirth::from(model.date_of_birth),
+        )
+        .map_err(|e| RepositoryError::PersistenceFailure(format!("Invalid profile: {}", e)))?;
 
         let roles = self.load_roles(user_id).await?;
 
         Ok(User::reconstitute(
             UserId::from(user_id),
+            PublicUserId::from(model.public_id),
             email,
             password,
             profile,
@@ -110,29 +225,37 @@ impl SeaOrmUserRepository {
     }
 
     /// Convert domain User to SeaORM ActiveModel for insert
-    fn to_active_model_insert(&self, user: &User) -> users::ActiveModel {
+    fn to_active_model_insert(user: &User) -> users::ActiveModel {
         users::ActiveModel {
+            public_id: Set(user.public_id().value()),
             email: Set(user.email().to_string()),
             password_hash: Set(user.password().hashed().to_string()),
             first_name: Set(user.profile().first_name().to_string()),
             last_name: Set(user.profile().last_name().to_string()),
-            age: Set(user.profile().age() as i32),
+            date_of_birth: Set(user.profile().date_of_birth().value()),
             create_at: Set(user.created_at()),
             ..Default::default()
         }
     }
 
     /// Convert domain User to SeaORM ActiveModel for update
-    fn to_active_model_update(&self, user: &User) -> users::ActiveModel {
+    ///
+    /// A withheld password leaves the stored hash as it is.
+    fn to_active_model_update(user: &User) -> users::ActiveModel {
         let id = user.id().expect("User must have an ID to update").value();
 
         users::ActiveModel {
             id: Set(id),
+            public_id: Set(user.public_id().value()),
             email: Set(user.email().to_string()),
-            password_hash: Set(user.password().hashed().to_string()),
+            password_hash: if user.password().is_withheld() {
+                NotSet
+            } else {
+                Set(user.password().hashed().to_string())
+            },
             first_name: Set(user.profile().first_name().to_string()),
             last_name: Set(user.profile().last_name().to_string()),
-            age: Set(user.profile().age() as i32),
+            date_of_birth: Set(user.profile().date_of_birth().value()),
             create_at: Set(user.created_at()),
         }
     }
@@ -152,6 +275,39 @@ impl UserRepository for SeaOrmUserRepository {
         }
     }
 
+    async fn find_by_public_id(
+        &self,
+        id: PublicUserId,
+        scope: TenantScope,
+    ) -> Result<Option<User>, RepositoryError> {
+        let model = Self::scoped(UsersEntity::find(), scope)
+            .filter(users::Column::PublicId.eq(id.value()))
+            .one(self.db.as_ref())
+            .await
+            .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?;
+
+        match model {
+            Some(m) => Ok(Some(self.to_domain(m).await?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_id_by_public_id(
+        &self,
+        id: PublicUserId,
+    ) -> Result<Option<UserId>, RepositoryError> {
+        let user_id: Option<i32> = UsersEntity::find()
+            .select_only()
+            .column(users::Column::Id)
+            .filter(users::Column::PublicId.eq(id.value()))
+            .into_tuple()
+            .one(self.db.as_ref())
+            .await
+            .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?;
+
+        Ok(user_id.map(UserId::from))
+    }
+
     async fn find_by_email(&self, email: &Email) -> Result<Option<User>, RepositoryError> {
         let model = UsersEntity::find()
             .filter(users::Column::Email.eq(email.to_string()))
@@ -166,36 +322,41 @@ impl UserRepository for SeaOrmUserRepository {
     }
 
     async fn save(&self, user: &mut User) -> Result<(), RepositoryError> {
-        if user.id().is_none() {
-            // Insert new user
-            let active_model = self.to_active_model_insert(user);
-            let inserted = active_model
-                .insert(self.db.as_ref())
-                .await
-                .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?;
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?;
 
-            // Set the ID on the user entity
-            let user_id = inserted.id;
-            user.set_id(UserId::from(user_id));
+        let events = Self::save_in(&txn, user).await?;
 
-            // Save roles for the new user
-            self.save_roles(user_id, user.roles()).await?;
-        } else {
-            // Update existing user
-            let active_model = self.to_active_model_update(user);
-            active_model
-                .update(self.db.as_ref())
-                .await
-                .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?;
+        txn.commit()
+            .await
+            .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?;
 
-            // Sync roles
-            let user_id = user.id().unwrap().value();
-            self.save_roles(user_id, user.roles()).await?;
-        }
+        // Notify in-process subscribers now that the changes are persisted
+        self.events.dispatch(events).await;
 
         Ok(())
     }
 
+    async fn update_password_hash(
+        &self,
+        id: UserId,
+        current: &str,
+        upgraded: &str,
+    ) -> Result<bool, RepositoryError> {
+        let result = UsersEntity::update_many()
+            .col_expr(users::Column::PasswordHash, Expr::value(upgraded))
+            .filter(users::Column::Id.eq(id.value()))
+            .filter(users::Column::PasswordHash.eq(current))
+            .exec(self.db.as_ref())
+            .await
+            .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?;
+
+        Ok(result.rows_affected > 0)
+    }
+
     async fn exists_with_email(&self, email: &Email) -> Result<bool, RepositoryError> {
         let exists = UsersEntity::find()
             .filter(users::Column::Email.eq(email.to_string()))
@@ -209,33 +370,129 @@ impl UserRepository for SeaOrmUserRepository {
 
     async fn list(
         &self,
+        scope: TenantScope,
         page: u64,
         rows_per_page: u64,
     ) -> Result<(Vec<User>, u64), RepositoryError> {
-        let offset = (page.saturating_sub(1)) * rows_per_page;
-
-        let models = UsersEntity::find()
-            .order_by_desc(users::Column::Id)
-            .offset(offset)
-            .limit(rows_per_page)
-            .all(self.db.as_ref())
-            .await
-            .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?;
-
-        let total = UsersEntity::find()
-            .count(self.db.as_ref())
-            .await
-            .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?;
-
-        let mut users = Vec::with_capacity(models.len());
-        for model in models {
-            users.push(self.to_domain(model).await?);
-        }
+        self.list_page(
+            Self::scoped(UsersEntity::find(), scope),
+            page,
+            rows_per_page,
+        )
+        .await
+    }
 
-        Ok((users, total))
+    async fn list_in_groups(
+        &self,
+        groups: &[GroupId],
+        scope: TenantScope,
+        page: u64,
+        rows_per_page: u64,
+    ) -> Result<(Vec<User>, u64), RepositoryError> {
+        let query = Self::scoped(UsersEntity::find(), scope).filter(
+            users::Column::Id.in_subquery(
+                Query::select()
+                    .column(group_members::Column::UserId)
+                    .from(GroupMembersEntity)
+                    .and_where(
+                        group_members::Column::GroupId.is_in(groups.iter().map(|id| id.value())),
+                    )
+                    .to_owned(),
+            ),
+        );
+        self.list_page(query, page, rows_per_page).await
     }
 
     async fn find_roles_by_user_id(&self, id: UserId) -> Result<HashSet<Role>, RepositoryError> {
         self.load_roles(id.value()).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::user::{PasswordHasher, PasswordPolicy};
+    use crate::infra::persistence::entities::outbox;
+    use chrono::NaiveDate;
+
+    #[tokio::test]
+    #[ignore = "requires a migrated database (docker-compose up postgresql)"]
+    async fn test_hash_upgrade_keeps_changes_made_since_loading() {
+        let db = Arc::new(crate::infra::config::database::connect().await.unwrap());
+        let repository =
+            SeaOrmUserRepository::new(db.clone(), Arc::new(EventDispatcher::new(vec![])));
+        let email = format!("{}@upgrade.test", uuid::Uuid::new_v4().simple());
+        let raw = "Secure-Pass-2024";
+
+        let mut user = User::register(
+            Email::try_from(email).unwrap(),
+            raw.to_string(),
+            "Ugo".to_string(),
+            "Grade".to_string(),
+            DateOfBirth::from(NaiveDate::from_ymd_opt(1990, 1, 1).unwrap()),
+            &PasswordPolicy::default(),
+            &PasswordHasher::bcrypt(4).unwrap(),
+        )
+        .unwrap();
+        user.add_role(Role::Admin);
+        repository.save(&mut user).await.unwrap();
+        let id = user.id().unwrap();
+
+        // Login loads the user and re-hashes its outdated password...
+        let mut loaded = repository.find_by_id(id).await.unwrap().unwrap();
+        let current = loaded.password().hashed().to_string();
+        assert!(
+            loaded
+                .upgrade_password_hash(raw, &PasswordHasher::argon2id(1024, 1, 1).unwrap())
+                .unwrap()
+        );
+
+        // ...while an admin revokes a role
+        let mut revoked = repository.find_by_id(id).await.unwrap().unwrap();
+        revoked.remove_role(&Role::Admin);
+        repository.save(&mut revoked).await.unwrap();
+
+        let upgraded = loaded.password().hashed();
+        assert!(
+            repository
+                .update_password_hash(id, &current, upgraded)
+                .await
+                .unwrap()
+        );
+        let stored = repository.find_by_id(id).await.unwrap().unwrap();
+        assert!(!stored.has_role(&Role::Admin));
+        assert_eq!(stored.password().hashed(), upgraded);
+
+        // A hash replaced meanwhile is not overwritten
+        assert!(
+            !repository
+                .update_password_hash(id, &current, "$2b$04$stale")
+                .await
+                .unwrap()
+        );
+
+        // Nor by saving a user read from the cache, whose hash is withheld
+        let mut cached = User::reconstitute(
+            id,
+            stored.public_id(),
+            stored.email().clone(),
+            Password::withheld(),
+            stored.profile().clone(),
+            stored.created_at(),
+            stored.roles().clone(),
+        );
+        repository.save(&mut cached).await.unwrap();
+        let stored = repository.find_by_id(id).await.unwrap().unwrap();
+        assert_eq!(stored.password().hashed(), upgraded);
+
+        OutboxEntity::delete_many()
+            .filter(outbox::Column::AggregateId.eq(user.public_id().value()))
+            .exec(db.as_ref())
+            .await
+            .unwrap();
+        UsersEntity::delete_by_id(id.value())
+            .exec(db.as_ref())
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,275 @@
+//! Declarative seed data
+//!
+//! A JSON file listing roles and users that must exist. Applying it only
+//! creates what is missing, so it runs safely at every startup: users who
+//! already exist are left as they are, and roles revoked from them are not
+//! granted again. Passwords are never stored in the file; each user names
+//! the environment variable holding theirs.
+
+use super::SeaOrmUserRepository;
+use super::schema;
+use crate::app::events::EventDispatcher;
+use crate::domain::user::{
+    DateOfBirth, Email, PasswordHasher, PasswordPolicy, Role, User, UserRepository,
+};
+use chrono::{NaiveDate, Utc};
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbBackend, Statement, TransactionTrait};
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Roles and users that must exist
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Seed {
+    #[serde(default)]
+    pub roles: Vec<String>,
+    #[serde(default)]
+    pub users: Vec<SeedUser>,
+}
+
+/// A user created, with their roles, when no user has the email
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SeedUser {
+    pub email: String,
+    /// Environment variable holding the initial password
+    pub password_env: String,
+    pub first_name: String,
+    pub last_name: String,
+    /// Date of birth (YYYY-MM-DD)
+    #[serde(default)]
+    pub date_of_birth: Option<NaiveDate>,
+    /// Deprecated: use `date_of_birth`
+    #[serde(default)]
+    pub age: Option<u8>,
+    /// Roles besides `user`
+    #[serde(default)]
+    pub roles: Vec<String>,
+}
+
+/// What applying a seed changed
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct SeedOutcome {
+    pub roles_created: usize,
+    pub users_created: usize,
+}
+
+impl SeedUser {
+    fn date_of_birth(&self) -> Result<DateOfBirth, String> {
+        match (self.date_of_birth, self.age) {
+            (Some(date), None) => Ok(DateOfBirth::from(date)),
+            (None, Some(age)) => Ok(DateOfBirth::from_age(age, Utc::now().date_naive())),
+            _ => Err(format!(
+                "{}: set exactly one of date_of_birth or age",
+                self.email
+            )),
+        }
+    }
+}
+
+impl Seed {
+    /// Read and validate a seed file
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content =
+            std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        Self::parse(&content).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    fn parse(content: &str) -> Result<Self, String> {
+        let seed: Seed = serde_json::from_str(content).map_err(|e| e.to_string())?;
+
+        // Roles are a fixed set known to the code; reject typos up front
+        for name in seed
+            .roles
+            .iter()
+            .chain(seed.users.iter().flat_map(|user| &user.roles))
+        {
+            Role::from_str(name)?;
+        }
+        for user in &seed.users {
+            user.date_of_birth()?;
+        }
+        Ok(seed)
+    }
+
+    /// Create the missing roles and users
+    ///
+    /// Runs in one transaction holding the migration lock, so instances
+    /// starting together do not race to create the same rows. Existing users
+    /// are never written, so no cached entry can go stale; the events of new
+    /// ones are dispatched once the transaction commits.
+    pub async fn apply(
+        &self,
+        db: &DatabaseConnection,
+        user_repository: &dyn UserRepository,
+        events: &EventDispatcher,
+        policy: &PasswordPolicy,
+        hasher: &PasswordHasher,
+    ) -> Result<SeedOutcome, String> {
+        let mut outcome = SeedOutcome::default();
+        let txn = db.begin().await.map_err(|e| e.to_string())?;
+        schema::lock(&txn).await?;
+
+        // Users reference roles, so roles go first
+        let roles: HashSet<&str> = self
+            .roles
+            .iter()
+            .chain(self.users.iter().flat_map(|user| &user.roles))
+            .map(|name| Role::from_str(name).map(|role| role.as_str()))
+            .collect::<Result<_, _>>()?;
+        for name in roles {
+            let inserted = txn
+                .execute(Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    "INSERT INTO roles (name) VALUES ($1) ON CONFLICT (name) DO NOTHING",
+                    [name.into()],
+                ))
+                .await
+                .map_err(|e| e.to_string())?;
+            outcome.roles_created += inserted.rows_affected() as usize;
+        }
+
+        let mut created = Vec::new();
+        for seed_user in &self.users {
+            let email = Email::try_from(seed_user.email.clone()).map_err(|e| e.to_string())?;
+            if user_repository
+                .exists_with_email(&email)
+                .await
+                .map_err(|e| e.to_string())?
+            {
+                continue;
+            }
+
+            let password = std::env::var(&seed_user.password_env).map_err(|_| {
+                format!(
+                    "{} must be set to seed {}",
+                    seed_user.password_env, seed_user.email
+                )
+            })?;
+            let mut user = User::register(
+                email,
+                password,
+                seed_user.first_name.clone(),
+                seed_user.last_name.clone(),
+                seed_user.date_of_birth()?,
+                policy,
+                hasher,
+            )
+            .map_err(|e| format!("{}: {}", seed_user.email, e))?;
+            for name in &seed_user.roles {
+                user.add_role(Role::from_str(name)?);
+            }
+
+            created.extend(
+                SeaOrmUserRepository::save_in(&txn, &mut user)
+                    .await
+                    .map_err(|e| e.to_string())?,
+            );
+            outcome.users_created += 1;
+        }
+
+        txn.commit().await.map_err(|e| e.to_string())?;
+        events.dispatch(created).await;
+
+        Ok(outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_shipped_seed() {
+        let seed = Seed::load(Path::new("seeds/default.json")).unwrap();
+        assert_eq!(seed.roles, vec!["admin", "user"]);
+        assert_eq!(seed.users[0].roles, vec!["admin"]);
+    }
+
+    #[test]
+    fn test_rejects_unknown_roles_and_fields() {
+        let unknown_role = r#"{ "roles": ["owner"] }"#;
+        assert_eq!(
+            Seed::parse(unknown_role).unwrap_err(),
+            "Unknown role: owner"
+        );
+
+        let plaintext_password = r#"{ "users": [{
+            "email": "root@example.com", "password": "Secret123",
+            "first_name": "Root", "last_name": "Admin", "date_of_birth": "1985-01-01"
+        }] }"#;
+        assert!(Seed::parse(plaintext_password).is_err());
+
+        let no_birth_date = r#"{ "users": [{
+            "email": "root@example.com", "password_env": "ROOT_PASSWORD",
+            "first_name": "Root", "last_name": "Admin"
+        }] }"#;
+        assert_eq!(
+            Seed::parse(no_birth_date).unwrap_err(),
+            "root@example.com: set exactly one of date_of_birth or age"
+        );
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated database (docker-compose up postgresql)"]
+    async fn test_leaves_existing_users_untouched() {
+        use crate::infra::persistence::entities::outbox::{self, Entity as OutboxEntity};
+        use crate::infra::persistence::entities::users::{self, Entity as UsersEntity};
+        use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+        use std::sync::Arc;
+
+        let db = Arc::new(crate::infra::config::database::connect().await.unwrap());
+        let events = Arc::new(EventDispatcher::new(vec![]));
+        let repository = SeaOrmUserRepository::new(db.clone(), events.clone());
+        let (policy, hasher) = (PasswordPolicy::default(), PasswordHasher::default());
+
+        let email = format!("{}@seed.test", uuid::Uuid::new_v4().simple());
+        let mut user = User::register(
+            Email::try_from(email.clone()).unwrap(),
+            "Secure-Pass-2024".to_string(),
+            "Seeded".to_string(),
+            "Admin".to_string(),
+            DateOfBirth::from(NaiveDate::from_ymd_opt(1985, 1, 1).unwrap()),
+            &policy,
+            &hasher,
+        )
+        .unwrap();
+        user.add_role(Role::User);
+        repository.save(&mut user).await.unwrap();
+
+        // The admin role was revoked, and the password variable is gone
+        let seed = Seed::parse(&format!(
+            r#"{{ "users": [{{
+                "email": "{email}", "password_env": "SEED_TEST_UNSET_PASSWORD",
+                "first_name": "Seeded", "last_name": "Admin",
+                "date_of_birth": "1985-01-01", "roles": ["admin"]
+            }}] }}"#
+        ))
+        .unwrap();
+        let outcome = seed
+            .apply(&db, &repository, &events, &policy, &hasher)
+            .await
+            .unwrap();
+        assert_eq!(outcome.users_created, 0);
+
+        let reloaded = repository
+            .find_by_email(&Email::try_from(email).unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(reloaded.roles(), &HashSet::from([Role::User]));
+
+        OutboxEntity::delete_many()
+            .filter(outbox::Column::AggregateId.eq(user.public_id().value()))
+            .exec(db.as_ref())
+            .await
+            .unwrap();
+        UsersEntity::delete_many()
+            .filter(users::Column::PublicId.eq(user.public_id().value()))
+            .exec(db.as_ref())
+            .await
+            .unwrap();
+    }
+}
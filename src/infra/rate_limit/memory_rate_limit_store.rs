@@ -0,0 +1,271 @@
+use crate::app::ports::{RateLimitAlgorithm, RateLimitDecision, RateLimitPolicy, RateLimitStore};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Number of hits between sweeps of expired keys
+const SWEEP_EVERY: u64 = 1024;
+
+/// Counter state for one key
+enum Entry {
+    Bucket {
+        tokens: f64,
+        updated: Instant,
+    },
+    Window {
+        start: Instant,
+        current: u32,
+        previous: u32,
+    },
+}
+
+struct Slot {
+    entry: Entry,
+    expires_at: Instant,
+}
+
+#[derive(Default)]
+struct Inner {
+    slots: HashMap<String, Slot>,
+    hits: u64,
+}
+
+/// In-memory implementation of RateLimitStore
+///
+/// Counters are local to the process, so limits apply per instance.
+#[derive(Default)]
+pub struct MemoryRateLimitStore {
+    inner: Mutex<Inner>,
+}
+
+impl MemoryRateLimitStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn hit_at(&self, key: &str, policy: &RateLimitPolicy, now: Instant) -> RateLimitDecision {
+        let mut inner = self.inner.lock().expect("rate limit store poisoned");
+
+        inner.hits += 1;
+        if inner.hits.is_multiple_of(SWEEP_EVERY) {
+            inner.slots.retain(|_, slot| slot.expires_at > now);
+        }
+
+        let slot = inner.slots.entry(key.to_string()).or_insert_with(|| Slot {
+            entry: Self::fresh_entry(policy, now),
+            expires_at: now,
+        });
+
+        // Policy changed for this key (e.g. config reload): start over
+        let matches = matches!(
+            (&slot.entry, policy.algorithm),
+            (Entry::Bucket { .. }, RateLimitAlgorithm::TokenBucket)
+                | (Entry::Window { .. }, RateLimitAlgorithm::SlidingWindow)
+        );
+        if !matches {
+            slot.entry = Self::fresh_entry(policy, now);
+        }
+
+        let decision = match &mut slot.entry {
+            Entry::Bucket { tokens, updated } => token_bucket(tokens, updated, policy, now),
+            Entry::Window {
+                start,
+                current,
+                previous,
+            } => sliding_window(start, current, previous, policy, now),
+        };
+
+        slot.expires_at = now + decision.reset_after;
+        decision
+    }
+
+    fn fresh_entry(policy: &RateLimitPolicy, now: Instant) -> Entry {
+        match policy.algorithm {
+            RateLimitAlgorithm::TokenBucket => Entry::Bucket {
+                tokens: policy.limit as f64,
+                updated: now,
+            },
+            RateLimitAlgorithm::SlidingWindow => Entry::Window {
+                start: now,
+                current: 0,
+                previous: 0,
+            },
+        }
+    }
+}
+
+fn token_bucket(
+    tokens: &mut f64,
+    updated: &mut Instant,
+    policy: &RateLimitPolicy,
+    now: Instant,
+) -> RateLimitDecision {
+    let capacity = policy.limit as f64;
+    let rate = capacity / policy.window.as_secs_f64();
+
+    *tokens = (*tokens + now.duration_since(*updated).as_secs_f64() * rate).min(capacity);
+    *updated = now;
+
+    let allowed = *tokens >= 1.0;
+    if allowed {
+        *tokens -= 1.0;
+    }
+
+    RateLimitDecision {
+        allowed,
+        limit: policy.limit,
+        remaining: tokens.floor() as u32,
+        reset_after: Duration::from_secs_f64((capacity - *tokens) / rate),
+        retry_after: (!allowed).then(|| Duration::from_secs_f64((1.0 - *tokens) / rate)),
+    }
+}
+
+/// Sliding window counter: the previous window's count is weighted by how
+/// much of it still overlaps the rolling window ending now
+fn sliding_window(
+    start: &mut Instant,
+    current: &mut u32,
+    previous: &mut u32,
+    policy: &RateLimitPolicy,
+    now: Instant,
+) -> RateLimitDecision {
+    let window = policy.window;
+    let elapsed_windows = (now.duration_since(*start).as_secs_f64() / window.as_secs_f64()) as u32;
+    if elapsed_windows == 1 {
+        *previous = *current;
+        *current = 0;
+        *start += window;
+    } else if elapsed_windows > 1 {
+        *previous = 0;
+        *current = 0;
+        *start += window * elapsed_windows;
+    }
+
+    let into_window = now.duration_since(*start);
+    let overlap = 1.0 - into_window.as_secs_f64() / window.as_secs_f64();
+    let estimated = *previous as f64 * overlap + *current as f64;
+
+    let allowed = estimated + 1.0 <= policy.limit as f64;
+    if allowed {
+        *current += 1;
+    }
+
+    let used = (*previous as f64 * overlap + *current as f64).ceil() as u32;
+    let until_window_end = window - into_window;
+    let reset_after = if *current > 0 {
+        until_window_end + window
+    } else {
+        until_window_end
+    };
+
+    let retry_after = (!allowed).then(|| {
+        if *current + 1 > policy.limit || *previous == 0 {
+            until_window_end
+        } else {
+            // Time until the weighted previous count leaves room for one more request
+            let room = (policy.limit - *current - 1) as f64 / *previous as f64;
+            let needed = window.as_secs_f64() * (1.0 - room);
+            Duration::from_secs_f64((needed - into_window.as_secs_f64()).max(0.0))
+        }
+    });
+
+    RateLimitDecision {
+        allowed,
+        limit: policy.limit,
+        remaining: policy.limit.saturating_sub(used),
+        reset_after,
+        retry_after,
+    }
+}
+
+#[async_trait]
+impl RateLimitStore for MemoryRateLimitStore {
+    async fn hit(&self, key: &str, policy: &RateLimitPolicy) -> Result<RateLimitDecision, String> {
+        Ok(self.hit_at(key, policy, Instant::now()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(algorithm: RateLimitAlgorithm) -> RateLimitPolicy {
+        RateLimitPolicy {
+            limit: 3,
+            window: Duration::from_secs(60),
+            algorithm,
+        }
+    }
+
+    #[test]
+    fn test_token_bucket_allows_burst_then_rejects() {
+        let store = MemoryRateLimitStore::new();
+        let policy = policy(RateLimitAlgorithm::TokenBucket);
+        let now = Instant::now();
+
+        for expected_remaining in [2, 1, 0] {
+            let decision = store.hit_at("ip:1", &policy, now);
+            assert!(decision.allowed);
+            assert_eq!(decision.remaining, expected_remaining);
+        }
+
+        let rejected = store.hit_at("ip:1", &policy, now);
+        assert!(!rejected.allowed);
+        assert_eq!(rejected.retry_after, Some(Duration::from_secs(20)));
+    }
+
+    #[test]
+    fn test_token_bucket_refills_over_time() {
+        let store = MemoryRateLimitStore::new();
+        let policy = policy(RateLimitAlgorithm::TokenBucket);
+        let now = Instant::now();
+
+        for _ in 0..3 {
+            store.hit_at("ip:1", &policy, now);
+        }
+        assert!(!store.hit_at("ip:1", &policy, now).allowed);
+        assert!(
+            store
+                .hit_at("ip:1", &policy, now + Duration::from_secs(20))
+                .allowed
+        );
+    }
+
+    #[test]
+    fn test_sliding_window_limits_and_recovers() {
+        let store = MemoryRateLimitStore::new();
+        let policy = policy(RateLimitAlgorithm::SlidingWindow);
+        let now = Instant::now();
+
+        for _ in 0..3 {
+            assert!(store.hit_at("user:1", &policy, now).allowed);
+        }
+        let rejected = store.hit_at("user:1", &policy, now);
+        assert!(!rejected.allowed);
+        assert_eq!(rejected.remaining, 0);
+        assert_eq!(rejected.retry_after, Some(Duration::from_secs(60)));
+
+        // Halfway into the next window, half of the previous window still counts
+        let later = now + Duration::from_secs(90);
+        assert!(store.hit_at("user:1", &policy, later).allowed);
+        assert!(!store.hit_at("user:1", &policy, later).allowed);
+
+        // Two full windows later everything has aged out
+        let much_later = now + Duration::from_secs(181);
+        assert!(store.hit_at("user:1", &policy, much_later).allowed);
+    }
+
+    #[test]
+    fn test_keys_are_independent() {
+        let store = MemoryRateLimitStore::new();
+        let policy = policy(RateLimitAlgorithm::TokenBucket);
+        let now = Instant::now();
+
+        for _ in 0..3 {
+            store.hit_at("ip:1", &policy, now);
+        }
+        assert!(!store.hit_at("ip:1", &policy, now).allowed);
+        assert!(store.hit_at("ip:2", &policy, now).allowed);
+    }
+}
@@ -0,0 +1,10 @@
+//! Rate limit stores
+//!
+//! Request counters behind the `RateLimitStore` port: a per-process
+//! in-memory store and a Redis store shared by every instance.
+
+pub mod memory_rate_limit_store;
+pub mod redis_rate_limit_store;
+
+pub use memory_rate_limit_store::MemoryRateLimitStore;
+pub use redis_rate_limit_store::RedisRateLimitStore;
@@ -0,0 +1,162 @@
+use crate::app::ports::{RateLimitAlgorithm, RateLimitDecision, RateLimitPolicy, RateLimitStore};
+use async_trait::async_trait;
+use redis::Script;
+use redis::aio::ConnectionManager;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Token bucket kept in a hash of `tokens` and last update `ts` (milliseconds)
+const TOKEN_BUCKET_SCRIPT: &str = r#"
+local capacity = tonumber(ARGV[1])
+local window = tonumber(ARGV[2])
+local now = tonumber(ARGV[3])
+local rate = capacity / window
+local state = redis.call('HMGET', KEYS[1], 'tokens', 'ts')
+local tokens = tonumber(state[1]) or capacity
+local ts = tonumber(state[2]) or now
+tokens = math.min(capacity, tokens + math.max(0, now - ts) * rate)
+local allowed = 0
+if tokens >= 1 then
+  tokens = tokens - 1
+  allowed = 1
+end
+redis.call('HSET', KEYS[1], 'tokens', tostring(tokens), 'ts', now)
+redis.call('PEXPIRE', KEYS[1], window)
+local retry = 0
+if allowed == 0 then
+  retry = math.ceil((1 - tokens) / rate)
+end
+return {allowed, math.floor(tokens), math.ceil((capacity - tokens) / rate), retry}
+"#;
+
+/// Sliding log kept in a sorted set scored by request time (milliseconds)
+const SLIDING_WINDOW_SCRIPT: &str = r#"
+local limit = tonumber(ARGV[1])
+local window = tonumber(ARGV[2])
+local now = tonumber(ARGV[3])
+redis.call('ZREMRANGEBYSCORE', KEYS[1], '-inf', now - window)
+local count = redis.call('ZCARD', KEYS[1])
+local allowed = 0
+if count < limit then
+  redis.call('ZADD', KEYS[1], now, ARGV[4])
+  count = count + 1
+  allowed = 1
+end
+redis.call('PEXPIRE', KEYS[1], window)
+local oldest = redis.call('ZRANGE', KEYS[1], 0, 0, 'WITHSCORES')
+local newest = redis.call('ZRANGE', KEYS[1], -1, -1, 'WITHSCORES')
+local reset = 0
+if newest[2] then
+  reset = tonumber(newest[2]) + window - now
+end
+local retry = 0
+if allowed == 0 and oldest[2] then
+  retry = tonumber(oldest[2]) + window - now
+end
+return {allowed, limit - count, reset, retry}
+"#;
+
+/// Redis implementation of RateLimitStore
+///
+/// Counters are shared across instances; each check is a single Lua script
+/// so concurrent requests cannot over-admit.
+pub struct RedisRateLimitStore {
+    connection: ConnectionManager,
+    key_prefix: String,
+    token_bucket: Script,
+    sliding_window: Script,
+}
+
+impl RedisRateLimitStore {
+    /// Connect to Redis at `url` (e.g. `redis://:password@localhost:6379`)
+    pub async fn connect(url: &str) -> Result<Self, String> {
+        let client = redis::Client::open(url).map_err(|e| e.to_string())?;
+        let connection = ConnectionManager::new(client)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(Self {
+            connection,
+            key_prefix: "rate_limit:".to_string(),
+            token_bucket: Script::new(TOKEN_BUCKET_SCRIPT),
+            sliding_window: Script::new(SLIDING_WINDOW_SCRIPT),
+        })
+    }
+}
+
+#[async_trait]
+impl RateLimitStore for RedisRateLimitStore {
+    async fn hit(&self, key: &str, policy: &RateLimitPolicy) -> Result<RateLimitDecision, String> {
+        let now_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| e.to_string())?
+            .as_millis() as u64;
+        let window_ms = policy.window.as_millis() as u64;
+        let redis_key = format!("{}{}", self.key_prefix, key);
+
+        let mut invocation = match policy.algorithm {
+            RateLimitAlgorithm::TokenBucket => self.token_bucket.key(&redis_key),
+            RateLimitAlgorithm::SlidingWindow => self.sliding_window.key(&redis_key),
+        };
+        invocation.arg(policy.limit).arg(window_ms).arg(now_ms);
+        if policy.algorithm == RateLimitAlgorithm::SlidingWindow {
+            invocation.arg(format!("{}-{}", now_ms, uuid::Uuid::new_v4()));
+        }
+
+        let mut connection = self.connection.clone();
+        let (allowed, remaining, reset_ms, retry_ms): (i64, i64, i64, i64) = invocation
+            .invoke_async(&mut connection)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(RateLimitDecision {
+            allowed: allowed == 1,
+            limit: policy.limit,
+            remaining: remaining.max(0) as u32,
+            reset_after: Duration::from_millis(reset_ms.max(0) as u64),
+            retry_after: (allowed != 1).then(|| Duration::from_millis(retry_ms.max(0) as u64)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn redis_url() -> String {
+        std::env::var("REDIS__URL").unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string())
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a local Redis (docker-compose up redis)"]
+    async fn test_redis_token_bucket() {
+        let store = RedisRateLimitStore::connect(&redis_url()).await.unwrap();
+        let key = format!("test:{}", uuid::Uuid::new_v4());
+        let policy = RateLimitPolicy {
+            limit: 2,
+            window: Duration::from_secs(60),
+            algorithm: RateLimitAlgorithm::TokenBucket,
+        };
+
+        assert!(store.hit(&key, &policy).await.unwrap().allowed);
+        assert!(store.hit(&key, &policy).await.unwrap().allowed);
+        let rejected = store.hit(&key, &policy).await.unwrap();
+        assert!(!rejected.allowed);
+        assert!(rejected.retry_after.unwrap() > Duration::ZERO);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a local Redis (docker-compose up redis)"]
+    async fn test_redis_sliding_window() {
+        let store = RedisRateLimitStore::connect(&redis_url()).await.unwrap();
+        let key = format!("test:{}", uuid::Uuid::new_v4());
+        let policy = RateLimitPolicy {
+            limit: 2,
+            window: Duration::from_secs(60),
+            algorithm: RateLimitAlgorithm::SlidingWindow,
+        };
+
+        assert_eq!(store.hit(&key, &policy).await.unwrap().remaining, 1);
+        assert_eq!(store.hit(&key, &policy).await.unwrap().remaining, 0);
+        assert!(!store.hit(&key, &policy).await.unwrap().allowed);
+    }
+}
@@ -0,0 +1,52 @@
+//! Retry scheduling and error recording shared by background workers
+
+use std::time::Duration;
+
+/// Delay before retry number `attempts`: `base` doubled each time, capped at `max`
+pub fn backoff(attempts: u32, base: Duration, max: Duration) -> Duration {
+    base.saturating_mul(2u32.saturating_pow(attempts.saturating_sub(1)))
+        .min(max)
+}
+
+/// Shorten `error` to at most `max_len` bytes for storage
+///
+/// Error text comes from remote servers and handlers, so it is cut at a
+/// character boundary rather than at exactly `max_len`.
+pub fn truncate_error(error: &mut String, max_len: usize) {
+    if error.len() > max_len {
+        let end = (0..=max_len)
+            .rev()
+            .find(|&index| error.is_char_boundary(index))
+            .unwrap_or(0);
+        error.truncate(end);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_doubles_up_to_max() {
+        let base = Duration::from_secs(1);
+        let max = Duration::from_secs(10);
+
+        assert_eq!(backoff(1, base, max), Duration::from_secs(1));
+        assert_eq!(backoff(2, base, max), Duration::from_secs(2));
+        assert_eq!(backoff(4, base, max), Duration::from_secs(8));
+        assert_eq!(backoff(5, base, max), max);
+        assert_eq!(backoff(40, base, max), max);
+    }
+
+    #[test]
+    fn test_truncate_error_keeps_whole_characters() {
+        // "é" is two bytes, so byte 5 falls inside the third one
+        let mut error = "ééééé".to_string();
+        truncate_error(&mut error, 5);
+        assert_eq!(error, "éé");
+
+        let mut short = "timeout".to_string();
+        truncate_error(&mut short, 500);
+        assert_eq!(short, "timeout");
+    }
+}
@@ -0,0 +1,38 @@
+//! Tracing subscriber setup
+//!
+//! Installs the global `tracing` subscriber in either text or JSON format.
+//! Request IDs reach the output through the `http_request` span created
+//! by the HTTP trace layer, so every event logged while handling a request
+//! carries it.
+
+use crate::infra::config::app_config::{Log, LogFormat};
+use tracing_subscriber::EnvFilter;
+
+/// Install the global tracing subscriber according to the logging configuration
+pub fn init(config: &Log) {
+    let filter =
+        EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(&config.filter));
+
+    match config.format {
+        LogFormat::Text => tracing_subscriber::fmt().with_env_filter(filter).init(),
+        LogFormat::Json => tracing_subscriber::fmt()
+            .json()
+            .flatten_event(true)
+            .with_current_span(true)
+            .with_span_list(false)
+            .with_env_filter(filter)
+            .init(),
+    }
+}
+
+/// Install a text subscriber on stderr, keeping stdout free for command output
+///
+/// Only warnings are shown unless `RUST_LOG` asks for more.
+pub fn init_stderr() {
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("warn"));
+
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .init();
+}
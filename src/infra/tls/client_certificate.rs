@@ -0,0 +1,169 @@
+use crate::domain::user::Role;
+use crate::infra::config::app_config::ServiceIdentity;
+use axum_server::accept::{Accept, DefaultAcceptor};
+use axum_server::tls_rustls::RustlsAcceptor;
+use rustls_pki_types::CertificateDer;
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::str::FromStr;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio_rustls::server::TlsStream;
+use tower_http::add_extension::AddExtension;
+use x509_parser::prelude::{FromDer, X509Certificate};
+
+/// The verified client certificate of the current TLS connection
+///
+/// Inserted into every request served over TLS; `subject` is `None` when the
+/// client did not present a certificate.
+#[derive(Clone, Debug, Default)]
+pub struct ClientCertificate {
+    pub subject: Option<String>,
+}
+
+impl ClientCertificate {
+    fn from_der(cert: &CertificateDer<'_>) -> Self {
+        let subject = X509Certificate::from_der(cert.as_ref())
+            .ok()
+            .map(|(_, cert)| normalize_subject(&cert.subject().to_string()));
+        Self { subject }
+    }
+}
+
+/// Canonical form used to compare subjects: no spaces after separators
+fn normalize_subject(subject: &str) -> String {
+    subject
+        .split(',')
+        .map(str::trim)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Acceptor that performs the TLS handshake and attaches the client certificate
+#[derive(Clone)]
+pub struct ClientCertAcceptor {
+    inner: RustlsAcceptor<DefaultAcceptor>,
+}
+
+impl ClientCertAcceptor {
+    pub fn new(inner: RustlsAcceptor<DefaultAcceptor>) -> Self {
+        Self { inner }
+    }
+}
+
+type AcceptFuture<I, S> = Pin<
+    Box<dyn Future<Output = io::Result<(TlsStream<I>, AddExtension<S, ClientCertificate>)>> + Send>,
+>;
+
+impl<I, S> Accept<I, S> for ClientCertAcceptor
+where
+    I: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+    S: Send + 'static,
+{
+    type Stream = TlsStream<I>;
+    type Service = AddExtension<S, ClientCertificate>;
+    type Future = AcceptFuture<I, S>;
+
+    fn accept(&self, stream: I, service: S) -> Self::Future {
+        let handshake = self.inner.accept(stream, service);
+
+        Box::pin(async move {
+            let (stream, service) = handshake.await?;
+            let certificate = stream
+                .get_ref()
+                .1
+                .peer_certificates()
+                .and_then(|certs| certs.first())
+                .map(ClientCertificate::from_der)
+                .unwrap_or_default();
+
+            Ok((stream, AddExtension::new(service, certificate)))
+        })
+    }
+}
+
+/// Lookup table from client certificate subject to service identity
+#[derive(Clone, Debug, Default)]
+pub struct ServiceIdentities {
+    by_subject: HashMap<String, (String, HashSet<Role>)>,
+}
+
+impl ServiceIdentities {
+    pub fn new(identities: &[ServiceIdentity]) -> Self {
+        let by_subject = identities
+            .iter()
+            .map(|identity| {
+                let roles = identity
+                    .roles
+                    .iter()
+                    .filter_map(|r| Role::from_str(r).ok())
+                    .collect();
+                (
+                    normalize_subject(&identity.subject),
+                    (identity.service.clone(), roles),
+                )
+            })
+            .collect();
+        Self { by_subject }
+    }
+
+    /// Resolve the service name and roles for a certificate subject
+    pub fn resolve(&self, certificate: &ClientCertificate) -> Option<(String, HashSet<Role>)> {
+        certificate
+            .subject
+            .as_ref()
+            .and_then(|subject| self.by_subject.get(subject))
+            .cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn identities() -> ServiceIdentities {
+        ServiceIdentities::new(&[ServiceIdentity {
+            subject: "CN=billing, O=Acme".to_string(),
+            service: "billing".to_string(),
+            roles: vec!["admin".to_string(), "bogus".to_string()],
+        }])
+    }
+
+    #[test]
+    fn test_resolve_known_subject() {
+        let certificate = ClientCertificate {
+            subject: Some("CN=billing,O=Acme".to_string()),
+        };
+
+        let (service, roles) = identities().resolve(&certificate).unwrap();
+        assert_eq!(service, "billing");
+        assert_eq!(roles, HashSet::from([Role::Admin]));
+    }
+
+    #[test]
+    fn test_resolve_unknown_or_missing_subject() {
+        let unknown = ClientCertificate {
+            subject: Some("CN=other".to_string()),
+        };
+        assert!(identities().resolve(&unknown).is_none());
+        assert!(
+            identities()
+                .resolve(&ClientCertificate::default())
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_subject_from_certificate() {
+        let mut params = rcgen::CertificateParams::new(vec!["billing".to_string()]).unwrap();
+        params
+            .distinguished_name
+            .push(rcgen::DnType::CommonName, "billing");
+        let key = rcgen::KeyPair::generate().unwrap();
+        let cert = params.self_signed(&key).unwrap();
+
+        let certificate = ClientCertificate::from_der(cert.der());
+        assert_eq!(certificate.subject.as_deref(), Some("CN=billing"));
+    }
+}
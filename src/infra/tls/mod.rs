@@ -0,0 +1,11 @@
+//! TLS termination
+//!
+//! Builds the rustls server configuration (HTTP/2 and HTTP/1.1 via ALPN,
+//! optional mutual TLS), reloads it when the certificate files change, and
+//! exposes the verified client certificate subject to request handlers.
+
+pub mod client_certificate;
+pub mod server_config;
+
+pub use client_certificate::{ClientCertAcceptor, ClientCertificate, ServiceIdentities};
+pub use server_config::{load_server_config, spawn_reloader};
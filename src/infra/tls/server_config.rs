@@ -0,0 +1,179 @@
+use crate::infra::config::app_config::Tls;
+use crate::infra::lifecycle::Lifecycle;
+use axum_server::tls_rustls::RustlsConfig;
+use rustls::crypto::ring;
+use rustls::server::WebPkiClientVerifier;
+use rustls::{RootCertStore, ServerConfig};
+use rustls_pki_types::pem::PemObject;
+use rustls_pki_types::{CertificateDer, PrivateKeyDer};
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+/// Build a rustls server configuration from the certificate files in `config`
+///
+/// ALPN advertises `h2` before `http/1.1` so capable clients negotiate HTTP/2.
+/// When a client CA is configured, client certificates are verified against
+/// it; anonymous clients are still accepted unless `client_auth_required`.
+pub fn load_server_config(config: &Tls) -> io::Result<ServerConfig> {
+    let provider = Arc::new(ring::default_provider());
+
+    let certs = CertificateDer::pem_file_iter(&config.cert_path)
+        .and_then(|certs| certs.collect::<Result<Vec<_>, _>>())
+        .map_err(|e| pem_error(&config.cert_path, e))?;
+    let key = PrivateKeyDer::from_pem_file(&config.key_path)
+        .map_err(|e| pem_error(&config.key_path, e))?;
+
+    let builder = ServerConfig::builder_with_provider(provider.clone())
+        .with_safe_default_protocol_versions()
+        .map_err(io::Error::other)?;
+
+    let builder = match &config.client_ca_path {
+        Some(ca_path) => {
+            let verifier = WebPkiClientVerifier::builder_with_provider(
+                Arc::new(load_roots(ca_path)?),
+                provider,
+            );
+            let verifier = if config.client_auth_required {
+                verifier
+            } else {
+                verifier.allow_unauthenticated()
+            };
+            builder.with_client_cert_verifier(verifier.build().map_err(io::Error::other)?)
+        }
+        None => builder.with_no_client_auth(),
+    };
+
+    let mut server_config = builder
+        .with_single_cert(certs, key)
+        .map_err(io::Error::other)?;
+    server_config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+
+    Ok(server_config)
+}
+
+fn load_roots(path: &Path) -> io::Result<RootCertStore> {
+    let mut roots = RootCertStore::empty();
+    for cert in CertificateDer::pem_file_iter(path).map_err(|e| pem_error(path, e))? {
+        roots
+            .add(cert.map_err(|e| pem_error(path, e))?)
+            .map_err(io::Error::other)?;
+    }
+    Ok(roots)
+}
+
+fn pem_error(path: &Path, error: rustls_pki_types::pem::Error) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("{}: {}", path.display(), error),
+    )
+}
+
+/// Poll the certificate files and swap in a new configuration when they change
+///
+/// A configuration that fails to load is logged and the previous one is kept,
+/// so a half-written certificate never takes the listener down.
+pub fn spawn_reloader(config: Tls, rustls_config: RustlsConfig, lifecycle: &Lifecycle) {
+    let shutdown = lifecycle.shutdown_token();
+
+    let handle = tokio::spawn(async move {
+        let mut last_modified = modified_times(&config);
+        let mut interval = tokio::time::interval(config.reload_interval);
+
+        loop {
+            tokio::select! {
+                _ = shutdown.cancelled() => break,
+                _ = interval.tick() => {}
+            }
+
+            let modified = modified_times(&config);
+            if modified == last_modified {
+                continue;
+            }
+
+            match load_server_config(&config) {
+                Ok(server_config) => {
+                    rustls_config.reload_from_config(Arc::new(server_config));
+                    last_modified = modified;
+                    tracing::info!("TLS certificates reloaded");
+                }
+                Err(e) => tracing::error!(error = %e, "Failed to reload TLS certificates"),
+            }
+        }
+    });
+
+    lifecycle.register_worker("tls_reloader", handle);
+}
+
+fn modified_times(config: &Tls) -> Vec<Option<SystemTime>> {
+    [
+        Some(config.cert_path.as_path()),
+        Some(config.key_path.as_path()),
+        config.client_ca_path.as_deref(),
+    ]
+    .into_iter()
+    .flatten()
+    .map(|path| std::fs::metadata(path).and_then(|m| m.modified()).ok())
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    fn write_self_signed(dir: &Path) -> (PathBuf, PathBuf) {
+        let cert = rcgen::generate_simple_self_signed(vec!["localhost".to_string()]).unwrap();
+        let cert_path = dir.join("cert.pem");
+        let key_path = dir.join("key.pem");
+        std::fs::write(&cert_path, cert.cert.pem()).unwrap();
+        std::fs::write(&key_path, cert.key_pair.serialize_pem()).unwrap();
+        (cert_path, key_path)
+    }
+
+    fn tls_config(cert_path: PathBuf, key_path: PathBuf) -> Tls {
+        Tls {
+            cert_path,
+            key_path,
+            client_ca_path: None,
+            client_auth_required: false,
+            client_identities: Vec::new(),
+            reload_interval: Duration::from_secs(30),
+        }
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("mini-rust-api-{}-{}", name, std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_load_server_config_advertises_h2() {
+        let dir = temp_dir("tls-h2");
+        let (cert_path, key_path) = write_self_signed(&dir);
+
+        let server_config = load_server_config(&tls_config(cert_path, key_path)).unwrap();
+        assert_eq!(server_config.alpn_protocols[0], b"h2".to_vec());
+    }
+
+    #[test]
+    fn test_load_server_config_with_client_ca() {
+        let dir = temp_dir("tls-mtls");
+        let (cert_path, key_path) = write_self_signed(&dir);
+
+        let mut config = tls_config(cert_path.clone(), key_path);
+        config.client_ca_path = Some(cert_path);
+        assert!(load_server_config(&config).is_ok());
+    }
+
+    #[test]
+    fn test_load_server_config_missing_file() {
+        let dir = temp_dir("tls-missing");
+        let config = tls_config(dir.join("nope.pem"), dir.join("nope.key"));
+        assert!(load_server_config(&config).is_err());
+    }
+}
@@ -0,0 +1,68 @@
+//! Outbound webhooks
+//!
+//! Domain events are queued as deliveries to every matching webhook by the
+//! outbox relay, in the transaction that marks them published, then POSTed
+//! by a background worker. Each request is
+//! signed so partners can check it came from us and is recent:
+//!
+//! ```text
+//! Webhook-Id: <event id, identical across retries>
+//! Webhook-Timestamp: <unix seconds>
+//! Webhook-Signature: t=<unix seconds>,v1=<hex HMAC-SHA256(secret, "<t>.<body>")>
+//! ```
+
+pub mod sea_orm_webhook_store;
+pub mod webhook_delivery_worker;
+
+pub use sea_orm_webhook_store::SeaOrmWebhookStore;
+pub use webhook_delivery_worker::WebhookDeliveryWorker;
+
+use crate::infra::lifecycle::Lifecycle;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::sync::Arc;
+
+/// `Webhook-Signature` value for a body sent at `timestamp`
+pub fn sign(secret: &str, timestamp: i64, body: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    format!("t={},v1={:x}", timestamp, mac.finalize().into_bytes())
+}
+
+/// Send due webhook deliveries every poll interval until shutdown
+pub fn spawn_delivery_worker(worker: Arc<WebhookDeliveryWorker>, lifecycle: &Lifecycle) {
+    let shutdown = lifecycle.shutdown_token();
+
+    let handle = tokio::spawn(async move {
+        let mut interval = tokio::time::interval(worker.poll_interval());
+
+        loop {
+            tokio::select! {
+                _ = shutdown.cancelled() => break,
+                _ = interval.tick() => {}
+            }
+
+            if let Err(e) = worker.run_once().await {
+                tracing::warn!(error = %e, "Webhook delivery failed");
+            }
+        }
+    });
+
+    lifecycle.register_worker("webhook_delivery", handle);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signature() {
+        assert_eq!(
+            sign("whsec_test", 1_700_000_000, br#"{"a":1}"#),
+            "t=1700000000,v1=38877139021993b830af32feea6e18a8da83eb2f6e49ee50bd9e4cf4ca4d3789"
+        );
+    }
+}
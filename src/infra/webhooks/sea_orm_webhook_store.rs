@@ -0,0 +1,216 @@
+use crate::app::ports::{DeliveryStatus, NewWebhook, Webhook, WebhookDelivery, WebhookStore};
+use crate::domain::user::repository::RepositoryError;
+use crate::infra::persistence::entities::webhook_deliveries::{
+    self, Entity as WebhookDeliveriesEntity,
+};
+use crate::infra::persistence::entities::webhooks::{self, Entity as WebhooksEntity};
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder, QuerySelect, Set,
+};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// SeaORM implementation of WebhookStore
+pub struct SeaOrmWebhookStore {
+    db: Arc<sea_orm::DatabaseConnection>,
+}
+
+impl SeaOrmWebhookStore {
+    pub fn new(db: Arc<sea_orm::DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    /// Queue a delivery of the event to every webhook that wants it, on `db`
+    /// so the outbox relay can do it in the transaction that marks the event
+    /// published; returns how many were queued
+    pub(crate) async fn enqueue_in(
+        db: &impl ConnectionTrait,
+        event_type: &str,
+        payload: serde_json::Value,
+    ) -> Result<u64, RepositoryError> {
+        let event_id = uuid::Uuid::new_v4();
+        let now = Utc::now();
+
+        let deliveries: Vec<_> = WebhooksEntity::find()
+            .order_by_asc(webhooks::Column::Id)
+            .all(db)
+            .await
+            .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?
+            .into_iter()
+            .map(to_webhook)
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .filter(|webhook| webhook.wants(event_type))
+            .map(|webhook| webhook_deliveries::ActiveModel {
+                webhook_id: Set(webhook.id),
+                event_id: Set(event_id),
+                event_type: Set(event_type.to_string()),
+                payload: Set(payload.clone()),
+                status: Set(DeliveryStatus::Pending.as_str().to_string()),
+                next_attempt_at: Set(now.into()),
+                created_at: Set(now.into()),
+                ..Default::default()
+            })
+            .collect();
+
+        let queued = deliveries.len() as u64;
+        if queued > 0 {
+            WebhookDeliveriesEntity::insert_many(deliveries)
+                .exec(db)
+                .await
+                .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?;
+        }
+
+        Ok(queued)
+    }
+}
+
+/// Convert a SeaORM model to a stored webhook
+pub(super) fn to_webhook(model: webhooks::Model) -> Result<Webhook, RepositoryError> {
+    let events = serde_json::from_value(model.events)
+        .map_err(|e| RepositoryError::PersistenceFailure(format!("Invalid events: {}", e)))?;
+
+    Ok(Webhook {
+        id: model.id,
+        url: model.url,
+        events,
+        secret: model.secret,
+        created_at: model.created_at.to_utc(),
+    })
+}
+
+/// Convert a SeaORM model to a delivery log entry
+pub(super) fn to_delivery(
+    model: webhook_deliveries::Model,
+) -> Result<WebhookDelivery, RepositoryError> {
+    let status =
+        DeliveryStatus::from_str(&model.status).map_err(RepositoryError::PersistenceFailure)?;
+
+    Ok(WebhookDelivery {
+        id: model.id,
+        webhook_id: model.webhook_id,
+        event_id: model.event_id,
+        event_type: model.event_type,
+        payload: model.payload,
+        status,
+        attempts: model.attempts,
+        last_status_code: model.last_status_code.map(|code| code as u16),
+        last_error: model.last_error,
+        next_attempt_at: model.next_attempt_at.to_utc(),
+        created_at: model.created_at.to_utc(),
+        delivered_at: model.delivered_at.map(|at| at.to_utc()),
+    })
+}
+
+#[async_trait]
+impl WebhookStore for SeaOrmWebhookStore {
+    async fn create(&self, webhook: NewWebhook) -> Result<Webhook, RepositoryError> {
+        let model = webhooks::ActiveModel {
+            url: Set(webhook.url),
+            events: Set(serde_json::json!(webhook.events)),
+            secret: Set(webhook.secret),
+            created_at: Set(Utc::now().into()),
+            ..Default::default()
+        }
+        .insert(self.db.as_ref())
+        .await
+        .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?;
+
+        to_webhook(model)
+    }
+
+    async fn list(&self) -> Result<Vec<Webhook>, RepositoryError> {
+        WebhooksEntity::find()
+            .order_by_asc(webhooks::Column::Id)
+            .all(self.db.as_ref())
+            .await
+            .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?
+            .into_iter()
+            .map(to_webhook)
+            .collect()
+    }
+
+    async fn delete(&self, id: i32) -> Result<bool, RepositoryError> {
+        let result = WebhooksEntity::delete_by_id(id)
+            .exec(self.db.as_ref())
+            .await
+            .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn enqueue(
+        &self,
+        event_type: &str,
+        payload: serde_json::Value,
+    ) -> Result<u64, RepositoryError> {
+        Self::enqueue_in(self.db.as_ref(), event_type, payload).await
+    }
+
+    async fn deliveries(
+        &self,
+        webhook_id: i32,
+        page: u64,
+        rows_per_page: u64,
+    ) -> Result<Option<(Vec<WebhookDelivery>, u64)>, RepositoryError> {
+        let exists = WebhooksEntity::find_by_id(webhook_id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?
+            .is_some();
+        if !exists {
+            return Ok(None);
+        }
+
+        let query = WebhookDeliveriesEntity::find()
+            .filter(webhook_deliveries::Column::WebhookId.eq(webhook_id));
+
+        let total = query
+            .clone()
+            .count(self.db.as_ref())
+            .await
+            .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?;
+
+        let deliveries = query
+            .order_by_desc(webhook_deliveries::Column::Id)
+            .offset(page.saturating_sub(1) * rows_per_page)
+            .limit(rows_per_page)
+            .all(self.db.as_ref())
+            .await
+            .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?
+            .into_iter()
+            .map(to_delivery)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Some((deliveries, total)))
+    }
+
+    async fn redeliver(
+        &self,
+        webhook_id: i32,
+        delivery_id: i64,
+    ) -> Result<Option<WebhookDelivery>, RepositoryError> {
+        let Some(model) = WebhookDeliveriesEntity::find_by_id(delivery_id)
+            .filter(webhook_deliveries::Column::WebhookId.eq(webhook_id))
+            .one(self.db.as_ref())
+            .await
+            .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?
+        else {
+            return Ok(None);
+        };
+
+        let mut delivery: webhook_deliveries::ActiveModel = model.into();
+        delivery.status = Set(DeliveryStatus::Pending.as_str().to_string());
+        delivery.attempts = Set(0);
+        delivery.next_attempt_at = Set(Utc::now().into());
+        let model = delivery
+            .update(self.db.as_ref())
+            .await
+            .map_err(|e| RepositoryError::PersistenceFailure(e.to_string()))?;
+
+        to_delivery(model).map(Some)
+    }
+}
@@ -0,0 +1,331 @@
+use super::sea_orm_webhook_store::{to_delivery, to_webhook};
+use super::sign;
+use crate::app::ports::{DeliveryStatus, Webhook, WebhookDelivery};
+use crate::infra::config::app_config::Webhooks;
+use crate::infra::persistence::entities::webhook_deliveries::{
+    self, Entity as WebhookDeliveriesEntity,
+};
+use crate::infra::persistence::entities::webhooks::Entity as WebhooksEntity;
+use crate::infra::retry::{backoff, truncate_error};
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DbBackend, EntityTrait, QueryFilter, Set,
+    Statement,
+};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Lease due deliveries so concurrent workers never send the same one twice
+const CLAIM_SQL: &str = r#"
+UPDATE webhook_deliveries
+SET next_attempt_at = now() + make_interval(secs => $2)
+WHERE id IN (
+    SELECT id FROM webhook_deliveries
+    WHERE status = 'pending' AND next_attempt_at <= now()
+    ORDER BY next_attempt_at, id
+    LIMIT $1
+    FOR UPDATE SKIP LOCKED
+)
+RETURNING id
+"#;
+
+/// Longest error text kept in the delivery log
+const MAX_ERROR_LEN: usize = 500;
+
+/// Outcome of one HTTP attempt
+enum Attempt {
+    Delivered(u16),
+    Failed(Option<u16>, String),
+}
+
+/// Sends queued webhook deliveries
+///
+/// A delivery succeeds on any 2xx response. Other responses, timeouts and
+/// connection errors are retried with exponential backoff; after
+/// `max_attempts` the delivery is marked dead and waits for a manual
+/// redelivery.
+pub struct WebhookDeliveryWorker {
+    db: Arc<sea_orm::DatabaseConnection>,
+    client: reqwest::Client,
+    config: Webhooks,
+}
+
+impl WebhookDeliveryWorker {
+    pub fn new(db: Arc<sea_orm::DatabaseConnection>, config: Webhooks) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(config.request_timeout)
+            .redirect(reqwest::redirect::Policy::none())
+            .user_agent(concat!(
+                "mini-rust-api-webhooks/",
+                env!("CARGO_PKG_VERSION")
+            ))
+            .build()
+            .expect("webhook HTTP client configuration is valid");
+
+        Self { db, client, config }
+    }
+
+    pub fn poll_interval(&self) -> Duration {
+        self.config.poll_interval
+    }
+
+    /// Send every delivery that is due
+    pub async fn run_once(&self) -> Result<(), String> {
+        while self.deliver_batch().await? == self.config.batch_size {}
+        Ok(())
+    }
+
+    /// Claim and send one batch, returning how many deliveries were claimed
+    async fn deliver_batch(&self) -> Result<u64, String> {
+        // Keep the lease long enough to outlast the HTTP timeout
+        let lease = self.config.request_timeout * 2;
+        let claimed: Vec<i64> = self
+            .db
+            .query_all(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                CLAIM_SQL,
+                [
+                    (self.config.batch_size as i64).into(),
+                    lease.as_secs_f64().into(),
+                ],
+            ))
+            .await
+            .map_err(|e| e.to_string())?
+            .iter()
+            .map(|row| row.try_get::<i64>("", "id"))
+            .collect::<Result<_, _>>()
+            .map_err(|e| e.to_string())?;
+        if claimed.is_empty() {
+            return Ok(0);
+        }
+
+        let rows = WebhookDeliveriesEntity::find()
+            .filter(webhook_deliveries::Column::Id.is_in(claimed.iter().copied()))
+            .find_also_related(WebhooksEntity)
+            .all(self.db.as_ref())
+            .await
+            .map_err(|e| e.to_string())?;
+
+        for (delivery, webhook) in rows {
+            // The webhook was deleted along with its deliveries in the meantime
+            let Some(webhook) = webhook else { continue };
+            let model = delivery.clone();
+            let delivery = to_delivery(delivery).map_err(|e| e.to_string())?;
+            let webhook = to_webhook(webhook).map_err(|e| e.to_string())?;
+
+            let attempt = self.send(&webhook, &delivery).await;
+            self.record(model, attempt).await?;
+        }
+
+        Ok(claimed.len() as u64)
+    }
+
+    async fn send(&self, webhook: &Webhook, delivery: &WebhookDelivery) -> Attempt {
+        let body = serde_json::json!({
+            "id": delivery.event_id,
+            "type": delivery.event_type,
+            "createdAt": delivery.created_at.to_rfc3339(),
+            "data": delivery.payload,
+        })
+        .to_string();
+        let timestamp = Utc::now().timestamp();
+
+        let result = self
+            .client
+            .post(&webhook.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header("webhook-id", delivery.event_id.to_string())
+            .header("webhook-timestamp", timestamp.to_string())
+            .header(
+                "webhook-signature",
+                sign(&webhook.secret, timestamp, body.as_bytes()),
+            )
+            .body(body)
+            .send()
+            .await;
+
+        match result {
+            Ok(response) if response.status().is_success() => {
+                Attempt::Delivered(response.status().as_u16())
+            }
+            Ok(response) => {
+                let status = response.status();
+                Attempt::Failed(Some(status.as_u16()), format!("HTTP {}", status))
+            }
+            Err(e) => Attempt::Failed(None, e.to_string()),
+        }
+    }
+
+    async fn record(
+        &self,
+        model: webhook_deliveries::Model,
+        attempt: Attempt,
+    ) -> Result<(), String> {
+        let attempts = model.attempts + 1;
+        let id = model.id;
+        let mut update: webhook_deliveries::ActiveModel = model.into();
+        update.attempts = Set(attempts);
+
+        match attempt {
+            Attempt::Delivered(status) => {
+                update.status = Set(DeliveryStatus::Delivered.as_str().to_string());
+                update.last_status_code = Set(Some(status as i32));
+                update.last_error = Set(None);
+                update.delivered_at = Set(Some(Utc::now().into()));
+            }
+            Attempt::Failed(status, mut error) => {
+                truncate_error(&mut error, MAX_ERROR_LEN);
+                update.last_status_code = Set(status.map(i32::from));
+                update.last_error = Set(Some(error.clone()));
+
+                if attempts >= self.config.max_attempts as i32 {
+                    tracing::warn!(id, attempts, error = %error, "Webhook delivery is dead");
+                    update.status = Set(DeliveryStatus::Dead.as_str().to_string());
+                } else {
+                    let delay = backoff(
+                        attempts as u32,
+                        self.config.retry_base,
+                        self.config.retry_max,
+                    );
+                    tracing::debug!(id, attempts, error = %error, "Webhook delivery will be retried");
+                    update.next_attempt_at = Set((Utc::now() + delay).into());
+                }
+            }
+        }
+
+        update
+            .update(self.db.as_ref())
+            .await
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::ports::{NewWebhook, WebhookStore};
+    use crate::infra::webhooks::SeaOrmWebhookStore;
+    use axum::{
+        Router,
+        body::Bytes,
+        extract::State,
+        http::{HeaderMap, StatusCode},
+        routing::post,
+    };
+    use std::sync::Mutex;
+
+    type Received = Arc<Mutex<Vec<(HeaderMap, Bytes)>>>;
+
+    /// Local HTTP receiver answering every request with `status`
+    async fn receiver(status: StatusCode) -> (String, Received) {
+        let received = Received::default();
+        let app = Router::new()
+            .route(
+                "/hook",
+                post(
+                    move |State(received): State<Received>, headers: HeaderMap, body: Bytes| async move {
+                        received.lock().unwrap().push((headers, body));
+                        status
+                    },
+                ),
+            )
+            .with_state(received.clone());
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        (url, received)
+    }
+
+    fn config(max_attempts: u32) -> Webhooks {
+        Webhooks {
+            poll_interval: Duration::from_secs(1),
+            batch_size: 100,
+            request_timeout: Duration::from_secs(5),
+            max_attempts,
+            retry_base: Duration::ZERO,
+            retry_max: Duration::ZERO,
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated database (docker-compose up postgresql)"]
+    async fn test_signed_delivery_retry_and_dead_letter() {
+        let db = Arc::new(crate::infra::config::database::connect().await.unwrap());
+        let store = SeaOrmWebhookStore::new(db.clone());
+        // Unique event name so deliveries queued by other tests are not involved
+        let event_type = format!("test.{}", uuid::Uuid::new_v4().simple());
+
+        let (ok_url, ok_received) = receiver(StatusCode::NO_CONTENT).await;
+        let (failing_url, _) = receiver(StatusCode::INTERNAL_SERVER_ERROR).await;
+        let ok = store
+            .create(NewWebhook {
+                url: ok_url,
+                events: vec![event_type.clone()],
+                secret: "whsec_test_secret".to_string(),
+            })
+            .await
+            .unwrap();
+        let failing = store
+            .create(NewWebhook {
+                url: failing_url,
+                events: vec!["test.*".to_string()],
+                secret: "whsec_other_secret".to_string(),
+            })
+            .await
+            .unwrap();
+
+        let queued = store
+            .enqueue(&event_type, serde_json::json!({ "userId": 1 }))
+            .await
+            .unwrap();
+        assert!(queued >= 2);
+
+        let worker = WebhookDeliveryWorker::new(db.clone(), config(2));
+        worker.run_once().await.unwrap();
+
+        // The receiver got a verifiable signature over the exact body
+        let (headers, body) = ok_received.lock().unwrap()[0].clone();
+        let timestamp: i64 = headers["webhook-timestamp"]
+            .to_str()
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert_eq!(
+            headers["webhook-signature"],
+            sign("whsec_test_secret", timestamp, &body)
+        );
+        let document: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(document["type"], event_type.as_str());
+        assert_eq!(document["data"]["userId"], 1);
+
+        let (log, _) = store.deliveries(ok.id, 1, 10).await.unwrap().unwrap();
+        assert_eq!(log[0].status, DeliveryStatus::Delivered);
+        assert_eq!(log[0].last_status_code, Some(204));
+
+        // The failing endpoint is retried, then dead-lettered
+        let (log, _) = store.deliveries(failing.id, 1, 10).await.unwrap().unwrap();
+        assert_eq!(log[0].status, DeliveryStatus::Pending);
+        assert_eq!(log[0].attempts, 1);
+        worker.run_once().await.unwrap();
+        let (log, _) = store.deliveries(failing.id, 1, 10).await.unwrap().unwrap();
+        assert_eq!(log[0].status, DeliveryStatus::Dead);
+        assert_eq!(
+            log[0].last_error.as_deref(),
+            Some("HTTP 500 Internal Server Error")
+        );
+
+        // A manual redelivery starts over
+        let redelivered = store
+            .redeliver(failing.id, log[0].id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(redelivered.status, DeliveryStatus::Pending);
+        assert_eq!(redelivered.attempts, 0);
+
+        assert!(store.delete(ok.id).await.unwrap());
+        assert!(store.delete(failing.id).await.unwrap());
+    }
+}
@@ -1,47 +1,195 @@
+use axum::http::header;
 use axum::{Router, middleware};
-use mini_rust_api::infra::Config;
-use mini_rust_api::presentation::api::{auth_routes, health_routes, user_routes};
-use mini_rust_api::presentation::middleware::{auth_middleware, cors_layer};
+use axum_server::Handle;
+use axum_server::tls_rustls::{RustlsAcceptor, RustlsConfig};
+use clap::Parser;
+use mini_rust_api::infra::config::app_config::{Server, Tls};
+use mini_rust_api::infra::lifecycle::{Lifecycle, shutdown_signal};
+use mini_rust_api::infra::tls::{ClientCertAcceptor, load_server_config, spawn_reloader};
+use mini_rust_api::infra::{Config, telemetry};
+use mini_rust_api::presentation::api::{
+    auth_routes, cache_routes, group_routes, health_routes, invitation_accept_routes,
+    invitation_routes, job_routes, organization_routes, policy_routes, user_routes, webhook_routes,
+};
+use mini_rust_api::presentation::cli::{Cli, Command, admin};
+use mini_rust_api::presentation::middleware::request_id::{LogRequestHeaders, RequestIdMakeSpan};
+use mini_rust_api::presentation::middleware::{
+    auth_middleware, cors_layer, harden_responses, idempotency_middleware, limit_requests,
+    rate_limit_middleware, request_id_middleware,
+};
 use mini_rust_api::presentation::openapi::ApiDoc;
+use std::net::SocketAddr;
+use std::process::ExitCode;
+use std::sync::Arc;
+use std::time::Duration;
+use tower_http::sensitive_headers::{
+    SetSensitiveRequestHeadersLayer, SetSensitiveResponseHeadersLayer,
+};
+use tower_http::trace::TraceLayer;
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
 #[tokio::main]
-async fn main() {
-    tracing_subscriber::fmt::init();
-
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
     let config = Config::from_env();
 
+    match cli.command {
+        None | Some(Command::Serve) => {
+            serve(config).await;
+            ExitCode::SUCCESS
+        }
+        Some(Command::Admin { command }) => {
+            telemetry::init_stderr();
+            admin::run(command, config).await
+        }
+    }
+}
+
+/// Run the HTTP server until a shutdown signal, then stop background workers
+async fn serve(config: Config) {
+    telemetry::init(&config.log);
+
     // Bootstrap: wire up all dependencies
     let state = mini_rust_api::create_app_state(config.clone())
         .await
         .expect("Failed to bootstrap application");
 
-    // Build the HTTP router
-    let app = Router::new()
-        .merge(auth_routes())
+    let lifecycle = state.lifecycle.clone();
+    let rate_limit =
+        middleware::from_fn_with_state(state.rate_limiter.clone(), rate_limit_middleware);
+    let idempotency =
+        middleware::from_fn_with_state(state.idempotency_guard.clone(), idempotency_middleware);
+    let auth = middleware::from_fn_with_state(state.clone(), auth_middleware);
+
+    // Rate limits and idempotency sit inside auth so they can see the caller;
+    // replayed retries still count against the rate limit
+    let public_api = auth_routes()
+        .merge(invitation_accept_routes())
+        .route_layer(idempotency.clone())
+        .route_layer(rate_limit.clone());
+    let protected_api = user_routes()
+        .merge(organization_routes())
+        .merge(invitation_routes())
+        .merge(group_routes())
+        .merge(policy_routes())
+        .merge(webhook_routes())
+        .merge(job_routes())
+        .merge(cache_routes())
+        .route_layer(idempotency)
+        .route_layer(rate_limit)
+        .route_layer(auth);
+
+    let routes = Router::new()
+        .merge(public_api)
         .merge(health_routes())
         .merge(SwaggerUi::new("/api-docs").url("/api-docs/openapi.json", ApiDoc::openapi()))
-        .merge(user_routes().route_layer(middleware::from_fn_with_state(
-            state.clone(),
-            auth_middleware,
-        )))
-        .layer(cors_layer())
+        .merge(protected_api);
+
+    // Build the HTTP router
+    // Timeouts and body limits run inside the request ID middleware so their
+    // errors carry the request ID; compression runs outside it so error
+    // bodies are still plain JSON when the ID is attached
+    let app = limit_requests(routes, &config.http)
+        .layer(
+            TraceLayer::new_for_http()
+                .make_span_with(RequestIdMakeSpan)
+                .on_request(LogRequestHeaders),
+        )
+        .layer(SetSensitiveRequestHeadersLayer::new([
+            header::AUTHORIZATION,
+            header::COOKIE,
+        ]))
+        .layer(SetSensitiveResponseHeadersLayer::new([header::SET_COOKIE]))
+        .layer(middleware::from_fn(request_id_middleware));
+    let app = harden_responses(app, &config.http, config.tls.is_some())
+        .layer(cors_layer(&config.cors))
         .with_state(state);
 
     // Start the server
-    let listener =
-        tokio::net::TcpListener::bind(format!("{}:{}", config.server.host, config.server.port))
-            .await
-            .expect("Failed to bind to address");
-
-    tracing::info!(
-        "Server is running on: http://{}:{}",
-        config.server.host,
-        config.server.port
-    );
-
-    axum::serve(listener, app)
+    let address = format!("{}:{}", config.server.host, config.server.port);
+    match config.tls.clone() {
+        Some(tls) => serve_tls(app, &address, tls, &config.server, &lifecycle).await,
+        None => serve_http(app, &address, &config.server, &lifecycle).await,
+    }
+
+    // Stop background workers, then close the database pool
+    lifecycle.close(config.server.drain_timeout).await;
+
+    tracing::info!("Shutdown complete");
+}
+
+/// Resolves once a shutdown signal has been received and the pre-drain delay
+/// has passed; readiness reports draining for the whole delay
+async fn drain_signal(lifecycle: Arc<Lifecycle>, pre_drain_delay: Duration) {
+    shutdown_signal().await;
+    lifecycle.begin_drain();
+    tokio::time::sleep(pre_drain_delay).await;
+    tracing::info!("No longer accepting connections");
+}
+
+/// Serve plain HTTP until a shutdown signal, then drain in-flight requests
+async fn serve_http(app: Router, address: &str, server: &Server, lifecycle: &Arc<Lifecycle>) {
+    let listener = tokio::net::TcpListener::bind(address)
+        .await
+        .expect("Failed to bind to address");
+
+    tracing::info!("Server is running on: http://{}", address);
+
+    // Stop accepting connections after the pre-drain delay and let in-flight requests finish
+    let serving = axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .with_graceful_shutdown(drain_signal(lifecycle.clone(), server.pre_drain_delay));
+
+    let drain_deadline = async {
+        lifecycle.draining().await;
+        tokio::time::sleep(server.pre_drain_delay + server.drain_timeout).await;
+    };
+
+    tokio::select! {
+        result = serving => result.expect("Server failed unexpectedly"),
+        _ = drain_deadline => tracing::warn!("Drain timeout elapsed, dropping remaining connections"),
+    }
+}
+
+/// Serve HTTPS (HTTP/2 or HTTP/1.1 via ALPN) with certificate hot reload
+async fn serve_tls(
+    app: Router,
+    address: &str,
+    tls: Tls,
+    server: &Server,
+    lifecycle: &Arc<Lifecycle>,
+) {
+    let server_config = load_server_config(&tls).expect("Failed to load TLS certificates");
+    let rustls_config = RustlsConfig::from_config(Arc::new(server_config));
+    spawn_reloader(tls, rustls_config.clone(), lifecycle);
+
+    let listener = std::net::TcpListener::bind(address).expect("Failed to bind to address");
+    listener
+        .set_nonblocking(true)
+        .expect("Failed to configure listener");
+
+    tracing::info!("Server is running on: https://{}", address);
+
+    // Stop accepting connections after the pre-drain delay; connections still
+    // open after the drain timeout are closed by the handle
+    let handle = Handle::new();
+    tokio::spawn({
+        let handle = handle.clone();
+        let drained = drain_signal(lifecycle.clone(), server.pre_drain_delay);
+        let drain_timeout = server.drain_timeout;
+        async move {
+            drained.await;
+            handle.graceful_shutdown(Some(drain_timeout));
+        }
+    });
+
+    axum_server::from_tcp(listener)
+        .acceptor(ClientCertAcceptor::new(RustlsAcceptor::new(rustls_config)))
+        .handle(handle)
+        .serve(app.into_make_service_with_connect_info::<SocketAddr>())
         .await
         .expect("Server failed unexpectedly");
 }
@@ -7,6 +7,7 @@ use axum::{Json, Router, extract::State, routing::post};
 use crate::app::ApplicationError;
 use crate::app::auth::{AuthToken, LoginCommand, RegisterCommand};
 use crate::app::user::UserResponse;
+use crate::presentation::extractors::ValidatedJson;
 use crate::presentation::responses::ApiResponse;
 use crate::presentation::state::AppState;
 
@@ -18,13 +19,16 @@ use crate::presentation::state::AppState;
     responses(
         (status = 200, description = "Login successful", body = ApiResponse<AuthToken>),
         (status = 401, description = "Invalid credentials"),
-        (status = 422, description = "Validation error")
+        (status = 403, description = "Not a member of the requested organization"),
+        (status = 404, description = "Requested organization not found"),
+        (status = 422, description = "Validation error"),
+        (status = 503, description = "Too many password checks in progress; retry after Retry-After")
     ),
     tag = "auth"
 )]
 pub async fn login(
     State(state): State<AppState>,
-    Json(command): Json<LoginCommand>,
+    ValidatedJson(command): ValidatedJson<LoginCommand>,
 ) -> Result<Json<ApiResponse<AuthToken>>, ApplicationError> {
     let auth_token = state.login_use_case.execute(command).await?;
     Ok(Json(ApiResponse::ok(auth_token)))
@@ -35,16 +39,21 @@ pub async fn login(
     post,
     path = "/register",
     request_body = RegisterCommand,
+    params(
+        ("Idempotency-Key" = Option<String>, Header, description = "Replays the original response when a request is retried with the same key and body")
+    ),
     responses(
         (status = 200, description = "User registered successfully", body = ApiResponse<UserResponse>),
+        (status = 409, description = "A request with the same Idempotency-Key and body is in progress"),
         (status = 422, description = "Validation error"),
-        (status = 400, description = "User already exists")
+        (status = 400, description = "User already exists"),
+        (status = 503, description = "Too many password checks in progress; retry after Retry-After")
     ),
     tag = "auth"
 )]
 pub async fn register(
     State(state): State<AppState>,
-    Json(command): Json<RegisterCommand>,
+    ValidatedJson(command): ValidatedJson<RegisterCommand>,
 ) -> Result<Json<ApiResponse<UserResponse>>, ApplicationError> {
     let user = state.register_use_case.execute(command).await?;
     Ok(Json(ApiResponse::ok(user)))
@@ -0,0 +1,38 @@
+//! Cache API handlers
+//!
+//! Admin endpoint reporting cache hit and miss counters.
+
+use axum::{Json, Router, extract::State, routing::get};
+
+use crate::app::ApplicationError;
+use crate::app::CallerContext;
+use crate::app::cache::CacheStatsResponse;
+use crate::presentation::responses::ApiResponse;
+use crate::presentation::state::AppState;
+
+/// Create cache routes
+pub fn cache_routes() -> Router<AppState> {
+    Router::new().route("/cache/stats", get(get_cache_stats))
+}
+
+/// Hit and miss counters of each cached lookup since startup
+#[utoipa::path(
+    get,
+    path = "/cache/stats",
+    responses(
+        (status = 200, description = "Cache counters; empty when caching is disabled", body = ApiResponse<Vec<CacheStatsResponse>>),
+        (status = 401, description = "Unauthorized - Valid JWT token required"),
+        (status = 403, description = "Forbidden - Admin role required")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "cache"
+)]
+pub async fn get_cache_stats(
+    State(state): State<AppState>,
+    caller: CallerContext,
+) -> Result<Json<ApiResponse<Vec<CacheStatsResponse>>>, ApplicationError> {
+    let stats = state.get_cache_stats_use_case.execute(&caller)?;
+    Ok(Json(ApiResponse::ok(stats)))
+}
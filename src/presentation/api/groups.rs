@@ -0,0 +1,326 @@
+//! Group API handlers
+//!
+//! Admins gather users of their tenant into groups. A group granting
+//! `view_members` lets its members view each other's profiles.
+
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    routing::{get, patch, put},
+};
+use uuid::Uuid;
+
+use crate::app::ApplicationError;
+use crate::app::CallerContext;
+use crate::app::group::{CreateGroupCommand, GroupResponse, ListGroupsQuery, UpdateGroupCommand};
+use crate::app::user::UserResponse;
+use crate::domain::shared::{GroupId, PublicUserId};
+use crate::presentation::extractors::{ValidatedJson, ValidatedPagination};
+use crate::presentation::responses::{ApiErrorResponse, ApiResponse, PaginationRequest};
+use crate::presentation::state::AppState;
+
+/// Create group routes
+pub fn group_routes() -> Router<AppState> {
+    Router::new()
+        .route("/groups", get(list_groups).post(create_group))
+        .route("/groups/{id}", patch(update_group).delete(delete_group))
+        .route("/groups/{id}/members", get(list_group_members))
+        .route(
+            "/groups/{id}/members/{user_id}",
+            put(add_group_member).delete(remove_group_member),
+        )
+        .route("/users/{id}/groups", get(list_user_groups))
+}
+
+/// List groups by name
+#[utoipa::path(
+    get,
+    path = "/groups",
+    params(
+        ("page" = Option<u32>, Query, description = "Page number (default: 1)"),
+        ("rowsPerPage" = Option<u32>, Query, description = "Number of items per page (default: 10)"),
+        ("X-Organization" = Option<String>, Header, description = "Slug of the organization to act in; only its groups are visible")
+    ),
+    responses(
+        (status = 200, description = "List of groups", body = ApiResponse<Vec<GroupResponse>>),
+        (status = 401, description = "Unauthorized - Valid JWT token required"),
+        (status = 403, description = "Forbidden - Admin role required")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "groups"
+)]
+pub async fn list_groups(
+    State(state): State<AppState>,
+    caller: CallerContext,
+    ValidatedPagination(pagination): ValidatedPagination<PaginationRequest>,
+) -> Result<Json<ApiResponse<Vec<GroupResponse>>>, ApplicationError> {
+    let page = pagination.page;
+    let rows_per_page = pagination.rows_per_page;
+
+    let query = ListGroupsQuery {
+        page: page as u64,
+        rows_per_page: rows_per_page as u64,
+    };
+
+    let (groups, total) = state.list_groups_use_case.execute(query, &caller).await?;
+
+    Ok(Json(ApiResponse::with_pagination(
+        groups,
+        total,
+        rows_per_page,
+        page,
+    )))
+}
+
+/// Create a group
+#[utoipa::path(
+    post,
+    path = "/groups",
+    request_body = CreateGroupCommand,
+    params(
+        ("X-Organization" = Option<String>, Header, description = "Slug of the organization to create the group in"),
+        ("Idempotency-Key" = Option<String>, Header, description = "Replays the original response when a request is retried with the same key")
+    ),
+    responses(
+        (status = 200, description = "Group created", body = ApiResponse<GroupResponse>),
+        (status = 400, description = "Empty name", body = ApiErrorResponse),
+        (status = 409, description = "Name taken, or a request with the same Idempotency-Key is in progress", body = ApiErrorResponse),
+        (status = 422, description = "Unknown grant, validation error or Idempotency-Key reused", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized - Valid JWT token required"),
+        (status = 403, description = "Forbidden - Admin role required")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "groups"
+)]
+pub async fn create_group(
+    State(state): State<AppState>,
+    caller: CallerContext,
+    ValidatedJson(command): ValidatedJson<CreateGroupCommand>,
+) -> Result<Json<ApiResponse<GroupResponse>>, ApplicationError> {
+    let group = state
+        .create_group_use_case
+        .execute(command, &caller)
+        .await?;
+    Ok(Json(ApiResponse::ok(group)))
+}
+
+/// Rename a group or replace its grants
+#[utoipa::path(
+    patch,
+    path = "/groups/{id}",
+    request_body = UpdateGroupCommand,
+    params(
+        ("id" = String, Path, description = "Group ID (UUID)"),
+        ("X-Organization" = Option<String>, Header, description = "Slug of the organization to act in"),
+        ("Idempotency-Key" = Option<String>, Header, description = "Replays the original response when a request is retried with the same key")
+    ),
+    responses(
+        (status = 200, description = "Group updated", body = ApiResponse<GroupResponse>),
+        (status = 400, description = "Empty name", body = ApiErrorResponse),
+        (status = 404, description = "Group not found", body = ApiErrorResponse),
+        (status = 409, description = "Name taken, or a request with the same Idempotency-Key is in progress", body = ApiErrorResponse),
+        (status = 422, description = "Unknown grant, validation error or Idempotency-Key reused", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized - Valid JWT token required"),
+        (status = 403, description = "Forbidden - Admin role required")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "groups"
+)]
+pub async fn update_group(
+    State(state): State<AppState>,
+    caller: CallerContext,
+    Path(id): Path<Uuid>,
+    ValidatedJson(command): ValidatedJson<UpdateGroupCommand>,
+) -> Result<Json<ApiResponse<GroupResponse>>, ApplicationError> {
+    let group = state
+        .update_group_use_case
+        .execute(GroupId::from(id), command, &caller)
+        .await?;
+    Ok(Json(ApiResponse::ok(group)))
+}
+
+/// Delete a group
+///
+/// Its members are kept; they lose what the group granted.
+#[utoipa::path(
+    delete,
+    path = "/groups/{id}",
+    params(
+        ("id" = String, Path, description = "Group ID (UUID)"),
+        ("X-Organization" = Option<String>, Header, description = "Slug of the organization to act in")
+    ),
+    responses(
+        (status = 204, description = "Group deleted"),
+        (status = 404, description = "Group not found", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized - Valid JWT token required"),
+        (status = 403, description = "Forbidden - Admin role required")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "groups"
+)]
+pub async fn delete_group(
+    State(state): State<AppState>,
+    caller: CallerContext,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, ApplicationError> {
+    state
+        .delete_group_use_case
+        .execute(GroupId::from(id), &caller)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// List the members of a group, newest first
+///
+/// Visible to admins managing the group and to members of a group granting
+/// `view_members`.
+#[utoipa::path(
+    get,
+    path = "/groups/{id}/members",
+    params(
+        ("id" = String, Path, description = "Group ID (UUID)"),
+        ("page" = Option<u32>, Query, description = "Page number (default: 1)"),
+        ("rowsPerPage" = Option<u32>, Query, description = "Number of items per page (default: 10)"),
+        ("X-Organization" = Option<String>, Header, description = "Slug of the organization to act in")
+    ),
+    responses(
+        (status = 200, description = "List of members", body = ApiResponse<Vec<UserResponse>>),
+        (status = 404, description = "Group not found", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized - Valid JWT token required")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "groups"
+)]
+pub async fn list_group_members(
+    State(state): State<AppState>,
+    caller: CallerContext,
+    Path(id): Path<Uuid>,
+    ValidatedPagination(pagination): ValidatedPagination<PaginationRequest>,
+) -> Result<Json<ApiResponse<Vec<UserResponse>>>, ApplicationError> {
+    let page = pagination.page;
+    let rows_per_page = pagination.rows_per_page;
+
+    let query = ListGroupsQuery {
+        page: page as u64,
+        rows_per_page: rows_per_page as u64,
+    };
+
+    let (users, total) = state
+        .list_group_members_use_case
+        .execute(GroupId::from(id), query, &caller)
+        .await?;
+
+    Ok(Json(ApiResponse::with_pagination(
+        users,
+        total,
+        rows_per_page,
+        page,
+    )))
+}
+
+/// Add a user to a group
+#[utoipa::path(
+    put,
+    path = "/groups/{id}/members/{user_id}",
+    params(
+        ("id" = String, Path, description = "Group ID (UUID)"),
+        ("user_id" = String, Path, description = "Public user ID (UUID)"),
+        ("X-Organization" = Option<String>, Header, description = "Slug of the organization to act in"),
+        ("Idempotency-Key" = Option<String>, Header, description = "Replays the original response when a request is retried with the same key")
+    ),
+    responses(
+        (status = 204, description = "User is a member"),
+        (status = 404, description = "Group not found, or user not in the group's organization", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized - Valid JWT token required"),
+        (status = 403, description = "Forbidden - Admin role required")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "groups"
+)]
+pub async fn add_group_member(
+    State(state): State<AppState>,
+    caller: CallerContext,
+    Path((id, user_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, ApplicationError> {
+    state
+        .add_group_member_use_case
+        .execute(GroupId::from(id), PublicUserId::from(user_id), &caller)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Remove a user from a group
+#[utoipa::path(
+    delete,
+    path = "/groups/{id}/members/{user_id}",
+    params(
+        ("id" = String, Path, description = "Group ID (UUID)"),
+        ("user_id" = String, Path, description = "Public user ID (UUID)"),
+        ("X-Organization" = Option<String>, Header, description = "Slug of the organization to act in")
+    ),
+    responses(
+        (status = 204, description = "Member removed"),
+        (status = 404, description = "Group not found or user not a member", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized - Valid JWT token required"),
+        (status = 403, description = "Forbidden - Admin role required")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "groups"
+)]
+pub async fn remove_group_member(
+    State(state): State<AppState>,
+    caller: CallerContext,
+    Path((id, user_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, ApplicationError> {
+    state
+        .remove_group_member_use_case
+        .execute(GroupId::from(id), PublicUserId::from(user_id), &caller)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// List the groups a user belongs to
+#[utoipa::path(
+    get,
+    path = "/users/{id}/groups",
+    params(
+        ("id" = String, Path, description = "Public user ID (UUID)"),
+        ("X-Organization" = Option<String>, Header, description = "Slug of the organization to act in; only its groups are listed")
+    ),
+    responses(
+        (status = 200, description = "The user's groups", body = ApiResponse<Vec<GroupResponse>>),
+        (status = 403, description = "Forbidden - Can only view own groups unless admin"),
+        (status = 404, description = "User not found", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized - Valid JWT token required")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "groups"
+)]
+pub async fn list_user_groups(
+    State(state): State<AppState>,
+    caller: CallerContext,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<GroupResponse>>>, ApplicationError> {
+    let groups = state
+        .list_user_groups_use_case
+        .execute(PublicUserId::from(id), &caller)
+        .await?;
+    Ok(Json(ApiResponse::ok(groups)))
+}
@@ -1,9 +1,14 @@
-//! Health check API handler
+//! Health check API handlers
 //!
-//! Simple endpoint to verify the API is running.
+//! `/health/live` reports that the process is up without touching any
+//! dependency; `/health/ready` runs the registered component checks and
+//! answers 503 while any of them is down, so traffic is only routed to
+//! instances that can serve it. `/health` is kept for existing clients.
 
+use crate::app::health::HealthReport;
+use crate::presentation::responses::ApiResponse;
 use crate::presentation::state::AppState;
-use axum::{Router, routing::get};
+use axum::{Json, Router, extract::State, http::StatusCode, routing::get};
 
 /// Health check endpoint
 #[utoipa::path(
@@ -18,7 +23,47 @@ pub async fn health_check() -> String {
     "Ok".to_string()
 }
 
+/// Liveness probe
+#[utoipa::path(
+    get,
+    path = "/health/live",
+    responses(
+        (status = 200, description = "Process is alive", body = ApiResponse<HealthReport>)
+    ),
+    tag = "health"
+)]
+pub async fn liveness() -> Json<ApiResponse<HealthReport>> {
+    Json(ApiResponse::ok(HealthReport::alive()))
+}
+
+/// Readiness probe
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    responses(
+        (status = 200, description = "All dependencies are healthy", body = ApiResponse<HealthReport>),
+        (status = 503, description = "At least one dependency is unhealthy", body = ApiResponse<HealthReport>)
+    ),
+    tag = "health"
+)]
+pub async fn readiness(
+    State(state): State<AppState>,
+) -> (StatusCode, Json<ApiResponse<HealthReport>>) {
+    let report = state.check_readiness_use_case.execute().await;
+
+    let status = if report.is_up() {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+
+    (status, Json(ApiResponse::ok(report)))
+}
+
 /// Create health check routes
 pub fn health_routes() -> Router<AppState> {
-    Router::new().route("/health", get(health_check))
+    Router::new()
+        .route("/health", get(health_check))
+        .route("/health/live", get(liveness))
+        .route("/health/ready", get(readiness))
 }
@@ -0,0 +1,211 @@
+//! Invitation API handlers
+//!
+//! Admins invite an email with preassigned roles; the invitee follows the
+//! emailed link and accepts with their own password and profile.
+
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    routing::{delete, get, post},
+};
+use uuid::Uuid;
+
+use crate::app::ApplicationError;
+use crate::app::CallerContext;
+use crate::app::invitation::{
+    AcceptInvitationCommand, CreateInvitationCommand, InvitationResponse, ListInvitationsQuery,
+};
+use crate::app::user::UserResponse;
+use crate::presentation::extractors::{ValidatedJson, ValidatedPagination};
+use crate::presentation::responses::{ApiErrorResponse, ApiResponse, PaginationRequest};
+use crate::presentation::state::AppState;
+
+/// Create invitation management routes (authenticated)
+pub fn invitation_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/invitations",
+            get(list_invitations).post(create_invitation),
+        )
+        .route("/invitations/{id}", delete(revoke_invitation))
+        .route("/invitations/{id}/resend", post(resend_invitation))
+}
+
+/// Create the invitation acceptance route (public)
+pub fn invitation_accept_routes() -> Router<AppState> {
+    Router::new().route("/invitations/accept", post(accept_invitation))
+}
+
+/// List invitations, newest first
+#[utoipa::path(
+    get,
+    path = "/invitations",
+    params(
+        ("page" = Option<u32>, Query, description = "Page number (default: 1)"),
+        ("rowsPerPage" = Option<u32>, Query, description = "Number of items per page (default: 10)"),
+        ("X-Organization" = Option<String>, Header, description = "Slug of the organization to act in; only invitations to it are visible")
+    ),
+    responses(
+        (status = 200, description = "List of invitations", body = ApiResponse<Vec<InvitationResponse>>),
+        (status = 401, description = "Unauthorized - Valid JWT token required"),
+        (status = 403, description = "Forbidden - Admin role required")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "invitations"
+)]
+pub async fn list_invitations(
+    State(state): State<AppState>,
+    caller: CallerContext,
+    ValidatedPagination(pagination): ValidatedPagination<PaginationRequest>,
+) -> Result<Json<ApiResponse<Vec<InvitationResponse>>>, ApplicationError> {
+    let page = pagination.page;
+    let rows_per_page = pagination.rows_per_page;
+
+    let query = ListInvitationsQuery {
+        page: page as u64,
+        rows_per_page: rows_per_page as u64,
+    };
+
+    let (invitations, total) = state
+        .list_invitations_use_case
+        .execute(query, &caller)
+        .await?;
+
+    Ok(Json(ApiResponse::with_pagination(
+        invitations,
+        total,
+        rows_per_page,
+        page,
+    )))
+}
+
+/// Invite an email to sign up
+///
+/// The invitee is emailed a signed link that expires; within an organization
+/// they join it with the given roles.
+#[utoipa::path(
+    post,
+    path = "/invitations",
+    request_body = CreateInvitationCommand,
+    params(
+        ("X-Organization" = Option<String>, Header, description = "Slug of the organization to invite into"),
+        ("Idempotency-Key" = Option<String>, Header, description = "Replays the original response when a request is retried with the same key")
+    ),
+    responses(
+        (status = 200, description = "Invitation sent", body = ApiResponse<InvitationResponse>),
+        (status = 400, description = "Invalid email", body = ApiErrorResponse),
+        (status = 409, description = "Email already registered or invited, or a request with the same Idempotency-Key is in progress", body = ApiErrorResponse),
+        (status = 422, description = "Unknown role, validation error or Idempotency-Key reused", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized - Valid JWT token required"),
+        (status = 403, description = "Forbidden - Admin role required")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "invitations"
+)]
+pub async fn create_invitation(
+    State(state): State<AppState>,
+    caller: CallerContext,
+    ValidatedJson(command): ValidatedJson<CreateInvitationCommand>,
+) -> Result<Json<ApiResponse<InvitationResponse>>, ApplicationError> {
+    let invitation = state
+        .create_invitation_use_case
+        .execute(command, &caller)
+        .await?;
+    Ok(Json(ApiResponse::ok(invitation)))
+}
+
+/// Email a pending invitation again
+///
+/// The expiry starts over and links sent earlier stop working.
+#[utoipa::path(
+    post,
+    path = "/invitations/{id}/resend",
+    params(
+        ("id" = String, Path, description = "Invitation ID (UUID)"),
+        ("X-Organization" = Option<String>, Header, description = "Slug of the organization to act in")
+    ),
+    responses(
+        (status = 200, description = "Invitation sent again", body = ApiResponse<InvitationResponse>),
+        (status = 404, description = "Invitation not found", body = ApiErrorResponse),
+        (status = 409, description = "Invitation already accepted or revoked", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized - Valid JWT token required"),
+        (status = 403, description = "Forbidden - Admin role required")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "invitations"
+)]
+pub async fn resend_invitation(
+    State(state): State<AppState>,
+    caller: CallerContext,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<InvitationResponse>>, ApplicationError> {
+    let invitation = state
+        .resend_invitation_use_case
+        .execute(id, &caller)
+        .await?;
+    Ok(Json(ApiResponse::ok(invitation)))
+}
+
+/// Revoke a pending invitation
+#[utoipa::path(
+    delete,
+    path = "/invitations/{id}",
+    params(
+        ("id" = String, Path, description = "Invitation ID (UUID)"),
+        ("X-Organization" = Option<String>, Header, description = "Slug of the organization to act in")
+    ),
+    responses(
+        (status = 204, description = "Invitation revoked"),
+        (status = 404, description = "Invitation not found", body = ApiErrorResponse),
+        (status = 409, description = "Invitation already accepted or revoked", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized - Valid JWT token required"),
+        (status = 403, description = "Forbidden - Admin role required")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "invitations"
+)]
+pub async fn revoke_invitation(
+    State(state): State<AppState>,
+    caller: CallerContext,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, ApplicationError> {
+    state
+        .revoke_invitation_use_case
+        .execute(id, &caller)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Accept an invitation, choosing a password and profile
+///
+/// Creates the invitee's account with the roles they were invited with.
+#[utoipa::path(
+    post,
+    path = "/invitations/accept",
+    request_body = AcceptInvitationCommand,
+    responses(
+        (status = 200, description = "Account created", body = ApiResponse<UserResponse>),
+        (status = 400, description = "Password or profile rejected", body = ApiErrorResponse),
+        (status = 409, description = "Email already registered", body = ApiErrorResponse),
+        (status = 410, description = "Invitation link invalid, replaced, expired, revoked or already used", body = ApiErrorResponse),
+        (status = 422, description = "Validation error", body = ApiErrorResponse),
+        (status = 503, description = "Too many password checks in progress; retry after Retry-After")
+    ),
+    tag = "invitations"
+)]
+pub async fn accept_invitation(
+    State(state): State<AppState>,
+    ValidatedJson(command): ValidatedJson<AcceptInvitationCommand>,
+) -> Result<Json<ApiResponse<UserResponse>>, ApplicationError> {
+    let user = state.accept_invitation_use_case.execute(command).await?;
+    Ok(Json(ApiResponse::ok(user)))
+}
@@ -0,0 +1,131 @@
+//! Background job API handlers
+//!
+//! Admin endpoints to inspect the job queue and retry failed jobs.
+
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    routing::{get, post},
+};
+use serde::Deserialize;
+
+use crate::app::ApplicationError;
+use crate::app::CallerContext;
+use crate::app::jobs::{JobResponse, ListJobsQuery};
+use crate::presentation::extractors::ValidatedPagination;
+use crate::presentation::responses::{ApiErrorResponse, ApiResponse, PaginationRequest};
+use crate::presentation::state::AppState;
+
+/// Create job routes
+pub fn job_routes() -> Router<AppState> {
+    Router::new()
+        .route("/jobs", get(list_jobs))
+        .route("/jobs/{id}", get(get_job))
+        .route("/jobs/{id}/retry", post(retry_job))
+}
+
+/// Filter for the job list
+#[derive(Debug, Deserialize)]
+pub struct JobFilter {
+    pub status: Option<String>,
+}
+
+/// List background jobs, newest first
+#[utoipa::path(
+    get,
+    path = "/jobs",
+    params(
+        ("status" = Option<String>, Query, description = "pending, running, completed or failed"),
+        ("page" = Option<u32>, Query, description = "Page number (default: 1)"),
+        ("rowsPerPage" = Option<u32>, Query, description = "Number of items per page (default: 10)")
+    ),
+    responses(
+        (status = 200, description = "List of jobs", body = ApiResponse<Vec<JobResponse>>),
+        (status = 422, description = "Unknown status", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized - Valid JWT token required"),
+        (status = 403, description = "Forbidden - Admin role required")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "jobs"
+)]
+pub async fn list_jobs(
+    State(state): State<AppState>,
+    caller: CallerContext,
+    Query(filter): Query<JobFilter>,
+    ValidatedPagination(pagination): ValidatedPagination<PaginationRequest>,
+) -> Result<Json<ApiResponse<Vec<JobResponse>>>, ApplicationError> {
+    let page = pagination.page;
+    let rows_per_page = pagination.rows_per_page;
+
+    let query = ListJobsQuery {
+        status: filter.status,
+        page: page as u64,
+        rows_per_page: rows_per_page as u64,
+    };
+
+    let (jobs, total) = state.list_jobs_use_case.execute(query, &caller).await?;
+
+    Ok(Json(ApiResponse::with_pagination(
+        jobs,
+        total,
+        rows_per_page,
+        page,
+    )))
+}
+
+/// Get a background job
+#[utoipa::path(
+    get,
+    path = "/jobs/{id}",
+    params(
+        ("id" = i64, Path, description = "Job ID")
+    ),
+    responses(
+        (status = 200, description = "Job found", body = ApiResponse<JobResponse>),
+        (status = 404, description = "Job not found", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized - Valid JWT token required"),
+        (status = 403, description = "Forbidden - Admin role required")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "jobs"
+)]
+pub async fn get_job(
+    State(state): State<AppState>,
+    caller: CallerContext,
+    Path(id): Path<i64>,
+) -> Result<Json<ApiResponse<JobResponse>>, ApplicationError> {
+    let job = state.get_job_use_case.execute(id, &caller).await?;
+    Ok(Json(ApiResponse::ok(job)))
+}
+
+/// Queue a failed job again with a fresh attempt count
+#[utoipa::path(
+    post,
+    path = "/jobs/{id}/retry",
+    params(
+        ("id" = i64, Path, description = "Job ID")
+    ),
+    responses(
+        (status = 200, description = "Job queued", body = ApiResponse<JobResponse>),
+        (status = 404, description = "Job not found", body = ApiErrorResponse),
+        (status = 409, description = "Job has not failed", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized - Valid JWT token required"),
+        (status = 403, description = "Forbidden - Admin role required")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "jobs"
+)]
+pub async fn retry_job(
+    State(state): State<AppState>,
+    caller: CallerContext,
+    Path(id): Path<i64>,
+) -> Result<Json<ApiResponse<JobResponse>>, ApplicationError> {
+    let job = state.retry_job_use_case.execute(id, &caller).await?;
+    Ok(Json(ApiResponse::ok(job)))
+}
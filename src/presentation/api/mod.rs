@@ -3,9 +3,23 @@
 //! HTTP request handlers organized by domain.
 
 pub mod auth;
+pub mod cache;
+pub mod groups;
 pub mod health;
+pub mod invitations;
+pub mod jobs;
+pub mod organizations;
+pub mod policy;
 pub mod users;
+pub mod webhooks;
 
 pub use auth::auth_routes;
+pub use cache::cache_routes;
+pub use groups::group_routes;
 pub use health::health_routes;
+pub use invitations::{invitation_accept_routes, invitation_routes};
+pub use jobs::job_routes;
+pub use organizations::organization_routes;
+pub use policy::policy_routes;
 pub use users::user_routes;
+pub use webhooks::webhook_routes;
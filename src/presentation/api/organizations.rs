@@ -0,0 +1,157 @@
+//! Organization management API handlers
+//!
+//! Tenants and their members. Users act in an organization by sending its
+//! slug in the `X-Organization` header.
+
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    routing::{get, put},
+};
+use uuid::Uuid;
+
+use crate::app::ApplicationError;
+use crate::app::CallerContext;
+use crate::app::organization::{
+    AddMemberCommand, CreateOrganizationCommand, MembershipResponse, OrganizationResponse,
+};
+use crate::domain::shared::PublicUserId;
+use crate::presentation::extractors::ValidatedJson;
+use crate::presentation::responses::{ApiErrorResponse, ApiResponse};
+use crate::presentation::state::AppState;
+
+/// Create organization routes
+pub fn organization_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/organizations",
+            get(list_organizations).post(create_organization),
+        )
+        .route(
+            "/organizations/{slug}/members/{user_id}",
+            put(add_member).delete(remove_member),
+        )
+}
+
+/// List organizations
+///
+/// Platform admins see every organization, other users those they belong to.
+#[utoipa::path(
+    get,
+    path = "/organizations",
+    responses(
+        (status = 200, description = "List of organizations", body = ApiResponse<Vec<OrganizationResponse>>),
+        (status = 401, description = "Unauthorized - Valid JWT token required")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "organizations"
+)]
+pub async fn list_organizations(
+    State(state): State<AppState>,
+    caller: CallerContext,
+) -> Result<Json<ApiResponse<Vec<OrganizationResponse>>>, ApplicationError> {
+    let organizations = state.list_organizations_use_case.execute(&caller).await?;
+    Ok(Json(ApiResponse::ok(organizations)))
+}
+
+/// Create an organization
+#[utoipa::path(
+    post,
+    path = "/organizations",
+    request_body = CreateOrganizationCommand,
+    params(
+        ("Idempotency-Key" = Option<String>, Header, description = "Replays the original response when a request is retried with the same key")
+    ),
+    responses(
+        (status = 200, description = "Organization created successfully", body = ApiResponse<OrganizationResponse>),
+        (status = 400, description = "Invalid slug", body = ApiErrorResponse),
+        (status = 409, description = "Slug taken, or a request with the same Idempotency-Key is in progress", body = ApiErrorResponse),
+        (status = 422, description = "Validation error or Idempotency-Key reused", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized - Valid JWT token required"),
+        (status = 403, description = "Forbidden - Platform admin role required")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "organizations"
+)]
+pub async fn create_organization(
+    State(state): State<AppState>,
+    caller: CallerContext,
+    ValidatedJson(command): ValidatedJson<CreateOrganizationCommand>,
+) -> Result<Json<ApiResponse<OrganizationResponse>>, ApplicationError> {
+    let organization = state
+        .create_organization_use_case
+        .execute(command, &caller)
+        .await?;
+    Ok(Json(ApiResponse::ok(organization)))
+}
+
+/// Add a user to an organization, or replace the roles they hold there
+#[utoipa::path(
+    put,
+    path = "/organizations/{slug}/members/{user_id}",
+    request_body = AddMemberCommand,
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("user_id" = String, Path, description = "Public user ID (UUID)"),
+        ("Idempotency-Key" = Option<String>, Header, description = "Replays the original response when a request is retried with the same key")
+    ),
+    responses(
+        (status = 200, description = "Membership saved", body = ApiResponse<MembershipResponse>),
+        (status = 404, description = "Organization or user not found", body = ApiErrorResponse),
+        (status = 422, description = "Unknown role, validation error or Idempotency-Key reused", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized - Valid JWT token required"),
+        (status = 403, description = "Forbidden - Platform admin role required")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "organizations"
+)]
+pub async fn add_member(
+    State(state): State<AppState>,
+    caller: CallerContext,
+    Path((slug, user_id)): Path<(String, Uuid)>,
+    ValidatedJson(command): ValidatedJson<AddMemberCommand>,
+) -> Result<Json<ApiResponse<MembershipResponse>>, ApplicationError> {
+    let membership = state
+        .add_member_use_case
+        .execute(&slug, PublicUserId::from(user_id), command, &caller)
+        .await?;
+    Ok(Json(ApiResponse::ok(membership)))
+}
+
+/// Remove a user from an organization
+#[utoipa::path(
+    delete,
+    path = "/organizations/{slug}/members/{user_id}",
+    params(
+        ("slug" = String, Path, description = "Organization slug"),
+        ("user_id" = String, Path, description = "Public user ID (UUID)")
+    ),
+    responses(
+        (status = 204, description = "Member removed"),
+        (status = 404, description = "Organization not found or user not a member", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized - Valid JWT token required"),
+        (status = 403, description = "Forbidden - Admin of the organization required")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "organizations"
+)]
+pub async fn remove_member(
+    State(state): State<AppState>,
+    caller: CallerContext,
+    Path((slug, user_id)): Path<(String, Uuid)>,
+) -> Result<StatusCode, ApplicationError> {
+    state
+        .remove_member_use_case
+        .execute(&slug, PublicUserId::from(user_id), &caller)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
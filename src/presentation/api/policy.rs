@@ -0,0 +1,67 @@
+//! Authorization policy API handlers
+//!
+//! Lets callers see how the policy decides their requests, for debugging
+//! denials.
+
+use axum::{
+    Json, Router,
+    extract::{Query, State},
+    routing::get,
+};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::app::ApplicationError;
+use crate::app::CallerContext;
+use crate::app::policy::{Decision, ExplainDecisionQuery};
+use crate::domain::shared::PublicUserId;
+use crate::presentation::responses::{ApiErrorResponse, ApiResponse};
+use crate::presentation::state::AppState;
+
+/// Create policy routes
+pub fn policy_routes() -> Router<AppState> {
+    Router::new().route("/policy/explain", get(explain_decision))
+}
+
+/// Action to explain, and the user it is on
+#[derive(Debug, Deserialize)]
+pub struct ExplainFilter {
+    pub action: String,
+    pub user_id: Option<Uuid>,
+}
+
+/// Explain whether the caller may take an action, rule by rule
+#[utoipa::path(
+    get,
+    path = "/policy/explain",
+    params(
+        ("action" = String, Query, description = "Action name, e.g. user:read"),
+        ("user_id" = Option<String>, Query, description = "Public ID (UUID) of the user the action is on; only for user actions; omit for actions on all resources of the type"),
+        ("X-Organization" = Option<String>, Header, description = "Slug of the organization to act in")
+    ),
+    responses(
+        (status = 200, description = "The decision and how each rule covering the action evaluated", body = ApiResponse<Decision>),
+        (status = 403, description = "The caller may not read the user the action is on", body = ApiErrorResponse),
+        (status = 422, description = "Unknown action, or a user given for an action not on users", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized - Valid JWT token required")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "policy"
+)]
+pub async fn explain_decision(
+    State(state): State<AppState>,
+    caller: CallerContext,
+    Query(filter): Query<ExplainFilter>,
+) -> Result<Json<ApiResponse<Decision>>, ApplicationError> {
+    let query = ExplainDecisionQuery {
+        action: filter.action,
+        user_id: filter.user_id.map(PublicUserId::from),
+    };
+    let decision = state
+        .explain_decision_use_case
+        .execute(query, &caller)
+        .await?;
+    Ok(Json(ApiResponse::ok(decision)))
+}
@@ -7,10 +7,12 @@ use axum::{
     extract::{Path, State},
     routing::get,
 };
+use uuid::Uuid;
 
 use crate::app::ApplicationError;
 use crate::app::CallerContext;
 use crate::app::user::{CreateUserCommand, ListUsersQuery, UpdateUserCommand, UserResponse};
+use crate::domain::shared::PublicUserId;
 use crate::presentation::extractors::{ValidatedJson, ValidatedPagination};
 use crate::presentation::responses::{ApiErrorResponse, ApiResponse, PaginationRequest};
 use crate::presentation::state::AppState;
@@ -23,17 +25,21 @@ pub fn user_routes() -> Router<AppState> {
 }
 
 /// List all users
+///
+/// Users who are not admins see the members of their teams: the groups they
+/// belong to that grant `view_members`.
 #[utoipa::path(
     get,
     path = "/users",
     params(
         ("page" = Option<u32>, Query, description = "Page number (default: 1)"),
-        ("rowsPerPage" = Option<u32>, Query, description = "Number of items per page (default: 10)")
+        ("rowsPerPage" = Option<u32>, Query, description = "Number of items per page (default: 10)"),
+        ("X-Organization" = Option<String>, Header, description = "Slug of the organization to act in; only its members are visible")
     ),
     responses(
         (status = 200, description = "List of users", body = ApiResponse<Vec<UserResponse>>),
         (status = 401, description = "Unauthorized - Valid JWT token required"),
-        (status = 403, description = "Forbidden - Admin role required")
+        (status = 403, description = "Forbidden - Admin role or a team required")
     ),
     security(
         ("bearer_auth" = [])
@@ -68,11 +74,12 @@ pub async fn list_users(
     get,
     path = "/users/{id}",
     params(
-        ("id" = i32, Path, description = "User ID")
+        ("id" = String, Path, description = "Public user ID (UUID)"),
+        ("X-Organization" = Option<String>, Header, description = "Slug of the organization to act in; only its members are visible")
     ),
     responses(
         (status = 200, description = "User found", body = ApiResponse<UserResponse>),
-        (status = 403, description = "Forbidden - Can only view own profile unless admin"),
+        (status = 403, description = "Forbidden - Can only view own and teammates' profiles unless admin"),
         (status = 404, description = "User not found"),
         (status = 401, description = "Unauthorized - Valid JWT token required")
     ),
@@ -84,9 +91,12 @@ pub async fn list_users(
 pub async fn get_user(
     State(state): State<AppState>,
     caller: CallerContext,
-    Path(id): Path<i32>,
+    Path(id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<UserResponse>>, ApplicationError> {
-    let user = state.get_user_use_case.execute(id, &caller).await?;
+    let user = state
+        .get_user_use_case
+        .execute(PublicUserId::from(id), &caller)
+        .await?;
     Ok(Json(ApiResponse::ok(user)))
 }
 
@@ -95,11 +105,17 @@ pub async fn get_user(
     post,
     path = "/users",
     request_body = CreateUserCommand,
+    params(
+        ("Idempotency-Key" = Option<String>, Header, description = "Replays the original response when a request is retried with the same key"),
+        ("X-Organization" = Option<String>, Header, description = "Slug of the organization to act in; the user joins it as a member")
+    ),
     responses(
         (status = 200, description = "User created successfully", body = ApiResponse<UserResponse>),
-        (status = 422, description = "Validation error", body = ApiErrorResponse),
+        (status = 409, description = "A request with the same Idempotency-Key is in progress", body = ApiErrorResponse),
+        (status = 422, description = "Validation error or Idempotency-Key reused", body = ApiErrorResponse),
         (status = 401, description = "Unauthorized - Valid JWT token required"),
-        (status = 403, description = "Forbidden - Admin role required")
+        (status = 403, description = "Forbidden - Admin role required"),
+        (status = 503, description = "Too many password checks in progress; retry after Retry-After", body = ApiErrorResponse)
     ),
     security(
         ("bearer_auth" = [])
@@ -120,9 +136,15 @@ pub async fn create_user(
     put,
     path = "/users/{id}",
     request_body = UpdateUserCommand,
+    params(
+        ("id" = String, Path, description = "Public user ID (UUID)"),
+        ("Idempotency-Key" = Option<String>, Header, description = "Replays the original response when a request is retried with the same key"),
+        ("X-Organization" = Option<String>, Header, description = "Slug of the organization to act in; only its members are visible")
+    ),
     responses(
         (status = 200, description = "User updated successfully", body = ApiResponse<UserResponse>),
-        (status = 422, description = "Validation error", body = ApiErrorResponse),
+        (status = 409, description = "A request with the same Idempotency-Key is in progress", body = ApiErrorResponse),
+        (status = 422, description = "Validation error or Idempotency-Key reused", body = ApiErrorResponse),
         (status = 401, description = "Unauthorized - Valid JWT token required"),
         (status = 403, description = "Forbidden - Can only update own profile unless admin")
     ),
@@ -134,12 +156,12 @@ pub async fn create_user(
 pub async fn update_user(
     State(state): State<AppState>,
     caller: CallerContext,
-    Path(id): Path<i32>,
+    Path(id): Path<Uuid>,
     ValidatedJson(command): ValidatedJson<UpdateUserCommand>,
 ) -> Result<Json<ApiResponse<UserResponse>>, ApplicationError> {
     let user = state
         .update_user_use_case
-        .execute(id, command, &caller)
+        .execute(PublicUserId::from(id), command, &caller)
         .await?;
     Ok(Json(ApiResponse::ok(user)))
 }
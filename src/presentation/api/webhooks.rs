@@ -0,0 +1,193 @@
+//! Webhook management API handlers
+//!
+//! Admin endpoints for webhook subscriptions and their delivery log.
+
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    routing::{delete, get, post},
+};
+
+use crate::app::ApplicationError;
+use crate::app::CallerContext;
+use crate::app::webhook::{
+    CreateWebhookCommand, ListWebhookDeliveriesQuery, WebhookDeliveryResponse, WebhookResponse,
+};
+use crate::presentation::extractors::{ValidatedJson, ValidatedPagination};
+use crate::presentation::responses::{ApiErrorResponse, ApiResponse, PaginationRequest};
+use crate::presentation::state::AppState;
+
+/// Create webhook routes
+pub fn webhook_routes() -> Router<AppState> {
+    Router::new()
+        .route("/webhooks", get(list_webhooks).post(create_webhook))
+        .route("/webhooks/{id}", delete(delete_webhook))
+        .route("/webhooks/{id}/deliveries", get(list_webhook_deliveries))
+        .route(
+            "/webhooks/{id}/deliveries/{delivery_id}/redeliver",
+            post(redeliver_webhook),
+        )
+}
+
+/// List webhook subscriptions
+#[utoipa::path(
+    get,
+    path = "/webhooks",
+    responses(
+        (status = 200, description = "List of webhooks", body = ApiResponse<Vec<WebhookResponse>>),
+        (status = 401, description = "Unauthorized - Valid JWT token required"),
+        (status = 403, description = "Forbidden - Admin role required")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "webhooks"
+)]
+pub async fn list_webhooks(
+    State(state): State<AppState>,
+    caller: CallerContext,
+) -> Result<Json<ApiResponse<Vec<WebhookResponse>>>, ApplicationError> {
+    let webhooks = state.list_webhooks_use_case.execute(&caller).await?;
+    Ok(Json(ApiResponse::ok(webhooks)))
+}
+
+/// Subscribe an endpoint to events
+///
+/// The signing secret is only returned in this response.
+#[utoipa::path(
+    post,
+    path = "/webhooks",
+    request_body = CreateWebhookCommand,
+    params(
+        ("Idempotency-Key" = Option<String>, Header, description = "Replays the original response when a request is retried with the same key")
+    ),
+    responses(
+        (status = 200, description = "Webhook created successfully", body = ApiResponse<WebhookResponse>),
+        (status = 409, description = "A request with the same Idempotency-Key is in progress", body = ApiErrorResponse),
+        (status = 422, description = "Validation error, an event filter matching no event, or Idempotency-Key reused", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized - Valid JWT token required"),
+        (status = 403, description = "Forbidden - Admin role required")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "webhooks"
+)]
+pub async fn create_webhook(
+    State(state): State<AppState>,
+    caller: CallerContext,
+    ValidatedJson(command): ValidatedJson<CreateWebhookCommand>,
+) -> Result<Json<ApiResponse<WebhookResponse>>, ApplicationError> {
+    let webhook = state
+        .create_webhook_use_case
+        .execute(command, &caller)
+        .await?;
+    Ok(Json(ApiResponse::ok(webhook)))
+}
+
+/// Delete a webhook and its delivery log
+#[utoipa::path(
+    delete,
+    path = "/webhooks/{id}",
+    params(
+        ("id" = i32, Path, description = "Webhook ID")
+    ),
+    responses(
+        (status = 204, description = "Webhook deleted"),
+        (status = 404, description = "Webhook not found", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized - Valid JWT token required"),
+        (status = 403, description = "Forbidden - Admin role required")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "webhooks"
+)]
+pub async fn delete_webhook(
+    State(state): State<AppState>,
+    caller: CallerContext,
+    Path(id): Path<i32>,
+) -> Result<StatusCode, ApplicationError> {
+    state.delete_webhook_use_case.execute(id, &caller).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// List the deliveries of a webhook, newest first
+#[utoipa::path(
+    get,
+    path = "/webhooks/{id}/deliveries",
+    params(
+        ("id" = i32, Path, description = "Webhook ID"),
+        ("page" = Option<u32>, Query, description = "Page number (default: 1)"),
+        ("rowsPerPage" = Option<u32>, Query, description = "Number of items per page (default: 10)")
+    ),
+    responses(
+        (status = 200, description = "Delivery log", body = ApiResponse<Vec<WebhookDeliveryResponse>>),
+        (status = 404, description = "Webhook not found", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized - Valid JWT token required"),
+        (status = 403, description = "Forbidden - Admin role required")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "webhooks"
+)]
+pub async fn list_webhook_deliveries(
+    State(state): State<AppState>,
+    caller: CallerContext,
+    Path(id): Path<i32>,
+    ValidatedPagination(pagination): ValidatedPagination<PaginationRequest>,
+) -> Result<Json<ApiResponse<Vec<WebhookDeliveryResponse>>>, ApplicationError> {
+    let page = pagination.page;
+    let rows_per_page = pagination.rows_per_page;
+
+    let query = ListWebhookDeliveriesQuery {
+        webhook_id: id,
+        page: page as u64,
+        rows_per_page: rows_per_page as u64,
+    };
+
+    let (deliveries, total) = state
+        .list_webhook_deliveries_use_case
+        .execute(query, &caller)
+        .await?;
+
+    Ok(Json(ApiResponse::with_pagination(
+        deliveries,
+        total,
+        rows_per_page,
+        page,
+    )))
+}
+
+/// Queue a delivery to be sent again with a fresh attempt count
+#[utoipa::path(
+    post,
+    path = "/webhooks/{id}/deliveries/{delivery_id}/redeliver",
+    params(
+        ("id" = i32, Path, description = "Webhook ID"),
+        ("delivery_id" = i64, Path, description = "Delivery ID")
+    ),
+    responses(
+        (status = 202, description = "Delivery queued", body = ApiResponse<WebhookDeliveryResponse>),
+        (status = 404, description = "Delivery not found", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized - Valid JWT token required"),
+        (status = 403, description = "Forbidden - Admin role required")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "webhooks"
+)]
+pub async fn redeliver_webhook(
+    State(state): State<AppState>,
+    caller: CallerContext,
+    Path((id, delivery_id)): Path<(i32, i64)>,
+) -> Result<(StatusCode, Json<ApiResponse<WebhookDeliveryResponse>>), ApplicationError> {
+    let delivery = state
+        .redeliver_webhook_use_case
+        .execute(id, delivery_id, &caller)
+        .await?;
+    Ok((StatusCode::ACCEPTED, Json(ApiResponse::ok(delivery))))
+}
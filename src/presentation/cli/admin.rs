@@ -0,0 +1,682 @@
+//! `mini-rust-api admin` subcommands
+//!
+//! Each command bootstraps the application like the server does and calls
+//! the use cases as an administrator service caller. Results go to stdout
+//! as one JSON document; failures go to stderr as `{"error": ...}` with a
+//! non-zero exit code.
+
+use crate::app::CallerContext;
+use crate::app::invitation::{CreateInvitationCommand, ListInvitationsQuery};
+use crate::app::organization::{AddMemberCommand, CreateOrganizationCommand};
+use crate::app::user::{CreateUserCommand, ListUsersQuery};
+use crate::domain::organization::OrganizationSlug;
+use crate::domain::shared::PublicUserId;
+use crate::domain::user::{Email, Role};
+use crate::infra::Config;
+use crate::infra::config;
+use crate::infra::persistence::schema;
+use crate::presentation::AppState;
+use chrono::NaiveDate;
+use clap::{Args, Subcommand};
+use serde_json::{Value, json};
+use std::collections::HashSet;
+use std::io::BufRead;
+use std::process::ExitCode;
+use validator::Validate;
+
+/// Service name recorded on the caller context of admin commands
+const SERVICE_NAME: &str = "admin-cli";
+
+#[derive(Debug, Subcommand)]
+pub enum AdminCommand {
+    /// Apply pending database migrations
+    Migrate,
+    /// Create a user, optionally with extra roles
+    CreateUser {
+        #[arg(long)]
+        email: String,
+        #[arg(long)]
+        first_name: String,
+        #[arg(long)]
+        last_name: String,
+        #[command(flatten)]
+        birth: BirthArgs,
+        #[command(flatten)]
+        password: PasswordArgs,
+        /// Role to grant besides `user`; repeatable
+        #[arg(long = "role")]
+        roles: Vec<Role>,
+        /// Organization slug; the user joins it and roles are granted there
+        #[arg(long)]
+        organization: Option<String>,
+    },
+    /// Grant a role to a user
+    GrantRole {
+        /// User ID or email
+        user: UserRef,
+        role: Role,
+        /// Organization slug; grants the role within that organization only
+        #[arg(long)]
+        organization: Option<String>,
+    },
+    /// Revoke a role from a user
+    RevokeRole {
+        /// User ID or email
+        user: UserRef,
+        role: Role,
+        /// Organization slug; revokes the role within that organization only
+        #[arg(long)]
+        organization: Option<String>,
+    },
+    /// Set a new password and revoke the user's tokens
+    ResetPassword {
+        /// User ID or email
+        user: UserRef,
+        #[command(flatten)]
+        password: PasswordArgs,
+    },
+    /// Reject every token issued to a user so far
+    RevokeTokens {
+        /// User ID or email
+        user: UserRef,
+    },
+    /// List users, one page at a time
+    ListUsers {
+        #[arg(long, default_value_t = 1)]
+        page: u64,
+        #[arg(long, default_value_t = 20)]
+        rows_per_page: u64,
+    },
+    /// Create an organization
+    CreateOrganization {
+        #[arg(long)]
+        slug: String,
+        #[arg(long)]
+        name: String,
+    },
+    /// List every organization
+    ListOrganizations,
+    /// Add a user to an organization, or replace the roles they hold there
+    AddMember {
+        /// Organization slug
+        organization: String,
+        /// User ID or email
+        user: UserRef,
+        /// Role to hold in the organization besides `user`; repeatable
+        #[arg(long = "role")]
+        roles: Vec<Role>,
+    },
+    /// Remove a user from an organization
+    RemoveMember {
+        /// Organization slug
+        organization: String,
+        /// User ID or email
+        user: UserRef,
+    },
+    /// Email an invitation to sign up with preassigned roles
+    Invite {
+        #[arg(long)]
+        email: String,
+        /// Role to grant on acceptance besides `user`; repeatable
+        #[arg(long = "role")]
+        roles: Vec<Role>,
+        /// Organization slug; the invitee joins it and roles are granted there
+        #[arg(long)]
+        organization: Option<String>,
+        /// Locale of the invitation email, e.g. `de`
+        #[arg(long)]
+        locale: Option<String>,
+    },
+    /// List invitations, newest first
+    ListInvitations {
+        #[arg(long, default_value_t = 1)]
+        page: u64,
+        #[arg(long, default_value_t = 20)]
+        rows_per_page: u64,
+    },
+    /// Email a pending invitation again with a fresh expiry
+    ResendInvitation {
+        /// Invitation ID
+        id: uuid::Uuid,
+    },
+    /// Revoke a pending invitation
+    RevokeInvitation {
+        /// Invitation ID
+        id: uuid::Uuid,
+    },
+}
+
+/// When a created user was born
+#[derive(Debug, Args)]
+#[group(required = true, multiple = false)]
+pub struct BirthArgs {
+    /// Date of birth (YYYY-MM-DD)
+    #[arg(long)]
+    date_of_birth: Option<NaiveDate>,
+    /// Age in years (deprecated; use --date-of-birth)
+    #[arg(long)]
+    age: Option<u8>,
+}
+
+/// Where a command takes the password from
+#[derive(Debug, Args)]
+#[group(required = true, multiple = false)]
+pub struct PasswordArgs {
+    /// Password on the command line (visible in the process list)
+    #[arg(long)]
+    password: Option<String>,
+    /// Read the password from the first line of stdin
+    #[arg(long)]
+    password_stdin: bool,
+}
+
+impl PasswordArgs {
+    fn read(self) -> Result<String, String> {
+        if let Some(password) = self.password {
+            return Ok(password);
+        }
+
+        let mut line = String::new();
+        std::io::stdin()
+            .lock()
+            .read_line(&mut line)
+            .map_err(|e| format!("Failed to read password from stdin: {}", e))?;
+        Ok(line.trim_end_matches(['\r', '\n']).to_string())
+    }
+}
+
+/// A user given on the command line, by ID or by email
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UserRef {
+    Id(PublicUserId),
+    Email(String),
+}
+
+impl std::str::FromStr for UserRef {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse::<PublicUserId>() {
+            Ok(id) => Ok(UserRef::Id(id)),
+            Err(_) if s.contains('@') => Ok(UserRef::Email(s.to_string())),
+            Err(_) => Err(format!("Expected a user ID or email: {}", s)),
+        }
+    }
+}
+
+/// Run an admin command, print its outcome and return the exit code
+pub async fn run(command: AdminCommand, config: Config) -> ExitCode {
+    match execute(command, config).await {
+        Ok(output) => {
+            println!("{}", output);
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("{}", json!({ "error": error }));
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn execute(command: AdminCommand, mut config: Config) -> Result<Value, String> {
+    // Migrations run before the schema the application expects exists
+    if let AdminCommand::Migrate = command {
+        return migrate().await;
+    }
+
+    // Events and jobs recorded here are left to the server's workers
+    config.server.background_workers = false;
+    let drain_timeout = config.server.drain_timeout;
+
+    let state = crate::create_app_state(config)
+        .await
+        .map_err(|e| e.to_string())?;
+    let result = dispatch(command, &state).await;
+
+    state.lifecycle.close(drain_timeout).await;
+    result
+}
+
+async fn migrate() -> Result<Value, String> {
+    let db = config::database::connect()
+        .await
+        .map_err(|e| format!("Failed to connect to database: {}", e))?;
+
+    let applied = schema::migrate(&db).await?;
+
+    Ok(json!({ "applied": applied }))
+}
+
+async fn dispatch(command: AdminCommand, state: &AppState) -> Result<Value, String> {
+    let caller = CallerContext::for_service(SERVICE_NAME.to_string(), HashSet::from([Role::Admin]));
+
+    match command {
+        AdminCommand::Migrate => unreachable!("handled before bootstrapping"),
+        AdminCommand::CreateUser {
+            email,
+            first_name,
+            last_name,
+            birth,
+            password,
+            roles,
+            organization,
+        } => {
+            let caller = within(state, caller, organization).await?;
+            let command = CreateUserCommand {
+                email,
+                password: password.read()?,
+                first_name,
+                last_name,
+                date_of_birth: birth.date_of_birth,
+                age: birth.age,
+            };
+            command.validate().map_err(|e| e.to_string())?;
+
+            let mut user = state
+                .create_user_use_case
+                .execute(command, &caller)
+                .await
+                .map_err(|e| e.to_string())?;
+            let user_id: PublicUserId = user.id.parse()?;
+            for role in roles {
+                user = state
+                    .grant_role_use_case
+                    .execute(user_id, role, &caller)
+                    .await
+                    .map_err(|e| e.to_string())?;
+            }
+            to_json(&user)
+        }
+        AdminCommand::GrantRole {
+            user,
+            role,
+            organization,
+        } => {
+            let caller = within(state, caller, organization).await?;
+            let user_id = resolve(state, user).await?;
+            let user = state
+                .grant_role_use_case
+                .execute(user_id, role, &caller)
+                .await
+                .map_err(|e| e.to_string())?;
+            to_json(&user)
+        }
+        AdminCommand::RevokeRole {
+            user,
+            role,
+            organization,
+        } => {
+            let caller = within(state, caller, organization).await?;
+            let user_id = resolve(state, user).await?;
+            let user = state
+                .revoke_role_use_case
+                .execute(user_id, role, &caller)
+                .await
+                .map_err(|e| e.to_string())?;
+            to_json(&user)
+        }
+        AdminCommand::ResetPassword { user, password } => {
+            let user_id = resolve(state, user).await?;
+            let user = state
+                .reset_password_use_case
+                .execute(user_id, password.read()?, &caller)
+                .await
+                .map_err(|e| e.to_string())?;
+            to_json(&user)
+        }
+        AdminCommand::RevokeTokens { user } => {
+            let user_id = resolve(state, user).await?;
+            let revoked = state
+                .revoke_tokens_use_case
+                .execute(user_id, &caller)
+                .await
+                .map_err(|e| e.to_string())?;
+            to_json(&revoked)
+        }
+        AdminCommand::ListUsers {
+            page,
+            rows_per_page,
+        } => {
+            let query = ListUsersQuery {
+                page,
+                rows_per_page,
+            };
+            let (users, total) = state
+                .list_users_use_case
+                .execute(query, &caller)
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok(json!({
+                "users": users,
+                "total": total,
+                "page": page,
+                "rows_per_page": rows_per_page,
+            }))
+        }
+        AdminCommand::CreateOrganization { slug, name } => {
+            let command = CreateOrganizationCommand { slug, name };
+            command.validate().map_err(|e| e.to_string())?;
+
+            let organization = state
+                .create_organization_use_case
+                .execute(command, &caller)
+                .await
+                .map_err(|e| e.to_string())?;
+            to_json(&organization)
+        }
+        AdminCommand::ListOrganizations => {
+            let organizations = state
+                .list_organizations_use_case
+                .execute(&caller)
+                .await
+                .map_err(|e| e.to_string())?;
+            to_json(&organizations)
+        }
+        AdminCommand::AddMember {
+            organization,
+            user,
+            roles,
+        } => {
+            let user_id = resolve(state, user).await?;
+            let command = AddMemberCommand {
+                roles: roles.iter().map(Role::to_string).collect(),
+            };
+            let membership = state
+                .add_member_use_case
+                .execute(&organization, user_id, command, &caller)
+                .await
+                .map_err(|e| e.to_string())?;
+            to_json(&membership)
+        }
+        AdminCommand::RemoveMember { organization, user } => {
+            let user_id = resolve(state, user).await?;
+            state
+                .remove_member_use_case
+                .execute(&organization, user_id, &caller)
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok(json!({ "organization": organization, "removed": user_id.to_string() }))
+        }
+        AdminCommand::Invite {
+            email,
+            roles,
+            organization,
+            locale,
+        } => {
+            let caller = within(state, caller, organization).await?;
+            let command = CreateInvitationCommand {
+                email,
+                roles: roles.iter().map(Role::to_string).collect(),
+                locale,
+            };
+            command.validate().map_err(|e| e.to_string())?;
+
+            let invitation = state
+                .create_invitation_use_case
+                .execute(command, &caller)
+                .await
+                .map_err(|e| e.to_string())?;
+            to_json(&invitation)
+        }
+        AdminCommand::ListInvitations {
+            page,
+            rows_per_page,
+        } => {
+            let query = ListInvitationsQuery {
+                page,
+                rows_per_page,
+            };
+            let (invitations, total) = state
+                .list_invitations_use_case
+                .execute(query, &caller)
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok(json!({
+                "invitations": invitations,
+                "total": total,
+                "page": page,
+                "rows_per_page": rows_per_page,
+            }))
+        }
+        AdminCommand::ResendInvitation { id } => {
+            let invitation = state
+                .resend_invitation_use_case
+                .execute(id, &caller)
+                .await
+                .map_err(|e| e.to_string())?;
+            to_json(&invitation)
+        }
+        AdminCommand::RevokeInvitation { id } => {
+            state
+                .revoke_invitation_use_case
+                .execute(id, &caller)
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok(json!({ "revoked": id.to_string() }))
+        }
+    }
+}
+
+/// Act within the organization with the given slug, keeping the caller's roles
+async fn within(
+    state: &AppState,
+    caller: CallerContext,
+    organization: Option<String>,
+) -> Result<CallerContext, String> {
+    let Some(slug) = organization else {
+        return Ok(caller);
+    };
+
+    let slug = OrganizationSlug::try_from(slug).map_err(|e| e.to_string())?;
+    let organization_id = state
+        .organization_repository
+        .find_by_slug(&slug)
+        .await
+        .map_err(|e| e.to_string())?
+        .and_then(|organization| organization.id())
+        .ok_or_else(|| "Organization not found".to_string())?;
+    let roles = caller.roles.clone();
+    Ok(caller.in_organization(organization_id, roles))
+}
+
+/// Look up the public ID of a user given by email
+async fn resolve(state: &AppState, user: UserRef) -> Result<PublicUserId, String> {
+    let email = match user {
+        UserRef::Id(id) => return Ok(id),
+        UserRef::Email(email) => Email::try_from(email).map_err(|e| e.to_string())?,
+    };
+
+    state
+        .user_repository
+        .find_by_email(&email)
+        .await
+        .map_err(|e| e.to_string())?
+        .map(|user| user.public_id())
+        .ok_or_else(|| "User not found".to_string())
+}
+
+fn to_json<T: serde::Serialize>(value: &T) -> Result<Value, String> {
+    serde_json::to_value(value).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::presentation::cli::{Cli, Command};
+    use clap::Parser;
+
+    fn parse(args: &[&str]) -> Result<AdminCommand, clap::Error> {
+        let cli = Cli::try_parse_from(args)?;
+        match cli.command {
+            Some(Command::Admin { command }) => Ok(command),
+            other => panic!("expected an admin command, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parses_user_refs() {
+        let id = PublicUserId::generate();
+        assert_eq!(id.to_string().parse::<UserRef>(), Ok(UserRef::Id(id)));
+        assert_eq!(
+            "ann@example.com".parse::<UserRef>(),
+            Ok(UserRef::Email("ann@example.com".to_string()))
+        );
+        assert!("ann".parse::<UserRef>().is_err());
+        // Internal integer keys are not accepted
+        assert!("42".parse::<UserRef>().is_err());
+    }
+
+    #[test]
+    fn test_parses_create_user() {
+        let command = parse(&[
+            "mini-rust-api",
+            "admin",
+            "create-user",
+            "--email",
+            "ann@example.com",
+            "--first-name",
+            "Ann",
+            "--last-name",
+            "Lee",
+            "--date-of-birth",
+            "1995-03-10",
+            "--password-stdin",
+            "--role",
+            "admin",
+        ])
+        .unwrap();
+
+        let AdminCommand::CreateUser {
+            birth,
+            password,
+            roles,
+            ..
+        } = command
+        else {
+            panic!("expected create-user");
+        };
+        assert_eq!(birth.date_of_birth, NaiveDate::from_ymd_opt(1995, 3, 10));
+        assert!(password.password_stdin && password.password.is_none());
+        assert_eq!(roles, vec![Role::Admin]);
+    }
+
+    #[test]
+    fn test_requires_exactly_one_password_source() {
+        let base = [
+            "mini-rust-api",
+            "admin",
+            "reset-password",
+            "ann@example.com",
+        ];
+        assert!(parse(&base).is_err());
+        assert!(parse(&[&base[..], &["--password", "x", "--password-stdin"]].concat()).is_err());
+        assert!(parse(&[&base[..], &["--password", "Secret123"]].concat()).is_ok());
+    }
+
+    #[test]
+    fn test_rejects_unknown_roles() {
+        assert!(
+            parse(&[
+                "mini-rust-api",
+                "admin",
+                "grant-role",
+                "ann@example.com",
+                "owner"
+            ])
+            .is_err()
+        );
+        assert!(
+            parse(&[
+                "mini-rust-api",
+                "admin",
+                "grant-role",
+                "ann@example.com",
+                "admin"
+            ])
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_parses_organization_commands() {
+        let command = parse(&[
+            "mini-rust-api",
+            "admin",
+            "add-member",
+            "acme",
+            "ann@example.com",
+            "--role",
+            "admin",
+        ])
+        .unwrap();
+        let AdminCommand::AddMember {
+            organization,
+            user,
+            roles,
+        } = command
+        else {
+            panic!("expected add-member");
+        };
+        assert_eq!(organization, "acme");
+        assert_eq!(user, UserRef::Email("ann@example.com".to_string()));
+        assert_eq!(roles, vec![Role::Admin]);
+
+        let command = parse(&[
+            "mini-rust-api",
+            "admin",
+            "grant-role",
+            "ann@example.com",
+            "admin",
+            "--organization",
+            "acme",
+        ])
+        .unwrap();
+        assert!(matches!(
+            command,
+            AdminCommand::GrantRole { organization: Some(slug), .. } if slug == "acme"
+        ));
+    }
+
+    #[test]
+    fn test_parses_invite() {
+        let command = parse(&[
+            "mini-rust-api",
+            "admin",
+            "invite",
+            "--email",
+            "ann@example.com",
+            "--role",
+            "admin",
+            "--organization",
+            "acme",
+            "--locale",
+            "de",
+        ])
+        .unwrap();
+        let AdminCommand::Invite {
+            email,
+            roles,
+            organization,
+            locale,
+        } = command
+        else {
+            panic!("expected invite");
+        };
+        assert_eq!(email, "ann@example.com");
+        assert_eq!(roles, vec![Role::Admin]);
+        assert_eq!(organization.as_deref(), Some("acme"));
+        assert_eq!(locale.as_deref(), Some("de"));
+
+        assert!(parse(&["mini-rust-api", "admin", "revoke-invitation", "42"]).is_err());
+    }
+
+    #[test]
+    fn test_serves_without_arguments() {
+        assert!(
+            Cli::try_parse_from(["mini-rust-api"])
+                .unwrap()
+                .command
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,30 @@
+//! Command-line interface
+//!
+//! `mini-rust-api` serves HTTP when run without arguments; `mini-rust-api
+//! admin <command>` runs one operational task through the same use cases
+//! and prints its result to stdout as JSON.
+
+pub mod admin;
+
+pub use admin::{AdminCommand, UserRef};
+
+use clap::{Parser, Subcommand};
+
+/// Mini Rust API
+#[derive(Debug, Parser)]
+#[command(name = "mini-rust-api", version, about)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Run the HTTP server (the default)
+    Serve,
+    /// Run an operational task and print the result as JSON
+    Admin {
+        #[command(subcommand)]
+        command: AdminCommand,
+    },
+}
@@ -4,10 +4,11 @@
 //! All HTTP concerns (status codes, JSON formatting) are isolated here.
 
 use axum::Json;
-use axum::http::StatusCode;
+use axum::http::{HeaderValue, StatusCode, header};
 use axum::response::{IntoResponse, Response};
 
 use crate::app::ApplicationError;
+use crate::domain::user::PasswordViolation;
 use crate::domain::user::errors::DomainError;
 
 use super::responses::{ApiErrorResponse, JsonApiError};
@@ -24,6 +25,14 @@ impl IntoResponse for ApplicationError {
                     ApiErrorResponse::from_single_error(error),
                 )
             }
+            ApplicationError::JobQueueError(queue_err) => {
+                let error = JsonApiError::new(500, "JOB_QUEUE_ERROR", "Job Queue Error")
+                    .with_detail(format!("A job queue error occurred: {}", queue_err));
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    ApiErrorResponse::from_single_error(error),
+                )
+            }
             ApplicationError::UserNotFound => {
                 let error = JsonApiError::new(404, "USER_NOT_FOUND", "User Not Found")
                     .with_detail("The requested user was not found");
@@ -80,10 +89,163 @@ impl IntoResponse for ApplicationError {
                     ApiErrorResponse::from_single_error(error),
                 )
             }
+            ApplicationError::WebhookNotFound => {
+                let error = JsonApiError::new(404, "WEBHOOK_NOT_FOUND", "Webhook Not Found")
+                    .with_detail("The requested webhook was not found");
+                (
+                    StatusCode::NOT_FOUND,
+                    ApiErrorResponse::from_single_error(error),
+                )
+            }
+            ApplicationError::WebhookDeliveryNotFound => {
+                let error = JsonApiError::new(
+                    404,
+                    "WEBHOOK_DELIVERY_NOT_FOUND",
+                    "Webhook Delivery Not Found",
+                )
+                .with_detail("The requested webhook delivery was not found");
+                (
+                    StatusCode::NOT_FOUND,
+                    ApiErrorResponse::from_single_error(error),
+                )
+            }
+            ApplicationError::JobNotFound => {
+                let error = JsonApiError::new(404, "JOB_NOT_FOUND", "Job Not Found")
+                    .with_detail("The requested job was not found");
+                (
+                    StatusCode::NOT_FOUND,
+                    ApiErrorResponse::from_single_error(error),
+                )
+            }
+            ApplicationError::JobNotRetryable(status) => {
+                let error =
+                    JsonApiError::new(409, "JOB_NOT_RETRYABLE", "Job Not Retryable").with_detail(
+                        format!("Only failed jobs can be retried; this job is {}", status),
+                    );
+                (
+                    StatusCode::CONFLICT,
+                    ApiErrorResponse::from_single_error(error),
+                )
+            }
+            ApplicationError::OrganizationNotFound => {
+                let error =
+                    JsonApiError::new(404, "ORGANIZATION_NOT_FOUND", "Organization Not Found")
+                        .with_detail("The requested organization was not found");
+                (
+                    StatusCode::NOT_FOUND,
+                    ApiErrorResponse::from_single_error(error),
+                )
+            }
+            ApplicationError::OrganizationAlreadyExists(slug) => {
+                let error = JsonApiError::new(
+                    409,
+                    "ORGANIZATION_ALREADY_EXISTS",
+                    "Organization Already Exists",
+                )
+                .with_detail(format!(
+                    "An organization with slug '{}' already exists",
+                    slug
+                ));
+                (
+                    StatusCode::CONFLICT,
+                    ApiErrorResponse::from_single_error(error),
+                )
+            }
+            ApplicationError::MembershipNotFound => {
+                let error = JsonApiError::new(404, "MEMBERSHIP_NOT_FOUND", "Membership Not Found")
+                    .with_detail("The user is not a member of the organization");
+                (
+                    StatusCode::NOT_FOUND,
+                    ApiErrorResponse::from_single_error(error),
+                )
+            }
+            ApplicationError::InvitationNotFound => {
+                let error = JsonApiError::new(404, "INVITATION_NOT_FOUND", "Invitation Not Found")
+                    .with_detail("The requested invitation was not found");
+                (
+                    StatusCode::NOT_FOUND,
+                    ApiErrorResponse::from_single_error(error),
+                )
+            }
+            ApplicationError::InvitationAlreadyExists(email) => {
+                let error = JsonApiError::new(
+                    409,
+                    "INVITATION_ALREADY_EXISTS",
+                    "Invitation Already Exists",
+                )
+                .with_detail(format!(
+                    "'{}' already has a pending invitation; resend it instead",
+                    email
+                ));
+                (
+                    StatusCode::CONFLICT,
+                    ApiErrorResponse::from_single_error(error),
+                )
+            }
+            ApplicationError::InvitationNotPending(status) => {
+                let error = JsonApiError::new(
+                    409,
+                    "INVITATION_NOT_PENDING",
+                    "Invitation Not Pending",
+                )
+                .with_detail(format!(
+                    "Only pending invitations can be resent or revoked; this invitation is {}",
+                    status
+                ));
+                (
+                    StatusCode::CONFLICT,
+                    ApiErrorResponse::from_single_error(error),
+                )
+            }
+            ApplicationError::InvalidInvitation(msg) => {
+                let error = JsonApiError::new(410, "INVALID_INVITATION", "Invalid Invitation")
+                    .with_detail(msg);
+                (StatusCode::GONE, ApiErrorResponse::from_single_error(error))
+            }
+            ApplicationError::GroupNotFound => {
+                let error = JsonApiError::new(404, "GROUP_NOT_FOUND", "Group Not Found")
+                    .with_detail("The requested group was not found");
+                (
+                    StatusCode::NOT_FOUND,
+                    ApiErrorResponse::from_single_error(error),
+                )
+            }
+            ApplicationError::GroupAlreadyExists(name) => {
+                let error = JsonApiError::new(409, "GROUP_ALREADY_EXISTS", "Group Already Exists")
+                    .with_detail(format!("A group named '{}' already exists", name));
+                (
+                    StatusCode::CONFLICT,
+                    ApiErrorResponse::from_single_error(error),
+                )
+            }
+            ApplicationError::GroupMemberNotFound => {
+                let error =
+                    JsonApiError::new(404, "GROUP_MEMBER_NOT_FOUND", "Group Member Not Found")
+                        .with_detail("The user is not a member of the group");
+                (
+                    StatusCode::NOT_FOUND,
+                    ApiErrorResponse::from_single_error(error),
+                )
+            }
+            ApplicationError::Overloaded(msg) => {
+                let error = JsonApiError::new(503, "SERVICE_OVERLOADED", "Service Overloaded")
+                    .with_detail(msg);
+                (
+                    StatusCode::SERVICE_UNAVAILABLE,
+                    ApiErrorResponse::from_single_error(error),
+                )
+            }
         };
 
         let body = Json(api_error);
-        (status, body).into_response()
+        let mut response = (status, body).into_response();
+        // Overload clears as soon as in-flight work finishes
+        if status == StatusCode::SERVICE_UNAVAILABLE {
+            response
+                .headers_mut()
+                .insert(header::RETRY_AFTER, HeaderValue::from(1));
+        }
+        response
     }
 }
 
@@ -98,21 +260,17 @@ fn domain_error_to_response(err: DomainError) -> (StatusCode, ApiErrorResponse)
                 ApiErrorResponse::from_single_error(error),
             )
         }
-        DomainError::PasswordTooShort => {
-            let error = JsonApiError::new(400, "PASSWORD_TOO_SHORT", "Password Too Short")
-                .with_detail("Password must be at least 8 characters long");
-            (
-                StatusCode::BAD_REQUEST,
-                ApiErrorResponse::from_single_error(error),
-            )
-        }
-        DomainError::PasswordTooWeak => {
-            let error = JsonApiError::new(400, "PASSWORD_TOO_WEAK", "Password Too Weak")
-                .with_detail("Password must contain at least one uppercase letter, one lowercase letter, and one number");
-            (
-                StatusCode::BAD_REQUEST,
-                ApiErrorResponse::from_single_error(error),
-            )
+        DomainError::WeakPassword(violations) => {
+            // One error per broken rule, so clients can show them all at once
+            let errors = violations
+                .iter()
+                .map(|violation| {
+                    JsonApiError::new(400, password_violation_code(violation), "Weak Password")
+                        .with_detail(violation.to_string())
+                        .with_source_pointer("/password")
+                })
+                .collect();
+            (StatusCode::BAD_REQUEST, ApiErrorResponse::new(errors))
         }
         DomainError::UserTooYoung => {
             let error = JsonApiError::new(400, "USER_TOO_YOUNG", "User Too Young")
@@ -154,7 +312,7 @@ fn domain_error_to_response(err: DomainError) -> (StatusCode, ApiErrorResponse)
                 ApiErrorResponse::from_single_error(error),
             )
         }
-        DomainError::PasswordHashingFailed => {
+        DomainError::PasswordHashingFailed | DomainError::InvalidHasherParameters(_) => {
             let error =
                 JsonApiError::new(500, "PASSWORD_HASHING_FAILED", "Password Hashing Failed")
                     .with_detail("Failed to hash password");
@@ -171,5 +329,52 @@ fn domain_error_to_response(err: DomainError) -> (StatusCode, ApiErrorResponse)
                 ApiErrorResponse::from_single_error(error),
             )
         }
+        DomainError::InvalidOrganizationSlug(slug) => {
+            let error = JsonApiError::new(
+                400,
+                "INVALID_ORGANIZATION_SLUG",
+                "Invalid Organization Slug",
+            )
+            .with_detail(format!(
+                "'{}' is not a valid organization slug: use 3 to 63 lowercase letters, digits or inner hyphens",
+                slug
+            ));
+            (
+                StatusCode::BAD_REQUEST,
+                ApiErrorResponse::from_single_error(error),
+            )
+        }
+        DomainError::EmptyOrganizationName => {
+            let error =
+                JsonApiError::new(400, "EMPTY_ORGANIZATION_NAME", "Empty Organization Name")
+                    .with_detail("Organization name cannot be empty");
+            (
+                StatusCode::BAD_REQUEST,
+                ApiErrorResponse::from_single_error(error),
+            )
+        }
+        DomainError::EmptyGroupName => {
+            let error = JsonApiError::new(400, "EMPTY_GROUP_NAME", "Empty Group Name")
+                .with_detail("Group name cannot be empty");
+            (
+                StatusCode::BAD_REQUEST,
+                ApiErrorResponse::from_single_error(error),
+            )
+        }
+    }
+}
+
+fn password_violation_code(violation: &PasswordViolation) -> &'static str {
+    match violation {
+        PasswordViolation::TooShort { .. } => "PASSWORD_TOO_SHORT",
+        PasswordViolation::TooLong { .. } => "PASSWORD_TOO_LONG",
+        PasswordViolation::MissingUppercase => "PASSWORD_MISSING_UPPERCASE",
+        PasswordViolation::MissingLowercase => "PASSWORD_MISSING_LOWERCASE",
+        PasswordViolation::MissingDigit => "PASSWORD_MISSING_DIGIT",
+        PasswordViolation::MissingSymbol => "PASSWORD_MISSING_SYMBOL",
+        PasswordViolation::TooPredictable { .. } => "PASSWORD_TOO_PREDICTABLE",
+        PasswordViolation::ContainsPersonalInfo => "PASSWORD_CONTAINS_PERSONAL_INFO",
+        PasswordViolation::RecentlyUsed { .. } => "PASSWORD_RECENTLY_USED",
+        PasswordViolation::Breached => "PASSWORD_BREACHED",
     }
 }
@@ -5,5 +5,5 @@
 mod validated_json;
 mod validated_pagination;
 
-pub use validated_json::ValidatedJson;
+pub use validated_json::{ValidatedJson, body_error_response};
 pub use validated_pagination::{PaginationQuery, ValidatedPagination};
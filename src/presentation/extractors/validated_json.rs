@@ -10,7 +10,9 @@ use axum::{
 };
 use axum_core::extract::FromRequest;
 use axum_core::response::IntoResponse;
+use http_body_util::LengthLimitError;
 use serde::de::DeserializeOwned;
+use tower_http::timeout::TimeoutError;
 use validator::Validate;
 
 /// Extractor that validates JSON request bodies
@@ -56,12 +58,7 @@ fn json_rejection_to_response(rejection: JsonRejection) -> Response {
             let body = Json(ApiErrorResponse::from_single_error(error));
             (StatusCode::UNSUPPORTED_MEDIA_TYPE, body).into_response()
         }
-        JsonRejection::BytesRejection(_) => {
-            let error = JsonApiError::new(400, "REQUEST_BODY_ERROR", "Request Body Error")
-                .with_detail("Failed to read request body");
-            let body = Json(ApiErrorResponse::from_single_error(error));
-            (StatusCode::BAD_REQUEST, body).into_response()
-        }
+        JsonRejection::BytesRejection(rejection) => body_error_response(&rejection),
         _ => {
             let error = JsonApiError::new(
                 rejection.status().as_u16(),
@@ -75,6 +72,46 @@ fn json_rejection_to_response(rejection: JsonRejection) -> Response {
     }
 }
 
+/// Map a failure to read the request body to a JSON:API error response
+///
+/// Bodies over the configured limit get `413`, bodies the client was too
+/// slow to send get `408`, anything else `400`.
+pub fn body_error_response(error: &(dyn std::error::Error + 'static)) -> Response {
+    if caused_by::<LengthLimitError>(error) {
+        let error = JsonApiError::new(413, "PAYLOAD_TOO_LARGE", "Payload Too Large")
+            .with_detail("Request body exceeds the maximum allowed size");
+        let body = Json(ApiErrorResponse::from_single_error(error));
+        return (StatusCode::PAYLOAD_TOO_LARGE, body).into_response();
+    }
+
+    if caused_by::<TimeoutError>(error) {
+        let error = JsonApiError::new(408, "REQUEST_TIMEOUT", "Request Timeout")
+            .with_detail("Request body was not received in time");
+        let body = Json(ApiErrorResponse::from_single_error(error));
+        return (StatusCode::REQUEST_TIMEOUT, body).into_response();
+    }
+
+    let error = JsonApiError::new(400, "REQUEST_BODY_ERROR", "Request Body Error")
+        .with_detail("Failed to read request body");
+    let body = Json(ApiErrorResponse::from_single_error(error));
+    (StatusCode::BAD_REQUEST, body).into_response()
+}
+
+/// Whether `E` appears in the error's source chain
+///
+/// Body errors are type-erased, sometimes with an extra `Box` around the
+/// original error, so both forms are checked.
+fn caused_by<E: std::error::Error + 'static>(error: &(dyn std::error::Error + 'static)) -> bool {
+    let mut cause = Some(error);
+    while let Some(current) = cause {
+        if current.is::<E>() || current.is::<Box<E>>() {
+            return true;
+        }
+        cause = current.source();
+    }
+    false
+}
+
 impl<S, T> FromRequest<S> for ValidatedJson<T>
 where
     S: Send + Sync,
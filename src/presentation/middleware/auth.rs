@@ -1,17 +1,29 @@
 //! Authentication middleware
 //!
 //! JWT token validation middleware for protected routes.
-//! After validating the token, roles are looked up from the database
-//! and a CallerContext is inserted into request extensions.
+//! After validating the token, the user is looked up by their public ID,
+//! revoked tokens are rejected and a CallerContext carrying the user's
+//! current roles is inserted into request extensions.
+//! Requests without a token are accepted when the TLS client certificate
+//! maps to a configured service identity; a token that fails validation is
+//! rejected even then.
+//! A request selecting an organization acts in it: users must be members
+//! and carry their roles there. Users also carry their teams there, the
+//! groups granting them sight of fellow members.
 
 use super::super::state::AppState;
+use super::tenant::requested_organization;
 use crate::app::CallerContext;
-use crate::domain::shared::UserId;
+use crate::domain::group::GroupGrant;
+use crate::domain::organization::OrganizationSlug;
+use crate::domain::shared::{PublicUserId, UserId};
+use crate::domain::user::Role;
 use crate::infra::auth::jwt_token_service::{Claims, JwtTokenService};
+use crate::infra::tls::ClientCertificate;
 use axum::{
     RequestPartsExt,
     extract::{Request, State},
-    http::{StatusCode, request::Parts},
+    http::{StatusCode, header, request::Parts},
     middleware::Next,
     response::Response,
 };
@@ -20,12 +32,34 @@ use axum_extra::{
     headers::{Authorization, authorization::Bearer},
 };
 use jsonwebtoken::{Validation, decode};
+use std::collections::HashSet;
+
+/// How a request authenticated
+enum Credentials {
+    /// A valid bearer token, with the service it arrived through, if any
+    Token(Claims, Option<String>),
+    /// A client certificate mapped to a service identity, without a token
+    Service(String, HashSet<Role>),
+}
 
 /// Authentication middleware that validates JWT tokens and builds CallerContext
 ///
 /// 1. Decodes and validates the JWT token
-/// 2. Looks up the user's current roles from the database
-/// 3. Inserts a CallerContext into request extensions for downstream handlers
+/// 2. Looks up the user and their current roles by public ID; tokens of
+///    deleted users are rejected
+/// 3. Rejects tokens issued at or before the user's last revocation
+/// 4. Resolves the organization the request acts in (see `tenant`); users
+///    who are not members get 403, except platform admins, who keep their
+///    own roles there
+/// 5. Loads the user's teams in that organization (or on the platform)
+/// 6. Inserts a CallerContext into request extensions for downstream handlers
+///
+/// With `CACHE__ENABLED`, the lookups of steps 2 to 5 are served from the
+/// cache for up to `CACHE__TTL_SECS` and dropped when the data changes.
+///
+/// Without a token, a client certificate mapped to a service identity
+/// authenticates the request as that service. A token that is present but
+/// invalid or expired gets 401 rather than that identity.
 pub async fn auth_middleware(
     State(state): State<AppState>,
     mut req: Request,
@@ -33,24 +67,125 @@ pub async fn auth_middleware(
 ) -> Result<Response, StatusCode> {
     let (mut parts, body) = req.into_parts();
 
-    // Extract and validate the JWT token
-    let claims = extract_claims(&mut parts).await?;
+    // Resolve the mutual TLS service identity, if any
+    let service = parts
+        .extensions
+        .get::<ClientCertificate>()
+        .and_then(|certificate| state.service_identities.resolve(certificate));
 
-    // Look up current roles from the database
-    let roles = state
-        .user_repository
-        .find_roles_by_user_id(UserId::from(claims.user_id))
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let has_token = parts.headers.contains_key(header::AUTHORIZATION);
+    let claims = extract_claims(&mut parts).await;
+    let (caller, user_id, bound_organization) = match authenticate(claims, has_token, service)? {
+        Credentials::Token(claims, service) => {
+            // Resolve the public ID to the user and their current roles
+            let public_id = PublicUserId::from(claims.user_id);
+            let user_id = state
+                .user_repository
+                .find_id_by_public_id(public_id)
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+                .ok_or(StatusCode::UNAUTHORIZED)?;
+
+            // Reject tokens revoked since they were issued
+            let revoked_at = state
+                .token_revocation_store
+                .revoked_at(user_id.value())
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+            if revoked_at.is_some_and(|cutoff| claims.issued_by(cutoff)) {
+                return Err(StatusCode::UNAUTHORIZED);
+            }
 
-    // Build CallerContext with fresh roles from DB
-    let caller = CallerContext::new(claims.user_id, roles);
+            // Build CallerContext with fresh roles from DB
+            let roles = state
+                .user_repository
+                .find_roles_by_user_id(user_id)
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+            let caller = CallerContext::new(public_id, roles).with_service(service);
+            (caller, Some(user_id), claims.org)
+        }
+        Credentials::Service(name, roles) => (CallerContext::for_service(name, roles), None, None),
+    };
+
+    let requested = requested_organization(
+        &parts.headers,
+        state.config.tenancy.base_domain.as_deref(),
+        bound_organization.as_deref(),
+    )?;
+    let caller = match requested {
+        Some(slug) => enter_organization(&state, caller, user_id, slug).await?,
+        None => caller,
+    };
+    let caller = match user_id {
+        Some(user_id) => {
+            let teams = state
+                .group_repository
+                .granting(user_id, caller.organization, GroupGrant::ViewMembers)
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+            caller.with_teams(teams.into_iter().collect())
+        }
+        None => caller,
+    };
 
     req = Request::from_parts(parts, body);
     req.extensions_mut().insert(caller);
     Ok(next.run(req).await)
 }
 
+/// Pick how the request authenticates
+///
+/// The client certificate only stands in for a missing token: falling back
+/// to it on a bad token would swap the user for a service that often holds
+/// more privileges.
+fn authenticate(
+    claims: Result<Claims, StatusCode>,
+    has_token: bool,
+    service: Option<(String, HashSet<Role>)>,
+) -> Result<Credentials, StatusCode> {
+    match (claims, service) {
+        (Ok(claims), service) => Ok(Credentials::Token(claims, service.map(|(name, _)| name))),
+        (Err(_), Some((name, roles))) if !has_token => Ok(Credentials::Service(name, roles)),
+        (Err(status), _) => Err(status),
+    }
+}
+
+/// Scope the caller to the organization with the given slug
+///
+/// Users act with the roles they hold there; platform admins may enter any
+/// organization and keep their own. Services keep their roles as well.
+async fn enter_organization(
+    state: &AppState,
+    caller: CallerContext,
+    user_id: Option<UserId>,
+    slug: String,
+) -> Result<CallerContext, StatusCode> {
+    let slug = OrganizationSlug::try_from(slug).map_err(|_| StatusCode::BAD_REQUEST)?;
+    let organization_id = state
+        .organization_repository
+        .find_id_by_slug(&slug)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::FORBIDDEN)?;
+
+    let membership = match user_id {
+        Some(user_id) => state
+            .organization_repository
+            .find_membership(organization_id, user_id)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?,
+        None => None,
+    };
+
+    let roles = match membership {
+        Some(membership) => membership.roles().clone(),
+        None if user_id.is_none() || caller.is_admin() => caller.roles.clone(),
+        None => return Err(StatusCode::FORBIDDEN),
+    };
+    Ok(caller.in_organization(organization_id, roles))
+}
+
 /// Extract and validate JWT claims from the request
 async fn extract_claims(parts: &mut Parts) -> Result<Claims, StatusCode> {
     let TypedHeader(Authorization(bearer)) = parts
@@ -67,3 +202,38 @@ async fn extract_claims(parts: &mut Parts) -> Result<Claims, StatusCode> {
 
     Ok(token_data.claims)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_token_does_not_fall_back_to_service_identity() {
+        let service = || Some(("billing".to_string(), HashSet::from([Role::Admin])));
+
+        assert!(matches!(
+            authenticate(Err(StatusCode::UNAUTHORIZED), true, service()),
+            Err(StatusCode::UNAUTHORIZED)
+        ));
+        assert!(matches!(
+            authenticate(Err(StatusCode::UNAUTHORIZED), false, service()),
+            Ok(Credentials::Service(name, _)) if name == "billing"
+        ));
+        assert!(matches!(
+            authenticate(Err(StatusCode::UNAUTHORIZED), false, None),
+            Err(StatusCode::UNAUTHORIZED)
+        ));
+
+        let claims = Claims {
+            sub: "ann@example.com".to_string(),
+            user_id: uuid::Uuid::now_v7(),
+            exp: usize::MAX,
+            iat: 0,
+            org: None,
+        };
+        assert!(matches!(
+            authenticate(Ok(claims), true, service()),
+            Ok(Credentials::Token(_, Some(name))) if name == "billing"
+        ));
+    }
+}
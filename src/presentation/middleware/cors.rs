@@ -1,16 +1,234 @@
 //! CORS middleware configuration
 //!
-//! Cross-Origin Resource Sharing configuration for the API.
+//! Cross-Origin Resource Sharing configuration for the API, built from the
+//! `Cors` section of the configuration.
 
-use crate::infra::Config;
-use axum::http::{HeaderValue, Method};
-use tower_http::cors::CorsLayer;
+use crate::infra::config::app_config::Cors;
+use axum::http::{HeaderName, HeaderValue, Method};
+use tower_http::cors::{AllowOrigin, CorsLayer};
+
+/// Create CORS layer from the configured policy
+///
+/// # Panics
+///
+/// Panics on invalid methods or header names, or when credentials are
+/// allowed together with the `*` origin (browsers reject that combination).
+pub fn cors_layer(config: &Cors) -> CorsLayer {
+    let any_origin = config.allowed_origins.iter().any(|origin| origin == "*");
+    assert!(
+        !(any_origin && config.allow_credentials),
+        "CORS__ALLOW_CREDENTIALS cannot be combined with the '*' origin"
+    );
+
+    let allow_origin = if any_origin {
+        AllowOrigin::any()
+    } else {
+        let patterns: Vec<String> = config
+            .allowed_origins
+            .iter()
+            .map(|origin| origin.to_ascii_lowercase())
+            .collect();
+        AllowOrigin::predicate(move |origin: &HeaderValue, _| {
+            origin.to_str().is_ok_and(|origin| {
+                let origin = origin.to_ascii_lowercase();
+                patterns
+                    .iter()
+                    .any(|pattern| origin_matches(pattern, &origin))
+            })
+        })
+    };
 
-/// Create CORS layer with configured origins
-pub fn cors_layer() -> CorsLayer {
-    let config = Config::from_env();
-    let address = format!("{}:{}", config.server.host, config.server.port);
     CorsLayer::new()
-        .allow_origin(address.parse::<HeaderValue>().unwrap())
-        .allow_methods([Method::GET, Method::POST, Method::PUT, Method::DELETE])
+        .allow_origin(allow_origin)
+        .allow_methods(
+            config
+                .allowed_methods
+                .iter()
+                .map(|m| m.parse::<Method>().expect("invalid CORS method"))
+                .collect::<Vec<_>>(),
+        )
+        .allow_headers(header_names(&config.allowed_headers))
+        .expose_headers(header_names(&config.exposed_headers))
+        .allow_credentials(config.allow_credentials)
+        .max_age(config.max_age)
+}
+
+fn header_names(names: &[String]) -> Vec<HeaderName> {
+    names
+        .iter()
+        .map(|name| {
+            name.parse::<HeaderName>()
+                .expect("invalid CORS header name")
+        })
+        .collect()
+}
+
+/// Match an origin against a pattern where `*` stands for any run of
+/// characters other than `/` (so it cannot swallow the scheme separator)
+fn origin_matches(pattern: &str, origin: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == origin,
+        Some((prefix, rest)) => {
+            let Some(tail) = origin.strip_prefix(prefix) else {
+                return false;
+            };
+            // Try every split point the wildcard could cover
+            tail.char_indices()
+                .map(|(i, _)| i)
+                .chain(std::iter::once(tail.len()))
+                .take_while(|&i| !tail[..i].contains('/'))
+                .any(|i| origin_matches(rest, &tail[i..]))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infra::config::app_config::Environment;
+    use axum::{Router, body::Body, extract::Request, http::header, routing::get};
+    use std::time::Duration;
+    use tower::ServiceExt;
+
+    fn app(cors: Cors) -> Router {
+        Router::new()
+            .route("/users", get(|| async { "ok" }))
+            .layer(cors_layer(&cors))
+    }
+
+    fn policy(origins: &[&str], allow_credentials: bool) -> Cors {
+        Cors {
+            allowed_origins: origins.iter().map(|o| o.to_string()).collect(),
+            allow_credentials,
+            max_age: Duration::from_secs(600),
+            ..Cors::defaults(Environment::Production)
+        }
+    }
+
+    fn preflight(origin: &str) -> Request {
+        Request::builder()
+            .method("OPTIONS")
+            .uri("/users")
+            .header(header::ORIGIN, origin)
+            .header(header::ACCESS_CONTROL_REQUEST_METHOD, "POST")
+            .header(
+                header::ACCESS_CONTROL_REQUEST_HEADERS,
+                "authorization,content-type",
+            )
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_origin_patterns() {
+        assert!(origin_matches(
+            "https://app.example.com",
+            "https://app.example.com"
+        ));
+        assert!(origin_matches(
+            "https://*.example.com",
+            "https://admin.example.com"
+        ));
+        assert!(origin_matches(
+            "http://localhost:*",
+            "http://localhost:5173"
+        ));
+        assert!(!origin_matches(
+            "https://*.example.com",
+            "https://example.com"
+        ));
+        assert!(!origin_matches(
+            "https://*.example.com",
+            "https://evil.com/.example.com"
+        ));
+        assert!(!origin_matches(
+            "https://*.example.com",
+            "https://app.example.com.evil.com"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_preflight_allowed_origin() {
+        let response = app(policy(&["https://*.example.com"], true))
+            .oneshot(preflight("https://app.example.com"))
+            .await
+            .unwrap();
+
+        let headers = response.headers();
+        assert!(response.status().is_success());
+        assert_eq!(
+            headers[header::ACCESS_CONTROL_ALLOW_ORIGIN],
+            "https://app.example.com"
+        );
+        assert_eq!(headers[header::ACCESS_CONTROL_ALLOW_CREDENTIALS], "true");
+        assert_eq!(headers[header::ACCESS_CONTROL_MAX_AGE], "600");
+        assert!(
+            headers[header::ACCESS_CONTROL_ALLOW_METHODS]
+                .to_str()
+                .unwrap()
+                .contains("POST")
+        );
+        assert!(
+            headers[header::ACCESS_CONTROL_ALLOW_HEADERS]
+                .to_str()
+                .unwrap()
+                .contains("authorization")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_preflight_rejected_origin() {
+        let response = app(policy(&["https://app.example.com"], false))
+            .oneshot(preflight("https://evil.com"))
+            .await
+            .unwrap();
+
+        assert!(
+            !response
+                .headers()
+                .contains_key(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_simple_request_exposes_headers() {
+        let response = app(policy(&["*"], false))
+            .oneshot(
+                Request::get("/users")
+                    .header(header::ORIGIN, "https://anywhere.test")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let headers = response.headers();
+        assert_eq!(headers[header::ACCESS_CONTROL_ALLOW_ORIGIN], "*");
+        assert!(
+            headers[header::ACCESS_CONTROL_EXPOSE_HEADERS]
+                .to_str()
+                .unwrap()
+                .contains("x-request-id")
+        );
+    }
+
+    #[test]
+    fn test_environment_defaults() {
+        assert!(
+            Cors::defaults(Environment::Production)
+                .allowed_origins
+                .is_empty()
+        );
+        assert!(
+            Cors::defaults(Environment::Development)
+                .allowed_origins
+                .contains(&"http://localhost:*".to_string())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot be combined")]
+    fn test_rejects_credentials_with_any_origin() {
+        let _ = cors_layer(&policy(&["*"], true));
+    }
 }
@@ -0,0 +1,274 @@
+//! HTTP hardening
+//!
+//! Request limits (handler timeouts, body read timeout, body size cap) and
+//! response hardening (compression, security headers), assembled from the
+//! `Http` configuration.
+//!
+//! Timeouts and size limits produce JSON:API errors: `504` when a handler
+//! runs past its timeout, `408` when the client is too slow to send the
+//! body, and `413` when the body is too large (reported by `ValidatedJson`).
+
+use crate::infra::config::app_config::{Http, RouteTimeout};
+use crate::presentation::middleware::route_match::route_matches;
+use crate::presentation::responses::{ApiErrorResponse, JsonApiError};
+use axum::{
+    Json, Router,
+    extract::{DefaultBodyLimit, MatchedPath, Request, State},
+    http::{HeaderName, HeaderValue, StatusCode, header},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+};
+use std::sync::Arc;
+use std::time::Duration;
+use tower_http::compression::CompressionLayer;
+use tower_http::set_header::SetResponseHeaderLayer;
+use tower_http::timeout::RequestBodyTimeoutLayer;
+
+/// Handler timeouts: a default plus per-route overrides
+pub struct RequestTimeouts {
+    default: Duration,
+    routes: Vec<RouteTimeout>,
+}
+
+impl RequestTimeouts {
+    pub fn new(default: Duration, routes: Vec<RouteTimeout>) -> Self {
+        Self { default, routes }
+    }
+
+    fn for_route(&self, route: &str, method: &str) -> Duration {
+        self.routes
+            .iter()
+            .find(|rule| route_matches(&rule.path, rule.method.as_deref(), route, method))
+            .map(|rule| Duration::from_millis(rule.timeout_ms))
+            .unwrap_or(self.default)
+    }
+}
+
+/// Middleware that aborts handlers running past their route's timeout
+pub async fn request_timeout_middleware(
+    State(timeouts): State<Arc<RequestTimeouts>>,
+    req: Request,
+    next: Next,
+) -> Response {
+    let timeout = match req.extensions().get::<MatchedPath>() {
+        Some(route) => timeouts.for_route(route.as_str(), req.method().as_str()),
+        None => timeouts.default,
+    };
+
+    match tokio::time::timeout(timeout, next.run(req)).await {
+        Ok(response) => response,
+        Err(_) => {
+            let error =
+                JsonApiError::new(504, "GATEWAY_TIMEOUT", "Gateway Timeout").with_detail(format!(
+                    "The request was not processed within {} ms",
+                    timeout.as_millis()
+                ));
+            (
+                StatusCode::GATEWAY_TIMEOUT,
+                Json(ApiErrorResponse::from_single_error(error)),
+            )
+                .into_response()
+        }
+    }
+}
+
+/// Apply handler timeouts, the body read timeout and the body size limit
+///
+/// Call on the routes before the request ID middleware so that timeout
+/// errors carry the request ID.
+pub fn limit_requests<S>(router: Router<S>, config: &Http) -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    let timeouts = Arc::new(RequestTimeouts::new(
+        config.request_timeout,
+        config.route_timeouts.clone(),
+    ));
+
+    router
+        .layer(middleware::from_fn_with_state(
+            timeouts,
+            request_timeout_middleware,
+        ))
+        .layer(DefaultBodyLimit::max(config.max_body_bytes))
+        .layer(RequestBodyTimeoutLayer::new(config.body_read_timeout))
+}
+
+/// Add security headers and, if enabled, response compression
+///
+/// Call outside the request ID middleware, which needs uncompressed error
+/// bodies. HSTS is only sent when serving TLS.
+pub fn harden_responses<S>(router: Router<S>, config: &Http, tls: bool) -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    let content_security_policy = HeaderValue::from_str(&config.content_security_policy)
+        .expect("HTTP__CONTENT_SECURITY_POLICY must be a valid header value");
+
+    let mut router = router
+        .layer(security_header(
+            header::X_CONTENT_TYPE_OPTIONS,
+            HeaderValue::from_static("nosniff"),
+        ))
+        .layer(security_header(
+            header::X_FRAME_OPTIONS,
+            HeaderValue::from_static("DENY"),
+        ))
+        .layer(security_header(
+            header::REFERRER_POLICY,
+            HeaderValue::from_static("no-referrer"),
+        ))
+        .layer(security_header(
+            header::CONTENT_SECURITY_POLICY,
+            content_security_policy,
+        ));
+
+    if tls {
+        router = router.layer(security_header(
+            header::STRICT_TRANSPORT_SECURITY,
+            HeaderValue::from_str(&format!(
+                "max-age={}; includeSubDomains",
+                config.hsts_max_age.as_secs()
+            ))
+            .expect("HSTS header is always valid"),
+        ));
+    }
+
+    if config.compression {
+        router = router.layer(CompressionLayer::new());
+    }
+
+    router
+}
+
+/// Set a header unless the handler already chose a value
+fn security_header(name: HeaderName, value: HeaderValue) -> SetResponseHeaderLayer<HeaderValue> {
+    SetResponseHeaderLayer::if_not_present(name, value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::presentation::extractors::ValidatedJson;
+    use axum::{
+        body::{Body, to_bytes},
+        routing::{get, post},
+    };
+    use serde::Deserialize;
+    use tower::ServiceExt;
+    use validator::Validate;
+
+    #[derive(Deserialize, Validate)]
+    struct Payload {
+        #[allow(dead_code)]
+        name: String,
+    }
+
+    fn config() -> Http {
+        Http {
+            request_timeout: Duration::from_secs(5),
+            route_timeouts: vec![RouteTimeout {
+                path: "/slow/{id}".to_string(),
+                method: None,
+                timeout_ms: 20,
+            }],
+            body_read_timeout: Duration::from_secs(5),
+            max_body_bytes: 64,
+            compression: true,
+            hsts_max_age: Duration::from_secs(60),
+            content_security_policy: "default-src 'self'".to_string(),
+        }
+    }
+
+    fn app(tls: bool) -> Router {
+        let routes = Router::new()
+            .route(
+                "/slow/{id}",
+                get(|| async {
+                    tokio::time::sleep(Duration::from_secs(1)).await;
+                    "done"
+                }),
+            )
+            .route(
+                "/echo",
+                post(|ValidatedJson(_): ValidatedJson<Payload>| async { "x".repeat(1024) }),
+            );
+        harden_responses(limit_requests(routes, &config()), &config(), tls)
+    }
+
+    async fn error_code(response: Response) -> String {
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let document: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        document["errors"][0]["code"].as_str().unwrap().to_string()
+    }
+
+    #[tokio::test]
+    async fn test_route_timeout_returns_504() {
+        let response = app(false)
+            .oneshot(Request::get("/slow/1").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::GATEWAY_TIMEOUT);
+        assert_eq!(error_code(response).await, "GATEWAY_TIMEOUT");
+    }
+
+    #[tokio::test]
+    async fn test_oversized_body_returns_413() {
+        let body = format!("{{\"name\":\"{}\"}}", "a".repeat(100));
+        let response = app(false)
+            .oneshot(
+                Request::post("/echo")
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .body(Body::from(body))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+        assert_eq!(error_code(response).await, "PAYLOAD_TOO_LARGE");
+    }
+
+    #[tokio::test]
+    async fn test_security_headers_and_compression() {
+        let response = app(true)
+            .oneshot(
+                Request::post("/echo")
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .header(header::ACCEPT_ENCODING, "gzip")
+                    .body(Body::from("{\"name\":\"a\"}"))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let headers = response.headers();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(headers[header::CONTENT_ENCODING], "gzip");
+        assert_eq!(headers[header::X_CONTENT_TYPE_OPTIONS], "nosniff");
+        assert_eq!(headers[header::X_FRAME_OPTIONS], "DENY");
+        assert_eq!(
+            headers[header::CONTENT_SECURITY_POLICY],
+            "default-src 'self'"
+        );
+        assert_eq!(
+            headers[header::STRICT_TRANSPORT_SECURITY],
+            "max-age=60; includeSubDomains"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_no_hsts_without_tls() {
+        let response = app(false)
+            .oneshot(Request::get("/missing").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert!(
+            !response
+                .headers()
+                .contains_key(header::STRICT_TRANSPORT_SECURITY)
+        );
+    }
+}
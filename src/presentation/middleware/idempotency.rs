@@ -0,0 +1,482 @@
+//! Idempotency-Key middleware
+//!
+//! Mutating requests carrying an `Idempotency-Key` header are processed at
+//! most once per key. Keys of authenticated callers are scoped to the
+//! caller; anonymous keys are scoped to the key and the request itself
+//! (method, path and body), so anonymous clients can only replay a request
+//! they could have sent anyway. The first request claims the key and its
+//! response is recorded; retries with the same body replay that response
+//! (marked with `Idempotent-Replayed: true`), retries of a caller with a
+//! different body are rejected, and retries arriving while the first is
+//! still running get `409 Conflict`. Server errors release the key so the
+//! request can be retried. Routes whose responses carry secrets (login and
+//! invitation acceptance) are excluded in the configuration.
+
+use crate::app::CallerContext;
+use crate::app::ports::{IdempotencyClaim, IdempotencyStore, StoredResponse};
+use crate::infra::config::app_config::Idempotency;
+use crate::presentation::extractors::body_error_response;
+use crate::presentation::responses::{ApiErrorResponse, JsonApiError};
+use axum::{
+    Json, RequestExt,
+    body::{Body, Bytes, to_bytes},
+    extract::{MatchedPath, Request, State},
+    http::{HeaderName, HeaderValue, Method, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+
+/// Header carrying the client-chosen idempotency key
+pub static IDEMPOTENCY_KEY_HEADER: HeaderName = HeaderName::from_static("idempotency-key");
+
+/// Header set on responses replayed from the store
+pub static IDEMPOTENT_REPLAYED_HEADER: HeaderName = HeaderName::from_static("idempotent-replayed");
+
+/// Longest idempotency key accepted
+const MAX_KEY_LEN: usize = 255;
+
+/// Upper bound when buffering a response body to record it
+const MAX_RESPONSE_BODY_BYTES: usize = 1024 * 1024;
+
+/// Idempotency settings together with the store that records responses
+pub struct IdempotencyGuard {
+    store: Arc<dyn IdempotencyStore>,
+    config: Idempotency,
+}
+
+impl IdempotencyGuard {
+    pub fn new(store: Arc<dyn IdempotencyStore>, config: Idempotency) -> Self {
+        Self { store, config }
+    }
+
+    fn applies_to(&self, req: &Request) -> bool {
+        let mutating = matches!(
+            *req.method(),
+            Method::POST | Method::PUT | Method::PATCH | Method::DELETE
+        );
+        let excluded = req.extensions().get::<MatchedPath>().is_some_and(|path| {
+            self.config
+                .excluded_paths
+                .iter()
+                .any(|p| p == path.as_str())
+        });
+        mutating && !excluded && req.headers().contains_key(&IDEMPOTENCY_KEY_HEADER)
+    }
+}
+
+/// Releases a claimed key if the request is dropped before it completes
+/// (e.g. the client disconnected), so retries do not wait for the lock timeout
+struct ClaimGuard {
+    store: Arc<dyn IdempotencyStore>,
+    key: Option<String>,
+}
+
+impl ClaimGuard {
+    fn disarm(&mut self) {
+        self.key = None;
+    }
+}
+
+impl Drop for ClaimGuard {
+    fn drop(&mut self) {
+        if let Some(key) = self.key.take() {
+            let store = self.store.clone();
+            tokio::spawn(async move {
+                if let Err(e) = store.release(&key).await {
+                    tracing::warn!(error = %e, "Failed to release idempotency key");
+                }
+            });
+        }
+    }
+}
+
+/// Middleware that enforces `Idempotency-Key` semantics on mutating routes
+///
+/// Apply with `route_layer` inside the auth middleware so keys are scoped
+/// to the authenticated caller, if any.
+pub async fn idempotency_middleware(
+    State(guard): State<Arc<IdempotencyGuard>>,
+    req: Request,
+    next: Next,
+) -> Response {
+    if !guard.applies_to(&req) {
+        return next.run(req).await;
+    }
+
+    let Some(client_key) = req
+        .headers()
+        .get(&IDEMPOTENCY_KEY_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .filter(|v| is_acceptable(v))
+        .map(str::to_string)
+    else {
+        return error_response(
+            StatusCode::BAD_REQUEST,
+            "INVALID_IDEMPOTENCY_KEY",
+            "Invalid Idempotency Key",
+            format!(
+                "Idempotency-Key must be 1 to {} printable ASCII characters",
+                MAX_KEY_LEN
+            ),
+        );
+    };
+
+    // Buffer the body under the same size limit the JSON extractor applies
+    let (parts, body) = req.with_limited_body().into_parts();
+    let body = match to_bytes(body, usize::MAX).await {
+        Ok(body) => body,
+        Err(e) => return body_error_response(&e),
+    };
+
+    let fingerprint = fingerprint(&parts.method, parts.uri.path_and_query(), &body);
+    let scope =
+        caller_scope(&parts.extensions).unwrap_or_else(|| format!("anonymous:{}", fingerprint));
+    let key = format!("{}:{}", scope, client_key);
+
+    let claim = match guard
+        .store
+        .claim(&key, &fingerprint, guard.config.ttl)
+        .await
+    {
+        Ok(claim) => claim,
+        Err(e) => {
+            tracing::warn!(error = %e, "Idempotency store unavailable, processing request");
+            return next.run(Request::from_parts(parts, Body::from(body))).await;
+        }
+    };
+
+    match claim {
+        IdempotencyClaim::Claimed => {
+            let mut claim_guard = ClaimGuard {
+                store: guard.store.clone(),
+                key: Some(key.clone()),
+            };
+            let response = next.run(Request::from_parts(parts, Body::from(body))).await;
+            let response = record(&guard.store, &key, response).await;
+            claim_guard.disarm();
+            response
+        }
+        IdempotencyClaim::InProgress {
+            fingerprint: stored,
+        } if stored == fingerprint => {
+            let mut response = error_response(
+                StatusCode::CONFLICT,
+                "IDEMPOTENCY_REQUEST_IN_PROGRESS",
+                "Request In Progress",
+                "A request with this Idempotency-Key is still being processed",
+            );
+            response
+                .headers_mut()
+                .insert(header::RETRY_AFTER, HeaderValue::from(1));
+            response
+        }
+        IdempotencyClaim::Completed {
+            fingerprint: stored,
+            response,
+        } if stored == fingerprint => replay(response),
+        IdempotencyClaim::InProgress { .. } | IdempotencyClaim::Completed { .. } => error_response(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "IDEMPOTENCY_KEY_REUSED",
+            "Idempotency Key Reused",
+            "This Idempotency-Key was already used for a different request",
+        ),
+    }
+}
+
+fn is_acceptable(value: &str) -> bool {
+    !value.is_empty() && value.len() <= MAX_KEY_LEN && value.bytes().all(|b| b.is_ascii_graphic())
+}
+
+/// Keys are namespaced per caller so clients cannot collide with each other;
+/// anonymous requests have no caller and are namespaced by their fingerprint
+fn caller_scope(extensions: &axum::http::Extensions) -> Option<String> {
+    match extensions.get::<CallerContext>() {
+        Some(CallerContext {
+            user_id: Some(user_id),
+            ..
+        }) => Some(format!("user:{}", user_id)),
+        Some(CallerContext {
+            service: Some(service),
+            ..
+        }) => Some(format!("service:{}", service)),
+        _ => None,
+    }
+}
+
+fn fingerprint(
+    method: &Method,
+    path_and_query: Option<&axum::http::uri::PathAndQuery>,
+    body: &Bytes,
+) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(method.as_str());
+    hasher.update(b"\n");
+    hasher.update(path_and_query.map(|p| p.as_str()).unwrap_or_default());
+    hasher.update(b"\n");
+    hasher.update(body);
+    format!("{:x}", hasher.finalize())
+}
+
+/// Buffer the response and record it, or release the key on server errors
+async fn record(store: &Arc<dyn IdempotencyStore>, key: &str, response: Response) -> Response {
+    let (parts, body) = response.into_parts();
+
+    let body = match to_bytes(body, MAX_RESPONSE_BODY_BYTES).await {
+        Ok(body) => body,
+        Err(e) => {
+            tracing::warn!(error = %e, "Response too large to record for idempotency");
+            if let Err(e) = store.release(key).await {
+                tracing::warn!(error = %e, "Failed to release idempotency key");
+            }
+            return error_response(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "RESPONSE_ERROR",
+                "Response Error",
+                "Failed to read response body",
+            );
+        }
+    };
+
+    let result = if parts.status.is_server_error() {
+        store.release(key).await
+    } else {
+        let stored = StoredResponse {
+            status: parts.status.as_u16(),
+            headers: parts
+                .headers
+                .iter()
+                .filter(|(name, _)| *name != header::CONTENT_LENGTH)
+                .filter_map(|(name, value)| {
+                    Some((name.to_string(), value.to_str().ok()?.to_string()))
+                })
+                .collect(),
+            body: body.to_vec(),
+        };
+        store.complete(key, &stored).await
+    };
+
+    if let Err(e) = result {
+        tracing::warn!(error = %e, "Failed to record idempotent response");
+    }
+
+    Response::from_parts(parts, Body::from(body))
+}
+
+fn replay(stored: StoredResponse) -> Response {
+    let mut response = Response::new(Body::from(stored.body));
+    *response.status_mut() = StatusCode::from_u16(stored.status).unwrap_or(StatusCode::OK);
+
+    let headers = response.headers_mut();
+    for (name, value) in stored.headers {
+        if let (Ok(name), Ok(value)) = (HeaderName::try_from(name), HeaderValue::try_from(value)) {
+            headers.append(name, value);
+        }
+    }
+    headers.insert(
+        IDEMPOTENT_REPLAYED_HEADER.clone(),
+        HeaderValue::from_static("true"),
+    );
+    response
+}
+
+fn error_response(
+    status: StatusCode,
+    code: &str,
+    title: &str,
+    detail: impl Into<String>,
+) -> Response {
+    let error = JsonApiError::new(status.as_u16(), code, title).with_detail(detail);
+    (status, Json(ApiErrorResponse::from_single_error(error))).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::shared::PublicUserId;
+    use crate::domain::user::Role;
+    use crate::infra::idempotency::MemoryIdempotencyStore;
+    use axum::{Extension, Router, middleware, routing::post};
+    use std::collections::HashSet;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::time::Duration;
+    use tower::ServiceExt;
+
+    fn caller() -> CallerContext {
+        CallerContext::new(PublicUserId::generate(), HashSet::from([Role::User]))
+    }
+
+    fn app(calls: Arc<AtomicU32>) -> Router {
+        anonymous_app(calls).layer(Extension(caller()))
+    }
+
+    /// The routes, without an authenticated caller
+    fn anonymous_app(calls: Arc<AtomicU32>) -> Router {
+        let guard = Arc::new(IdempotencyGuard::new(
+            Arc::new(MemoryIdempotencyStore::new(Duration::from_secs(60))),
+            Idempotency {
+                ttl: Duration::from_secs(3600),
+                lock_timeout: Duration::from_secs(60),
+                excluded_paths: vec!["/login".to_string()],
+                sweep_interval: Duration::from_secs(300),
+            },
+        ));
+
+        let handler = move || {
+            let calls = calls.clone();
+            async move {
+                let n = calls.fetch_add(1, Ordering::SeqCst) + 1;
+                (StatusCode::CREATED, format!("created {}", n))
+            }
+        };
+
+        Router::new()
+            .route("/users", post(handler.clone()))
+            .route("/login", post(handler))
+            .route_layer(middleware::from_fn_with_state(
+                guard,
+                idempotency_middleware,
+            ))
+    }
+
+    fn request(uri: &str, key: &str, body: &'static str) -> Request {
+        Request::builder()
+            .method("POST")
+            .uri(uri)
+            .header("idempotency-key", key)
+            .body(Body::from(body))
+            .unwrap()
+    }
+
+    async fn body_text(response: Response) -> String {
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        String::from_utf8(bytes.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_replays_response_for_same_request() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let app = app(calls.clone());
+
+        let first = app
+            .clone()
+            .oneshot(request("/users", "key-1", "{\"a\":1}"))
+            .await
+            .unwrap();
+        assert_eq!(first.status(), StatusCode::CREATED);
+        assert_eq!(body_text(first).await, "created 1");
+
+        let retry = app
+            .oneshot(request("/users", "key-1", "{\"a\":1}"))
+            .await
+            .unwrap();
+        assert_eq!(retry.status(), StatusCode::CREATED);
+        assert_eq!(retry.headers()["idempotent-replayed"], "true");
+        assert_eq!(body_text(retry).await, "created 1");
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_rejects_key_reuse_with_different_body() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let app = app(calls.clone());
+
+        app.clone()
+            .oneshot(request("/users", "key-1", "{\"a\":1}"))
+            .await
+            .unwrap();
+        let reused = app
+            .oneshot(request("/users", "key-1", "{\"a\":2}"))
+            .await
+            .unwrap();
+        assert_eq!(reused.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(body_text(reused).await.contains("IDEMPOTENCY_KEY_REUSED"));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_duplicate_gets_conflict() {
+        let store = Arc::new(MemoryIdempotencyStore::new(Duration::from_secs(60)));
+        let guard = Arc::new(IdempotencyGuard::new(
+            store.clone(),
+            Idempotency {
+                ttl: Duration::from_secs(3600),
+                lock_timeout: Duration::from_secs(60),
+                excluded_paths: vec![],
+                sweep_interval: Duration::from_secs(300),
+            },
+        ));
+        let caller = caller();
+        let key = format!("user:{}:key-1", caller.user_id.unwrap());
+        let app = Router::new()
+            .route("/users", post(|| async { StatusCode::CREATED }))
+            .route_layer(middleware::from_fn_with_state(
+                guard,
+                idempotency_middleware,
+            ))
+            .layer(Extension(caller));
+
+        // Simulate the first request still running
+        let req = request("/users", "key-1", "{}");
+        let fp = fingerprint(req.method(), req.uri().path_and_query(), &Bytes::from("{}"));
+        store
+            .claim(&key, &fp, Duration::from_secs(3600))
+            .await
+            .unwrap();
+
+        let duplicate = app.oneshot(req).await.unwrap();
+        assert_eq!(duplicate.status(), StatusCode::CONFLICT);
+        assert_eq!(duplicate.headers()[header::RETRY_AFTER], "1");
+    }
+
+    #[tokio::test]
+    async fn test_ignores_excluded_routes_and_rejects_invalid_keys() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let app = app(calls.clone());
+
+        for _ in 0..2 {
+            app.clone()
+                .oneshot(request("/login", "key-1", "{}"))
+                .await
+                .unwrap();
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        let invalid = app
+            .oneshot(request("/users", "has space", "{}"))
+            .await
+            .unwrap();
+        assert_eq!(invalid.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_anonymous_keys_are_scoped_to_the_request() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let app = anonymous_app(calls.clone());
+
+        let first = app
+            .clone()
+            .oneshot(request("/users", "key-1", "{\"a\":1}"))
+            .await
+            .unwrap();
+        assert_eq!(body_text(first).await, "created 1");
+
+        let retry = app
+            .clone()
+            .oneshot(request("/users", "key-1", "{\"a\":1}"))
+            .await
+            .unwrap();
+        assert_eq!(retry.headers()["idempotent-replayed"], "true");
+        assert_eq!(body_text(retry).await, "created 1");
+
+        // Another client reusing the key with its own body is not served the
+        // first client's response
+        let other = app
+            .oneshot(request("/users", "key-1", "{\"a\":2}"))
+            .await
+            .unwrap();
+        assert!(!other.headers().contains_key("idempotent-replayed"));
+        assert_eq!(body_text(other).await, "created 2");
+    }
+}
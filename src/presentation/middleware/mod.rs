@@ -4,6 +4,16 @@
 
 pub mod auth;
 pub mod cors;
+pub mod hardening;
+pub mod idempotency;
+pub mod rate_limit;
+pub mod request_id;
+pub mod route_match;
+pub mod tenant;
 
 pub use auth::auth_middleware;
 pub use cors::cors_layer;
+pub use hardening::{harden_responses, limit_requests};
+pub use idempotency::{IdempotencyGuard, idempotency_middleware};
+pub use rate_limit::{RateLimiter, rate_limit_middleware};
+pub use request_id::{RequestId, request_id_middleware};
@@ -0,0 +1,307 @@
+//! Rate limiting middleware
+//!
+//! Counts each request against the first matching rule (or the default
+//! rule) in a `RateLimitStore`, keyed by client IP, authenticated caller or
+//! API key. Every response carries the `RateLimit-*` headers; rejected
+//! requests get `429 Too Many Requests` with `Retry-After` and a JSON:API
+//! error body. If the store is unreachable, requests are let through.
+
+use crate::app::CallerContext;
+use crate::app::ports::{RateLimitDecision, RateLimitPolicy, RateLimitStore};
+use crate::infra::config::app_config::{RateLimit, RateLimitKey, RateLimitRule};
+use crate::presentation::middleware::route_match::route_matches;
+use crate::presentation::responses::{ApiErrorResponse, JsonApiError};
+use axum::{
+    Json,
+    extract::{ConnectInfo, MatchedPath, Request, State},
+    http::{HeaderMap, HeaderName, HeaderValue, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use sha2::{Digest, Sha256};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Header carrying the client API key
+pub static API_KEY_HEADER: HeaderName = HeaderName::from_static("x-api-key");
+
+static RATE_LIMIT_LIMIT: HeaderName = HeaderName::from_static("ratelimit-limit");
+static RATE_LIMIT_REMAINING: HeaderName = HeaderName::from_static("ratelimit-remaining");
+static RATE_LIMIT_RESET: HeaderName = HeaderName::from_static("ratelimit-reset");
+static RATE_LIMIT_POLICY: HeaderName = HeaderName::from_static("ratelimit-policy");
+static X_FORWARDED_FOR: HeaderName = HeaderName::from_static("x-forwarded-for");
+
+/// Rate limit rules together with the store that counts requests
+pub struct RateLimiter {
+    store: Arc<dyn RateLimitStore>,
+    config: RateLimit,
+}
+
+impl RateLimiter {
+    pub fn new(store: Arc<dyn RateLimitStore>, config: RateLimit) -> Self {
+        Self { store, config }
+    }
+
+    /// First rule matching the route and method, falling back to the default rule
+    fn rule_for(&self, route: &str, method: &str) -> &RateLimitRule {
+        self.config
+            .rules
+            .iter()
+            .find(|rule| route_matches(&rule.path, rule.method.as_deref(), route, method))
+            .unwrap_or(&self.config.default_rule)
+    }
+
+    /// Identify the client according to the rule's key
+    fn client_key(&self, rule: &RateLimitRule, req: &Request) -> String {
+        match rule.key {
+            RateLimitKey::Ip => format!("ip:{}", self.client_ip(req)),
+            RateLimitKey::User => match req.extensions().get::<CallerContext>() {
+                Some(CallerContext {
+                    user_id: Some(user_id),
+                    ..
+                }) => format!("user:{}", user_id),
+                Some(CallerContext {
+                    service: Some(service),
+                    ..
+                }) => format!("service:{}", service),
+                _ => format!("ip:{}", self.client_ip(req)),
+            },
+            RateLimitKey::ApiKey => match req.headers().get(&API_KEY_HEADER) {
+                // Hash the key so secrets never end up in the store
+                Some(api_key) => format!("api_key:{:x}", Sha256::digest(api_key.as_bytes())),
+                None => format!("ip:{}", self.client_ip(req)),
+            },
+        }
+    }
+
+    fn client_ip(&self, req: &Request) -> String {
+        let forwarded = self
+            .config
+            .trust_forwarded_for
+            .then(|| req.headers().get(&X_FORWARDED_FOR))
+            .flatten()
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.split(',').next())
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty());
+
+        forwarded
+            .or_else(|| {
+                req.extensions()
+                    .get::<ConnectInfo<SocketAddr>>()
+                    .map(|ConnectInfo(addr)| addr.ip().to_string())
+            })
+            .unwrap_or_else(|| "unknown".to_string())
+    }
+}
+
+/// Middleware that enforces the configured rate limits
+///
+/// Apply with `route_layer` so the matched route template is available, and
+/// inside the auth middleware where limits are keyed by user.
+pub async fn rate_limit_middleware(
+    State(limiter): State<Arc<RateLimiter>>,
+    req: Request,
+    next: Next,
+) -> Response {
+    if !limiter.config.enabled {
+        return next.run(req).await;
+    }
+
+    let route = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| req.uri().path().to_string());
+    let rule = limiter.rule_for(&route, req.method().as_str());
+    let key = format!(
+        "{}:{}:{}",
+        rule.method.as_deref().unwrap_or("*"),
+        rule.path,
+        limiter.client_key(rule, &req)
+    );
+    let policy = RateLimitPolicy {
+        limit: rule.limit,
+        window: Duration::from_secs(rule.window_secs),
+        algorithm: rule.algorithm,
+    };
+
+    let decision = match limiter.store.hit(&key, &policy).await {
+        Ok(decision) => decision,
+        Err(e) => {
+            tracing::warn!(error = %e, "Rate limit store unavailable, allowing request");
+            return next.run(req).await;
+        }
+    };
+
+    let mut response = if decision.allowed {
+        next.run(req).await
+    } else {
+        too_many_requests(&decision)
+    };
+    set_rate_limit_headers(response.headers_mut(), &policy, &decision);
+    response
+}
+
+fn too_many_requests(decision: &RateLimitDecision) -> Response {
+    let retry_after = whole_seconds(decision.retry_after.unwrap_or_default());
+    let error = JsonApiError::new(429, "RATE_LIMITED", "Too Many Requests").with_detail(format!(
+        "Rate limit exceeded, retry in {} seconds",
+        retry_after
+    ));
+
+    let mut response = (
+        StatusCode::TOO_MANY_REQUESTS,
+        Json(ApiErrorResponse::from_single_error(error)),
+    )
+        .into_response();
+    response
+        .headers_mut()
+        .insert(header::RETRY_AFTER, HeaderValue::from(retry_after));
+    response
+}
+
+fn set_rate_limit_headers(
+    headers: &mut HeaderMap,
+    policy: &RateLimitPolicy,
+    decision: &RateLimitDecision,
+) {
+    headers.insert(RATE_LIMIT_LIMIT.clone(), HeaderValue::from(decision.limit));
+    headers.insert(
+        RATE_LIMIT_REMAINING.clone(),
+        HeaderValue::from(decision.remaining),
+    );
+    headers.insert(
+        RATE_LIMIT_RESET.clone(),
+        HeaderValue::from(whole_seconds(decision.reset_after)),
+    );
+    headers.insert(
+        RATE_LIMIT_POLICY.clone(),
+        HeaderValue::from_str(&format!("{};w={}", policy.limit, policy.window.as_secs()))
+            .expect("policy is always a valid header"),
+    );
+}
+
+/// Round up so clients never retry too early
+fn whole_seconds(duration: Duration) -> u64 {
+    duration.as_millis().div_ceil(1000) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::ports::RateLimitAlgorithm;
+    use crate::infra::config::app_config::RateLimitBackend;
+    use crate::infra::rate_limit::MemoryRateLimitStore;
+    use axum::{Router, body::Body, middleware, routing::get};
+    use tower::ServiceExt;
+
+    fn rule(path: &str, method: Option<&str>, limit: u32, key: RateLimitKey) -> RateLimitRule {
+        RateLimitRule {
+            path: path.to_string(),
+            method: method.map(str::to_string),
+            limit,
+            window_secs: 60,
+            algorithm: RateLimitAlgorithm::TokenBucket,
+            key,
+        }
+    }
+
+    fn app(rules: Vec<RateLimitRule>) -> Router {
+        let limiter = Arc::new(RateLimiter::new(
+            Arc::new(MemoryRateLimitStore::new()),
+            RateLimit {
+                enabled: true,
+                backend: RateLimitBackend::Memory,
+                trust_forwarded_for: true,
+                default_rule: rule("*", None, 5, RateLimitKey::Ip),
+                rules,
+            },
+        ));
+
+        Router::new()
+            .route(
+                "/auth/login",
+                get(|| async { "ok" }).post(|| async { "ok" }),
+            )
+            .route("/users/{id}", get(|| async { "ok" }))
+            .route_layer(middleware::from_fn_with_state(
+                limiter,
+                rate_limit_middleware,
+            ))
+    }
+
+    fn request(method: &str, uri: &str, ip: &str) -> Request {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("x-forwarded-for", ip)
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_sets_headers_and_rejects_over_limit() {
+        let app = app(vec![rule("/auth/login", Some("POST"), 2, RateLimitKey::Ip)]);
+
+        let first = app
+            .clone()
+            .oneshot(request("POST", "/auth/login", "10.0.0.1"))
+            .await
+            .unwrap();
+        assert_eq!(first.status(), StatusCode::OK);
+        assert_eq!(first.headers()["ratelimit-limit"], "2");
+        assert_eq!(first.headers()["ratelimit-remaining"], "1");
+        assert_eq!(first.headers()["ratelimit-policy"], "2;w=60");
+
+        app.clone()
+            .oneshot(request("POST", "/auth/login", "10.0.0.1"))
+            .await
+            .unwrap();
+        let rejected = app
+            .clone()
+            .oneshot(request("POST", "/auth/login", "10.0.0.1"))
+            .await
+            .unwrap();
+        assert_eq!(rejected.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(rejected.headers()[header::RETRY_AFTER], "30");
+
+        let body = axum::body::to_bytes(rejected.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let document: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(document["errors"][0]["code"], "RATE_LIMITED");
+
+        // Other clients and the default rule are counted separately
+        let other_client = app
+            .clone()
+            .oneshot(request("POST", "/auth/login", "10.0.0.2"))
+            .await
+            .unwrap();
+        assert_eq!(other_client.status(), StatusCode::OK);
+        let default_rule = app
+            .oneshot(request("GET", "/auth/login", "10.0.0.1"))
+            .await
+            .unwrap();
+        assert_eq!(default_rule.headers()["ratelimit-limit"], "5");
+    }
+
+    #[tokio::test]
+    async fn test_api_key_is_counted_per_key() {
+        let app = app(vec![rule("/users/*", None, 1, RateLimitKey::ApiKey)]);
+        let with_key = |key: &'static str| {
+            let mut req = request("GET", "/users/1", "10.0.0.1");
+            req.headers_mut()
+                .insert(API_KEY_HEADER.clone(), HeaderValue::from_static(key));
+            req
+        };
+
+        let first = app.clone().oneshot(with_key("key-a")).await.unwrap();
+        assert_eq!(first.status(), StatusCode::OK);
+        let second = app.clone().oneshot(with_key("key-a")).await.unwrap();
+        assert_eq!(second.status(), StatusCode::TOO_MANY_REQUESTS);
+        let other_key = app.oneshot(with_key("key-b")).await.unwrap();
+        assert_eq!(other_key.status(), StatusCode::OK);
+    }
+}
@@ -0,0 +1,215 @@
+//! Request ID middleware
+//!
+//! Accepts an incoming `X-Request-Id` header or generates a new ID, makes it
+//! available to handlers and the trace span via request extensions, echoes it
+//! in the response headers, and copies it into the `meta` of every JSON:API
+//! error so a failing response can be matched to its log lines.
+
+use axum::{
+    body::{Body, to_bytes},
+    extract::Request,
+    http::{HeaderName, HeaderValue, Response, header},
+    middleware::Next,
+};
+use serde_json::Value;
+use tower_http::trace::{MakeSpan, OnRequest};
+use tracing::Span;
+
+/// Header carrying the request ID in both directions
+pub static REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+
+/// Longest client-supplied request ID that is accepted as-is
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+/// Upper bound when buffering an error body to attach the request ID
+const MAX_ERROR_BODY_BYTES: usize = 64 * 1024;
+
+/// The ID of the current request, stored in request extensions
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RequestId(pub String);
+
+impl RequestId {
+    /// Accept a client-supplied ID if it is short and printable, otherwise generate one
+    fn from_header(value: Option<&HeaderValue>) -> Self {
+        value
+            .and_then(|v| v.to_str().ok())
+            .filter(|v| is_acceptable(v))
+            .map(|v| Self(v.to_string()))
+            .unwrap_or_else(Self::generate)
+    }
+
+    fn generate() -> Self {
+        Self(uuid::Uuid::new_v4().to_string())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+fn is_acceptable(value: &str) -> bool {
+    !value.is_empty()
+        && value.len() <= MAX_REQUEST_ID_LEN
+        && value.bytes().all(|b| b.is_ascii_graphic())
+}
+
+/// Middleware that assigns a request ID and propagates it to the response
+pub async fn request_id_middleware(mut req: Request, next: Next) -> Response<Body> {
+    let request_id = RequestId::from_header(req.headers().get(&REQUEST_ID_HEADER));
+    let header_value =
+        HeaderValue::from_str(request_id.as_str()).expect("request ID is always a valid header");
+
+    req.headers_mut()
+        .insert(REQUEST_ID_HEADER.clone(), header_value.clone());
+    req.extensions_mut().insert(request_id.clone());
+
+    let mut response = next.run(req).await;
+
+    if is_json_error(&response) {
+        response = attach_to_error_body(response, &request_id).await;
+    }
+
+    response
+        .headers_mut()
+        .insert(REQUEST_ID_HEADER.clone(), header_value);
+    response
+}
+
+fn is_json_error(response: &Response<Body>) -> bool {
+    let is_error = response.status().is_client_error() || response.status().is_server_error();
+    let is_json = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("application/json"));
+    is_error && is_json
+}
+
+/// Rewrite a JSON:API error body so each error carries `meta.requestId`
+async fn attach_to_error_body(response: Response<Body>, request_id: &RequestId) -> Response<Body> {
+    let (mut parts, body) = response.into_parts();
+
+    let bytes = match to_bytes(body, MAX_ERROR_BODY_BYTES).await {
+        Ok(bytes) => bytes,
+        Err(_) => return Response::from_parts(parts, Body::empty()),
+    };
+
+    let mut document = match serde_json::from_slice::<Value>(&bytes) {
+        Ok(document) => document,
+        Err(_) => return Response::from_parts(parts, Body::from(bytes)),
+    };
+
+    let body = if inject_request_id(&mut document, request_id.as_str()) {
+        parts.headers.remove(header::CONTENT_LENGTH);
+        Body::from(document.to_string())
+    } else {
+        Body::from(bytes)
+    };
+
+    Response::from_parts(parts, body)
+}
+
+/// Add `requestId` to the `meta` object of every entry in `errors`
+///
+/// Returns `false` if the document is not a JSON:API error document.
+fn inject_request_id(document: &mut Value, request_id: &str) -> bool {
+    let Some(errors) = document.get_mut("errors").and_then(Value::as_array_mut) else {
+        return false;
+    };
+
+    for error in errors.iter_mut().filter_map(Value::as_object_mut) {
+        let meta = error
+            .entry("meta")
+            .or_insert_with(|| Value::Object(Default::default()));
+        if let Some(meta) = meta.as_object_mut() {
+            meta.insert("requestId".to_string(), Value::from(request_id));
+        }
+    }
+
+    true
+}
+
+/// Builds the `http_request` span with the request ID recorded on it
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RequestIdMakeSpan;
+
+impl<B> MakeSpan<B> for RequestIdMakeSpan {
+    fn make_span(&mut self, req: &Request<B>) -> Span {
+        let request_id = req
+            .extensions()
+            .get::<RequestId>()
+            .map(RequestId::as_str)
+            .unwrap_or_default();
+
+        tracing::info_span!(
+            "http_request",
+            request_id = %request_id,
+            method = %req.method(),
+            uri = %req.uri(),
+        )
+    }
+}
+
+/// Logs request headers at debug level
+///
+/// Headers marked sensitive (e.g. `Authorization`) are printed as `Sensitive`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LogRequestHeaders;
+
+impl<B> OnRequest<B> for LogRequestHeaders {
+    fn on_request(&mut self, req: &Request<B>, _span: &Span) {
+        tracing::debug!(headers = ?req.headers(), "started processing request");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_accepts_client_request_id() {
+        let value = HeaderValue::from_static("abc-123");
+        assert_eq!(RequestId::from_header(Some(&value)).as_str(), "abc-123");
+    }
+
+    #[test]
+    fn test_generates_request_id_when_missing_or_invalid() {
+        let generated = RequestId::from_header(None);
+        assert!(uuid::Uuid::parse_str(generated.as_str()).is_ok());
+
+        let too_long = HeaderValue::from_str(&"a".repeat(MAX_REQUEST_ID_LEN + 1)).unwrap();
+        assert_ne!(
+            RequestId::from_header(Some(&too_long)).as_str(),
+            "a".repeat(MAX_REQUEST_ID_LEN + 1)
+        );
+
+        let with_space = HeaderValue::from_static("has space");
+        assert_ne!(
+            RequestId::from_header(Some(&with_space)).as_str(),
+            "has space"
+        );
+    }
+
+    #[test]
+    fn test_inject_request_id_into_errors() {
+        let mut document = json!({
+            "errors": [
+                { "status": "404", "title": "Not Found" },
+                { "status": "422", "title": "Invalid", "meta": { "field": "email" } }
+            ]
+        });
+
+        assert!(inject_request_id(&mut document, "req-1"));
+        assert_eq!(document["errors"][0]["meta"]["requestId"], "req-1");
+        assert_eq!(document["errors"][1]["meta"]["requestId"], "req-1");
+        assert_eq!(document["errors"][1]["meta"]["field"], "email");
+    }
+
+    #[test]
+    fn test_inject_request_id_ignores_other_documents() {
+        let mut document = json!({ "data": { "id": 1 } });
+        assert!(!inject_request_id(&mut document, "req-1"));
+        assert_eq!(document, json!({ "data": { "id": 1 } }));
+    }
+}
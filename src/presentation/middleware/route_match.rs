@@ -0,0 +1,34 @@
+//! Route matching for per-route middleware settings
+//!
+//! Patterns are route templates as registered with the router (e.g.
+//! `/users/{id}`), compared against the request's `MatchedPath`. A trailing
+//! `*` matches by prefix.
+
+/// Whether a pattern and optional method match the request's route and method
+pub fn route_matches(
+    pattern: &str,
+    pattern_method: Option<&str>,
+    route: &str,
+    method: &str,
+) -> bool {
+    let path_matches = match pattern.strip_suffix('*') {
+        Some(prefix) => route.starts_with(prefix),
+        None => pattern == route,
+    };
+    let method_matches = pattern_method.is_none_or(|m| m.eq_ignore_ascii_case(method));
+    path_matches && method_matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_route_matches() {
+        assert!(route_matches("/users/{id}", None, "/users/{id}", "GET"));
+        assert!(route_matches("/users*", Some("post"), "/users", "POST"));
+        assert!(route_matches("/users*", None, "/users/{id}", "PUT"));
+        assert!(!route_matches("/users*", Some("POST"), "/users", "GET"));
+        assert!(!route_matches("/users", None, "/users/{id}", "GET"));
+    }
+}
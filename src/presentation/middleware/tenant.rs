@@ -0,0 +1,104 @@
+//! Organization selection
+//!
+//! Works out which organization a request acts in. The `X-Organization`
+//! header wins, then the subdomain of the `Host` under the configured base
+//! domain, then the organization the token was issued for. A token bound to
+//! one organization cannot be used in another.
+
+use axum::http::{HeaderMap, StatusCode, header};
+
+/// Header naming the organization a request acts in
+pub const ORGANIZATION_HEADER: &str = "x-organization";
+
+/// The slug of the organization a request selects, if any
+///
+/// Fails with 403 when the header or subdomain contradicts the token.
+pub fn requested_organization(
+    headers: &HeaderMap,
+    base_domain: Option<&str>,
+    claim: Option<&str>,
+) -> Result<Option<String>, StatusCode> {
+    let header = headers
+        .get(ORGANIZATION_HEADER)
+        .map(|value| value.to_str().map_err(|_| StatusCode::BAD_REQUEST))
+        .transpose()?
+        .map(|value| value.trim().to_lowercase());
+    let subdomain = base_domain.and_then(|base| subdomain(headers, base));
+
+    match (header.or(subdomain), claim) {
+        (Some(requested), Some(bound)) if requested != bound => Err(StatusCode::FORBIDDEN),
+        (Some(requested), _) => Ok(Some(requested)),
+        (None, bound) => Ok(bound.map(str::to_string)),
+    }
+}
+
+/// The single label in front of `base_domain` in the `Host` header
+fn subdomain(headers: &HeaderMap, base_domain: &str) -> Option<String> {
+    let host = headers.get(header::HOST)?.to_str().ok()?.to_lowercase();
+    let host = host.split(':').next()?;
+    let label = host.strip_suffix(base_domain)?.strip_suffix('.')?;
+    (!label.is_empty() && !label.contains('.')).then(|| label.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+        pairs
+            .iter()
+            .map(|(name, value)| {
+                (
+                    header::HeaderName::from_static(name),
+                    HeaderValue::from_static(value),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_header_then_subdomain_then_token() {
+        let both = headers(&[("x-organization", "Acme"), ("host", "globex.example.com")]);
+        assert_eq!(
+            requested_organization(&both, Some("example.com"), None),
+            Ok(Some("acme".to_string()))
+        );
+
+        let host = headers(&[("host", "globex.example.com:3000")]);
+        assert_eq!(
+            requested_organization(&host, Some("example.com"), None),
+            Ok(Some("globex".to_string()))
+        );
+        assert_eq!(requested_organization(&host, None, None), Ok(None));
+
+        assert_eq!(
+            requested_organization(&HeaderMap::new(), Some("example.com"), Some("acme")),
+            Ok(Some("acme".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_only_direct_subdomains_name_organizations() {
+        for host in ["example.com", "a.b.example.com", "notexample.com"] {
+            let host = headers(&[("host", host)]);
+            assert_eq!(
+                requested_organization(&host, Some("example.com"), None),
+                Ok(None)
+            );
+        }
+    }
+
+    #[test]
+    fn test_token_bound_to_another_organization_is_rejected() {
+        let header = headers(&[("x-organization", "globex")]);
+        assert_eq!(
+            requested_organization(&header, None, Some("acme")),
+            Err(StatusCode::FORBIDDEN)
+        );
+        assert_eq!(
+            requested_organization(&header, None, Some("globex")),
+            Ok(Some("globex".to_string()))
+        );
+    }
+}
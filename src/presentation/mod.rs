@@ -1,6 +1,6 @@
 //! Presentation Layer
 //!
-//! This layer handles all HTTP/API concerns:
+//! This layer handles all HTTP/API and CLI concerns:
 //! - API handlers (controllers)
 //! - HTTP extractors
 //! - HTTP middleware
@@ -8,8 +8,10 @@
 //! - Error responses (HTTP translation)
 //! - OpenAPI documentation
 //! - Application state
+//! - The command-line interface (admin tasks)
 
 pub mod api;
+pub mod cli;
 pub mod errors;
 pub mod extractors;
 pub mod middleware;
@@ -3,7 +3,19 @@
 //! Swagger/OpenAPI specification generation using utoipa.
 
 use crate::app::auth::{AuthToken, LoginCommand, RegisterCommand};
+use crate::app::cache::CacheStatsResponse;
+use crate::app::group::{CreateGroupCommand, GroupResponse, UpdateGroupCommand};
+use crate::app::health::{ComponentHealth, HealthReport, HealthStatus};
+use crate::app::invitation::{
+    AcceptInvitationCommand, CreateInvitationCommand, InvitationResponse,
+};
+use crate::app::jobs::JobResponse;
+use crate::app::organization::{
+    AddMemberCommand, CreateOrganizationCommand, MembershipResponse, OrganizationResponse,
+};
+use crate::app::policy::{ConditionTrace, Decision, Effect, Op, RuleTrace};
 use crate::app::user::{CreateUserCommand, UpdateUserCommand, UserResponse};
+use crate::app::webhook::{CreateWebhookCommand, WebhookDeliveryResponse, WebhookResponse};
 use utoipa::OpenApi;
 
 /// API Documentation
@@ -14,17 +26,53 @@ use utoipa::OpenApi;
         crate::presentation::api::users::create_user,
         crate::presentation::api::users::update_user,
         crate::presentation::api::users::get_user,
+        crate::presentation::api::organizations::list_organizations,
+        crate::presentation::api::organizations::create_organization,
+        crate::presentation::api::organizations::add_member,
+        crate::presentation::api::organizations::remove_member,
+        crate::presentation::api::invitations::list_invitations,
+        crate::presentation::api::invitations::create_invitation,
+        crate::presentation::api::invitations::resend_invitation,
+        crate::presentation::api::invitations::revoke_invitation,
+        crate::presentation::api::invitations::accept_invitation,
+        crate::presentation::api::groups::list_groups,
+        crate::presentation::api::groups::create_group,
+        crate::presentation::api::groups::update_group,
+        crate::presentation::api::groups::delete_group,
+        crate::presentation::api::groups::list_group_members,
+        crate::presentation::api::groups::add_group_member,
+        crate::presentation::api::groups::remove_group_member,
+        crate::presentation::api::groups::list_user_groups,
+        crate::presentation::api::policy::explain_decision,
+        crate::presentation::api::webhooks::list_webhooks,
+        crate::presentation::api::webhooks::create_webhook,
+        crate::presentation::api::webhooks::delete_webhook,
+        crate::presentation::api::webhooks::list_webhook_deliveries,
+        crate::presentation::api::webhooks::redeliver_webhook,
+        crate::presentation::api::jobs::list_jobs,
+        crate::presentation::api::jobs::get_job,
+        crate::presentation::api::jobs::retry_job,
+        crate::presentation::api::cache::get_cache_stats,
         crate::presentation::api::health::health_check,
+        crate::presentation::api::health::liveness,
+        crate::presentation::api::health::readiness,
         crate::presentation::api::auth::login,
         crate::presentation::api::auth::register
     ),
     components(
-        schemas(UserResponse, CreateUserCommand, UpdateUserCommand, LoginCommand, RegisterCommand, AuthToken)
+        schemas(UserResponse, CreateUserCommand, UpdateUserCommand, LoginCommand, RegisterCommand, AuthToken, HealthReport, ComponentHealth, HealthStatus, WebhookResponse, CreateWebhookCommand, WebhookDeliveryResponse, JobResponse, CacheStatsResponse, OrganizationResponse, CreateOrganizationCommand, MembershipResponse, AddMemberCommand, InvitationResponse, CreateInvitationCommand, AcceptInvitationCommand, GroupResponse, CreateGroupCommand, UpdateGroupCommand, Decision, RuleTrace, ConditionTrace, Effect, Op)
     ),
     modifiers(&SecurityAddon),
     tags(
         (name = "health", description = "Health check endpoints"),
         (name = "users", description = "User management endpoints"),
+        (name = "organizations", description = "Organization and membership endpoints"),
+        (name = "invitations", description = "User invitation endpoints"),
+        (name = "groups", description = "Group and team endpoints"),
+        (name = "policy", description = "Authorization policy endpoints"),
+        (name = "webhooks", description = "Webhook subscription endpoints"),
+        (name = "jobs", description = "Background job endpoints"),
+        (name = "cache", description = "Cache statistics endpoints"),
         (name = "auth", description = "Authentication endpoints")
     )
 )]
@@ -2,12 +2,42 @@
 //!
 //! Contains the shared application state passed to all handlers.
 //! Handlers interact with use cases only, which abstract away persistence.
-//! The user_repository is exposed for role lookups in the auth middleware.
+//! The user_repository, organization_repository, group_repository and
+//! token_revocation_store are exposed for the role, membership, team and
+//! revocation lookups in the auth middleware.
 
-use crate::app::auth::{LoginUseCase, RegisterUseCase};
-use crate::app::user::{CreateUserUseCase, GetUserUseCase, ListUsersUseCase, UpdateUserUseCase};
+use crate::app::auth::{LoginUseCase, RegisterUseCase, RevokeTokensUseCase};
+use crate::app::cache::GetCacheStatsUseCase;
+use crate::app::group::{
+    AddGroupMemberUseCase, CreateGroupUseCase, DeleteGroupUseCase, ListGroupMembersUseCase,
+    ListGroupsUseCase, ListUserGroupsUseCase, RemoveGroupMemberUseCase, UpdateGroupUseCase,
+};
+use crate::app::health::CheckReadinessUseCase;
+use crate::app::invitation::{
+    AcceptInvitationUseCase, CreateInvitationUseCase, ListInvitationsUseCase,
+    ResendInvitationUseCase, RevokeInvitationUseCase,
+};
+use crate::app::jobs::{GetJobUseCase, ListJobsUseCase, RetryJobUseCase};
+use crate::app::organization::{
+    AddMemberUseCase, CreateOrganizationUseCase, ListOrganizationsUseCase, RemoveMemberUseCase,
+};
+use crate::app::policy::ExplainDecisionUseCase;
+use crate::app::ports::TokenRevocationStore;
+use crate::app::user::{
+    CreateUserUseCase, GetUserUseCase, GrantRoleUseCase, ListUsersUseCase, ResetPasswordUseCase,
+    RevokeRoleUseCase, UpdateUserUseCase,
+};
+use crate::app::webhook::{
+    CreateWebhookUseCase, DeleteWebhookUseCase, ListWebhookDeliveriesUseCase, ListWebhooksUseCase,
+    RedeliverWebhookUseCase,
+};
+use crate::domain::group::GroupRepository;
+use crate::domain::organization::OrganizationRepository;
 use crate::domain::user::UserRepository;
 use crate::infra::Config;
+use crate::infra::lifecycle::Lifecycle;
+use crate::infra::tls::ServiceIdentities;
+use crate::presentation::middleware::{IdempotencyGuard, RateLimiter};
 use std::sync::Arc;
 
 /// Shared application state
@@ -17,14 +47,68 @@ use std::sync::Arc;
 #[derive(Clone)]
 pub struct AppState {
     pub config: Config,
+    // Shutdown coordination - flips readiness and owns background workers
+    pub lifecycle: Arc<Lifecycle>,
     // Repository (domain trait) - used by auth middleware for role lookups
     pub user_repository: Arc<dyn UserRepository>,
+    // Repository (domain trait) - used by auth middleware for membership lookups
+    pub organization_repository: Arc<dyn OrganizationRepository>,
+    // Repository (domain trait) - used by auth middleware for team lookups
+    pub group_repository: Arc<dyn GroupRepository>,
+    // Per-user token cut-offs - checked by auth middleware
+    pub token_revocation_store: Arc<dyn TokenRevocationStore>,
+    // Client certificate subjects accepted as service callers (mutual TLS)
+    pub service_identities: Arc<ServiceIdentities>,
+    // Request throttling rules and their counter store
+    pub rate_limiter: Arc<RateLimiter>,
+    // Idempotency-Key handling for mutating routes
+    pub idempotency_guard: Arc<IdempotencyGuard>,
     // Auth use cases
     pub login_use_case: Arc<LoginUseCase>,
     pub register_use_case: Arc<RegisterUseCase>,
+    pub revoke_tokens_use_case: Arc<RevokeTokensUseCase>,
     // User use cases
     pub create_user_use_case: Arc<CreateUserUseCase>,
     pub get_user_use_case: Arc<GetUserUseCase>,
     pub list_users_use_case: Arc<ListUsersUseCase>,
     pub update_user_use_case: Arc<UpdateUserUseCase>,
+    pub grant_role_use_case: Arc<GrantRoleUseCase>,
+    pub revoke_role_use_case: Arc<RevokeRoleUseCase>,
+    pub reset_password_use_case: Arc<ResetPasswordUseCase>,
+    // Organization use cases
+    pub create_organization_use_case: Arc<CreateOrganizationUseCase>,
+    pub list_organizations_use_case: Arc<ListOrganizationsUseCase>,
+    pub add_member_use_case: Arc<AddMemberUseCase>,
+    pub remove_member_use_case: Arc<RemoveMemberUseCase>,
+    // Invitation use cases
+    pub create_invitation_use_case: Arc<CreateInvitationUseCase>,
+    pub list_invitations_use_case: Arc<ListInvitationsUseCase>,
+    pub resend_invitation_use_case: Arc<ResendInvitationUseCase>,
+    pub revoke_invitation_use_case: Arc<RevokeInvitationUseCase>,
+    pub accept_invitation_use_case: Arc<AcceptInvitationUseCase>,
+    // Group use cases
+    pub create_group_use_case: Arc<CreateGroupUseCase>,
+    pub list_groups_use_case: Arc<ListGroupsUseCase>,
+    pub update_group_use_case: Arc<UpdateGroupUseCase>,
+    pub delete_group_use_case: Arc<DeleteGroupUseCase>,
+    pub add_group_member_use_case: Arc<AddGroupMemberUseCase>,
+    pub remove_group_member_use_case: Arc<RemoveGroupMemberUseCase>,
+    pub list_group_members_use_case: Arc<ListGroupMembersUseCase>,
+    pub list_user_groups_use_case: Arc<ListUserGroupsUseCase>,
+    // Policy use cases
+    pub explain_decision_use_case: Arc<ExplainDecisionUseCase>,
+    // Webhook use cases
+    pub create_webhook_use_case: Arc<CreateWebhookUseCase>,
+    pub list_webhooks_use_case: Arc<ListWebhooksUseCase>,
+    pub delete_webhook_use_case: Arc<DeleteWebhookUseCase>,
+    pub list_webhook_deliveries_use_case: Arc<ListWebhookDeliveriesUseCase>,
+    pub redeliver_webhook_use_case: Arc<RedeliverWebhookUseCase>,
+    // Job use cases
+    pub list_jobs_use_case: Arc<ListJobsUseCase>,
+    pub get_job_use_case: Arc<GetJobUseCase>,
+    pub retry_job_use_case: Arc<RetryJobUseCase>,
+    // Cache use cases
+    pub get_cache_stats_use_case: Arc<GetCacheStatsUseCase>,
+    // Health use cases
+    pub check_readiness_use_case: Arc<CheckReadinessUseCase>,
 }
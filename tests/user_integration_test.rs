@@ -3,7 +3,13 @@
 //! These tests verify the complete lifecycle of a User,
 //! including registration, authentication, profile updates, and email changes.
 
-use mini_rust_api::domain::user::{Email, User};
+use chrono::Utc;
+use mini_rust_api::domain::user::{DateOfBirth, Email, PasswordHasher, PasswordPolicy, User};
+
+/// Date of birth of someone turning `age` today
+fn born(age: u8) -> DateOfBirth {
+    DateOfBirth::from_age(age, Utc::now().date_naive())
+}
 
 /// Test the complete user registration and authentication flow
 #[test]
@@ -18,7 +24,9 @@ fn test_user_registration_and_authentication_flow() {
         "SecurePassword123".to_string(),
         "John".to_string(),
         "Doe".to_string(),
-        30,
+        born(30),
+        &PasswordPolicy::default(),
+        &PasswordHasher::default(),
     )
     .expect("User registration should succeed");
 
@@ -56,7 +64,9 @@ fn test_user_profile_modification_flow() {
         "ValidPass123".to_string(),
         "Jane".to_string(),
         "Smith".to_string(),
-        25,
+        born(25),
+        &PasswordPolicy::default(),
+        &PasswordHasher::default(),
     )
     .unwrap();
 
@@ -65,7 +75,7 @@ fn test_user_profile_modification_flow() {
     assert_eq!(user.profile().age(), 25);
 
     // Update the profile
-    user.update_profile("Janet".to_string(), "Johnson".to_string(), 26)
+    user.update_profile("Janet".to_string(), "Johnson".to_string(), born(26))
         .expect("Profile update should succeed");
 
     // Verify updated profile
@@ -84,7 +94,9 @@ fn test_user_email_change_flow() {
         "SecurePass123".to_string(),
         "Test".to_string(),
         "User".to_string(),
-        21,
+        born(21),
+        &PasswordPolicy::default(),
+        &PasswordHasher::default(),
     )
     .unwrap();
 
@@ -114,7 +126,9 @@ fn test_user_password_change_flow() {
         "OriginalPass123".to_string(),
         "Password".to_string(),
         "Tester".to_string(),
-        35,
+        born(35),
+        &PasswordPolicy::default(),
+        &PasswordHasher::default(),
     )
     .unwrap();
 
@@ -122,8 +136,12 @@ fn test_user_password_change_flow() {
     assert!(user.authenticate("OriginalPass123").is_ok());
 
     // Change the password
-    user.change_password("NewSecurePass456".to_string())
-        .expect("Password change should succeed");
+    user.change_password(
+        "NewSecurePass456".to_string(),
+        &PasswordPolicy::default(),
+        &PasswordHasher::default(),
+    )
+    .expect("Password change should succeed");
 
     // Old password should no longer work
     assert!(
@@ -152,7 +170,9 @@ fn test_user_registration_validation_errors() {
         "weakpassword123".to_string(),
         "Test".to_string(),
         "User".to_string(),
-        25,
+        born(25),
+        &PasswordPolicy::default(),
+        &PasswordHasher::default(),
     );
     assert!(
         weak_password_result.is_err(),
@@ -166,7 +186,9 @@ fn test_user_registration_validation_errors() {
         "Short1".to_string(),
         "Test".to_string(),
         "User".to_string(),
-        25,
+        born(25),
+        &PasswordPolicy::default(),
+        &PasswordHasher::default(),
     );
     assert!(
         short_password_result.is_err(),
@@ -180,7 +202,9 @@ fn test_user_registration_validation_errors() {
         "ValidPass123".to_string(),
         "".to_string(),
         "User".to_string(),
-        25,
+        born(25),
+        &PasswordPolicy::default(),
+        &PasswordHasher::default(),
     );
     assert!(
         empty_name_result.is_err(),
@@ -194,7 +218,9 @@ fn test_user_registration_validation_errors() {
         "ValidPass123".to_string(),
         "Test".to_string(),
         "User".to_string(),
-        17,
+        born(17),
+        &PasswordPolicy::default(),
+        &PasswordHasher::default(),
     );
     assert!(underage_result.is_err(), "Underage user should be rejected");
 }
@@ -211,7 +237,9 @@ fn test_email_normalization_in_user_flow() {
         "SecurePass123".to_string(),
         "John".to_string(),
         "Doe".to_string(),
-        25,
+        born(25),
+        &PasswordPolicy::default(),
+        &PasswordHasher::default(),
     )
     .unwrap();
 